@@ -1,128 +1,131 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use clap::Args;
+use clap::{Args, ValueEnum};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
 
-use chaos_core::config::ChaosConfig;
+use chaos_core::config::{SkillsManifest, TargetSet};
 use chaos_core::event::TracingEventSink;
+use chaos_core::history::HistoryStore;
 use chaos_core::orchestrator::Orchestrator;
-use chaos_llm::mcp::{McpClient, McpServerConfig};
-use chaos_llm::planner::ChaosPlanner;
-use chaos_llm::provider::LlmProviderConfig;
+use chaos_core::duration::format_duration;
+use chaos_core::report::ExperimentReport;
+use chaos_core::safety::SafetyConfig;
+use chaos_core::skill::TargetDomain;
+use chaos_llm::io_log::IoLogger;
+use chaos_llm::planner::DEFAULT_IDLE_LIMIT;
+use chaos_llm::target_hint::extract_target_config_from_prompt;
+use serde_yaml::{Mapping, Value};
 
 use crate::execution::{
-    build_provider_config_from_parts, collect_skill_definitions, convert_experiments,
-    register_agent_for_experiment, LiveDiscoverResourcesTool,
+    cancel_on_ctrl_c, collect_skill_definitions, ensure_healthy, ensure_not_production, warn_production, warn_profile,
+    register_agent_for_experiment, LimitArgs,
 };
-
-#[derive(Debug, serde::Deserialize)]
-struct PlanConfig {
-    llm: LlmProviderConfig,
-    #[serde(default)]
-    mcp_servers: Vec<McpServerConfig>,
-    #[serde(default)]
-    system_prompt: Option<String>,
-    #[serde(default = "default_max_turns")]
-    max_turns: u32,
-}
-
-fn default_max_turns() -> u32 {
-    10
-}
+use crate::effective_config;
+use crate::planning::{self, default_max_turns, Plan, PlanConfig, PlanningArgs};
+use crate::run_output::RunOutput;
 
 #[derive(Args)]
 pub struct AgentArgs {
-    /// User prompt describing what chaos to create
-    pub prompt: String,
-    /// Path to LLM/MCP config file
-    #[arg(short, long)]
-    pub config: Option<PathBuf>,
-    /// LLM provider: anthropic, openai, or ollama (auto-detected from API key env vars if not set)
-    #[arg(long, env = "CHAOS_PROVIDER")]
-    pub provider: Option<String>,
-    /// Model to use
-    #[arg(long, env = "CHAOS_MODEL")]
-    pub model: Option<String>,
-    /// API key (or set via ANTHROPIC_API_KEY / OPENAI_API_KEY env var)
-    #[arg(long)]
-    pub api_key: Option<String>,
-    /// Dry-run: show generated config without executing
+    #[command(flatten)]
+    pub planning: PlanningArgs,
+    /// Dry-run: show generated config without executing (like `chaos plan`)
     #[arg(long)]
     pub dry_run: bool,
     /// Save the generated experiment config to a YAML file
     #[arg(long)]
     pub save: Option<PathBuf>,
-    /// Max number of LLM planning turns (default: 10)
-    #[arg(long)]
-    pub max_turns: Option<u32>,
     /// Skip confirmation prompt
     #[arg(long, short = 'y')]
     pub yes: bool,
+    /// Run even if a target fails its health check
+    #[arg(long)]
+    pub force: bool,
+    /// Run even if a target looks like production (see `safety` in the config)
+    #[arg(long)]
+    pub i_understand: bool,
+    /// Run skills that can't be rolled back (`reversible: false`) in every
+    /// experiment, not just those with `allow_irreversible: true`
+    #[arg(long)]
+    pub allow_irreversible: bool,
+    #[command(flatten)]
+    pub limits: LimitArgs,
+    /// Write config.yaml, trace.jsonl (raw LLM exchanges), events.jsonl,
+    /// report.json and summary.md into a timestamped subdirectory of this
+    /// directory
+    #[arg(long, value_name = "DIR")]
+    pub output_dir: Option<PathBuf>,
+    /// Don't ask the LLM for a written summary of the results after execution
+    #[arg(long)]
+    pub no_summary: bool,
+    /// How to run the planned experiments: one at a time in planned order,
+    /// all at once, or one at a time in a shuffled order
+    #[arg(long, value_enum, default_value_t = Strategy::Sequential)]
+    pub strategy: Strategy,
+    /// Seed for `--strategy random`, to replay the same order. Random (and
+    /// printed) if omitted
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Print the effective configuration (provider, planner settings, named
+    /// targets, fallback targets from the prompt; secrets redacted) and exit
+    /// without calling the LLM
+    #[arg(long)]
+    pub print_config: bool,
 }
 
-pub async fn execute(args: AgentArgs) -> anyhow::Result<()> {
-    // --- Phase 1: Planning ---
-    let plan_result = if let Some(config_path) = &args.config {
-        let content = std::fs::read_to_string(config_path)?;
-        let plan_config: PlanConfig = serde_yaml::from_str(&content)?;
-
-        let mut planner = ChaosPlanner::new(&plan_config.llm);
-        planner.set_verbose(true);
-        planner.update_skills(collect_skill_definitions());
-        planner.register_tool(Box::new(LiveDiscoverResourcesTool { user_prompt: args.prompt.clone() }));
-
-        if let Some(prompt) = plan_config.system_prompt {
-            planner.set_system_prompt(prompt);
-        }
-        planner.set_max_turns(args.max_turns.unwrap_or(plan_config.max_turns));
-
-        for mcp_config in plan_config.mcp_servers {
-            let client = McpClient::new(mcp_config);
-            planner.add_mcp_server(client).await?;
-        }
-
-        println!("Planning chaos experiments...\n");
-        planner.plan(&args.prompt).await?
-    } else {
-        let provider_config = build_provider_config(&args)?;
-        let mut planner = ChaosPlanner::new(&provider_config);
-        planner.set_verbose(true);
-        planner.update_skills(collect_skill_definitions());
-        planner.register_tool(Box::new(LiveDiscoverResourcesTool { user_prompt: args.prompt.clone() }));
-        if let Some(max_turns) = args.max_turns {
-            planner.set_max_turns(max_turns);
-        }
-
-        println!("Planning chaos experiments...\n");
-        planner.plan(&args.prompt).await?
-    };
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Strategy {
+    Sequential,
+    Parallel,
+    Random,
+}
 
-    // --- Display plan ---
-    println!("{}", plan_result.message);
+/// When one experiment ran, relative to the start of the execution phase.
+struct Timing {
+    name: String,
+    started_after: Duration,
+    elapsed: Duration,
+    status: String,
+}
 
-    if plan_result.experiments.is_empty() {
-        println!("\nNo experiments were planned.");
-        return Ok(());
+pub async fn execute(args: AgentArgs) -> anyhow::Result<()> {
+    if args.print_config {
+        return print_config(&args);
     }
 
-    println!("\nPlanned experiments ({}):", plan_result.experiments.len());
-    for (i, exp) in plan_result.experiments.iter().enumerate() {
-        println!(
-            "  {}. {} (target: {})",
-            i + 1,
-            exp["name"].as_str().unwrap_or("unnamed"),
-            exp["target"].as_str().unwrap_or("unknown"),
-        );
+    let output = args
+        .output_dir
+        .as_deref()
+        .map(|dir| RunOutput::create(dir, "agent"))
+        .transpose()?;
+    let io_logger = io_logger(args.planning.log_llm_io.as_deref(), output.as_ref())?;
+    if let Some(ref output) = output {
+        println!("Writing run artifacts to {}\n", output.dir().display());
     }
-    println!("\n(Completed in {} turns)", plan_result.turns);
 
-    // --- Phase 2: Convert to ExperimentConfig ---
-    let experiment_configs = convert_experiments(&plan_result.experiments, &args.prompt)?;
-    let chaos_config = ChaosConfig {
-        experiments: experiment_configs,
+    // --- Phase 1: Planning ---
+    let Some(Plan {
+        planner,
+        config: chaos_config,
+        targets,
+        profile,
+        blast_radius,
+    }) = planning::plan(&args.planning, io_logger).await?
+    else {
+        return Ok(());
     };
 
+    // --- Phase 2: Build the config ---
     let yaml_output = serde_yaml::to_string(&chaos_config)?;
+    if let Some(ref output) = output {
+        output.write_config(&yaml_output)?;
+    }
+
+    // Named targets stay out of the saved config; resolve them only to run.
+    let experiments = targets.resolve_all(&chaos_config.experiments)?;
 
     // --- Save if requested ---
     if let Some(ref save_path) = args.save {
@@ -134,12 +137,22 @@ pub async fn execute(args: AgentArgs) -> anyhow::Result<()> {
     if args.dry_run {
         println!("\n--- Generated Configuration (dry-run) ---\n");
         println!("{yaml_output}");
+        planning::print_blast_radius(&blast_radius);
+        warn_production(&chaos_config.safety, &experiments);
+        if let Some(ref profile) = profile {
+            warn_profile(profile, &chaos_config.default_durations, &experiments);
+        }
         return Ok(());
     }
 
     // --- Confirmation ---
     println!("\n--- Generated Configuration ---\n");
     println!("{yaml_output}");
+    planning::print_blast_radius(&blast_radius);
+    ensure_not_production(&chaos_config.safety, &experiments, args.i_understand)?;
+    if let Some(ref profile) = profile {
+        warn_profile(profile, &chaos_config.default_durations, &experiments);
+    }
 
     if !args.yes && !confirm_execution() {
         println!("Aborted.");
@@ -149,66 +162,279 @@ pub async fn execute(args: AgentArgs) -> anyhow::Result<()> {
     // --- Phase 3: Execute ---
     let mut orchestrator = Orchestrator::new();
     orchestrator.add_event_sink(Arc::new(TracingEventSink));
+    orchestrator.set_allow_irreversible(args.allow_irreversible);
+    orchestrator.set_limits(args.limits.limits());
+    if let Some(profile) = profile {
+        orchestrator.set_profile(profile);
+    }
+    if let Some(history) = HistoryStore::from_env() {
+        orchestrator.set_history(history);
+    }
+    if let Some(ref output) = output {
+        orchestrator.add_event_sink(Arc::new(output.event_sink()?));
+    }
 
-    // Set up Ctrl+C handler to cancel experiments gracefully (rollback still runs)
-    let cancel_flag = orchestrator.cancel_flag();
-    tokio::spawn(async move {
-        if tokio::signal::ctrl_c().await.is_ok() {
-            eprintln!("\nReceived Ctrl+C, cancelling experiment (rollback will still run)...");
-            cancel_flag.store(true, std::sync::atomic::Ordering::Relaxed);
-        }
-    });
+    // Ctrl+C cancels experiments gracefully (rollback still runs)
+    let cancelled = orchestrator.cancel_token();
+    cancel_on_ctrl_c(cancelled.clone());
 
-    for experiment in &chaos_config.experiments {
+    for experiment in &experiments {
         register_agent_for_experiment(&mut orchestrator, experiment)?;
     }
+    ensure_healthy(&orchestrator, args.force).await?;
 
-    for experiment in chaos_config.experiments {
-        tracing::info!(name = %experiment.name, "Starting experiment");
-        match orchestrator.run_experiment(experiment.clone()).await {
-            Ok(report) => {
-                println!("{report}");
+    let mut experiments = experiments;
+    if args.strategy == Strategy::Random {
+        let seed = args.seed.unwrap_or_else(rand::random);
+        experiments.shuffle(&mut StdRng::seed_from_u64(seed));
+        let order: Vec<&str> = experiments.iter().map(|e| e.name.as_str()).collect();
+        println!("Random order (--seed {seed}): {}\n", order.join(", "));
+    }
+
+    let orchestrator = Arc::new(orchestrator);
+    let phase_start = Instant::now();
+    let mut reports = Vec::new();
+    let mut timings = Vec::new();
+    if args.strategy == Strategy::Parallel {
+        let mut running = tokio::task::JoinSet::new();
+        for experiment in experiments {
+            let orchestrator = orchestrator.clone();
+            running.spawn(async move {
+                tracing::info!(name = %experiment.name, "Starting experiment");
+                let started_after = phase_start.elapsed();
+                let result = orchestrator.run_experiment(experiment.clone()).await;
+                (experiment.name, started_after, result)
+            });
+        }
+        while let Some(joined) = running.join_next().await {
+            let (name, started_after, result) = joined?;
+            let elapsed = phase_start.elapsed() - started_after;
+            record_outcome(name, started_after, elapsed, result, &mut reports, &mut timings);
+            if let Some(ref output) = output {
+                output.write_reports(&reports)?;
             }
-            Err(e) => {
-                eprintln!("Experiment '{}' failed: {e}", experiment.name);
+        }
+    } else {
+        for experiment in experiments {
+            if cancelled.is_cancelled() {
+                break;
+            }
+            tracing::info!(name = %experiment.name, "Starting experiment");
+            let started_after = phase_start.elapsed();
+            let result = orchestrator.run_experiment(experiment.clone()).await;
+            let elapsed = phase_start.elapsed() - started_after;
+            record_outcome(experiment.name, started_after, elapsed, result, &mut reports, &mut timings);
+            if let Some(ref output) = output {
+                output.write_reports(&reports)?;
+            }
+        }
+    }
+    print_timings(&timings, phase_start.elapsed());
+
+    // --- Phase 4: Summarize ---
+    if cancelled.is_cancelled() {
+        anyhow::bail!(
+            "Cancelled by Ctrl+C after {} experiment(s) started; rollback ran for what was applied",
+            timings.len()
+        );
+    }
+    if !args.no_summary && !reports.is_empty() {
+        println!("Summarizing results...\n");
+        match planner.summarize(&reports).await {
+            Ok(summary) => {
+                println!("--- Summary ---\n\n{summary}");
+                if let Some(ref output) = output {
+                    output.write_summary(&summary)?;
+                }
             }
+            Err(e) => eprintln!("Could not summarize the results: {e}"),
         }
     }
 
     Ok(())
 }
 
-fn confirm_execution() -> bool {
-    use std::io::{self, Write};
-    print!("Proceed with execution? [y/N] ");
-    io::stdout().flush().unwrap();
-    let mut input = String::new();
-    io::stdin().read_line(&mut input).unwrap();
-    matches!(input.trim().to_lowercase().as_str(), "y" | "yes")
-}
+/// `--print-config`: everything planning and execution would use, resolved
+/// the same way `execute` resolves it.
+fn print_config(args: &AgentArgs) -> anyhow::Result<()> {
+    let planning = &args.planning;
+    let mut effective = Mapping::new();
+    let mut targets = TargetSet::default();
+    let mut safety = SafetyConfig::default();
+    let mut profile = None;
+    let mut planner = Mapping::new();
+    let mut max_turns = planning.max_turns.unwrap_or_else(default_max_turns);
+    let mut idle_limit = DEFAULT_IDLE_LIMIT;
+    let mut context_limit = None;
+    let llm = match planning.config {
+        Some(ref path) => {
+            let mut plan_config = PlanConfig::from_file(path)?;
+            plan_config.llm.override_sampling(planning.temperature, planning.top_p);
+            effective.insert("config_file".into(), path.display().to_string().into());
+            max_turns = planning.max_turns.unwrap_or(plan_config.max_turns);
+            idle_limit = plan_config.idle_limit.unwrap_or(idle_limit);
+            context_limit = plan_config.context_limit;
+            if plan_config.system_prompt.is_some() {
+                planner.insert("system_prompt".into(), "custom (from --config)".into());
+            }
+            if !plan_config.default_durations.is_empty() {
+                planner.insert(
+                    "default_durations".into(),
+                    serde_yaml::to_value(&plan_config.default_durations)?,
+                );
+            }
+            if !plan_config.mcp_servers.is_empty() {
+                effective.insert("mcp_servers".into(), serde_yaml::to_value(&plan_config.mcp_servers)?);
+            }
+            if let Some(ref name) = planning.profile {
+                profile = Some(plan_config.profiles.select(name)?);
+            }
+            safety = plan_config.safety;
+            targets = plan_config.targets;
+            effective_config::llm(&plan_config.llm, "llm section of --config")?
+        }
+        None => effective_config::llm(&planning.provider_config()?, planning.detect_provider().1)?,
+    };
+    if let Some(ref path) = planning.targets_file {
+        targets.extend(TargetSet::from_file(path)?);
+    }
+    effective.insert("llm".into(), llm);
+
+    planner.insert("max_turns".into(), max_turns.into());
+    planner.insert("idle_limit".into(), idle_limit.into());
+    if let Some(limit) = context_limit {
+        planner.insert("context_limit".into(), (limit as u64).into());
+    }
+    planner.insert("require_discovery_first".into(), planning.require_discovery_first.into());
+    planner.insert("summary".into(), (!args.no_summary).into());
+    effective.insert("planner".into(), Value::Mapping(planner));
+
+    let mut skills = collect_skill_definitions();
+    if let Some(ref path) = planning.skills_from {
+        let manifest = SkillsManifest::from_file(path)?;
+        skills.retain(|d| manifest.allows(&d.name));
+        effective.insert("skills_from".into(), path.display().to_string().into());
+    }
+    if let Some(ref profile) = profile {
+        skills.retain(|d| profile.allows_skill(&d.name));
+    }
+    effective.insert(
+        "skills".into(),
+        skills.into_iter().map(|d| Value::from(d.name)).collect::<Vec<_>>().into(),
+    );
 
-fn detect_provider(args: &AgentArgs) -> String {
-    if let Some(ref provider) = args.provider {
-        return provider.clone();
+    let mut named = Mapping::new();
+    for (name, target) in &targets.0 {
+        named.insert(
+            name.clone().into(),
+            effective_config::target(target.target, &target.target_config)?,
+        );
+    }
+    if !named.is_empty() {
+        effective.insert("targets".into(), Value::Mapping(named));
     }
-    if args.api_key.is_some() {
-        return "anthropic".to_string();
+    // What discovery falls back to when the planner leaves out target_config
+    let mut fallbacks = Mapping::new();
+    for (domain, name) in [(TargetDomain::Database, "database"), (TargetDomain::Kubernetes, "kubernetes")] {
+        if let Some(config) = extract_target_config_from_prompt(&planning.prompt, Some(name)) {
+            let config: Value = serde_yaml::from_str(&serde_json::to_string(&config)?)?;
+            fallbacks.insert(name.into(), effective_config::target(domain, &config)?);
+        }
+    }
+    if !fallbacks.is_empty() {
+        effective.insert("prompt_targets".into(), Value::Mapping(fallbacks));
     }
-    if std::env::var("ANTHROPIC_API_KEY").is_ok() {
-        return "anthropic".to_string();
+
+    let mut execution = Mapping::new();
+    execution.insert(
+        "strategy".into(),
+        Strategy::to_possible_value(&args.strategy)
+            .map(|v| v.get_name().to_string())
+            .unwrap_or_default()
+            .into(),
+    );
+    if let Some(seed) = args.seed {
+        execution.insert("seed".into(), seed.into());
     }
-    if std::env::var("OPENAI_API_KEY").is_ok() {
-        return "openai".to_string();
+    execution.insert("allow_irreversible".into(), args.allow_irreversible.into());
+    if let Some(profile) = profile {
+        let mut value = serde_yaml::to_value(&profile)?;
+        if let Value::Mapping(ref mut map) = value {
+            map.insert("name".into(), profile.name.clone().into());
+        }
+        execution.insert("profile".into(), value);
     }
-    "ollama".to_string()
+    execution.insert("safety".into(), serde_yaml::to_value(&safety)?);
+    effective.insert("execution".into(), Value::Mapping(execution));
+
+    effective_config::print(Value::Mapping(effective))
 }
 
-fn build_provider_config(args: &AgentArgs) -> anyhow::Result<LlmProviderConfig> {
-    let provider = detect_provider(args);
-    build_provider_config_from_parts(
-        &provider,
-        args.api_key.as_deref(),
-        args.model.as_deref(),
-        None,
-    )
+/// Print one finished experiment and keep its report and timing.
+fn record_outcome(
+    name: String,
+    started_after: Duration,
+    elapsed: Duration,
+    result: chaos_core::error::ChaosResult<ExperimentReport>,
+    reports: &mut Vec<ExperimentReport>,
+    timings: &mut Vec<Timing>,
+) {
+    let status = match result {
+        Ok(report) => {
+            println!("{report}");
+            let status = report.status.clone();
+            reports.push(report);
+            status
+        }
+        Err(e) => {
+            eprintln!("Experiment '{name}' failed: {e}");
+            format!("error: {e}")
+        }
+    };
+    timings.push(Timing {
+        name,
+        started_after,
+        elapsed,
+        status,
+    });
+}
+
+/// Start offset and wall-clock time of every experiment, in start order.
+fn print_timings(timings: &[Timing], total: Duration) {
+    let mut timings: Vec<&Timing> = timings.iter().collect();
+    timings.sort_by_key(|t| t.started_after);
+    let name_w = timings.iter().map(|t| t.name.len()).max().unwrap_or(0).max(10);
+    println!("\nTIMING\n");
+    println!("  {:<name_w$}  {:<10} {:<10} STATUS", "EXPERIMENT", "START", "ELAPSED");
+    for t in timings {
+        println!(
+            "  {:<name_w$}  {:<10} {:<10} {}",
+            t.name,
+            format!("+{}", format_duration(t.started_after)),
+            format_duration(t.elapsed),
+            t.status
+        );
+    }
+    println!("\n  Total: {}\n", format_duration(total));
+}
+
+/// Logger for raw LLM exchanges: per-exchange files under `--log-llm-io`
+/// and/or the run's `trace.jsonl`.
+fn io_logger(log_dir: Option<&Path>, output: Option<&RunOutput>) -> std::io::Result<Option<IoLogger>> {
+    let trace = output.map(RunOutput::trace_path);
+    Ok(match (log_dir, trace) {
+        (Some(dir), Some(trace)) => Some(IoLogger::new(dir)?.with_trace_file(&trace)?),
+        (Some(dir), None) => Some(IoLogger::new(dir)?),
+        (None, Some(trace)) => Some(IoLogger::trace_file(&trace)?),
+        (None, None) => None,
+    })
+}
+
+fn confirm_execution() -> bool {
+    use std::io::{self, Write};
+    print!("Proceed with execution? [y/N] ");
+    io::stdout().flush().unwrap();
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    matches!(input.trim().to_lowercase().as_str(), "y" | "yes")
 }
@@ -0,0 +1,82 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use chaos_core::cleanup::{CleanupAction, CleanupItem};
+
+use crate::execution::{build_agent, load_target_file};
+
+#[derive(Args)]
+pub struct CleanupArgs {
+    /// YAML file with `target` + `target_config`, or an experiment config
+    /// whose targets should be scanned
+    pub config: PathBuf,
+    /// Only report leftover artifacts, don't remove anything
+    #[arg(long)]
+    pub dry_run: bool,
+    /// YAML file with a `targets:` section of named targets for `target_ref`
+    #[arg(long, value_name = "FILE")]
+    pub targets_file: Option<PathBuf>,
+}
+
+pub async fn execute(args: CleanupArgs) -> anyhow::Result<()> {
+    let mut targets = load_target_file(&args.config, args.targets_file.as_deref())?;
+    // Several experiments often share one target; scan each only once.
+    let mut seen = Vec::new();
+    targets.retain(|t| {
+        let key = (t.target, t.target_config.clone());
+        if seen.contains(&key) {
+            false
+        } else {
+            seen.push(key);
+            true
+        }
+    });
+
+    let mut failures = 0;
+    for target in &targets {
+        println!("\nScanning {} target...", target.target);
+        let mut agent = build_agent(target.target, &target.target_config)?;
+        agent.initialize().await?;
+        let result = agent.cleanup(args.dry_run).await;
+        if let Err(e) = agent.shutdown().await {
+            tracing::warn!(error = %e, "Agent shutdown failed");
+        }
+
+        let items = result?;
+        failures += items
+            .iter()
+            .filter(|i| matches!(i.action, CleanupAction::Failed(_)))
+            .count();
+        print_items(&items);
+    }
+
+    println!();
+    if failures > 0 {
+        anyhow::bail!("{failures} artifact(s) could not be removed");
+    }
+    Ok(())
+}
+
+fn print_items(items: &[CleanupItem]) {
+    if items.is_empty() {
+        println!("  No chaos artifacts found.");
+        return;
+    }
+
+    let kind_w = items.iter().map(|i| i.kind.len()).max().unwrap_or(0).max(4);
+    let name_w = items.iter().map(|i| i.name.len()).max().unwrap_or(0).max(4);
+    let detail_w = items.iter().map(|i| i.detail.len()).max().unwrap_or(0).max(6);
+
+    println!(
+        "  {:<kind_w$}  {:<name_w$}  {:<detail_w$}  ACTION",
+        "KIND", "NAME", "DETAIL"
+    );
+    for item in items {
+        println!(
+            "  {:<kind_w$}  {:<name_w$}  {:<detail_w$}  {}",
+            item.kind, item.name, item.detail, item.action
+        );
+    }
+    println!("\n  {} artifact(s) found.", items.len());
+}
@@ -1,22 +1,35 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::path::PathBuf;
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use chrono::Utc;
 use clap::Args;
 use cron::Schedule;
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use std::str::FromStr;
 use tokio::sync::Semaphore;
 
-use chaos_core::config::DaemonConfig;
-use chaos_core::event::TracingEventSink;
+use chaos_core::config::{DaemonConfig, ScheduledExperiment, TargetSet};
+use chaos_core::duration::parse_duration;
+use chaos_core::event::{EventSink, ExperimentEvent, TracingEventSink};
+use chaos_core::experiment::ExperimentConfig;
+use chaos_core::history::HistoryStore;
 use chaos_core::orchestrator::Orchestrator;
+use chaos_core::profile::Profile;
+use chaos_core::report::ExperimentReport;
 use chaos_core::skill::TargetDomain;
 use chaos_db::agent::DbAgent;
 use chaos_db::mongo_agent::MongoAgent;
 use chaos_k8s::agent::K8sAgent;
 use chaos_server::agent::ServerAgent;
 
+use crate::control::{ControlRegistry, ControlSocket};
+use crate::execution::{ensure_not_production, is_mongo_target, warn_profile};
+
 #[derive(Args)]
 pub struct DaemonArgs {
     /// Path to the daemon schedule YAML config
@@ -24,10 +37,118 @@ pub struct DaemonArgs {
     /// PID file for daemon management
     #[arg(long)]
     pub pid_file: Option<PathBuf>,
+    /// Chaos monkey mode: ignore schedules and run one randomly chosen
+    /// experiment, weighted by its `weight`, every --interval
+    #[arg(long)]
+    pub monkey: bool,
+    /// Time between chaos monkey picks, e.g. 30m
+    #[arg(long, default_value = "30m", requires = "monkey")]
+    pub interval: String,
+    /// Seed for chaos monkey picks, to replay the same sequence. Random (and
+    /// logged) if omitted
+    #[arg(long, requires = "monkey")]
+    pub seed: Option<u64>,
+    /// Schedule experiments even if a target looks like production (see
+    /// `safety` in the config)
+    #[arg(long)]
+    pub i_understand: bool,
+    /// YAML file with a `targets:` section of named targets for `target_ref`;
+    /// entries replace same-named targets in the config
+    #[arg(long, value_name = "FILE")]
+    pub targets_file: Option<PathBuf>,
+    /// Enforce this profile from the config's `profiles:` section: allowed
+    /// and denied skills, max duration, blast radius, irreversible skills.
+    /// The daemon won't start if an enabled experiment breaks it
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+    /// Listen on this Unix socket for `abort <experiment_id>` and `abort-all`
+    /// from other processes; aborted experiments roll back and scheduling goes on
+    #[arg(long, value_name = "PATH")]
+    pub control_socket: Option<PathBuf>,
+}
+
+/// Names of scheduled experiments that currently have a run in flight.
+type InFlight = Arc<Mutex<HashSet<String>>>;
+
+/// Marks an experiment as in flight for as long as the guard is alive.
+struct InFlightGuard {
+    registry: InFlight,
+    name: String,
+}
+
+impl InFlightGuard {
+    /// Returns `None` if a run of `name` is already in flight.
+    fn acquire(registry: &InFlight, name: &str) -> Option<Self> {
+        let mut running = registry.lock().unwrap();
+        if !running.insert(name.to_string()) {
+            return None;
+        }
+        Some(Self {
+            registry: registry.clone(),
+            name: name.to_string(),
+        })
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.registry.lock().unwrap().remove(&self.name);
+    }
+}
+
+/// Reports of recently completed runs, keyed by a hash of the experiment
+/// config, so identical runs within `cache_ttl` aren't repeated.
+struct ResultCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<u64, (Instant, ExperimentReport)>>,
+}
+
+impl ResultCache {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn key(experiment: &ExperimentConfig) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        serde_yaml::to_string(experiment).unwrap_or_default().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// The report of an identical run that completed within the TTL.
+    fn get(&self, experiment: &ExperimentConfig) -> Option<ExperimentReport> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, (at, _)| at.elapsed() < self.ttl);
+        let (_, report) = entries.get(&Self::key(experiment))?;
+        Some(ExperimentReport {
+            from_cache: true,
+            ..report.clone()
+        })
+    }
+
+    /// Remember `report` if the run completed; failed or cancelled runs are
+    /// always retried.
+    fn insert(&self, experiment: &ExperimentConfig, report: &ExperimentReport) {
+        if report.status == "completed" {
+            self.entries
+                .lock()
+                .unwrap()
+                .insert(Self::key(experiment), (Instant::now(), report.clone()));
+        }
+    }
 }
 
 pub async fn execute(args: DaemonArgs) -> anyhow::Result<()> {
-    let config = DaemonConfig::from_file(&args.config)?;
+    let mut config = DaemonConfig::from_file(&args.config)?;
+    let mut targets = std::mem::take(&mut config.targets);
+    if let Some(ref path) = args.targets_file {
+        targets.extend(TargetSet::from_file(path)?);
+    }
+    for scheduled in &mut config.experiments {
+        scheduled.experiment = targets.resolve(&scheduled.experiment)?;
+    }
 
     tracing::info!(
         experiments = config.experiments.len(),
@@ -35,12 +156,36 @@ pub async fn execute(args: DaemonArgs) -> anyhow::Result<()> {
         "Daemon starting"
     );
 
-    // Validate all cron expressions upfront
-    for scheduled in &config.experiments {
+    // Validate all cron expressions upfront; monkey mode doesn't use them
+    for scheduled in config.experiments.iter().filter(|_| !args.monkey) {
+        if scheduled.schedule.is_empty() {
+            anyhow::bail!(
+                "Experiment '{}' has no schedule (only --monkey mode can omit it)",
+                scheduled.experiment.name
+            );
+        }
         Schedule::from_str(&scheduled.schedule)
             .map_err(|e| anyhow::anyhow!("Invalid cron expression '{}': {e}", scheduled.schedule))?;
     }
 
+    ensure_not_production(
+        &config.safety,
+        config.experiments.iter().filter(|s| s.enabled).map(|s| &s.experiment),
+        args.i_understand,
+    )?;
+
+    let profile = args
+        .profile
+        .as_deref()
+        .map(|name| config.profiles.select(name))
+        .transpose()?;
+    if let Some(ref profile) = profile {
+        let enabled = config.experiments.iter().filter(|s| s.enabled).map(|s| &s.experiment);
+        if warn_profile(profile, &config.default_durations, enabled) {
+            anyhow::bail!("Enabled experiments break profile '{}'; not starting", profile.name);
+        }
+    }
+
     // Write PID file if requested
     if let Some(ref pid_path) = args.pid_file {
         std::fs::write(pid_path, std::process::id().to_string())?;
@@ -56,93 +201,66 @@ pub async fn execute(args: DaemonArgs) -> anyhow::Result<()> {
     });
 
     let semaphore = Arc::new(Semaphore::new(config.settings.max_concurrent));
-    let mut interval = tokio::time::interval(Duration::from_secs(30));
-    let mut last_check = Utc::now();
+    let in_flight: InFlight = Arc::new(Mutex::new(HashSet::new()));
+    let event_sink = Arc::new(TracingEventSink);
+    let cache = config.settings.cache_ttl.map(|ttl| Arc::new(ResultCache::new(ttl)));
+    let control = args.control_socket.as_ref().map(|_| Arc::new(ControlRegistry::default()));
+    let _control_socket = match (&args.control_socket, &control) {
+        (Some(path), Some(registry)) => Some(ControlSocket::bind(path, registry.clone())?),
+        _ => None,
+    };
 
-    loop {
-        tokio::select! {
-            _ = interval.tick() => {
-                let now = Utc::now();
+    if args.monkey {
+        let period = parse_duration(&args.interval)?;
+        let seed = args.seed.unwrap_or_else(rand::random);
+        let mut monkey = Monkey::new(&config, seed)?;
+        tracing::info!(seed, interval = ?period, "Chaos monkey mode: picking a random experiment each interval");
 
-                for scheduled in &config.experiments {
-                    if !scheduled.enabled {
-                        continue;
-                    }
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    let scheduled = monkey.pick();
+                    tracing::info!(experiment = %scheduled.experiment.name, "Chaos monkey picked experiment");
+                    try_spawn(&scheduled.experiment, &semaphore, &in_flight, &event_sink, control.as_ref(), profile.as_ref(), cache.as_ref()).await;
+                }
+                _ = shutdown_rx.changed() => {
+                    tracing::info!("Shutdown signal received, stopping chaos monkey");
+                    break;
+                }
+            }
+        }
+    } else {
+        let mut interval = tokio::time::interval(Duration::from_secs(30));
+        let mut last_check = Utc::now();
+        loop {
+            tokio::select! {
+                _ = interval.tick() => {
+                    let now = Utc::now();
+
+                    for scheduled in &config.experiments {
+                        if !scheduled.enabled {
+                            continue;
+                        }
+
+                        let sched = Schedule::from_str(&scheduled.schedule).unwrap();
+                        let has_trigger = sched
+                            .after(&last_check)
+                            .take_while(|t| t <= &now)
+                            .next()
+                            .is_some();
 
-                    let sched = Schedule::from_str(&scheduled.schedule).unwrap();
-                    let has_trigger = sched
-                        .after(&last_check)
-                        .take_while(|t| t <= &now)
-                        .next()
-                        .is_some();
-
-                    if has_trigger {
-                        let permit = match semaphore.clone().try_acquire_owned() {
-                            Ok(p) => p,
-                            Err(_) => {
-                                tracing::warn!(
-                                    experiment = %scheduled.experiment.name,
-                                    "Skipping: max concurrent experiments reached"
-                                );
-                                continue;
-                            }
-                        };
-
-                        let exp_config = scheduled.experiment.clone();
-                        let exp_name = exp_config.name.clone();
-
-                        tokio::spawn(async move {
-                            let _permit = permit;
-
-                            // Create a fresh orchestrator for this experiment run
-                            let mut orchestrator = Orchestrator::new();
-                            orchestrator.add_event_sink(Arc::new(TracingEventSink));
-
-                            match exp_config.target {
-                                TargetDomain::Database => {
-                                    let is_mongo = exp_config
-                                        .target_config
-                                        .get("db_type")
-                                        .and_then(|v| v.as_str())
-                                        .map_or(false, |t| t == "mongo_d_b" || t == "mongodb" || t == "mongo");
-                                    if is_mongo {
-                                        if let Ok(agent) = MongoAgent::from_yaml(&exp_config.target_config) {
-                                            orchestrator.register_agent(Box::new(agent));
-                                        }
-                                    } else if let Ok(agent) = DbAgent::from_yaml(&exp_config.target_config) {
-                                        orchestrator.register_agent(Box::new(agent));
-                                    }
-                                }
-                                TargetDomain::Kubernetes => {
-                                    if let Ok(agent) = K8sAgent::from_yaml(&exp_config.target_config) {
-                                        orchestrator.register_agent(Box::new(agent));
-                                    }
-                                }
-                                TargetDomain::Server => {
-                                    if let Ok(agent) = ServerAgent::from_yaml(&exp_config.target_config) {
-                                        orchestrator.register_agent(Box::new(agent));
-                                    }
-                                }
-                            }
-
-                            tracing::info!(experiment = %exp_name, "Scheduled experiment starting");
-                            match orchestrator.run_experiment(exp_config).await {
-                                Ok(report) => {
-                                    tracing::info!(experiment = %exp_name, report = %report, "Scheduled experiment completed");
-                                }
-                                Err(e) => {
-                                    tracing::error!(experiment = %exp_name, error = %e, "Scheduled experiment failed");
-                                }
-                            }
-                        });
+                        if has_trigger {
+                            try_spawn(&scheduled.experiment, &semaphore, &in_flight, &event_sink, control.as_ref(), profile.as_ref(), cache.as_ref()).await;
+                        }
                     }
-                }
 
-                last_check = now;
-            }
-            _ = shutdown_rx.changed() => {
-                tracing::info!("Shutdown signal received, stopping scheduler");
-                break;
+                    last_check = now;
+                }
+                _ = shutdown_rx.changed() => {
+                    tracing::info!("Shutdown signal received, stopping scheduler");
+                    break;
+                }
             }
         }
     }
@@ -161,3 +279,147 @@ pub async fn execute(args: DaemonArgs) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Start `experiment` in the background on a fresh orchestrator, unless an
+/// identical run completed within the cache TTL, a run of it is still in
+/// flight or `max_concurrent` runs are already going.
+async fn try_spawn(
+    experiment: &ExperimentConfig,
+    semaphore: &Arc<Semaphore>,
+    in_flight: &InFlight,
+    event_sink: &Arc<TracingEventSink>,
+    control: Option<&Arc<ControlRegistry>>,
+    profile: Option<&Profile>,
+    cache: Option<&Arc<ResultCache>>,
+) {
+    let exp_name = experiment.name.clone();
+    if let Some(report) = cache.and_then(|c| c.get(experiment)) {
+        tracing::info!(experiment = %exp_name, report = %report, "Scheduled experiment served from cache");
+        return;
+    }
+    let guard = match InFlightGuard::acquire(in_flight, &exp_name) {
+        Some(g) => g,
+        None => {
+            tracing::warn!(
+                experiment = %exp_name,
+                "Skipping: previous run is still in flight"
+            );
+            event_sink
+                .emit(ExperimentEvent::Skipped {
+                    experiment_name: exp_name,
+                    reason: "previous run is still in flight".into(),
+                })
+                .await;
+            return;
+        }
+    };
+
+    let permit = match semaphore.clone().try_acquire_owned() {
+        Ok(p) => p,
+        Err(_) => {
+            tracing::warn!(
+                experiment = %exp_name,
+                "Skipping: max concurrent experiments reached"
+            );
+            return;
+        }
+    };
+
+    let exp_config = experiment.clone();
+    let event_sink = event_sink.clone();
+    let control = control.cloned();
+    let profile = profile.cloned();
+    let cache = cache.cloned();
+
+    tokio::spawn(async move {
+        let _permit = permit;
+        let _guard = guard;
+
+        // Create a fresh orchestrator for this experiment run
+        let mut orchestrator = Orchestrator::new();
+        orchestrator.add_event_sink(event_sink);
+        if let Some(control) = control {
+            orchestrator.add_event_sink(Arc::new(control.sink(orchestrator.cancel_token())));
+        }
+        if let Some(history) = HistoryStore::from_env() {
+            orchestrator.set_history(history);
+        }
+        if let Some(profile) = profile {
+            orchestrator.set_profile(profile);
+        }
+
+        match exp_config.target {
+            TargetDomain::Database => {
+                if is_mongo_target(&exp_config.target_config) {
+                    if let Ok(agent) = MongoAgent::from_yaml(&exp_config.target_config) {
+                        orchestrator.register_agent(Box::new(agent));
+                    }
+                } else if let Ok(agent) = DbAgent::from_yaml(&exp_config.target_config) {
+                    orchestrator.register_agent(Box::new(agent));
+                }
+            }
+            TargetDomain::Kubernetes => {
+                if let Ok(agent) = K8sAgent::from_yaml(&exp_config.target_config) {
+                    orchestrator.register_agent(Box::new(agent));
+                }
+            }
+            TargetDomain::Server => {
+                if let Ok(agent) = ServerAgent::from_yaml(&exp_config.target_config) {
+                    orchestrator.register_agent(Box::new(agent));
+                }
+            }
+        }
+
+        tracing::info!(experiment = %exp_name, "Scheduled experiment starting");
+        match orchestrator.run_experiment(exp_config.clone()).await {
+            Ok(report) => {
+                if let Some(cache) = cache {
+                    cache.insert(&exp_config, &report);
+                }
+                tracing::info!(experiment = %exp_name, report = %report, "Scheduled experiment completed");
+            }
+            Err(e) => {
+                tracing::error!(experiment = %exp_name, error = %e, "Scheduled experiment failed");
+            }
+        }
+    });
+}
+
+/// Weighted random choice among the enabled experiments, reproducible from its seed.
+struct Monkey<'a> {
+    candidates: Vec<&'a ScheduledExperiment>,
+    weights: WeightedIndex<f64>,
+    rng: StdRng,
+}
+
+impl<'a> Monkey<'a> {
+    fn new(config: &'a DaemonConfig, seed: u64) -> anyhow::Result<Self> {
+        if let Some(bad) = config
+            .experiments
+            .iter()
+            .find(|s| !s.weight.is_finite() || s.weight < 0.0)
+        {
+            anyhow::bail!(
+                "Experiment '{}' has invalid weight {}; weights must be >= 0",
+                bad.experiment.name,
+                bad.weight
+            );
+        }
+        let candidates: Vec<_> = config
+            .experiments
+            .iter()
+            .filter(|s| s.enabled && s.weight > 0.0)
+            .collect();
+        let weights = WeightedIndex::new(candidates.iter().map(|s| s.weight))
+            .map_err(|_| anyhow::anyhow!("Chaos monkey needs at least one enabled experiment with weight > 0"))?;
+        Ok(Self {
+            candidates,
+            weights,
+            rng: StdRng::seed_from_u64(seed),
+        })
+    }
+
+    fn pick(&mut self) -> &'a ScheduledExperiment {
+        self.candidates[self.weights.sample(&mut self.rng)]
+    }
+}
@@ -0,0 +1,116 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use crate::execution::{build_agent, load_target_file};
+
+#[derive(Args)]
+pub struct InspectArgs {
+    /// YAML file with `target` + `target_config`, or an experiment config
+    #[arg(long)]
+    pub target: PathBuf,
+    /// Skill to inspect, e.g. db.row_lock
+    #[arg(long)]
+    pub skill: String,
+    /// Skill params as inline YAML, e.g. '{tables: [orders]}'. Defaults to the
+    /// params the config file gives this skill, if any
+    #[arg(long)]
+    pub params: Option<String>,
+    /// YAML file with a `targets:` section of named targets for `target_ref`
+    #[arg(long, value_name = "FILE")]
+    pub targets_file: Option<PathBuf>,
+}
+
+pub async fn execute(args: InspectArgs) -> anyhow::Result<()> {
+    let entries = load_target_file(&args.target, args.targets_file.as_deref())?;
+
+    // Prefer a target whose experiment actually runs the skill, so its params apply.
+    let mut candidates = Vec::new();
+    for entry in &entries {
+        let agent = build_agent(entry.target, &entry.target_config)?;
+        if agent.skill_by_name(&args.skill).is_some() {
+            let configured = entry
+                .skills
+                .iter()
+                .find(|s| s.skill_name == args.skill)
+                .map(|s| s.params.clone());
+            candidates.push((agent, entry.target, configured));
+        }
+    }
+    candidates.sort_by_key(|(_, _, configured)| configured.is_none());
+    let (mut agent, target, configured) = candidates.into_iter().next().ok_or_else(|| {
+        anyhow::anyhow!(
+            "No target in {} supports skill '{}'",
+            args.target.display(),
+            args.skill
+        )
+    })?;
+
+    let params = match args.params {
+        Some(ref raw) => serde_yaml::from_str(raw)
+            .map_err(|e| anyhow::anyhow!("Invalid --params YAML: {e}"))?,
+        None => configured.unwrap_or(serde_yaml::Value::Null),
+    };
+
+    let skill = agent
+        .skill_by_name(&args.skill)
+        .ok_or_else(|| anyhow::anyhow!("Unknown skill: {}", args.skill))?;
+    let descriptor = skill.descriptor();
+    println!("Skill:      {}", descriptor.name);
+    println!("Target:     {target}");
+    println!("Reversible: {}", if descriptor.reversible { "yes" } else { "no" });
+    println!("About:      {}", descriptor.description);
+    if !params.is_null() {
+        print!("Params:\n{}", indent(&serde_yaml::to_string(&params)?));
+    }
+    skill.validate_params(&params)?;
+
+    agent.initialize().await?;
+    let result = inspect(agent.as_mut(), &args.skill, params).await;
+    if let Err(e) = agent.shutdown().await {
+        tracing::warn!(error = %e, "Agent shutdown failed");
+    }
+    result
+}
+
+/// Discovery, preflight and the skill's own inspection. Nothing is modified.
+async fn inspect(
+    agent: &mut dyn chaos_core::agent::Agent,
+    skill_name: &str,
+    params: serde_yaml::Value,
+) -> anyhow::Result<()> {
+    let discovery = agent.discover().await?;
+    println!("\nDiscovered resources ({}):", discovery.resources.len());
+    for r in &discovery.resources {
+        println!("  {:<15} {}", r.resource_type(), r.name());
+    }
+    for e in &discovery.errors {
+        println!("  Not discovered: {e}");
+    }
+
+    let skill = agent
+        .skill_by_name(skill_name)
+        .ok_or_else(|| anyhow::anyhow!("Unknown skill: {skill_name}"))?;
+    let mut ctx = agent.build_context().await?;
+    ctx.params = params;
+    ctx.skill_name = skill_name.to_string();
+
+    match skill.preflight(&ctx).await {
+        Ok(()) => println!("\nPreflight: OK"),
+        Err(e) => println!("\nPreflight: FAILED - {e}"),
+    }
+
+    let lines = skill.inspect(&ctx).await?;
+    println!("\nWould act on:");
+    if lines.is_empty() {
+        println!("  (no skill-specific inspection; see params and discovered resources)");
+    }
+    for line in lines {
+        println!("  {line}");
+    }
+    Ok(())
+}
+
+fn indent(text: &str) -> String {
+    text.lines().map(|l| format!("  {l}\n")).collect()
+}
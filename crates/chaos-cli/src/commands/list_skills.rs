@@ -1,8 +1,11 @@
-use clap::Args;
+use clap::{Args, ValueEnum};
 
-use chaos_core::skill::TargetDomain;
+use chaos_core::agent::Agent;
+use chaos_core::skill::{Skill, TargetDomain};
 use chaos_db::agent::DbAgent;
 use chaos_db::config::{DbTargetConfig, DbType};
+use chaos_db::mongo_agent::MongoAgent;
+use chaos_db::mongo_config::MongoTargetConfig;
 use chaos_k8s::agent::K8sAgent;
 use chaos_k8s::config::K8sTargetConfig;
 use chaos_server::agent::ServerAgent;
@@ -13,9 +16,28 @@ pub struct ListSkillsArgs {
     /// Filter by target domain (database, kubernetes, server)
     #[arg(long)]
     pub target: Option<String>,
+
+    /// Print every supported target domain with its config schema and skills as JSON
+    #[arg(long)]
+    pub list_targets: bool,
+
+    /// Output format: a table, or a JSON array of skills with their params schema
+    #[arg(long, value_enum, default_value_t = ListFormat::Table)]
+    pub format: ListFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ListFormat {
+    Table,
+    Json,
 }
 
 pub async fn execute(args: ListSkillsArgs) -> anyhow::Result<()> {
+    if args.list_targets {
+        println!("{}", serde_json::to_string_pretty(&target_manifest())?);
+        return Ok(());
+    }
+
     let filter: Option<TargetDomain> = args.target.as_deref().map(|t| match t {
         "database" | "db" => TargetDomain::Database,
         "kubernetes" | "k8s" => TargetDomain::Kubernetes,
@@ -23,44 +45,128 @@ pub async fn execute(args: ListSkillsArgs) -> anyhow::Result<()> {
         _ => TargetDomain::Database, // fallback
     });
 
-    // Create dummy agents to extract skill descriptors
-    let db_agent = DbAgent::new(DbTargetConfig {
+    let targets = domain_agents();
+    let skills = targets
+        .iter()
+        .filter(|(domain, _, _)| filter.is_none_or(|f| f == *domain))
+        .flat_map(|(_, _, agents)| unique_skills(agents));
+
+    match args.format {
+        ListFormat::Table => {
+            println!("{:<25} {:<12} DESCRIPTION", "SKILL", "TARGET");
+            println!("{}", "-".repeat(70));
+            for skill in skills {
+                let desc = skill.descriptor();
+                println!("{:<25} {:<12} {}", desc.name, desc.target.to_string(), desc.description);
+            }
+        }
+        ListFormat::Json => {
+            let skills: Vec<serde_json::Value> = skills
+                .map(|skill| {
+                    let desc = skill.descriptor();
+                    serde_json::json!({
+                        "name": desc.name,
+                        "description": desc.description,
+                        "target": desc.target,
+                        "reversible": desc.reversible,
+                        "params_schema": skill.params_schema(),
+                    })
+                })
+                .collect();
+            println!("{}", serde_json::to_string_pretty(&skills)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// A target domain, its connection-config schema and placeholder agents.
+type DomainAgents = (TargetDomain, serde_json::Value, Vec<Box<dyn Agent>>);
+
+/// Every target domain. Database has one agent per SQL dialect plus MongoDB,
+/// since each adds dialect-specific skills.
+fn domain_agents() -> Vec<DomainAgents> {
+    let db_agents: Vec<Box<dyn Agent>> = [
+        DbType::Postgres,
+        DbType::Mysql,
+        DbType::CockroachDb,
+        DbType::YugabyteDb,
+    ]
+    .into_iter()
+    .map(|db_type| {
+        Box::new(DbAgent::new(DbTargetConfig {
+            connection_url: String::new(),
+            db_type,
+            schemas: Vec::new(),
+        })) as Box<dyn Agent>
+    })
+    .chain(std::iter::once(Box::new(MongoAgent::new(MongoTargetConfig {
         connection_url: String::new(),
-        db_type: DbType::Postgres,
-        schemas: Vec::new(),
-    });
+        databases: Vec::new(),
+    })) as Box<dyn Agent>))
+    .collect();
 
-    let k8s_agent = K8sAgent::new(K8sTargetConfig {
+    let k8s_agents: Vec<Box<dyn Agent>> = vec![Box::new(K8sAgent::new(K8sTargetConfig {
         kubeconfig: None,
         namespace: "default".into(),
         label_selector: None,
-    });
+    }))];
 
-    let server_agent = ServerAgent::new(ServerTargetConfig {
+    let server_agents: Vec<Box<dyn Agent>> = vec![Box::new(ServerAgent::new(ServerTargetConfig {
         hosts: Vec::new(),
         discovery: Default::default(),
-    });
+        exec: Default::default(),
+    }))];
 
-    println!("{:<25} {:<12} {}", "SKILL", "TARGET", "DESCRIPTION");
-    println!("{}", "-".repeat(70));
-
-    let agents: Vec<Box<dyn chaos_core::agent::Agent>> = vec![
-        Box::new(db_agent),
-        Box::new(k8s_agent),
-        Box::new(server_agent),
-    ];
-
-    for agent in &agents {
-        for skill in agent.skills() {
-            let desc = skill.descriptor();
-            if let Some(ref f) = filter {
-                if &desc.target != f {
-                    continue;
-                }
-            }
-            println!("{:<25} {:<12} {}", desc.name, desc.target, desc.description);
-        }
-    }
+    vec![
+        (
+            TargetDomain::Database,
+            serde_json::json!({ "oneOf": [DbTargetConfig::schema(), MongoTargetConfig::schema()] }),
+            db_agents,
+        ),
+        (TargetDomain::Kubernetes, K8sTargetConfig::schema(), k8s_agents),
+        (TargetDomain::Server, ServerTargetConfig::schema(), server_agents),
+    ]
+}
 
-    Ok(())
+/// Skills of `agents`, first occurrence of each name only.
+fn unique_skills(agents: &[Box<dyn Agent>]) -> Vec<&dyn Skill> {
+    let mut seen = std::collections::HashSet::new();
+    agents
+        .iter()
+        .flat_map(|agent| agent.skills())
+        .filter(|skill| seen.insert(skill.descriptor().name))
+        .collect()
+}
+
+/// Capability manifest: each target domain, its connection-config schema and
+/// the skills this binary supports for it.
+fn target_manifest() -> serde_json::Value {
+    let targets: Vec<serde_json::Value> = domain_agents()
+        .iter()
+        .map(|(domain, config_schema, agents)| {
+            let skills: Vec<serde_json::Value> = unique_skills(agents)
+                .into_iter()
+                .map(|skill| {
+                    let desc = skill.descriptor();
+                    serde_json::json!({
+                        "name": desc.name,
+                        "description": desc.description,
+                        "reversible": desc.reversible,
+                        "params_schema": skill.params_schema(),
+                    })
+                })
+                .collect();
+            serde_json::json!({
+                "target": domain,
+                "config_schema": config_schema,
+                "skills": skills,
+            })
+        })
+        .collect();
+
+    serde_json::json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "targets": targets,
+    })
 }
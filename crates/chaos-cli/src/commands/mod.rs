@@ -1,17 +1,21 @@
 use clap::Subcommand;
 
 pub mod agent;
+pub mod cleanup;
 pub mod daemon;
+pub mod inspect;
 pub mod list_skills;
+pub mod models;
 pub mod plan;
 pub mod run;
+pub mod stats;
 pub mod validate;
 
 #[derive(Subcommand)]
 pub enum Commands {
     /// Run a chaos experiment from a config file
     Run(run::RunArgs),
-    /// Use an LLM to plan and orchestrate chaos experiments
+    /// Plan experiments with an LLM and print or save the config, without executing
     Plan(plan::PlanArgs),
     /// Plan experiments with an LLM, then execute them interactively
     Agent(agent::AgentArgs),
@@ -19,6 +23,14 @@ pub enum Commands {
     Daemon(daemon::DaemonArgs),
     /// List all available chaos skills
     ListSkills(list_skills::ListSkillsArgs),
+    /// List the models an LLM provider offers
+    Models(models::ModelsArgs),
     /// Validate a config file without executing
     Validate(validate::ValidateArgs),
+    /// Show what a single skill would act on, without running it
+    Inspect(inspect::InspectArgs),
+    /// Find and remove leftover chaos artifacts on a target
+    Cleanup(cleanup::CleanupArgs),
+    /// Aggregate skill usage and success rates across recorded runs
+    Stats(stats::StatsArgs),
 }
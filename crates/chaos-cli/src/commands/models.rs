@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use clap::Args;
+
+use chaos_llm::provider::{create_provider, LlmProviderConfig, ModelList};
+
+use crate::execution::build_provider_config_from_parts;
+
+#[derive(Args)]
+pub struct ModelsArgs {
+    /// LLM provider: anthropic, openai, or ollama
+    #[arg(long, env = "CHAOS_PROVIDER", required_unless_present = "config")]
+    pub provider: Option<String>,
+    /// Path to an LLM config file; lists models for its `llm` provider
+    #[arg(short, long, conflicts_with = "provider")]
+    pub config: Option<PathBuf>,
+    /// API key (or set via ANTHROPIC_API_KEY / OPENAI_API_KEY env var)
+    #[arg(long)]
+    pub api_key: Option<String>,
+    /// Base URL for OpenAI-compatible APIs or a remote Ollama
+    #[arg(long)]
+    pub base_url: Option<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct LlmSection {
+    llm: LlmProviderConfig,
+}
+
+pub async fn execute(args: ModelsArgs) -> anyhow::Result<()> {
+    let config = match (&args.config, &args.provider) {
+        (Some(path), _) => {
+            let content = std::fs::read_to_string(path)?;
+            serde_yaml::from_str::<LlmSection>(&content)?.llm
+        }
+        (None, Some(provider)) => build_provider_config_from_parts(
+            provider,
+            args.api_key.as_deref(),
+            None,
+            args.base_url.as_deref(),
+        )?,
+        (None, None) => anyhow::bail!("Pass --provider or --config"),
+    };
+    let provider = create_provider(&config)?;
+    let list = provider.list_models().await?;
+
+    if list.models.is_empty() {
+        println!("{} reports no models.", provider.name());
+        return Ok(());
+    }
+    for model in &list.models {
+        let marker = if ModelList::same_model(model, provider.model()) { "*" } else { " " };
+        println!("{marker} {model}");
+    }
+    if !list.live {
+        println!("\n(built-in list; {} may offer newer models)", provider.name());
+    }
+    Ok(())
+}
@@ -2,12 +2,27 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use clap::Args;
+use serde_yaml::{Mapping, Value};
 
-use chaos_core::config::ChaosConfig;
+use chaos_core::config::{ChaosConfig, SkillsManifest, TargetSet};
+use chaos_core::duration::parse_duration;
 use chaos_core::event::TracingEventSink;
+use chaos_core::experiment::ExperimentConfig;
+use chaos_core::history::HistoryStore;
 use chaos_core::orchestrator::Orchestrator;
+use chaos_core::profile::Profile;
+use chaos_core::duration::format_duration;
+use chaos_core::report::ExperimentReport;
 use chaos_core::skill::TargetDomain;
 use chaos_db::agent::DbAgent;
+
+use crate::control::{ControlRegistry, ControlSocket};
+use crate::effective_config;
+use crate::execution::{
+    cancel_on_ctrl_c, ensure_healthy, ensure_not_production, is_mongo_target, warn_production, warn_profile,
+    LimitArgs,
+};
+use crate::run_output::RunOutput;
 use chaos_db::mongo_agent::MongoAgent;
 use chaos_k8s::agent::K8sAgent;
 use chaos_server::agent::ServerAgent;
@@ -19,29 +34,122 @@ pub struct RunArgs {
     /// Dry-run mode: discover and validate but don't execute
     #[arg(long)]
     pub dry_run: bool,
+    /// Run each skill in dry-run mode: connect, discover and preflight, then
+    /// report what every skill would change without changing anything
+    #[arg(long, conflicts_with = "dry_run")]
+    pub dry_run_skills: bool,
+    /// Skills manifest restricting which skills may run, with default params
+    #[arg(long)]
+    pub skills_from: Option<PathBuf>,
+    /// Run the whole config this many times, for soak-style testing
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub repeat: u32,
+    /// Pause between repeats, e.g. 30s or 5m
+    #[arg(long, requires = "repeat")]
+    pub interval: Option<String>,
+    /// Run even if a target fails its health check
+    #[arg(long)]
+    pub force: bool,
+    /// Run even if a target looks like production (see `safety` in the config)
+    #[arg(long)]
+    pub i_understand: bool,
+    /// Run skills that can't be rolled back (`reversible: false`) in every
+    /// experiment, not just those with `allow_irreversible: true`
+    #[arg(long)]
+    pub allow_irreversible: bool,
+    #[command(flatten)]
+    pub limits: LimitArgs,
+    /// Write config.yaml, events.jsonl and report.json into a timestamped
+    /// subdirectory of this directory
+    #[arg(long, value_name = "DIR")]
+    pub output_dir: Option<PathBuf>,
+    /// YAML file with a `targets:` section of named targets for `target_ref`;
+    /// entries replace same-named targets in the config
+    #[arg(long, value_name = "FILE")]
+    pub targets_file: Option<PathBuf>,
+    /// Enforce this profile from the config's `profiles:` section: allowed
+    /// and denied skills, max duration, blast radius, irreversible skills
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+    /// Listen on this Unix socket for `abort <experiment_id>` and `abort-all`
+    /// from other processes; an abort rolls back and stops the run, like Ctrl+C
+    #[arg(long, value_name = "PATH")]
+    pub control_socket: Option<PathBuf>,
+    /// Print the effective configuration (targets resolved, defaults filled
+    /// in, secrets redacted) and exit without connecting to anything
+    #[arg(long)]
+    pub print_config: bool,
 }
 
 pub async fn execute(args: RunArgs) -> anyhow::Result<()> {
-    let config = ChaosConfig::from_file(&args.config)?;
+    let mut config = ChaosConfig::from_file(&args.config)?;
+    let interval = args
+        .interval
+        .as_deref()
+        .map(parse_duration)
+        .transpose()?
+        .unwrap_or_default();
+
+    if let Some(ref path) = args.skills_from {
+        let manifest = SkillsManifest::from_file(path)?;
+        for experiment in &mut config.experiments {
+            manifest.apply(experiment)?;
+        }
+    }
+
+    // Taken out of the config so it never lands in --output-dir.
+    let mut targets = std::mem::take(&mut config.targets);
+    if let Some(ref path) = args.targets_file {
+        targets.extend(TargetSet::from_file(path)?);
+    }
+    let mut experiments = targets.resolve_all(&config.experiments)?;
+    if args.dry_run_skills {
+        for experiment in &mut experiments {
+            experiment.dry_run = true;
+        }
+    }
+    let profile = args
+        .profile
+        .as_deref()
+        .map(|name| config.profiles.select(name))
+        .transpose()?;
 
     tracing::info!(
         experiments = config.experiments.len(),
         "Loaded configuration"
     );
 
+    if args.print_config {
+        return print_config(&args, &config, &experiments, profile.as_ref());
+    }
+
+    let output = args
+        .output_dir
+        .as_deref()
+        .map(|dir| RunOutput::create(dir, "run"))
+        .transpose()?;
+
     let mut orchestrator = Orchestrator::new();
     orchestrator.add_event_sink(Arc::new(TracingEventSink));
+    orchestrator.set_allow_irreversible(args.allow_irreversible);
+    orchestrator.set_limits(args.limits.limits());
+    if let Some(ref profile) = profile {
+        orchestrator.set_profile(profile.clone());
+    }
+    if let Some(history) = HistoryStore::from_env() {
+        orchestrator.set_history(history);
+    }
+    if let Some(ref output) = output {
+        output.write_config(&serde_yaml::to_string(&config)?)?;
+        orchestrator.add_event_sink(Arc::new(output.event_sink()?));
+        println!("Writing run artifacts to {}", output.dir().display());
+    }
 
-    for experiment in &config.experiments {
+    for experiment in &experiments {
         // Register the appropriate agent
         match experiment.target {
             TargetDomain::Database => {
-                let is_mongo = experiment
-                    .target_config
-                    .get("db_type")
-                    .and_then(|v| v.as_str())
-                    .map_or(false, |t| t == "mongo_d_b" || t == "mongodb" || t == "mongo");
-                if is_mongo {
+                if is_mongo_target(&experiment.target_config) {
                     let agent = MongoAgent::from_yaml(&experiment.target_config)?;
                     orchestrator.register_agent(Box::new(agent));
                 } else {
@@ -62,7 +170,11 @@ pub async fn execute(args: RunArgs) -> anyhow::Result<()> {
 
     if args.dry_run {
         tracing::info!("Dry-run mode: validating configuration only");
-        for experiment in &config.experiments {
+        warn_production(&config.safety, &experiments);
+        if let Some(ref profile) = profile {
+            warn_profile(profile, &config.default_durations, &experiments);
+        }
+        for experiment in &experiments {
             tracing::info!(
                 name = %experiment.name,
                 target = %experiment.target,
@@ -75,17 +187,153 @@ pub async fn execute(args: RunArgs) -> anyhow::Result<()> {
         return Ok(());
     }
 
-    for experiment in config.experiments {
-        tracing::info!(name = %experiment.name, "Starting experiment");
-        match orchestrator.run_experiment(experiment.clone()).await {
-            Ok(report) => {
-                println!("{report}");
+    // Skill dry runs leave production untouched, so only warn
+    if experiments.iter().all(|e| e.dry_run) {
+        warn_production(&config.safety, &experiments);
+    } else {
+        ensure_not_production(&config.safety, &experiments, args.i_understand)?;
+    }
+    ensure_healthy(&orchestrator, args.force).await?;
+
+    // Ctrl+C cancels experiments gracefully (rollback still runs)
+    let cancelled = orchestrator.cancel_token();
+    cancel_on_ctrl_c(cancelled.clone());
+    let _control = match args.control_socket {
+        Some(ref path) => {
+            let registry = Arc::new(ControlRegistry::default());
+            orchestrator.add_event_sink(Arc::new(registry.sink(cancelled.clone())));
+            Some(ControlSocket::bind(path, registry)?)
+        }
+        None => None,
+    };
+
+    let mut outcomes = Vec::new();
+    'iterations: for iteration in 1..=args.repeat {
+        if iteration > 1 && !interval.is_zero() {
+            tracing::info!(interval = ?interval, "Waiting before next iteration");
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                _ = cancelled.cancelled() => {}
             }
-            Err(e) => {
-                eprintln!("Experiment '{}' failed: {e}", experiment.name);
+        }
+        if cancelled.is_cancelled() {
+            break;
+        }
+        if args.repeat > 1 {
+            println!("\n=== Iteration {iteration}/{} ===", args.repeat);
+        }
+
+        for experiment in &experiments {
+            if cancelled.is_cancelled() {
+                break 'iterations;
+            }
+            tracing::info!(name = %experiment.name, "Starting experiment");
+            match orchestrator.run_experiment(experiment.clone()).await {
+                Ok(report) => {
+                    println!("{report}");
+                    outcomes.push((iteration, experiment.name.clone(), Ok(report)));
+                }
+                Err(e) => {
+                    eprintln!("Experiment '{}' failed: {e}", experiment.name);
+                    outcomes.push((iteration, experiment.name.clone(), Err(e.to_string())));
+                }
+            }
+            if let Some(ref output) = output {
+                output.write_reports(&finished_reports(&outcomes))?;
             }
         }
     }
 
+    if args.repeat > 1 {
+        print_summary(&outcomes);
+    }
+    if cancelled.is_cancelled() {
+        anyhow::bail!(
+            "Cancelled after {} experiment(s) started; rollback ran for what was applied",
+            outcomes.len()
+        );
+    }
+
     Ok(())
 }
+
+/// `--print-config`: the config file, profile, safety settings and every
+/// experiment with its target resolved.
+fn print_config(
+    args: &RunArgs,
+    config: &ChaosConfig,
+    experiments: &[ExperimentConfig],
+    profile: Option<&Profile>,
+) -> anyhow::Result<()> {
+    let mut effective = Mapping::new();
+    effective.insert("config_file".into(), args.config.display().to_string().into());
+    if let Some(path) = &args.targets_file {
+        effective.insert("targets_file".into(), path.display().to_string().into());
+    }
+    if let Some(path) = &args.skills_from {
+        effective.insert("skills_from".into(), path.display().to_string().into());
+    }
+    if let Some(profile) = profile {
+        let mut value = serde_yaml::to_value(profile)?;
+        if let Value::Mapping(ref mut map) = value {
+            map.insert("name".into(), profile.name.clone().into());
+        }
+        effective.insert("profile".into(), value);
+    }
+    effective.insert("allow_irreversible".into(), args.allow_irreversible.into());
+    effective.insert("safety".into(), serde_yaml::to_value(&config.safety)?);
+    effective.insert(
+        "experiments".into(),
+        Value::Sequence(
+            experiments
+                .iter()
+                .map(effective_config::experiment)
+                .collect::<anyhow::Result<_>>()?,
+        ),
+    );
+    effective_config::print(Value::Mapping(effective))
+}
+
+type Outcome = (u32, String, Result<ExperimentReport, String>);
+
+fn finished_reports(outcomes: &[Outcome]) -> Vec<ExperimentReport> {
+    outcomes
+        .iter()
+        .filter_map(|(_, _, outcome)| outcome.as_ref().ok().cloned())
+        .collect()
+}
+
+/// One line per experiment run across all iterations, then an OK count per experiment.
+fn print_summary(outcomes: &[Outcome]) {
+    let name_w = outcomes.iter().map(|(_, n, _)| n.len()).max().unwrap_or(0).max(10);
+    println!("\nREPEAT SUMMARY\n");
+    println!("  {:<5} {:<name_w$}  {:<10} STATUS", "ITER", "EXPERIMENT", "DURATION");
+    for (iteration, name, outcome) in outcomes {
+        match outcome {
+            Ok(report) => println!(
+                "  {:<5} {:<name_w$}  {:<10} {}",
+                iteration,
+                name,
+                format_duration(report.total_duration),
+                report.status
+            ),
+            Err(e) => println!("  {iteration:<5} {name:<name_w$}  {:<10} error: {e}", "-"),
+        }
+    }
+
+    println!();
+    let mut names: Vec<&String> = Vec::new();
+    for (_, name, _) in outcomes {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    for name in names {
+        let runs: Vec<_> = outcomes.iter().filter(|(_, n, _)| n == name).collect();
+        let ok = runs
+            .iter()
+            .filter(|(_, _, o)| o.as_ref().is_ok_and(|r| r.status == "completed"))
+            .count();
+        println!("  {name}: {ok}/{} completed", runs.len());
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::Args;
+
+use chaos_core::history::HistoryStore;
+use chaos_core::duration::format_duration;
+use chaos_core::report::{ExperimentReport, SkillExecutionRecord};
+
+#[derive(Args)]
+pub struct StatsArgs {
+    /// History file to read. Defaults to $CHAOS_HISTORY, else
+    /// ~/.chaos-agents/history.jsonl
+    #[arg(long)]
+    pub history: Option<PathBuf>,
+    /// How many of the most-targeted resources to list
+    #[arg(long, default_value_t = 10)]
+    pub top: usize,
+}
+
+#[derive(Default)]
+struct SkillStats {
+    executions: usize,
+    successes: usize,
+    durations: Vec<Duration>,
+    rollbacks: usize,
+    rollback_successes: usize,
+}
+
+pub async fn execute(args: StatsArgs) -> anyhow::Result<()> {
+    let store = match args.history {
+        Some(path) => HistoryStore::new(path),
+        None => HistoryStore::from_env()
+            .ok_or_else(|| anyhow::anyhow!("No history file; pass --history <FILE>"))?,
+    };
+    let reports = store.load()?;
+    if reports.is_empty() {
+        println!("No experiments recorded in {}.", store.path().display());
+        return Ok(());
+    }
+
+    let mut skills: BTreeMap<String, SkillStats> = BTreeMap::new();
+    let mut resources: BTreeMap<(String, String), usize> = BTreeMap::new();
+    for report in &reports {
+        for exec in &report.skill_executions {
+            let stats = skills.entry(exec.skill_name.clone()).or_default();
+            stats.executions += 1;
+            stats.successes += usize::from(exec.success);
+            stats.durations.push(exec.duration);
+            for key in targeted_resources(report, exec) {
+                *resources.entry(key).or_default() += 1;
+            }
+        }
+        for step in &report.rollback_steps {
+            let stats = skills.entry(step.skill_name.clone()).or_default();
+            stats.rollbacks += 1;
+            stats.rollback_successes += usize::from(step.success);
+        }
+    }
+
+    let first = reports.iter().map(|r| r.started_at).min().unwrap_or_default();
+    let last = reports.iter().map(|r| r.started_at).max().unwrap_or_default();
+    println!(
+        "{} experiment(s) from {} to {}\n",
+        reports.len(),
+        first.format("%Y-%m-%d %H:%M"),
+        last.format("%Y-%m-%d %H:%M")
+    );
+
+    let name_w = skills.keys().map(|k| k.len()).max().unwrap_or(0).max(5);
+    println!(
+        "  {:<name_w$}  {:>5}  {:>8}  {:>10}  {:>9}  ROLLBACK OK",
+        "SKILL", "RUNS", "SUCCESS", "MEDIAN", "ROLLBACKS"
+    );
+    let mut ordered: Vec<_> = skills.iter().collect();
+    ordered.sort_by(|a, b| b.1.executions.cmp(&a.1.executions).then(a.0.cmp(b.0)));
+    for (name, s) in ordered {
+        println!(
+            "  {:<name_w$}  {:>5}  {:>8}  {:>10}  {:>9}  {}",
+            name,
+            s.executions,
+            rate(s.successes, s.executions),
+            median(&s.durations).map(format_duration).unwrap_or_else(|| "-".into()),
+            s.rollbacks,
+            rate(s.rollback_successes, s.rollbacks),
+        );
+    }
+
+    println!("\nMost-targeted resources:");
+    if resources.is_empty() {
+        println!("  (none named in skill params or actions)");
+        return Ok(());
+    }
+    let mut ranked: Vec<_> = resources.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    for ((resource_type, name), count) in ranked.into_iter().take(args.top) {
+        println!("  {count:>5}  {resource_type:<15} {name}");
+    }
+    Ok(())
+}
+
+/// Discovered resources an execution acted on: those named in its params or
+/// in the actions it recorded. Skills that pick targets at random and record
+/// nothing don't contribute.
+fn targeted_resources(
+    report: &ExperimentReport,
+    exec: &SkillExecutionRecord,
+) -> Vec<(String, String)> {
+    let mut named = Vec::new();
+    collect_strings(&exec.params, &mut named);
+    report
+        .discovered_resources
+        .iter()
+        .filter(|r| {
+            named.iter().any(|s| *s == r.name)
+                || exec.output.actions.iter().any(|a| a.contains(&r.name))
+        })
+        .map(|r| (r.resource_type.clone(), r.name.clone()))
+        .collect()
+}
+
+fn collect_strings<'a>(value: &'a serde_yaml::Value, out: &mut Vec<&'a str>) {
+    match value {
+        serde_yaml::Value::String(s) => out.push(s),
+        serde_yaml::Value::Sequence(seq) => seq.iter().for_each(|v| collect_strings(v, out)),
+        serde_yaml::Value::Mapping(map) => map.values().for_each(|v| collect_strings(v, out)),
+        serde_yaml::Value::Tagged(tagged) => collect_strings(&tagged.value, out),
+        _ => {}
+    }
+}
+
+fn rate(ok: usize, total: usize) -> String {
+    if total == 0 {
+        "-".into()
+    } else {
+        format!("{:.0}%", ok as f64 * 100.0 / total as f64)
+    }
+}
+
+fn median(durations: &[Duration]) -> Option<Duration> {
+    let mut sorted = durations.to_vec();
+    sorted.sort();
+    let mid = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(sorted[mid]),
+        _ => Some((sorted[mid - 1] + sorted[mid]) / 2),
+    }
+}
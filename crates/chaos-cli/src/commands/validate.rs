@@ -2,7 +2,8 @@ use std::path::PathBuf;
 
 use clap::Args;
 
-use chaos_core::config::ChaosConfig;
+use chaos_core::config::{ChaosConfig, TargetSet};
+use chaos_core::duration::format_duration;
 use chaos_core::skill::TargetDomain;
 use chaos_db::agent::DbAgent;
 use chaos_k8s::agent::K8sAgent;
@@ -12,6 +13,9 @@ use chaos_server::agent::ServerAgent;
 pub struct ValidateArgs {
     /// Path to config file to validate
     pub config: PathBuf,
+    /// YAML file with a `targets:` section of named targets for `target_ref`
+    #[arg(long, value_name = "FILE")]
+    pub targets_file: Option<PathBuf>,
 }
 
 pub async fn execute(args: ValidateArgs) -> anyhow::Result<()> {
@@ -20,13 +24,39 @@ pub async fn execute(args: ValidateArgs) -> anyhow::Result<()> {
     let config = ChaosConfig::from_file(&args.config)?;
     println!("  YAML parsing: OK");
     println!("  Experiments found: {}", config.experiments.len());
+    let mut targets = config.targets.clone();
+    if let Some(ref path) = args.targets_file {
+        targets.extend(TargetSet::from_file(path)?);
+    }
 
     let mut errors = Vec::new();
 
     for (i, experiment) in config.experiments.iter().enumerate() {
         println!("\n  Experiment #{}: '{}'", i + 1, experiment.name);
         println!("    Target: {}", experiment.target);
-        println!("    Duration: {:?}", experiment.duration);
+        let experiment = match targets.resolve(experiment) {
+            Ok(resolved) => {
+                if let Some(ref name) = experiment.target_ref {
+                    println!("    Target ref: {name}");
+                }
+                resolved
+            }
+            Err(e) => {
+                println!("    Target ref: INVALID - {e}");
+                errors.push(e.to_string());
+                continue;
+            }
+        };
+        match experiment.duration {
+            Some(duration) => println!("    Duration: {}", format_duration(duration)),
+            None => {
+                println!("    Duration: MISSING");
+                errors.push(format!(
+                    "Experiment '{}': no duration and no default_durations entry for target {}",
+                    experiment.name, experiment.target
+                ));
+            }
+        }
         println!("    Skills: {}", experiment.skills.len());
 
         // Validate target config can be parsed
@@ -47,7 +77,13 @@ pub async fn execute(args: ValidateArgs) -> anyhow::Result<()> {
                 for invocation in &experiment.skills {
                     match agent.skill_by_name(&invocation.skill_name) {
                         Some(skill) => {
-                            match skill.validate_params(&invocation.params) {
+                            let valid = skill.validate_params(&invocation.params).and_then(|()| {
+                                match experiment.duration {
+                                    Some(duration) => skill.validate_duration(&invocation.params, duration),
+                                    None => Ok(()),
+                                }
+                            });
+                            match valid {
                                 Ok(()) => {
                                     println!("    Skill '{}': OK", invocation.skill_name);
                                 }
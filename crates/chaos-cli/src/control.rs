@@ -0,0 +1,175 @@
+//! `--control-socket`: a Unix socket through which other processes abort
+//! running experiments, e.g. incident automation when a real alert fires.
+//!
+//! One command per line, one reply line each (`ok ...` or `error ...`):
+//!
+//! - `abort <experiment_id>`: cancel that experiment; rollback still runs
+//! - `abort-all`: cancel every running experiment
+//! - `list`: ids of the running experiments
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use tokio_util::sync::CancellationToken;
+
+use chaos_core::event::{EventSink, ExperimentEvent};
+
+/// Cancel tokens of running experiments, by experiment id.
+#[derive(Default)]
+pub struct ControlRegistry {
+    running: Mutex<HashMap<String, CancellationToken>>,
+}
+
+impl ControlRegistry {
+    /// Event sink for an orchestrator whose cancel token is `cancel`; it
+    /// registers each experiment the orchestrator starts until it finishes.
+    pub fn sink(self: &Arc<Self>, cancel: CancellationToken) -> ControlSink {
+        ControlSink {
+            registry: self.clone(),
+            cancel,
+            current: Mutex::new(None),
+        }
+    }
+
+    /// Handle one command line, returning the reply.
+    fn command(&self, line: &str) -> String {
+        let mut running = self.running.lock().unwrap();
+        let mut words = line.split_whitespace();
+        match (words.next(), words.next(), words.next()) {
+            (Some("abort"), Some(id), None) => match running.remove(id) {
+                Some(cancel) => {
+                    cancel.cancel();
+                    tracing::warn!(experiment_id = %id, "Abort requested on control socket");
+                    format!("ok aborting {id}")
+                }
+                None => format!("error no running experiment {id}"),
+            },
+            (Some("abort-all"), None, None) => {
+                let count = running.len();
+                for (_, cancel) in running.drain() {
+                    cancel.cancel();
+                }
+                tracing::warn!(count, "Abort of all experiments requested on control socket");
+                format!("ok aborting {count} experiment(s)")
+            }
+            (Some("list"), None, None) => {
+                let mut ids: Vec<&str> = running.keys().map(String::as_str).collect();
+                ids.sort();
+                format!("ok {}", ids.join(" "))
+            }
+            _ => "error expected `abort <experiment_id>`, `abort-all` or `list`".into(),
+        }
+    }
+}
+
+/// Registers the running experiment of one orchestrator with a [`ControlRegistry`].
+pub struct ControlSink {
+    registry: Arc<ControlRegistry>,
+    cancel: CancellationToken,
+    current: Mutex<Option<String>>,
+}
+
+impl ControlSink {
+    fn unregister(&self) {
+        if let Some(id) = self.current.lock().unwrap().take() {
+            self.registry.running.lock().unwrap().remove(&id);
+        }
+    }
+}
+
+#[async_trait]
+impl EventSink for ControlSink {
+    async fn emit(&self, event: ExperimentEvent) {
+        match event {
+            ExperimentEvent::Started { experiment_id, .. } => {
+                self.unregister();
+                let id = experiment_id.to_string();
+                self.registry
+                    .running
+                    .lock()
+                    .unwrap()
+                    .insert(id.clone(), self.cancel.clone());
+                *self.current.lock().unwrap() = Some(id);
+            }
+            ExperimentEvent::Completed { .. }
+            | ExperimentEvent::Skipped { .. }
+            | ExperimentEvent::Blocked { .. } => self.unregister(),
+            _ => {}
+        }
+    }
+}
+
+impl Drop for ControlSink {
+    fn drop(&mut self) {
+        self.unregister();
+    }
+}
+
+/// A listening control socket; the socket file is removed on drop.
+pub struct ControlSocket {
+    path: PathBuf,
+}
+
+impl ControlSocket {
+    /// Listen on `path`, replacing a stale socket left by a previous run.
+    /// Only the current user may connect.
+    #[cfg(unix)]
+    pub fn bind(path: &Path, registry: Arc<ControlRegistry>) -> anyhow::Result<Self> {
+        use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+        use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+        if let Ok(meta) = std::fs::symlink_metadata(path) {
+            if !meta.file_type().is_socket() {
+                anyhow::bail!("{} exists and is not a socket", path.display());
+            }
+            std::fs::remove_file(path)?;
+        }
+        let listener = tokio::net::UnixListener::bind(path)
+            .map_err(|e| anyhow::anyhow!("Failed to bind control socket {}: {e}", path.display()))?;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+        tracing::info!(path = %path.display(), "Listening on control socket");
+
+        tokio::spawn(async move {
+            loop {
+                let stream = match listener.accept().await {
+                    Ok((stream, _)) => stream,
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Control socket accept failed");
+                        continue;
+                    }
+                };
+                let registry = registry.clone();
+                tokio::spawn(async move {
+                    let (reader, mut writer) = stream.into_split();
+                    let mut lines = BufReader::new(reader).lines();
+                    while let Ok(Some(line)) = lines.next_line().await {
+                        if line.trim().is_empty() {
+                            continue;
+                        }
+                        let reply = registry.command(&line);
+                        if writer.write_all(format!("{reply}\n").as_bytes()).await.is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            path: path.to_path_buf(),
+        })
+    }
+
+    #[cfg(not(unix))]
+    pub fn bind(path: &Path, _registry: Arc<ControlRegistry>) -> anyhow::Result<Self> {
+        anyhow::bail!("--control-socket {} needs Unix domain sockets", path.display())
+    }
+}
+
+impl Drop for ControlSocket {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
@@ -0,0 +1,117 @@
+//! `--print-config`: the configuration a command would actually use, after
+//! config files, flags, env vars, named targets and auto-detection, with
+//! secrets redacted.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_yaml::{Mapping, Value};
+
+use chaos_core::experiment::ExperimentConfig;
+use chaos_core::redact::redact_yaml;
+use chaos_core::safety::{kube_current_context, kubeconfig_path};
+use chaos_core::skill::TargetDomain;
+use chaos_db::config::DbTargetConfig;
+use chaos_db::mongo_config::MongoTargetConfig;
+use chaos_k8s::config::K8sTargetConfig;
+use chaos_llm::provider::LlmProviderConfig;
+use chaos_server::config::ServerTargetConfig;
+
+use crate::execution::is_mongo_target;
+
+/// Print `value` as YAML under a header line.
+pub fn print(mut value: Value) -> anyhow::Result<()> {
+    redact_yaml(&mut value);
+    println!("# Effective configuration (secrets redacted)");
+    print!("{}", serde_yaml::to_string(&value)?);
+    Ok(())
+}
+
+/// `experiment` with its `target_config` as the agent will see it: parsed
+/// into the agent's config type, so defaults are filled in, plus which agent
+/// handles it and, for Kubernetes, the kubeconfig and context in effect.
+pub fn experiment(experiment: &ExperimentConfig) -> anyhow::Result<Value> {
+    let mut value = serde_yaml::to_value(experiment)?;
+    if let Value::Mapping(ref mut map) = value {
+        map.insert(
+            "target_config".into(),
+            target(experiment.target, &experiment.target_config)?,
+        );
+    }
+    Ok(value)
+}
+
+/// A `target_config` as the `target` agent resolves it.
+pub fn target(target: TargetDomain, config: &Value) -> anyhow::Result<Value> {
+    let (agent, resolved) = match target {
+        TargetDomain::Database if is_mongo_target(config) => {
+            ("mongodb", parsed::<MongoTargetConfig>(target, config)?.1)
+        }
+        TargetDomain::Database => ("sql", parsed::<DbTargetConfig>(target, config)?.1),
+        TargetDomain::Kubernetes => {
+            let config = if config.is_null() { Value::Mapping(Mapping::new()) } else { config.clone() };
+            let (k8s, mut resolved) = parsed::<K8sTargetConfig>(target, &config)?;
+            let path = kubeconfig_path(k8s.kubeconfig.as_deref()).filter(|p| p.exists());
+            let context = kube_current_context(k8s.kubeconfig.as_deref());
+            if let Value::Mapping(ref mut map) = resolved {
+                map.insert(
+                    "kubeconfig".into(),
+                    match path {
+                        Some(path) => path.display().to_string().into(),
+                        None => "in-cluster".into(),
+                    },
+                );
+                if let Some((context, server)) = context {
+                    map.insert("context".into(), context.into());
+                    if let Some(server) = server {
+                        map.insert("api_server".into(), server.into());
+                    }
+                }
+            }
+            ("kubernetes", resolved)
+        }
+        TargetDomain::Server => ("server", parsed::<ServerTargetConfig>(target, config)?.1),
+    };
+    let mut map = Mapping::new();
+    map.insert("agent".into(), agent.into());
+    if let Value::Mapping(resolved) = resolved {
+        map.extend(resolved);
+    }
+    Ok(Value::Mapping(map))
+}
+
+/// `config` parsed as `T`, and back to YAML with `T`'s defaults filled in.
+fn parsed<T: DeserializeOwned + Serialize>(target: TargetDomain, config: &Value) -> anyhow::Result<(T, Value)> {
+    let typed: T = serde_yaml::from_value(config.clone())
+        .map_err(|e| anyhow::anyhow!("Invalid {target} target_config: {e}"))?;
+    let value = serde_yaml::to_value(&typed)?;
+    Ok((typed, value))
+}
+
+/// Proxy variables reqwest reads, in the order it prefers them.
+const PROXY_VARS: &[&str] = &["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy", "ALL_PROXY", "all_proxy"];
+
+/// An LLM provider config, with why that provider was picked and, when the
+/// config sets no `proxy`, the proxy taken from the environment.
+pub fn llm(config: &LlmProviderConfig, selected_by: &str) -> anyhow::Result<Value> {
+    let mut value = serde_yaml::to_value(config)?;
+    if let Value::Mapping(ref mut map) = value {
+        map.insert("selected_by".into(), selected_by.into());
+        // f32 settings as written, e.g. 0.2 rather than 0.20000000298023224
+        for key in ["temperature", "top_p"] {
+            if let Some(value) = map.get_mut(key) {
+                if let Some(v) = value.as_f64() {
+                    *value = serde_yaml::from_str(&(v as f32).to_string())?;
+                }
+            }
+        }
+        if map.get("proxy").is_none_or(Value::is_null) {
+            let env = PROXY_VARS
+                .iter()
+                .find_map(|var| std::env::var(var).ok().filter(|v| !v.is_empty()).map(|v| (var, v)));
+            if let Some((var, proxy)) = env {
+                map.insert("proxy".into(), format!("{proxy} (from {var})").into());
+            }
+        }
+    }
+    Ok(value)
+}
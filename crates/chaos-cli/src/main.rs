@@ -1,7 +1,12 @@
 use clap::Parser;
+use tracing_subscriber::EnvFilter;
 
 mod commands;
+mod control;
+mod effective_config;
+mod planning;
 pub mod execution;
+mod run_output;
 
 #[derive(Parser)]
 #[command(
@@ -16,6 +21,11 @@ struct Cli {
     /// Verbosity level (-v, -vv, -vvv)
     #[arg(short, long, action = clap::ArgAction::Count, global = true)]
     verbose: u8,
+
+    /// Extra log directives in `RUST_LOG` syntax, applied on top of the
+    /// `-v` level, e.g. `chaos_llm=trace,sqlx=warn`
+    #[arg(long, value_name = "DIRECTIVES", global = true)]
+    log: Option<String>,
 }
 
 #[tokio::main]
@@ -28,11 +38,24 @@ async fn main() -> anyhow::Result<()> {
             chaos_tui::launch_tui().await
         }
         Some(command) => {
-            let filter = match cli.verbose {
+            let quiet = match &command {
+                commands::Commands::Agent(args) => args.planning.quiet,
+                commands::Commands::Plan(args) => args.planning.quiet,
+                _ => false,
+            };
+            let level = match cli.verbose {
+                0 if quiet => "warn",
                 0 => "info",
                 1 => "debug",
                 _ => "trace",
             };
+            // Per-module directives are more specific than the bare level, so they win
+            let filter = match cli.log {
+                Some(ref directives) => EnvFilter::builder()
+                    .parse(format!("{level},{directives}"))
+                    .map_err(|e| anyhow::anyhow!("Invalid --log '{directives}': {e}"))?,
+                None => EnvFilter::new(level),
+            };
             tracing_subscriber::fmt()
                 .with_env_filter(filter)
                 .init();
@@ -45,7 +68,11 @@ async fn main() -> anyhow::Result<()> {
                 commands::Commands::ListSkills(args) => {
                     commands::list_skills::execute(args).await
                 }
+                commands::Commands::Models(args) => commands::models::execute(args).await,
                 commands::Commands::Validate(args) => commands::validate::execute(args).await,
+                commands::Commands::Inspect(args) => commands::inspect::execute(args).await,
+                commands::Commands::Cleanup(args) => commands::cleanup::execute(args).await,
+                commands::Commands::Stats(args) => commands::stats::execute(args).await,
             }
         }
     }
@@ -0,0 +1,353 @@
+//! LLM planning shared by `plan` (plan and print the config) and `agent`
+//! (plan, then execute).
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+use chaos_core::blast_radius::{self, BlastRadius};
+use chaos_core::config::{ChaosConfig, DefaultDurations, SkillsManifest, TargetSet};
+use chaos_core::experiment::ExperimentConfig;
+use chaos_core::profile::{Profile, ProfileSet};
+use chaos_core::safety::SafetyConfig;
+use chaos_llm::io_log::IoLogger;
+use chaos_llm::mcp::{McpClient, McpServerConfig};
+use chaos_llm::planner::{ChaosPlanner, PlanResult};
+use chaos_llm::pricing::{estimate_cost_with, format_cost, ModelPrice};
+use chaos_llm::provider::LlmProviderConfig;
+
+use crate::execution::{build_provider_config_from_parts, collect_skill_definitions, LiveDiscoverResourcesTool};
+
+/// The `--config` file of `plan` and `agent`.
+#[derive(Debug, serde::Deserialize)]
+pub struct PlanConfig {
+    pub llm: LlmProviderConfig,
+    #[serde(default)]
+    pub mcp_servers: Vec<McpServerConfig>,
+    #[serde(default)]
+    pub system_prompt: Option<String>,
+    #[serde(default = "default_max_turns")]
+    pub max_turns: u32,
+    /// Approximate token budget for the conversation; older tool results are
+    /// trimmed past it.
+    #[serde(default)]
+    pub context_limit: Option<usize>,
+    /// Consecutive turns without a new experiment (or identical repeated tool
+    /// calls) before planning stops early; 0 disables.
+    #[serde(default)]
+    pub idle_limit: Option<u32>,
+    /// Durations for planned experiments that don't set their own.
+    #[serde(default)]
+    pub default_durations: DefaultDurations,
+    /// Production-target heuristics checked before execution.
+    #[serde(default)]
+    pub safety: SafetyConfig,
+    /// Named targets the planner may use by `target_ref`.
+    #[serde(default)]
+    pub targets: TargetSet,
+    /// Per-environment guards, picked with `--profile`.
+    #[serde(default)]
+    pub profiles: ProfileSet,
+    /// Per-million-token prices by model name prefix, for the cost estimate;
+    /// entries override the built-in prices.
+    #[serde(default)]
+    pub pricing: BTreeMap<String, ModelPrice>,
+}
+
+pub fn default_max_turns() -> u32 {
+    10
+}
+
+impl PlanConfig {
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        Ok(serde_yaml::from_str(&content)?)
+    }
+}
+
+/// Flags shared by `plan` and `agent`.
+#[derive(Args)]
+pub struct PlanningArgs {
+    /// User prompt describing what chaos to create
+    pub prompt: String,
+    /// Path to LLM/MCP config file
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+    /// LLM provider: anthropic, openai, or ollama (auto-detected from API key env vars if not set)
+    #[arg(long, env = "CHAOS_PROVIDER")]
+    pub provider: Option<String>,
+    /// Model to use
+    #[arg(long, env = "CHAOS_MODEL")]
+    pub model: Option<String>,
+    /// API key (or set via ANTHROPIC_API_KEY / OPENAI_API_KEY env var)
+    #[arg(long)]
+    pub api_key: Option<String>,
+    /// Sampling temperature, e.g. 0 for more repeatable plans; overrides
+    /// `temperature` in --config (unset: the provider's default)
+    #[arg(long)]
+    pub temperature: Option<f32>,
+    /// Nucleus sampling cutoff; overrides `top_p` in --config (unset: the
+    /// provider's default)
+    #[arg(long)]
+    pub top_p: Option<f32>,
+    /// Max number of LLM planning turns (default: 10)
+    #[arg(long)]
+    pub max_turns: Option<u32>,
+    /// Don't print the planner's intermediate messages and tool calls, or
+    /// info-level logs; the plan (and with `agent`, the confirmation and
+    /// reports) is still shown
+    #[arg(long, short = 'q')]
+    pub quiet: bool,
+    /// Skills manifest restricting which skills the planner may use, with default params
+    #[arg(long)]
+    pub skills_from: Option<PathBuf>,
+    /// Write raw LLM request/response bodies to timestamped files in this directory
+    #[arg(long, value_name = "DIR")]
+    pub log_llm_io: Option<PathBuf>,
+    /// Force the first planning turn to call discover_resources, and reject
+    /// run_experiment until discovery has succeeded
+    #[arg(long)]
+    pub require_discovery_first: bool,
+    /// YAML file with a `targets:` section of named targets. The planner sees
+    /// only their names and descriptions; entries replace same-named targets
+    /// from --config
+    #[arg(long, value_name = "FILE")]
+    pub targets_file: Option<PathBuf>,
+    /// Enforce this profile from --config's `profiles:` section: allowed
+    /// and denied skills, max duration, blast radius, irreversible skills
+    /// (the planner is only offered allowed skills)
+    #[arg(long, value_name = "NAME", requires = "config")]
+    pub profile: Option<String>,
+}
+
+impl PlanningArgs {
+    /// The provider to use without --config, and why it was picked.
+    pub fn detect_provider(&self) -> (String, &'static str) {
+        if let Some(ref provider) = self.provider {
+            return (provider.clone(), "--provider or CHAOS_PROVIDER");
+        }
+        if self.api_key.is_some() {
+            return ("anthropic".to_string(), "--api-key given without --provider");
+        }
+        if std::env::var("ANTHROPIC_API_KEY").is_ok() {
+            return ("anthropic".to_string(), "ANTHROPIC_API_KEY is set");
+        }
+        if std::env::var("OPENAI_API_KEY").is_ok() {
+            return ("openai".to_string(), "OPENAI_API_KEY is set");
+        }
+        ("ollama".to_string(), "default: no API key found")
+    }
+
+    /// The provider config from flags and env vars, for use without --config.
+    pub fn provider_config(&self) -> anyhow::Result<LlmProviderConfig> {
+        let (provider, _) = self.detect_provider();
+        let mut config = build_provider_config_from_parts(
+            &provider,
+            self.api_key.as_deref(),
+            self.model.as_deref(),
+            None,
+        )?;
+        config.override_sampling(self.temperature, self.top_p);
+        Ok(config)
+    }
+}
+
+/// A finished plan: the planner (for a later summary) and the experiments as
+/// a config ready to save or run.
+pub struct Plan {
+    pub planner: ChaosPlanner,
+    /// Planned experiments, with `target_ref`s left unresolved so the config
+    /// can be saved without credentials.
+    pub config: ChaosConfig,
+    /// Named targets from --config and --targets-file, to resolve before
+    /// running.
+    pub targets: TargetSet,
+    /// The --profile selected from --config.
+    pub profile: Option<Profile>,
+    /// What each experiment may touch, by the resources discovered while
+    /// planning.
+    pub blast_radius: Vec<BlastRadius>,
+}
+
+/// Run the planner for `args.prompt` and print its message and the planned
+/// experiments. `None` when nothing was planned.
+pub async fn plan(args: &PlanningArgs, mut io_logger: Option<IoLogger>) -> anyhow::Result<Option<Plan>> {
+    let manifest = args
+        .skills_from
+        .as_deref()
+        .map(SkillsManifest::from_file)
+        .transpose()?;
+    let mut skill_definitions = collect_skill_definitions();
+    if let Some(ref manifest) = manifest {
+        skill_definitions.retain(|d| manifest.allows(&d.name));
+    }
+
+    let mut targets = args
+        .targets_file
+        .as_deref()
+        .map(TargetSet::from_file)
+        .transpose()?
+        .unwrap_or_default();
+
+    let mut safety = SafetyConfig::default();
+    let mut profile: Option<Profile> = None;
+    let (provider_config, plan_config) = match args.config {
+        Some(ref path) => {
+            let mut plan_config = PlanConfig::from_file(path)?;
+            plan_config.llm.override_sampling(args.temperature, args.top_p);
+            safety = std::mem::take(&mut plan_config.safety);
+            if let Some(ref name) = args.profile {
+                let selected = plan_config.profiles.select(name)?;
+                skill_definitions.retain(|d| selected.allows_skill(&d.name));
+                profile = Some(selected);
+            }
+            let mut config_targets = std::mem::take(&mut plan_config.targets);
+            config_targets.extend(std::mem::take(&mut targets));
+            targets = config_targets;
+            (plan_config.llm.clone(), Some(plan_config))
+        }
+        None => (args.provider_config()?, None),
+    };
+
+    let pricing = plan_config
+        .as_ref()
+        .map(|c| c.pricing.clone())
+        .unwrap_or_default();
+    let mut planner = ChaosPlanner::new(&provider_config)?;
+    planner.set_verbose(!args.quiet);
+    if let Some(log) = io_logger.take() {
+        planner.set_io_logger(log);
+    }
+    planner.set_require_discovery_first(args.require_discovery_first);
+    planner.update_skills(skill_definitions);
+    planner.set_named_targets(&targets);
+    planner.register_tool(Box::new(LiveDiscoverResourcesTool {
+        user_prompt: args.prompt.clone(),
+        targets: targets.clone(),
+    }));
+    match plan_config {
+        Some(plan_config) => {
+            planner.set_default_durations(plan_config.default_durations);
+            if let Some(prompt) = plan_config.system_prompt {
+                planner.set_system_prompt(prompt);
+            }
+            planner.set_max_turns(args.max_turns.unwrap_or(plan_config.max_turns));
+            if let Some(limit) = plan_config.context_limit {
+                planner.set_context_limit(limit);
+            }
+            if let Some(turns) = plan_config.idle_limit {
+                planner.set_idle_limit(turns);
+            }
+            for mcp_config in plan_config.mcp_servers {
+                let client = McpClient::new(mcp_config);
+                planner.add_mcp_server(client).await?;
+            }
+        }
+        None => {
+            if let Some(max_turns) = args.max_turns {
+                planner.set_max_turns(max_turns);
+            }
+        }
+    }
+
+    println!("Planning chaos experiments...\n");
+    let mut result = planner.plan(&args.prompt).await?;
+    let discovered = std::mem::take(&mut result.discovered);
+    let Some(mut experiments) = print_plan(result, provider_config.model(), &pricing) else {
+        return Ok(None);
+    };
+
+    for (i, experiment) in experiments.iter().enumerate() {
+        if experiment.duration.is_none() {
+            anyhow::bail!(
+                "Experiment #{} '{}' has no duration and no default is configured for target {}",
+                i + 1,
+                experiment.name,
+                experiment.target
+            );
+        }
+    }
+    if let Some(ref manifest) = manifest {
+        for experiment in &mut experiments {
+            manifest.apply(experiment)?;
+        }
+    }
+    let blast_radius = blast_radius::project_all(&experiments, &discovered);
+    Ok(Some(Plan {
+        planner,
+        config: ChaosConfig {
+            experiments,
+            default_durations: DefaultDurations::default(),
+            safety,
+            targets: TargetSet::default(),
+            profiles: ProfileSet::default(),
+        },
+        targets,
+        profile,
+        blast_radius,
+    }))
+}
+
+/// A table of what each experiment may touch, for approving the plan.
+pub fn print_blast_radius(radii: &[BlastRadius]) {
+    let name_w = radii.iter().map(|r| r.experiment.len()).max().unwrap_or(0).max(10);
+    println!("\nBlast radius (upper bounds from discovery and skill params):\n");
+    println!("  {:<name_w$}  {:<11} TOUCHES", "EXPERIMENT", "TARGET");
+    for radius in radii {
+        println!(
+            "  {:<name_w$}  {:<11} {}",
+            radius.experiment,
+            radius.target.to_string(),
+            radius.summary()
+        );
+    }
+    println!();
+}
+
+/// Print the planner's message and the planned experiments, with the tokens
+/// used and their estimated cost on `model`, returning the experiments;
+/// `None` when there are none.
+fn print_plan(
+    result: PlanResult,
+    model: &str,
+    pricing: &BTreeMap<String, ModelPrice>,
+) -> Option<Vec<ExperimentConfig>> {
+    println!("{}", result.message);
+
+    if result.experiments.is_empty() {
+        if result.empty_targets.is_empty() {
+            println!("\nNo experiments were planned.");
+        } else {
+            println!(
+                "\nNo experiments were planned: discovery found no resources on {}.",
+                result.empty_targets.join(", ")
+            );
+        }
+        // A plan that came to nothing still cost tokens
+        print_usage(&result, model, pricing);
+        return None;
+    }
+
+    println!("\nPlanned experiments ({}):", result.experiments.len());
+    for (i, exp) in result.experiments.iter().enumerate() {
+        println!(
+            "  {}. {} (target: {})",
+            i + 1,
+            exp.name, exp.target,
+        );
+    }
+    println!("\n(Completed in {} turns)", result.turns);
+    print_usage(&result, model, pricing);
+    Some(result.experiments)
+}
+
+/// Print the tokens `result` used and their estimated cost on `model`.
+fn print_usage(result: &PlanResult, model: &str, pricing: &BTreeMap<String, ModelPrice>) {
+    if let Some(ref usage) = result.total_usage {
+        println!("Total tokens: {} in / {} out", usage.input_tokens, usage.output_tokens);
+        if let Some(cost) = estimate_cost_with(pricing, model, usage) {
+            println!("Estimated cost: ~{} ({model})", format_cost(cost));
+        }
+    }
+}
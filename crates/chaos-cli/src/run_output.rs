@@ -0,0 +1,70 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use chaos_core::event::JsonlEventSink;
+use chaos_core::report::ExperimentReport;
+
+/// Per-run artifact directory created under `--output-dir`: the resolved
+/// config, the LLM trace, the event stream, the reports and their summary of
+/// one run.
+pub struct RunOutput {
+    dir: PathBuf,
+}
+
+impl RunOutput {
+    /// Create `<base>/<command>-<timestamp>`, suffixed with `-2`, `-3`, ... if
+    /// a run in the same second already took the name.
+    pub fn create(base: &Path, command: &str) -> anyhow::Result<Self> {
+        std::fs::create_dir_all(base)
+            .with_context(|| format!("Failed to create output dir {}", base.display()))?;
+        let stem = format!("{command}-{}", chrono::Local::now().format("%Y%m%dT%H%M%S"));
+        for n in 1.. {
+            let name = if n == 1 { stem.clone() } else { format!("{stem}-{n}") };
+            let dir = base.join(name);
+            match std::fs::create_dir(&dir) {
+                Ok(()) => return Ok(Self { dir }),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(e) => {
+                    return Err(e).with_context(|| format!("Failed to create {}", dir.display()))
+                }
+            }
+        }
+        unreachable!()
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// `config.yaml`: the experiments exactly as they will run.
+    pub fn write_config(&self, yaml: &str) -> anyhow::Result<()> {
+        let path = self.dir.join("config.yaml");
+        std::fs::write(&path, yaml).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// `trace.jsonl`: raw LLM request/response pairs, one per line.
+    pub fn trace_path(&self) -> PathBuf {
+        self.dir.join("trace.jsonl")
+    }
+
+    /// Sink writing `events.jsonl`.
+    pub fn event_sink(&self) -> anyhow::Result<JsonlEventSink> {
+        let path = self.dir.join("events.jsonl");
+        JsonlEventSink::create(&path).with_context(|| format!("Failed to create {}", path.display()))
+    }
+
+    /// `report.json`: every report finished so far. Rewritten after each
+    /// experiment so an interrupted run still leaves the completed ones.
+    pub fn write_reports(&self, reports: &[ExperimentReport]) -> anyhow::Result<()> {
+        let path = self.dir.join("report.json");
+        let json = serde_json::to_string_pretty(reports)?;
+        std::fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// `summary.md`: the LLM-written summary of the reports.
+    pub fn write_summary(&self, summary: &str) -> anyhow::Result<()> {
+        let path = self.dir.join("summary.md");
+        std::fs::write(&path, summary).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
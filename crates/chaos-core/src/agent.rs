@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 
-use crate::discovery::DiscoveredResource;
+use crate::cleanup::CleanupItem;
+use crate::discovery::Discovery;
 use crate::error::ChaosResult;
 use crate::skill::{Skill, SkillContext, TargetDomain};
 
@@ -27,8 +28,10 @@ pub trait Agent: Send + Sync {
     /// Initialize: connect to the target, verify access.
     async fn initialize(&mut self) -> ChaosResult<()>;
 
-    /// Discover resources on the target.
-    async fn discover(&mut self) -> ChaosResult<Vec<Box<dyn DiscoveredResource>>>;
+    /// Discover resources on the target. Parts that can't be discovered (one
+    /// host of many, one table) are reported in [`Discovery::errors`] rather
+    /// than failing the whole discovery.
+    async fn discover(&mut self) -> ChaosResult<Discovery>;
 
     /// Return all skills this agent can perform.
     fn skills(&self) -> Vec<&dyn Skill>;
@@ -36,9 +39,22 @@ pub trait Agent: Send + Sync {
     /// Look up a skill by name.
     fn skill_by_name(&self, name: &str) -> Option<&dyn Skill>;
 
-    /// Build a SkillContext for executing skills.
+    /// Build a SkillContext for executing skills. Agents leave `params` and the
+    /// experiment identity empty; the orchestrator fills them in per invocation.
     async fn build_context(&self) -> ChaosResult<SkillContext>;
 
+    /// Check the target is healthy enough to take chaos: reachable, and not
+    /// already failing. Call after `initialize`. Errors name what's wrong.
+    async fn health_check(&self) -> ChaosResult<()> {
+        Ok(())
+    }
+
+    /// Find (and unless `dry_run`, remove) artifacts that skills leave behind
+    /// when a run crashes before rollback. Call after `initialize`.
+    async fn cleanup(&mut self, _dry_run: bool) -> ChaosResult<Vec<CleanupItem>> {
+        Ok(Vec::new())
+    }
+
     /// Graceful shutdown: close connections, clean up.
     async fn shutdown(&mut self) -> ChaosResult<()>;
 }
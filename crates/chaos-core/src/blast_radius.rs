@@ -0,0 +1,245 @@
+//! Projected blast radius of a planned experiment: how many of the
+//! discovered tables, collections, pods or services its skills may touch,
+//! worked out from skill params before anything runs.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde_yaml::Value;
+
+use crate::experiment::ExperimentConfig;
+use crate::name_pattern::{is_pattern, is_regex, NamePattern};
+use crate::report::DiscoveredResourceSummary;
+use crate::skill::TargetDomain;
+
+/// How a skill picks the resources it acts on.
+enum Selection {
+    /// Listed in a param (globs and `re:` regexes stand for the discovered
+    /// ones they match), otherwise every discovered one; optionally at most
+    /// `cap` per invocation.
+    Named { param: &'static str, cap: Option<usize> },
+    /// Matched by a selector param; at most the `cap` param (or its
+    /// default) per invocation, otherwise every match.
+    Selector { param: &'static str, cap: Option<(&'static str, usize)> },
+    /// Listed in a param, otherwise at most the `cap` param (or its default)
+    /// per invocation.
+    Capped { param: &'static str, cap: &'static str, default: usize },
+}
+
+/// Skills that act on discovered resources, with the resource type they touch.
+/// Any other skill acts on the target as a whole.
+const SKILL_REACH: &[(&str, &str, Selection)] = &[
+    ("db.insert_load", "table", Selection::Named { param: "tables", cap: None }),
+    ("db.index_hotspot", "table", Selection::Named { param: "table", cap: Some(1) }),
+    ("db.update_load", "table", Selection::Named { param: "tables", cap: None }),
+    ("db.select_load", "table", Selection::Named { param: "tables", cap: None }),
+    ("db.table_lock", "table", Selection::Named { param: "tables", cap: None }),
+    ("db.row_lock", "table", Selection::Named { param: "tables", cap: None }),
+    ("db.deadlock", "table", Selection::Named { param: "tables", cap: Some(2) }),
+    ("db.autovacuum_disrupt", "table", Selection::Named { param: "tables", cap: None }),
+    ("mongo.insert_load", "collection", Selection::Named { param: "collections", cap: None }),
+    ("mongo.update_load", "collection", Selection::Named { param: "collections", cap: None }),
+    ("mongo.find_load", "collection", Selection::Named { param: "collections", cap: None }),
+    ("mongo.index_drop", "collection", Selection::Named { param: "collections", cap: None }),
+    ("k8s.pod_kill", "Pod", Selection::Selector { param: "label_selector", cap: Some(("count", 1)) }),
+    ("k8s.pod_evict", "Pod", Selection::Selector { param: "label_selector", cap: Some(("count", 1)) }),
+    ("k8s.network_latency", "Pod", Selection::Selector { param: "label_selector", cap: Some(("count", 1)) }),
+    ("k8s.network_chaos", "Pod", Selection::Selector { param: "pod_selector", cap: None }),
+    ("server.service_stop", "service", Selection::Capped { param: "services", cap: "max_services", default: 1 }),
+];
+
+/// Resources of one type an experiment may touch.
+#[derive(Debug, Clone)]
+pub struct Reach {
+    pub resource_type: String,
+    /// Upper bound on how many are touched.
+    pub count: usize,
+    /// How many of this type discovery found.
+    pub discovered: usize,
+    /// The selector picking them, if any.
+    pub selector: Option<String>,
+}
+
+impl fmt::Display for Reach {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = self.resource_type.to_lowercase();
+        let plural = if kind.ends_with('s') { kind } else { format!("{kind}s") };
+        if self.count == self.discovered && self.count > 0 && self.selector.is_none() {
+            write!(f, "all {} {plural}", self.discovered)?;
+        } else if self.count > self.discovered {
+            write!(f, "{} {plural} ({} discovered)", self.count, self.discovered)?;
+        } else {
+            write!(f, "{} of {} {plural}", self.count, self.discovered)?;
+        }
+        if let Some(ref selector) = self.selector {
+            write!(f, " matching {selector}")?;
+        }
+        Ok(())
+    }
+}
+
+/// What one planned experiment may touch.
+#[derive(Debug, Clone)]
+pub struct BlastRadius {
+    pub experiment: String,
+    pub target: TargetDomain,
+    pub reach: Vec<Reach>,
+    /// Skills acting on the target as a whole (config, CPU, disk...) rather
+    /// than on discovered resources.
+    pub target_wide: Vec<String>,
+}
+
+impl BlastRadius {
+    /// Project `experiment` onto the resources `discovered` on its target.
+    /// Counts are upper bounds: named resources that discovery didn't find
+    /// still count, and selectors are assumed to match everything.
+    pub fn project(experiment: &ExperimentConfig, discovered: &[DiscoveredResourceSummary]) -> Self {
+        let mut reach: Vec<Reach> = Vec::new();
+        let mut target_wide = Vec::new();
+        for invocation in &experiment.skills {
+            let Some((_, resource_type, selection)) = SKILL_REACH
+                .iter()
+                .find(|(name, _, _)| *name == invocation.skill_name)
+            else {
+                if !target_wide.contains(&invocation.skill_name) {
+                    target_wide.push(invocation.skill_name.clone());
+                }
+                continue;
+            };
+            let of_type: Vec<_> = discovered
+                .iter()
+                .filter(|r| r.resource_type == *resource_type)
+                .collect();
+            let available = of_type.len();
+            let params = &invocation.params;
+            let invocations = invocation.count.max(1) as usize;
+            let (count, selector) = match *selection {
+                Selection::Named { param, cap } => {
+                    let named = names(params, param);
+                    let count = if named.is_empty() { available } else { named_count(&named, &of_type) };
+                    (cap.map_or(count, |cap| count.min(cap * invocations)), None)
+                }
+                Selection::Selector { param, cap } => {
+                    let count = match cap {
+                        Some((cap, default)) => (number(params, cap).unwrap_or(default) * invocations).min(available),
+                        None => available,
+                    };
+                    (count, selector(params, param))
+                }
+                Selection::Capped { param, cap, default } => {
+                    let named = names(params, param);
+                    let count = if named.is_empty() {
+                        (number(params, cap).unwrap_or(default) * invocations).min(available)
+                    } else {
+                        named.len()
+                    };
+                    (count, None)
+                }
+            };
+            match reach.iter_mut().find(|r| r.resource_type == *resource_type) {
+                // Skills of one experiment may hit the same resources: the
+                // widest one bounds the reach.
+                Some(existing) => {
+                    if count > existing.count {
+                        existing.count = count;
+                        existing.selector = selector;
+                    }
+                }
+                None => reach.push(Reach {
+                    resource_type: resource_type.to_string(),
+                    count,
+                    discovered: available,
+                    selector,
+                }),
+            }
+        }
+        Self {
+            experiment: experiment.name.clone(),
+            target: experiment.target,
+            reach,
+            target_wide,
+        }
+    }
+
+    /// The reach as one line, e.g. `3 of 12 pods matching app=web;
+    /// target-wide: k8s.node_drain`.
+    pub fn summary(&self) -> String {
+        let mut parts: Vec<String> = self.reach.iter().map(ToString::to_string).collect();
+        if !self.target_wide.is_empty() {
+            parts.push(format!("target-wide: {}", self.target_wide.join(", ")));
+        }
+        if parts.is_empty() {
+            "nothing".into()
+        } else {
+            parts.join("; ")
+        }
+    }
+}
+
+/// The blast radius of each of `experiments`, given the resources
+/// `discovered` on each target.
+pub fn project_all(
+    experiments: &[ExperimentConfig],
+    discovered: &HashMap<TargetDomain, Vec<DiscoveredResourceSummary>>,
+) -> Vec<BlastRadius> {
+    experiments
+        .iter()
+        .map(|e| BlastRadius::project(e, discovered.get(&e.target).map(Vec::as_slice).unwrap_or_default()))
+        .collect()
+}
+
+/// Entries of a list param, or a single string.
+fn names(params: &Value, key: &str) -> Vec<String> {
+    let mut names: Vec<String> = match params.get(key) {
+        Some(Value::Sequence(items)) => items.iter().filter_map(|v| v.as_str().map(String::from)).collect(),
+        Some(Value::String(s)) => vec![s.clone()],
+        _ => Vec::new(),
+    };
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// How many resources `named` stands for: one per literal name, plus every
+/// discovered one a glob or regex matches.
+fn named_count(named: &[String], discovered: &[&DiscoveredResourceSummary]) -> usize {
+    let (patterns, literals): (Vec<_>, Vec<_>) = named.iter().partition(|n| is_pattern(n));
+    if patterns.is_empty() {
+        return literals.len();
+    }
+    // Discovered names are unqualified; an invalid pattern matches everything
+    let patterns: Vec<_> = patterns
+        .iter()
+        .map(|p| match p.split_once('.') {
+            Some((_, table)) if !is_regex(p) => NamePattern::parse(table).ok(),
+            _ => NamePattern::parse(p).ok(),
+        })
+        .collect();
+    let matched = discovered
+        .iter()
+        .filter(|r| patterns.iter().any(|p| p.as_ref().is_none_or(|p| p.matches(&r.name))))
+        .count();
+    literals.len() + matched
+}
+
+fn number(params: &Value, key: &str) -> Option<usize> {
+    params.get(key).and_then(Value::as_u64).map(|n| n as usize)
+}
+
+/// A selector param as text: a string as is, a label map as `k=v,k=v`.
+fn selector(params: &Value, key: &str) -> Option<String> {
+    match params.get(key)? {
+        Value::String(s) if !s.is_empty() => Some(s.clone()),
+        Value::Mapping(labels) if !labels.is_empty() => Some(
+            labels
+                .iter()
+                .map(|(k, v)| {
+                    let text = |v: &Value| v.as_str().map(String::from).unwrap_or_else(|| serde_json::to_string(v).unwrap_or_default());
+                    format!("{}={}", text(k), text(v))
+                })
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        _ => None,
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt;
+
+use uuid::Uuid;
+
+/// Label carrying the experiment id on every Kubernetes object a skill creates.
+pub const EXPERIMENT_ID_LABEL: &str = "chaos-agent/experiment-id";
+
+/// Directory under which server skills keep files for one experiment:
+/// `<base>/chaos-agent/<experiment_id>`. `base` is usually `/tmp`.
+pub fn server_work_dir(base: &str, experiment_id: Uuid) -> String {
+    format!("{}/chaos-agent/{experiment_id}", base.trim_end_matches('/'))
+}
+
+/// What happened to a leftover chaos artifact found by [`Agent::cleanup`].
+///
+/// [`Agent::cleanup`]: crate::agent::Agent::cleanup
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CleanupAction {
+    Removed,
+    /// Dry run: would have been removed.
+    WouldRemove,
+    /// Found but can't be undone automatically (e.g. original values lost).
+    ReportOnly(String),
+    Failed(String),
+}
+
+/// A chaos artifact left behind by a failed or crashed run.
+#[derive(Debug, Clone)]
+pub struct CleanupItem {
+    /// Artifact type, e.g. "rows", "table", "NetworkPolicy", "file".
+    pub kind: String,
+    /// Where it was found, e.g. "public.users.name" or "staging/chaos-deny-1a2b".
+    pub name: String,
+    pub detail: String,
+    pub action: CleanupAction,
+}
+
+impl CleanupItem {
+    pub fn new(
+        kind: impl Into<String>,
+        name: impl Into<String>,
+        detail: impl Into<String>,
+        action: CleanupAction,
+    ) -> Self {
+        Self {
+            kind: kind.into(),
+            name: name.into(),
+            detail: detail.into(),
+            action,
+        }
+    }
+}
+
+impl CleanupAction {
+    /// `Removed` or `Failed` depending on the outcome of a removal.
+    pub fn from_result<E: fmt::Display>(result: Result<(), E>) -> Self {
+        match result {
+            Ok(()) => Self::Removed,
+            Err(e) => Self::Failed(e.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for CleanupAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Removed => write!(f, "removed"),
+            Self::WouldRemove => write!(f, "would remove"),
+            Self::ReportOnly(reason) => write!(f, "left in place: {reason}"),
+            Self::Failed(e) => write!(f, "FAILED: {e}"),
+        }
+    }
+}
@@ -1,30 +1,95 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::Path;
+use std::time::Duration;
 
 use crate::error::{ChaosError, ChaosResult};
-use crate::experiment::ExperimentConfig;
+use crate::experiment::{ExperimentConfig, SkillInvocation};
+use crate::profile::ProfileSet;
+use crate::safety::SafetyConfig;
+use crate::skill::TargetDomain;
 
 /// Top-level config file structure.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ChaosConfig {
     pub experiments: Vec<ExperimentConfig>,
+    /// Durations inherited by experiments that don't set their own.
+    #[serde(default, skip_serializing_if = "DefaultDurations::is_empty")]
+    pub default_durations: DefaultDurations,
+    /// Production-target heuristics checked before anything runs.
+    #[serde(default, skip_serializing_if = "SafetyConfig::is_default")]
+    pub safety: SafetyConfig,
+    /// Named targets experiments can use via `target_ref`.
+    #[serde(default, skip_serializing_if = "TargetSet::is_empty")]
+    pub targets: TargetSet,
+    /// Per-environment guards, picked with `--profile`.
+    #[serde(default, skip_serializing_if = "ProfileSet::is_empty")]
+    pub profiles: ProfileSet,
+}
+
+/// Per-target default soak durations.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DefaultDurations {
+    #[serde(default, with = "crate::duration::option", skip_serializing_if = "Option::is_none")]
+    pub database: Option<Duration>,
+    #[serde(default, with = "crate::duration::option", skip_serializing_if = "Option::is_none")]
+    pub kubernetes: Option<Duration>,
+    #[serde(default, with = "crate::duration::option", skip_serializing_if = "Option::is_none")]
+    pub server: Option<Duration>,
+}
+
+impl DefaultDurations {
+    pub fn for_target(&self, target: TargetDomain) -> Option<Duration> {
+        match target {
+            TargetDomain::Database => self.database,
+            TargetDomain::Kubernetes => self.kubernetes,
+            TargetDomain::Server => self.server,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.database.is_none() && self.kubernetes.is_none() && self.server.is_none()
+    }
+
+    /// Fill in the experiment's duration from its target's default if unset.
+    pub fn apply(&self, experiment: &mut ExperimentConfig) {
+        if experiment.duration.is_none() {
+            experiment.duration = self.for_target(experiment.target);
+        }
+    }
 }
 
 /// Daemon-mode schedule config.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DaemonConfig {
     pub experiments: Vec<ScheduledExperiment>,
+    #[serde(default, skip_serializing_if = "DefaultDurations::is_empty")]
+    pub default_durations: DefaultDurations,
     #[serde(default)]
     pub settings: DaemonSettings,
+    #[serde(default, skip_serializing_if = "SafetyConfig::is_default")]
+    pub safety: SafetyConfig,
+    #[serde(default, skip_serializing_if = "TargetSet::is_empty")]
+    pub targets: TargetSet,
+    #[serde(default, skip_serializing_if = "ProfileSet::is_empty")]
+    pub profiles: ProfileSet,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScheduledExperiment {
     pub experiment: ExperimentConfig,
-    /// Cron expression, e.g. "0 */30 * * * *"
+    /// Cron expression, e.g. "0 */30 * * * *". Optional in chaos monkey mode.
+    #[serde(default)]
     pub schedule: String,
     #[serde(default = "default_true")]
     pub enabled: bool,
+    /// Relative chance of being picked in chaos monkey mode.
+    #[serde(default = "default_weight")]
+    pub weight: f64,
+}
+
+fn default_weight() -> f64 {
+    1.0
 }
 
 fn default_true() -> bool {
@@ -36,6 +101,10 @@ pub struct DaemonSettings {
     #[serde(default = "default_max_concurrent")]
     pub max_concurrent: usize,
     pub health_bind: Option<String>,
+    /// Serve a scheduled run from the report of an identical experiment that
+    /// completed less than this long ago, instead of running it again.
+    #[serde(default, with = "crate::duration::option", skip_serializing_if = "Option::is_none")]
+    pub cache_ttl: Option<Duration>,
 }
 
 impl Default for DaemonSettings {
@@ -43,6 +112,7 @@ impl Default for DaemonSettings {
         Self {
             max_concurrent: default_max_concurrent(),
             health_bind: None,
+            cache_ttl: None,
         }
     }
 }
@@ -51,12 +121,180 @@ fn default_max_concurrent() -> usize {
     2
 }
 
-impl ChaosConfig {
+/// Targets defined once by name, so experiment definitions and LLM prompts
+/// can refer to them (`target_ref`) without carrying connection URLs or
+/// credentials. Read from a `targets:` section or a `--targets-file`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TargetSet(pub BTreeMap<String, NamedTarget>);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NamedTarget {
+    pub target: TargetDomain,
+    pub target_config: serde_yaml::Value,
+    /// What the target is, shown to the LLM planner instead of its config.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// File read by `--targets-file`.
+#[derive(Deserialize)]
+struct TargetsFile {
+    targets: TargetSet,
+}
+
+impl TargetSet {
+    /// Read the `targets:` section of `path`.
+    pub fn from_file(path: &Path) -> ChaosResult<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| ChaosError::Config(format!("Cannot read {}: {e}", path.display())))?;
+        let file: TargetsFile = serde_yaml::from_str(&content)
+            .map_err(|e| ChaosError::Config(format!("Invalid targets file {}: {e}", path.display())))?;
+        Ok(file.targets)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Add `other`'s targets, replacing same-named ones.
+    pub fn extend(&mut self, other: TargetSet) {
+        self.0.extend(other.0);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&NamedTarget> {
+        self.0.get(name)
+    }
+
+    /// `config` on top of the named target's config, checking the ref exists
+    /// and is a `target` target. Without a ref, `config` as is. `owner` names
+    /// what holds the ref, for errors.
+    pub fn resolve_config(
+        &self,
+        owner: &str,
+        target: TargetDomain,
+        target_ref: Option<&str>,
+        config: &serde_yaml::Value,
+    ) -> ChaosResult<serde_yaml::Value> {
+        let Some(name) = target_ref else {
+            return Ok(config.clone());
+        };
+        let named = self.get(name).ok_or_else(|| {
+            let known: Vec<&str> = self.0.keys().map(String::as_str).collect();
+            ChaosError::Config(format!(
+                "{owner} references unknown target '{name}' (known: {})",
+                if known.is_empty() { "none".to_string() } else { known.join(", ") }
+            ))
+        })?;
+        if named.target != target {
+            return Err(ChaosError::Config(format!(
+                "{owner} is a {target} experiment but target '{name}' is {}",
+                named.target
+            )));
+        }
+        let mut resolved = named.target_config.clone();
+        if let (serde_yaml::Value::Mapping(base), serde_yaml::Value::Mapping(overrides)) =
+            (&mut resolved, config)
+        {
+            for (key, value) in overrides {
+                base.insert(key.clone(), value.clone());
+            }
+        }
+        Ok(resolved)
+    }
+
+    /// `experiment` with its `target_ref` resolved into `target_config`.
+    pub fn resolve(&self, experiment: &ExperimentConfig) -> ChaosResult<ExperimentConfig> {
+        let mut resolved = experiment.clone();
+        resolved.target_config = self.resolve_config(
+            &format!("Experiment '{}'", experiment.name),
+            experiment.target,
+            experiment.target_ref.as_deref(),
+            &experiment.target_config,
+        )?;
+        Ok(resolved)
+    }
+
+    pub fn resolve_all<'a>(
+        &self,
+        experiments: impl IntoIterator<Item = &'a ExperimentConfig>,
+    ) -> ChaosResult<Vec<ExperimentConfig>> {
+        experiments.into_iter().map(|e| self.resolve(e)).collect()
+    }
+}
+
+/// Curated set of skills allowed for a run (`--skills-from`), with optional
+/// default params merged into every invocation of that skill.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillsManifest {
+    pub skills: Vec<SkillPreset>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillPreset {
+    pub skill_name: String,
+    /// Default params; keys set on the invocation itself take precedence.
+    #[serde(default)]
+    pub params: serde_yaml::Value,
+}
+
+impl SkillsManifest {
     pub fn from_file(path: &Path) -> ChaosResult<Self> {
         let content = std::fs::read_to_string(path)
             .map_err(|e| ChaosError::Config(format!("Cannot read {}: {e}", path.display())))?;
         serde_yaml::from_str(&content)
-            .map_err(|e| ChaosError::Config(format!("Invalid YAML: {e}")))
+            .map_err(|e| ChaosError::Config(format!("Invalid skills manifest: {e}")))
+    }
+
+    pub fn allows(&self, skill_name: &str) -> bool {
+        self.preset(skill_name).is_some()
+    }
+
+    fn preset(&self, skill_name: &str) -> Option<&SkillPreset> {
+        self.skills.iter().find(|p| p.skill_name == skill_name)
+    }
+
+    /// Reject skills not in the manifest and merge preset params into the rest.
+    pub fn apply(&self, experiment: &mut ExperimentConfig) -> ChaosResult<()> {
+        for invocation in &mut experiment.skills {
+            let preset = self.preset(&invocation.skill_name).ok_or_else(|| {
+                ChaosError::Config(format!(
+                    "Experiment '{}' uses skill '{}' which is not allowed by the skills manifest",
+                    experiment.name, invocation.skill_name
+                ))
+            })?;
+            merge_params(invocation, &preset.params);
+        }
+        Ok(())
+    }
+}
+
+fn merge_params(invocation: &mut SkillInvocation, defaults: &serde_yaml::Value) {
+    match (&mut invocation.params, defaults) {
+        (_, serde_yaml::Value::Null) => {}
+        (params @ serde_yaml::Value::Null, defaults) => *params = defaults.clone(),
+        (serde_yaml::Value::Mapping(params), serde_yaml::Value::Mapping(defaults)) => {
+            for (key, value) in defaults {
+                if !params.contains_key(key) {
+                    params.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+impl ChaosConfig {
+    pub fn from_file(path: &Path) -> ChaosResult<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| ChaosError::Config(format!("Cannot read {}: {e}", path.display())))?;
+        let mut config: Self = serde_yaml::from_str(&content)
+            .map_err(|e| ChaosError::Config(format!("Invalid YAML: {e}")))?;
+        for experiment in &mut config.experiments {
+            experiment.validate()?;
+            config.default_durations.apply(experiment);
+        }
+        Ok(config)
     }
 }
 
@@ -64,7 +302,12 @@ impl DaemonConfig {
     pub fn from_file(path: &Path) -> ChaosResult<Self> {
         let content = std::fs::read_to_string(path)
             .map_err(|e| ChaosError::Config(format!("Cannot read {}: {e}", path.display())))?;
-        serde_yaml::from_str(&content)
-            .map_err(|e| ChaosError::Config(format!("Invalid YAML: {e}")))
+        let mut config: Self = serde_yaml::from_str(&content)
+            .map_err(|e| ChaosError::Config(format!("Invalid YAML: {e}")))?;
+        for scheduled in &mut config.experiments {
+            scheduled.experiment.validate()?;
+            config.default_durations.apply(&mut scheduled.experiment);
+        }
+        Ok(config)
     }
 }
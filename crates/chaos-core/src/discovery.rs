@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use crate::error::{ChaosError, ChaosResult};
 use crate::skill::TargetDomain;
 
 /// A resource discovered on a target.
@@ -9,6 +10,55 @@ pub trait DiscoveredResource: Send + Sync + fmt::Debug {
     fn resource_type(&self) -> &str;
     fn name(&self) -> &str;
     fn metadata(&self) -> serde_yaml::Value;
+    /// Identifies the resource across discoveries of the same target.
+    /// Defaults to [`name`](Self::name); override when names can repeat
+    /// (e.g. across schemas, namespaces or hosts).
+    fn key(&self) -> String {
+        self.name().to_string()
+    }
+}
+
+/// What a discovery found, and the parts of the target it couldn't see,
+/// such as one unreachable host among many. Callers go on with `resources`
+/// and surface `errors`.
+#[derive(Debug, Default)]
+pub struct Discovery {
+    pub resources: Vec<Box<dyn DiscoveredResource>>,
+    pub errors: Vec<DiscoveryError>,
+}
+
+impl Discovery {
+    /// A discovery that saw the whole target.
+    pub fn complete(resources: Vec<Box<dyn DiscoveredResource>>) -> Self {
+        Self {
+            resources,
+            errors: Vec::new(),
+        }
+    }
+
+    /// `self`, unless parts failed and nothing at all was found: then the
+    /// discovery as a whole failed, with every part's error.
+    pub fn into_result(self) -> ChaosResult<Self> {
+        if self.resources.is_empty() && !self.errors.is_empty() {
+            let errors: Vec<String> = self.errors.iter().map(ToString::to_string).collect();
+            return Err(ChaosError::Discovery(errors.join("; ")));
+        }
+        Ok(self)
+    }
+}
+
+/// A part of the target that discovery couldn't see.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscoveryError {
+    /// What wasn't discovered, e.g. a host, a table or a database.
+    pub scope: String,
+    pub error: String,
+}
+
+impl fmt::Display for DiscoveryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.scope, self.error)
+    }
 }
 
 /// Concrete resource for database targets.
@@ -33,6 +83,9 @@ impl DiscoveredResource for DbResource {
     fn metadata(&self) -> serde_yaml::Value {
         serde_yaml::to_value(self).unwrap_or(serde_yaml::Value::Null)
     }
+    fn key(&self) -> String {
+        format!("{}.{}", self.schema, self.table_name)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -64,6 +117,9 @@ impl DiscoveredResource for MongoResource {
     fn metadata(&self) -> serde_yaml::Value {
         serde_yaml::to_value(self).unwrap_or(serde_yaml::Value::Null)
     }
+    fn key(&self) -> String {
+        format!("{}.{}", self.database, self.collection)
+    }
 }
 
 /// Concrete resource for Kubernetes targets.
@@ -88,6 +144,9 @@ impl DiscoveredResource for K8sResource {
     fn metadata(&self) -> serde_yaml::Value {
         serde_yaml::to_value(self).unwrap_or(serde_yaml::Value::Null)
     }
+    fn key(&self) -> String {
+        format!("{}/{}", self.namespace, self.name)
+    }
 }
 
 /// Concrete resource for server targets.
@@ -125,4 +184,7 @@ impl DiscoveredResource for ServerResource {
     fn metadata(&self) -> serde_yaml::Value {
         serde_yaml::to_value(self).unwrap_or(serde_yaml::Value::Null)
     }
+    fn key(&self) -> String {
+        format!("{}/{}", self.host, self.name)
+    }
 }
@@ -0,0 +1,251 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::error::{ChaosError, ChaosResult};
+
+/// Parse a human duration string such as `30s`, `5m`, `1h30m` or `2d 12h`.
+///
+/// Supported units are `ms`, `s`, `m`, `h` and `d` (plus `us` and `ns`, which
+/// [`serialize`] writes for sub-millisecond values). Components may be
+/// combined and separated by whitespace; every number must carry a unit.
+pub fn parse_duration(input: &str) -> ChaosResult<Duration> {
+    parse_str(input).map_err(ChaosError::Config)
+}
+
+fn parse_str(input: &str) -> Result<Duration, String> {
+    let invalid = |reason: String| format!("Invalid duration '{input}': {reason}");
+
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(invalid("duration is empty (expected e.g. 30s, 5m, 1h30m)".into()));
+    }
+
+    let mut total = Duration::ZERO;
+    let mut chars = trimmed.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        let mut digits = String::new();
+        while let Some(&d) = chars.peek() {
+            if !d.is_ascii_digit() {
+                break;
+            }
+            digits.push(d);
+            chars.next();
+        }
+        if digits.is_empty() {
+            return Err(invalid(format!("expected a number before '{c}'")));
+        }
+
+        let mut unit = String::new();
+        while let Some(&u) = chars.peek() {
+            if !u.is_alphabetic() {
+                break;
+            }
+            unit.push(u);
+            chars.next();
+        }
+
+        let value: u64 = digits
+            .parse()
+            .map_err(|_| invalid(format!("number '{digits}' is too large")))?;
+        let part = match unit.as_str() {
+            "ns" => Some(Duration::from_nanos(value)),
+            "us" => Some(Duration::from_micros(value)),
+            "ms" => Some(Duration::from_millis(value)),
+            "s" => Some(Duration::from_secs(value)),
+            "m" => value.checked_mul(60).map(Duration::from_secs),
+            "h" => value.checked_mul(3600).map(Duration::from_secs),
+            "d" => value.checked_mul(86_400).map(Duration::from_secs),
+            "" => return Err(invalid(format!("missing unit after '{digits}' (use ms, s, m, h or d)"))),
+            other => {
+                return Err(invalid(format!(
+                    "unknown unit '{other}' (use ms, s, m, h or d)"
+                )))
+            }
+        };
+        total = part
+            .and_then(|part| total.checked_add(part))
+            .ok_or_else(|| invalid("duration is too large".into()))?;
+    }
+
+    Ok(total)
+}
+
+/// Config form written by [`serialize`], e.g. `1h 30m` or `1s 500ms`, which
+/// [`parse_duration`] reads back exactly.
+fn to_config_string(d: Duration) -> String {
+    if d.is_zero() {
+        return "0s".into();
+    }
+    let secs = d.as_secs();
+    let nanos = d.subsec_nanos();
+    let parts = [
+        (secs / 86_400, "d"),
+        (secs % 86_400 / 3600, "h"),
+        (secs % 3600 / 60, "m"),
+        (secs % 60, "s"),
+        (u64::from(nanos / 1_000_000), "ms"),
+        (u64::from(nanos / 1000 % 1000), "us"),
+        (u64::from(nanos % 1000), "ns"),
+    ];
+    parts
+        .iter()
+        .filter(|(value, _)| *value > 0)
+        .map(|(value, unit)| format!("{value}{unit}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Display form used for every duration shown to people: `850ms`, `5.2s`,
+/// `1m 30s`, `2h 5m 0s`. Sub-second precision is dropped from a minute up.
+pub fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    if secs >= 3600 {
+        format!("{}h {}m {}s", secs / 3600, secs % 3600 / 60, secs % 60)
+    } else if secs >= 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else if secs > 0 {
+        match d.subsec_millis() / 100 {
+            0 => format!("{secs}s"),
+            tenths => format!("{secs}.{tenths}s"),
+        }
+    } else if d.is_zero() {
+        "0s".into()
+    } else {
+        format!("{}ms", d.as_millis())
+    }
+}
+
+/// Serde adapter for `#[serde(with = "chaos_core::duration")]` fields.
+///
+/// Deserializes through [`parse_duration`] so malformed values produce a
+/// readable error; serializes in a form it reads back.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let raw = String::deserialize(deserializer)?;
+    parse_str(&raw).map_err(serde::de::Error::custom)
+}
+
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&to_config_string(*duration))
+}
+
+/// Serde adapter for `Option<Duration>` fields, used as
+/// `#[serde(default, with = "chaos_core::duration::option")]`.
+pub mod option {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|raw| super::parse_str(&raw).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+
+    pub fn serialize<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match duration {
+            Some(d) => serializer.serialize_some(&super::to_config_string(*d)),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Config {
+        #[serde(with = "crate::duration")]
+        duration: Duration,
+        #[serde(default, with = "crate::duration::option")]
+        timeout: Option<Duration>,
+    }
+
+    #[test]
+    fn parses_single_units() {
+        assert_eq!(parse_duration("250ms").unwrap(), Duration::from_millis(250));
+        assert_eq!(parse_duration("30s").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse_duration("5m").unwrap(), Duration::from_secs(300));
+        assert_eq!(parse_duration("2h").unwrap(), Duration::from_secs(7200));
+        assert_eq!(parse_duration("1d").unwrap(), Duration::from_secs(86_400));
+    }
+
+    #[test]
+    fn parses_combined_forms_with_whitespace() {
+        assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5400));
+        assert_eq!(parse_duration("2d 12h").unwrap(), Duration::from_secs(216_000));
+        assert_eq!(parse_duration("  1m 30s  ").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("1s 500ms").unwrap(), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("   ").is_err());
+    }
+
+    #[test]
+    fn rejects_missing_unit() {
+        let err = parse_duration("5").unwrap_err().to_string();
+        assert!(err.contains("missing unit"), "{err}");
+        assert!(parse_duration("1h 5").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_unit() {
+        let err = parse_duration("5min").unwrap_err().to_string();
+        assert!(err.contains("unknown unit 'min'"), "{err}");
+        assert!(parse_duration("h").is_err());
+        assert!(parse_duration("-5s").is_err());
+    }
+
+    #[test]
+    fn rejects_overflow() {
+        assert!(parse_duration("99999999999999999999s").is_err());
+        assert!(parse_duration(&format!("{}d", u64::MAX / 86_400 + 1)).is_err());
+        assert!(parse_duration(&format!("{}s {}s", u64::MAX, 1)).is_err());
+    }
+
+    #[test]
+    fn round_trips_through_serde() {
+        for duration in [
+            Duration::ZERO,
+            Duration::from_millis(1500),
+            Duration::from_secs(5400),
+            Duration::from_secs(216_000),
+            Duration::new(90, 1_001),
+        ] {
+            let config = Config {
+                duration,
+                timeout: Some(duration),
+            };
+            let yaml = serde_yaml::to_string(&config).unwrap();
+            assert_eq!(serde_yaml::from_str::<Config>(&yaml).unwrap(), config, "{yaml}");
+        }
+
+        let config = Config {
+            duration: Duration::from_secs(60),
+            timeout: None,
+        };
+        let yaml = serde_yaml::to_string(&config).unwrap();
+        assert_eq!(serde_yaml::from_str::<Config>(&yaml).unwrap(), config);
+    }
+}
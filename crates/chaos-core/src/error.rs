@@ -25,7 +25,13 @@ pub enum ChaosError {
     #[error("Discovery failed: {0}")]
     Discovery(String),
 
-    #[error("Experiment timeout after {0:?}")]
+    #[error("Target unhealthy: {0}")]
+    Unhealthy(String),
+
+    #[error("Experiment blocked: {0}")]
+    Blocked(String),
+
+    #[error("Experiment timeout after {}", crate::duration::format_duration(*.0))]
     Timeout(std::time::Duration),
 
     #[error(transparent)]
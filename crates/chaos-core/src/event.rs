@@ -1,8 +1,14 @@
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 use uuid::Uuid;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
 pub enum ExperimentEvent {
     Started {
         experiment_id: Uuid,
@@ -15,8 +21,21 @@ pub enum ExperimentEvent {
     },
     DurationWaitBegin {
         experiment_id: Uuid,
+        #[serde(with = "humantime_serde")]
         duration: std::time::Duration,
     },
+    /// The target's health check failed during an `until_recovered` soak.
+    Degraded {
+        experiment_id: Uuid,
+        error: String,
+    },
+    /// The target's health check passed again during an `until_recovered`
+    /// soak; rollback starts now.
+    Recovered {
+        experiment_id: Uuid,
+        #[serde(with = "humantime_serde")]
+        time_to_recovery: std::time::Duration,
+    },
     RollbackStarted {
         experiment_id: Uuid,
     },
@@ -33,6 +52,18 @@ pub enum ExperimentEvent {
         experiment_id: Uuid,
         error: String,
     },
+    /// A run was not started, e.g. because a previous run of the same
+    /// experiment is still in flight.
+    Skipped {
+        experiment_name: String,
+        reason: String,
+    },
+    /// A run was refused before touching the target, e.g. because it uses
+    /// irreversible skills without `allow_irreversible`.
+    Blocked {
+        experiment_name: String,
+        reason: String,
+    },
 }
 
 /// Sink for experiment events.
@@ -69,3 +100,41 @@ impl EventSink for TracingEventSink {
         tracing::info!(?event, "experiment_event");
     }
 }
+
+/// Event sink that appends each event as a JSON line to a file, stamped with
+/// the time it was emitted.
+pub struct JsonlEventSink {
+    file: Mutex<std::fs::File>,
+}
+
+impl JsonlEventSink {
+    /// Write to `path`, truncating it.
+    pub fn create(path: &Path) -> std::io::Result<Self> {
+        Ok(Self {
+            file: Mutex::new(std::fs::File::create(path)?),
+        })
+    }
+}
+
+#[async_trait]
+impl EventSink for JsonlEventSink {
+    async fn emit(&self, event: ExperimentEvent) {
+        #[derive(Serialize)]
+        struct Line<'a> {
+            emitted_at: DateTime<Utc>,
+            #[serde(flatten)]
+            event: &'a ExperimentEvent,
+        }
+        let line = match serde_json::to_string(&Line { emitted_at: Utc::now(), event: &event }) {
+            Ok(line) => line,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to encode experiment event");
+                return;
+            }
+        };
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err(e) = writeln!(file, "{line}") {
+            tracing::warn!(error = %e, "Failed to write experiment event");
+        }
+    }
+}
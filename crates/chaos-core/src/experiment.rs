@@ -3,26 +3,117 @@ use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use uuid::Uuid;
 
+use crate::error::{ChaosError, ChaosResult};
 use crate::rollback::RollbackLog;
+use crate::safety::SafetyLimits;
 use crate::skill::TargetDomain;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExperimentConfig {
     pub name: String,
     pub target: TargetDomain,
+    /// Name of a configured target (see [`crate::config::TargetSet`]) whose
+    /// config this experiment uses; keys in `target_config` override it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_ref: Option<String>,
     /// Target-specific connection/auth config (parsed by the agent).
+    #[serde(default, skip_serializing_if = "serde_yaml::Value::is_null")]
     pub target_config: serde_yaml::Value,
     /// Which skills to run and their parameters.
     pub skills: Vec<SkillInvocation>,
     /// How long to let the chaos run before triggering rollback.
-    #[serde(with = "humantime_serde")]
-    pub duration: Duration,
+    /// When omitted, the config's per-target default applies.
+    #[serde(
+        default,
+        with = "crate::duration::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub duration: Option<Duration>,
+    /// How the soak period ends: after the full `duration`, or as soon as the
+    /// target recovers.
+    #[serde(default, skip_serializing_if = "SoakMode::is_fixed")]
+    pub soak: SoakMode,
+    /// How often to run the health check during an `until_recovered` soak.
+    /// Defaults to 5s.
+    #[serde(
+        default,
+        with = "crate::duration::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub health_poll_interval: Option<Duration>,
     /// Whether to run skills in parallel or sequentially.
     #[serde(default)]
     pub parallel: bool,
     /// Only target discovered resources matching these regex patterns.
     #[serde(default)]
     pub resource_filters: Vec<String>,
+    /// Run skills that can't be rolled back (`reversible: false`). Without it
+    /// (or the orchestrator-wide override), such experiments are blocked.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_irreversible: bool,
+    /// Shell command run locally (`sh -c`) before the experiment starts. A
+    /// non-zero exit aborts the experiment before any chaos is applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pre_hook: Option<String>,
+    /// Shell command run locally after rollback, whatever the outcome. Its
+    /// exit status is reported but doesn't change the experiment's status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_hook: Option<String>,
+    /// How long either hook may run before it's killed and counted as
+    /// failed. Defaults to 5m.
+    #[serde(
+        default,
+        with = "crate::duration::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub hook_timeout: Option<Duration>,
+    /// Run every skill in dry-run mode: skills read the target and report
+    /// what they would change, nothing is changed, and there is no soak or
+    /// rollback.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+    /// Caps on the pods, tables and rows each skill invocation may touch;
+    /// the orchestrator's own limits apply too, and the tighter one wins.
+    #[serde(default, skip_serializing_if = "SafetyLimits::is_empty")]
+    pub limits: SafetyLimits,
+}
+
+/// How the soak period between execution and rollback ends.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SoakMode {
+    /// Wait for the full `duration`.
+    #[default]
+    Fixed,
+    /// Poll the target's health check and roll back once it passes again
+    /// after having failed, or when `duration` runs out. A target that never
+    /// degrades soaks for the full `duration`.
+    UntilRecovered,
+}
+
+impl ExperimentConfig {
+    /// Checks serde can't express, run when a config is loaded.
+    pub fn validate(&self) -> ChaosResult<()> {
+        if self.health_poll_interval.is_some_and(|d| d.is_zero()) {
+            return Err(ChaosError::Config(format!(
+                "'{}': health_poll_interval must be greater than 0",
+                self.name
+            )));
+        }
+        if let Some(skill) = self.skills.iter().find(|s| s.count == 0) {
+            return Err(ChaosError::Config(format!(
+                "'{}': {} count must be at least 1",
+                self.name, skill.skill_name
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl SoakMode {
+    pub fn is_fixed(&self) -> bool {
+        *self == SoakMode::Fixed
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,6 +123,9 @@ pub struct SkillInvocation {
     pub params: serde_yaml::Value,
     #[serde(default = "default_count")]
     pub count: u32,
+    /// Overrides the skill's [`rollback_priority`](crate::skill::Skill::rollback_priority).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollback_priority: Option<i32>,
 }
 
 fn default_count() -> u32 {
@@ -46,6 +140,11 @@ pub enum ExperimentStatus {
     WaitingDuration,
     RollingBack,
     Completed,
+    /// Cancelled by the user: remaining skills and the soak were skipped,
+    /// and whatever had run was rolled back.
+    Cancelled,
+    /// Discovery found nothing for the experiment's skills to act on.
+    SkippedNoResources,
     Failed(String),
 }
 
@@ -0,0 +1,85 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::error::{ChaosError, ChaosResult};
+use crate::report::ExperimentReport;
+
+/// Environment variable overriding where run history is kept.
+pub const HISTORY_ENV: &str = "CHAOS_HISTORY";
+
+/// Append-only log of experiment reports, one JSON document per line.
+#[derive(Debug, Clone)]
+pub struct HistoryStore {
+    path: PathBuf,
+}
+
+impl HistoryStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// `$CHAOS_HISTORY`, else `~/.chaos-agents/history.jsonl`. `None` when
+    /// neither is available, or when `CHAOS_HISTORY` is set to an empty string
+    /// to turn history off.
+    pub fn from_env() -> Option<Self> {
+        match std::env::var(HISTORY_ENV) {
+            Ok(path) if path.is_empty() => None,
+            Ok(path) => Some(Self::new(path)),
+            Err(_) => {
+                let home = std::env::var("HOME").ok()?;
+                Some(Self::new(
+                    Path::new(&home).join(".chaos-agents").join("history.jsonl"),
+                ))
+            }
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn append(&self, report: &ExperimentReport) -> ChaosResult<()> {
+        if let Some(dir) = self.path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir).map_err(|e| self.io_error(e))?;
+        }
+        let line = serde_json::to_string(report)
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Failed to encode report: {e}")))?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| self.io_error(e))?;
+        writeln!(file, "{line}").map_err(|e| self.io_error(e))
+    }
+
+    /// Every report recorded so far, oldest first. A missing file is an empty
+    /// history; lines that don't parse (e.g. a write cut short) are skipped.
+    pub fn load(&self) -> ChaosResult<Vec<ExperimentReport>> {
+        let text = match std::fs::read_to_string(&self.path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(self.io_error(e)),
+        };
+
+        let mut reports = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match serde_json::from_str(line) {
+                Ok(report) => reports.push(report),
+                Err(e) => tracing::warn!(
+                    path = %self.path.display(),
+                    line = i + 1,
+                    error = %e,
+                    "Skipping unreadable history entry"
+                ),
+            }
+        }
+        Ok(reports)
+    }
+
+    fn io_error(&self, e: std::io::Error) -> ChaosError {
+        ChaosError::Other(anyhow::anyhow!("History file {}: {e}", self.path.display()))
+    }
+}
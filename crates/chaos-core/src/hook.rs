@@ -0,0 +1,96 @@
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+
+use uuid::Uuid;
+
+use crate::experiment::ExperimentConfig;
+use crate::report::{HookRecord, HookStage};
+
+/// Lines of hook output kept in the report.
+const OUTPUT_TAIL_LINES: usize = 20;
+
+/// How long a hook may run when the experiment sets no `hook_timeout`.
+pub const DEFAULT_HOOK_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Run `command` with `sh -c` on the machine running chaos, wait for it (up
+/// to the experiment's `hook_timeout`, then kill it) and record how it went.
+/// The experiment's metadata is passed as environment
+/// variables:
+///
+/// - `CHAOS_HOOK`: `pre` or `post`
+/// - `CHAOS_EXPERIMENT_ID`, `CHAOS_EXPERIMENT_NAME`
+/// - `CHAOS_TARGET`: `database`, `kubernetes` or `server`
+/// - `CHAOS_TARGET_REF`: the named target, if any
+/// - `CHAOS_DURATION_SECS`: the configured soak duration
+/// - `CHAOS_SKILLS`: comma-separated skill names
+/// - `CHAOS_STATUS`: the experiment's final status (post hooks only)
+pub async fn run_hook(
+    stage: HookStage,
+    command: &str,
+    config: &ExperimentConfig,
+    experiment_id: Uuid,
+    status: Option<&str>,
+) -> HookRecord {
+    let skills: Vec<&str> = config.skills.iter().map(|s| s.skill_name.as_str()).collect();
+    let mut cmd = tokio::process::Command::new("sh");
+    cmd.arg("-c")
+        .arg(command)
+        .env(
+            "CHAOS_HOOK",
+            match stage {
+                HookStage::Pre => "pre",
+                HookStage::Post => "post",
+            },
+        )
+        .env("CHAOS_EXPERIMENT_ID", experiment_id.to_string())
+        .env("CHAOS_EXPERIMENT_NAME", &config.name)
+        .env("CHAOS_TARGET", config.target.to_string())
+        .env("CHAOS_TARGET_REF", config.target_ref.as_deref().unwrap_or_default())
+        .env(
+            "CHAOS_DURATION_SECS",
+            config.duration.map(|d| d.as_secs().to_string()).unwrap_or_default(),
+        )
+        .env("CHAOS_SKILLS", skills.join(","))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    if let Some(status) = status {
+        cmd.env("CHAOS_STATUS", status);
+    }
+
+    tracing::info!(hook = %stage, command, experiment = %config.name, "Running hook");
+    let start = Instant::now();
+    let timeout = config.hook_timeout.unwrap_or(DEFAULT_HOOK_TIMEOUT);
+    // Dropping the timed-out future kills the child
+    let (exit_code, success, output) = match tokio::time::timeout(timeout, cmd.output()).await {
+        Ok(Ok(out)) => {
+            let mut combined = String::from_utf8_lossy(&out.stdout).into_owned();
+            combined.push_str(&String::from_utf8_lossy(&out.stderr));
+            (out.status.code(), out.status.success(), tail(&combined))
+        }
+        Ok(Err(e)) => (None, false, format!("failed to start: {e}")),
+        Err(_) => (
+            None,
+            false,
+            format!("timed out after {}, killed", crate::duration::format_duration(timeout)),
+        ),
+    };
+    let record = HookRecord {
+        stage,
+        command: command.to_string(),
+        exit_code,
+        success,
+        duration: start.elapsed(),
+        output,
+    };
+    if !record.success {
+        tracing::warn!(hook = %stage, exit_code = ?record.exit_code, output = %record.output, "Hook failed");
+    }
+    record
+}
+
+fn tail(output: &str) -> String {
+    let lines: Vec<&str> = output.lines().collect();
+    lines[lines.len().saturating_sub(OUTPUT_TAIL_LINES)..].join("\n")
+}
@@ -1,10 +1,21 @@
 pub mod agent;
+pub mod blast_radius;
+pub mod cleanup;
 pub mod config;
 pub mod discovery;
+pub mod duration;
 pub mod error;
 pub mod event;
 pub mod experiment;
+pub mod history;
+pub mod name_pattern;
+pub mod hook;
 pub mod orchestrator;
+pub mod profile;
+pub mod redact;
 pub mod report;
 pub mod rollback;
+pub mod safety;
 pub mod skill;
+pub mod skill_registry;
+pub mod target_config;
@@ -0,0 +1,142 @@
+//! Names in skill params that pick out many tables at once: globs such as
+//! `orders_*` and regexes such as `re:^orders_\d{6}$`.
+
+use regex::Regex;
+
+use crate::error::{ChaosError, ChaosResult};
+
+const REGEX_PREFIX: &str = "re:";
+
+/// Whether `name` is a regex, which is never schema-qualified.
+pub fn is_regex(name: &str) -> bool {
+    name.starts_with(REGEX_PREFIX)
+}
+
+/// Whether `name` is a glob or regex rather than a literal name.
+pub fn is_pattern(name: &str) -> bool {
+    is_regex(name) || name.contains(['*', '?', '['])
+}
+
+/// A glob or `re:` regex, matched against whole names.
+#[derive(Debug, Clone)]
+pub struct NamePattern(Regex);
+
+impl NamePattern {
+    pub fn parse(pattern: &str) -> ChaosResult<Self> {
+        let source = match pattern.strip_prefix(REGEX_PREFIX) {
+            Some(regex) => format!("^(?:{regex})$"),
+            None => glob_to_regex(pattern),
+        };
+        Regex::new(&source)
+            .map(Self)
+            .map_err(|e| ChaosError::Config(format!("Invalid name pattern '{pattern}': {e}")))
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        self.0.is_match(name)
+    }
+}
+
+/// `*` matches any run of characters, `?` any one, `[abc]` or `[a-z]` one
+/// of a set and `[!abc]` one not in it.
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            '[' => {
+                regex.push('[');
+                if chars.next_if_eq(&'!').is_some() {
+                    regex.push('^');
+                }
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                    if matches!(c, '\\' | '[' | '&' | '~') {
+                        regex.push('\\');
+                    }
+                    regex.push(c);
+                }
+                regex.push(']');
+            }
+            c => regex.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_is_anchored() {
+        assert_eq!(glob_to_regex("orders"), "^orders$");
+        let pattern = NamePattern::parse("orders_*").unwrap();
+        assert!(pattern.matches("orders_2024"));
+        assert!(pattern.matches("orders_"));
+        assert!(!pattern.matches("old_orders_2024"));
+        assert!(!pattern.matches("orders"));
+    }
+
+    #[test]
+    fn glob_wildcards() {
+        let pattern = NamePattern::parse("t?_*").unwrap();
+        assert!(pattern.matches("t1_users"));
+        assert!(!pattern.matches("t12_users"));
+    }
+
+    #[test]
+    fn glob_sets_and_negated_sets() {
+        let set = NamePattern::parse("shard_[0-2]").unwrap();
+        assert!(set.matches("shard_1"));
+        assert!(!set.matches("shard_3"));
+
+        assert_eq!(glob_to_regex("shard_[!0-2]"), "^shard_[^0-2]$");
+        let negated = NamePattern::parse("shard_[!0-2]").unwrap();
+        assert!(negated.matches("shard_3"));
+        assert!(!negated.matches("shard_1"));
+    }
+
+    #[test]
+    fn glob_escapes_regex_metacharacters() {
+        let pattern = NamePattern::parse("a.b+c(d)*").unwrap();
+        assert!(pattern.matches("a.b+c(d)_x"));
+        assert!(!pattern.matches("aXb+c(d)_x"));
+        assert!(!pattern.matches("abbc(d)"));
+
+        // Set members that mean something in a regex class stay literal
+        let set = NamePattern::parse("x[\\&]").unwrap();
+        assert!(set.matches("x&"));
+        assert!(set.matches("x\\"));
+        assert!(!set.matches("xa"));
+    }
+
+    #[test]
+    fn regex_patterns_are_anchored() {
+        assert!(is_regex("re:^a$"));
+        let pattern = NamePattern::parse("re:orders_\\d{4}").unwrap();
+        assert!(pattern.matches("orders_2024"));
+        assert!(!pattern.matches("orders_2024_old"));
+        assert!(!pattern.matches("x_orders_2024"));
+    }
+
+    #[test]
+    fn invalid_patterns_are_config_errors() {
+        assert!(matches!(NamePattern::parse("re:orders_("), Err(ChaosError::Config(_))));
+        assert!(matches!(NamePattern::parse("orders_[z-a]"), Err(ChaosError::Config(_))));
+    }
+
+    #[test]
+    fn detects_patterns() {
+        assert!(is_pattern("orders_*"));
+        assert!(is_pattern("t?"));
+        assert!(is_pattern("shard_[12]"));
+        assert!(is_pattern("re:x"));
+        assert!(!is_pattern("public.orders"));
+    }
+}
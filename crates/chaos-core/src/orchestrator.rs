@@ -1,24 +1,39 @@
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
 use crate::agent::Agent;
+use crate::config::DefaultDurations;
+use crate::discovery::{DiscoveredResource, DiscoveryError};
+use crate::duration::format_duration;
 use crate::error::{ChaosError, ChaosResult};
 use crate::event::{EventSink, ExperimentEvent};
-use crate::experiment::{Experiment, ExperimentConfig, ExperimentStatus};
+use crate::experiment::{Experiment, ExperimentConfig, ExperimentStatus, SoakMode};
+use crate::history::HistoryStore;
+use crate::hook::run_hook;
+use crate::profile::Profile;
+use crate::safety::SafetyLimits;
 use crate::report::{
-    DiscoveredResourceSummary, ExperimentReport, RollbackStepRecord, SkillExecutionRecord,
+    DiscoveredResourceSummary, ExperimentReport, HookRecord, HookStage, RecoveryRecord,
+    ResourceDiff, RollbackStepRecord, SkillExecutionRecord,
 };
-use crate::skill::TargetDomain;
+use crate::skill::{ParsedParams, SkillContext, TargetDomain};
+
+/// Health check interval of `until_recovered` soaks without an explicit one.
+const DEFAULT_HEALTH_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
 
 pub struct Orchestrator {
     agents: HashMap<TargetDomain, Arc<RwLock<Box<dyn Agent>>>>,
     experiments: Arc<RwLock<HashMap<Uuid, Experiment>>>,
     event_sinks: Vec<Arc<dyn EventSink>>,
-    cancelled: Arc<AtomicBool>,
+    cancel: CancellationToken,
+    history: Option<HistoryStore>,
+    allow_irreversible: bool,
+    profile: Option<Profile>,
+    limits: SafetyLimits,
 }
 
 impl Orchestrator {
@@ -27,14 +42,18 @@ impl Orchestrator {
             agents: HashMap::new(),
             experiments: Arc::new(RwLock::new(HashMap::new())),
             event_sinks: Vec::new(),
-            cancelled: Arc::new(AtomicBool::new(false)),
+            cancel: CancellationToken::new(),
+            history: None,
+            allow_irreversible: false,
+            profile: None,
+            limits: SafetyLimits::default(),
         }
     }
 
-    /// Returns a shared cancellation flag. Set it to `true` to cancel running experiments.
+    /// Returns the token that cancels running experiments.
     /// Cancelled experiments skip remaining skills and soak period, but still run rollback.
-    pub fn cancel_flag(&self) -> Arc<AtomicBool> {
-        self.cancelled.clone()
+    pub fn cancel_token(&self) -> CancellationToken {
+        self.cancel.clone()
     }
 
     pub fn register_agent(&mut self, agent: Box<dyn Agent>) {
@@ -42,6 +61,28 @@ impl Orchestrator {
         self.agents.insert(domain, Arc::new(RwLock::new(agent)));
     }
 
+    /// Append every finished experiment's report to `history`.
+    pub fn set_history(&mut self, history: HistoryStore) {
+        self.history = Some(history);
+    }
+
+    /// Run irreversible skills even in experiments without `allow_irreversible`
+    /// (`--allow-irreversible`).
+    pub fn set_allow_irreversible(&mut self, allow: bool) {
+        self.allow_irreversible = allow;
+    }
+
+    /// Block experiments that break `profile`'s guards (`--profile`).
+    pub fn set_profile(&mut self, profile: Profile) {
+        self.profile = Some(profile);
+    }
+
+    /// Blast-radius caps for every experiment (`--max-pods` etc.), combined
+    /// with each experiment's own `limits`; the tighter one wins.
+    pub fn set_limits(&mut self, limits: SafetyLimits) {
+        self.limits = limits;
+    }
+
     pub fn add_event_sink(&mut self, sink: Arc<dyn EventSink>) {
         self.event_sinks.push(sink);
     }
@@ -52,11 +93,38 @@ impl Orchestrator {
         }
     }
 
+    /// Initialize every registered agent and run its health check, so no chaos
+    /// starts while a target is already down. Fails listing every unhealthy target.
+    pub async fn ensure_targets_healthy(&self) -> ChaosResult<()> {
+        let mut domains: Vec<_> = self.agents.keys().copied().collect();
+        domains.sort_by_key(|d| d.to_string());
+
+        let mut problems = Vec::new();
+        for domain in domains {
+            let mut agent = self.agents[&domain].write().await;
+            let result = match agent.initialize().await {
+                Ok(()) => agent.health_check().await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(()) => tracing::info!(target = %domain, "Target healthy"),
+                Err(e) => problems.push(format!("{domain} ({}): {e}", agent.name())),
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ChaosError::Unhealthy(problems.join("; ")))
+        }
+    }
+
     /// Run a single experiment to completion (execute -> wait duration -> rollback).
     pub async fn run_experiment(
         &self,
-        config: ExperimentConfig,
+        mut config: ExperimentConfig,
     ) -> ChaosResult<ExperimentReport> {
+        config.limits = config.limits.tightest(self.limits);
         let agent_lock = self
             .agents
             .get(&config.target)
@@ -65,7 +133,44 @@ impl Orchestrator {
             })?
             .clone();
 
-        let mut experiment = Experiment::new(config.clone());
+        let duration = config.duration.ok_or_else(|| {
+            ChaosError::Config(format!(
+                "Experiment '{}' has no duration and no default is configured for target {}",
+                config.name, config.target
+            ))
+        })?;
+
+        if let Some(ref profile) = self.profile {
+            // The duration is resolved by now, so no defaults apply
+            if let Err(reason) = profile.check(&config, &DefaultDurations::default()) {
+                return Err(self.block(&config, reason).await);
+            }
+        }
+
+        let profile_irreversible = self.profile.as_ref().and_then(|p| p.allow_irreversible);
+        let allow_irreversible = profile_irreversible
+            .unwrap_or(config.allow_irreversible || self.allow_irreversible);
+        // A dry run changes nothing, so it has nothing it can't roll back
+        if !allow_irreversible && !config.dry_run {
+            let irreversible = Self::irreversible_skills(&agent_lock, &config).await;
+            if !irreversible.is_empty() {
+                let reason = match self.profile {
+                    Some(ref profile) if profile_irreversible == Some(false) => format!(
+                        "{} cannot be rolled back and profile '{}' does not allow irreversible skills",
+                        irreversible.join(", "),
+                        profile.name
+                    ),
+                    _ => format!(
+                        "{} cannot be rolled back; back up the target, then set \
+                         `allow_irreversible: true` on the experiment or pass --allow-irreversible",
+                        irreversible.join(", ")
+                    ),
+                };
+                return Err(self.block(&config, reason).await);
+            }
+        }
+
+        let experiment = Experiment::new(config.clone());
         let experiment_id = experiment.id;
 
         self.emit(ExperimentEvent::Started {
@@ -74,6 +179,108 @@ impl Orchestrator {
         })
         .await;
 
+        // Hooks surround real chaos; a dry run skips them
+        let mut hooks = Vec::new();
+        if let Some(command) = config.pre_hook.as_ref().filter(|_| !config.dry_run) {
+            let record = run_hook(HookStage::Pre, command, &config, experiment_id, None).await;
+            let passed = record.success;
+            hooks.push(record);
+            if !passed {
+                let report = self.abort_experiment(&config, experiment_id, hooks).await;
+                self.record_history(&report);
+                return Ok(report);
+            }
+        }
+
+        let result = self
+            .run_phases(&agent_lock, &config, experiment, duration)
+            .await;
+
+        if let Some(command) = config.post_hook.as_ref().filter(|_| !config.dry_run) {
+            let status = match result {
+                Ok(ref report) => report.status.clone(),
+                Err(ref e) => format!("error: {e}"),
+            };
+            hooks.push(run_hook(HookStage::Post, command, &config, experiment_id, Some(&status)).await);
+        }
+
+        let mut report = result?;
+        report.hooks = hooks;
+        if !config.dry_run {
+            self.record_history(&report);
+        }
+        Ok(report)
+    }
+
+    /// Announce that `config` won't run, and why.
+    async fn block(&self, config: &ExperimentConfig, reason: String) -> ChaosError {
+        tracing::warn!(experiment = %config.name, reason = %reason, "Experiment blocked");
+        self.emit(ExperimentEvent::Blocked {
+            experiment_name: config.name.clone(),
+            reason: reason.clone(),
+        })
+        .await;
+        ChaosError::Blocked(reason)
+    }
+
+    /// Report for an experiment whose `pre_hook` failed: nothing was touched,
+    /// so there's nothing to roll back and no `post_hook` to run.
+    async fn abort_experiment(
+        &self,
+        config: &ExperimentConfig,
+        experiment_id: Uuid,
+        hooks: Vec<HookRecord>,
+    ) -> ExperimentReport {
+        let reason = match hooks.last().and_then(|h| h.exit_code) {
+            Some(code) => format!("pre_hook exited with status {code}"),
+            None => "pre_hook failed".to_string(),
+        };
+        tracing::warn!(experiment = %config.name, reason = %reason, "Experiment aborted");
+        self.emit(ExperimentEvent::Blocked {
+            experiment_name: config.name.clone(),
+            reason: reason.clone(),
+        })
+        .await;
+
+        let now = chrono::Utc::now();
+        ExperimentReport {
+            experiment_id,
+            experiment_name: config.name.clone(),
+            target_domain: config.target,
+            status: format!("aborted: {reason}"),
+            started_at: now,
+            completed_at: now,
+            total_duration: std::time::Duration::ZERO,
+            soak_duration: std::time::Duration::ZERO,
+            recovery: None,
+            discovered_resources: Vec::new(),
+            discovery_errors: Vec::new(),
+            skill_executions: Vec::new(),
+            rollback_steps: Vec::new(),
+            hooks,
+            resource_diff: None,
+            from_cache: false,
+        }
+    }
+
+    fn record_history(&self, report: &ExperimentReport) {
+        if let Some(ref history) = self.history {
+            if let Err(e) = history.append(report) {
+                tracing::warn!(error = %e, "Failed to record experiment history");
+            }
+        }
+    }
+
+    /// Discovery, execution, soak and rollback of a started experiment.
+    async fn run_phases(
+        &self,
+        agent_lock: &Arc<RwLock<Box<dyn Agent>>>,
+        config: &ExperimentConfig,
+        mut experiment: Experiment,
+        duration: std::time::Duration,
+    ) -> ChaosResult<ExperimentReport> {
+        let experiment_id = experiment.id;
+
         // Initialize agent
         {
             let mut agent = agent_lock.write().await;
@@ -83,14 +290,22 @@ impl Orchestrator {
         // Discovery phase
         experiment.status = ExperimentStatus::Discovering;
         let discovered_summaries: Vec<DiscoveredResourceSummary>;
+        // Snapshot to compare with re-discovery after rollback
+        let snapshot;
+        let discovery_errors: Vec<DiscoveryError>;
         {
             let mut agent = agent_lock.write().await;
-            let resources = agent.discover().await?;
+            let discovery = agent.discover().await?;
+            snapshot = discovery.resources;
+            discovery_errors = discovery.errors;
             tracing::info!(
-                count = resources.len(),
+                count = snapshot.len(),
                 "Discovered resources on target"
             );
-            discovered_summaries = resources
+            for e in &discovery_errors {
+                tracing::warn!(experiment = %config.name, scope = %e.scope, error = %e.error, "Partial discovery failure");
+            }
+            discovered_summaries = snapshot
                 .iter()
                 .map(|r| DiscoveredResourceSummary {
                     resource_type: r.resource_type().to_string(),
@@ -99,14 +314,48 @@ impl Orchestrator {
                 .collect();
         }
 
+        // Nothing to act on: skip instead of letting every skill fail obscurely
+        let skipped = discovered_summaries.is_empty()
+            && Self::all_skills_require_resources(agent_lock, config).await;
+        if skipped {
+            tracing::warn!(
+                experiment = %config.name,
+                "Discovery found no resources, skipping experiment"
+            );
+            experiment.status = ExperimentStatus::SkippedNoResources;
+            self.emit(ExperimentEvent::Skipped {
+                experiment_name: config.name.clone(),
+                reason: format!("discovery found no resources on target {}", config.target),
+            })
+            .await;
+        }
+
+        // One typed-params cache per invocation, shared by preflight and execution
+        let parsed_params: Vec<ParsedParams> =
+            config.skills.iter().map(|_| ParsedParams::default()).collect();
+
+        // Fail fast on params that can't work against this target; the
+        // experiment then ends as failed with nothing to roll back
+        let preflight_result = if skipped {
+            Ok(())
+        } else {
+            Self::preflight_skills(agent_lock, config, experiment_id, &parsed_params).await
+        };
+        let preflight_failed = preflight_result.is_err();
+
         // Execution phase
-        experiment.status = ExperimentStatus::Executing;
         experiment.started_at = Some(chrono::Utc::now());
 
         let mut skill_records = Vec::new();
-        let execution_result = self
-            .execute_skills(&agent_lock, &mut experiment, &mut skill_records)
-            .await;
+        let execution_result = if skipped {
+            Ok(())
+        } else if let Err(e) = preflight_result {
+            Err(e)
+        } else {
+            experiment.status = ExperimentStatus::Executing;
+            self.execute_skills(agent_lock, &mut experiment, &parsed_params, &mut skill_records)
+                .await
+        };
 
         if let Err(ref e) = execution_result {
             tracing::error!(error = %e, "Skill execution failed, initiating rollback");
@@ -117,55 +366,75 @@ impl Orchestrator {
             .await;
         }
 
-        // Wait for configured duration (soak period), interruptible by cancel flag
-        if execution_result.is_ok() && !self.cancelled.load(Ordering::Relaxed) {
+        // Wait for configured duration (soak period), interruptible by cancel
+        // How long the chaos actually soaked: shorter on cancel or recovery
+        let mut soak_duration = std::time::Duration::ZERO;
+        let mut recovery = None;
+        if execution_result.is_ok() && !skipped && !config.dry_run && !self.cancel.is_cancelled() {
             experiment.status = ExperimentStatus::WaitingDuration;
             self.emit(ExperimentEvent::DurationWaitBegin {
                 experiment_id,
-                duration: config.duration,
+                duration,
             })
             .await;
-            tracing::info!(duration = ?config.duration, "Waiting for chaos duration");
+            tracing::info!(duration = ?duration, soak = ?config.soak, "Waiting for chaos duration");
 
-            let cancel = self.cancelled.clone();
+            let soak_start = Instant::now();
+            let mut record = RecoveryRecord::default();
             tokio::select! {
-                _ = tokio::time::sleep(config.duration) => {}
-                _ = async {
-                    loop {
-                        tokio::time::sleep(std::time::Duration::from_millis(250)).await;
-                        if cancel.load(Ordering::Relaxed) {
-                            break;
-                        }
-                    }
-                } => {
+                _ = tokio::time::sleep(duration) => {}
+                _ = self.cancel.cancelled() => {
                     tracing::info!("Experiment cancelled during soak period, proceeding to rollback");
                 }
+                _ = self.wait_for_recovery(agent_lock, config, experiment_id, soak_start, &mut record),
+                    if config.soak == SoakMode::UntilRecovered => {}
             }
-        }
 
-        // Rollback phase (always runs)
-        experiment.status = ExperimentStatus::RollingBack;
-        self.emit(ExperimentEvent::RollbackStarted { experiment_id })
-            .await;
+            soak_duration = soak_start.elapsed();
+            if config.soak == SoakMode::UntilRecovered {
+                recovery = Some(record);
+            }
+        }
 
+        // Rollback phase (always runs unless nothing was executed)
         let mut rollback_records = Vec::new();
-        self.rollback_experiment(&agent_lock, &mut experiment, &mut rollback_records)
-            .await;
+        let mut resource_diff = None;
+        if !skipped {
+            if config.dry_run {
+                // Nothing was changed, so there's nothing to undo or compare
+                tracing::info!(experiment = %config.name, "Dry run finished, no rollback needed");
+            } else if preflight_failed {
+                tracing::info!(experiment = %config.name, "Preflight failed before any chaos, no rollback needed");
+            } else {
+                experiment.status = ExperimentStatus::RollingBack;
+                self.emit(ExperimentEvent::RollbackStarted { experiment_id })
+                    .await;
+
+                self.rollback_experiment(agent_lock, &mut experiment, &mut rollback_records)
+                    .await;
+
+                resource_diff = Self::diff_resources(agent_lock, &snapshot).await;
+            }
 
-        // Complete
-        let failure_error = execution_result.err().map(|e| e.to_string());
-        if let Some(ref err) = failure_error {
-            experiment.status = ExperimentStatus::Failed(err.clone());
-        } else {
-            experiment.status = ExperimentStatus::Completed;
+            // Complete
+            let failure_error = execution_result.err().map(|e| e.to_string());
+            if let Some(ref err) = failure_error {
+                experiment.status = ExperimentStatus::Failed(err.clone());
+            } else if self.cancel.is_cancelled() {
+                experiment.status = ExperimentStatus::Cancelled;
+            } else {
+                experiment.status = ExperimentStatus::Completed;
+            }
         }
         experiment.completed_at = Some(chrono::Utc::now());
 
-        self.emit(ExperimentEvent::Completed {
-            experiment_id,
-            at: chrono::Utc::now(),
-        })
-        .await;
+        if !skipped {
+            self.emit(ExperimentEvent::Completed {
+                experiment_id,
+                at: chrono::Utc::now(),
+            })
+            .await;
+        }
 
         // Build report
         let started_at = experiment.started_at.unwrap_or_else(chrono::Utc::now);
@@ -179,17 +448,27 @@ impl Orchestrator {
             experiment_name: config.name.clone(),
             target_domain: config.target,
             status: match &experiment.status {
+                ExperimentStatus::Completed if config.dry_run => "completed (dry run)".to_string(),
                 ExperimentStatus::Completed => "completed".to_string(),
+                ExperimentStatus::Cancelled => "cancelled".to_string(),
+                ExperimentStatus::SkippedNoResources => {
+                    "skipped: no resources discovered".to_string()
+                }
                 ExperimentStatus::Failed(e) => format!("failed: {e}"),
                 other => format!("{other:?}"),
             },
             started_at,
             completed_at,
             total_duration,
-            soak_duration: config.duration,
+            soak_duration,
+            recovery,
             discovered_resources: discovered_summaries,
+            discovery_errors,
             skill_executions: skill_records,
             rollback_steps: rollback_records,
+            hooks: Vec::new(),
+            resource_diff,
+            from_cache: false,
         };
 
         // Store experiment
@@ -201,16 +480,190 @@ impl Orchestrator {
         Ok(report)
     }
 
+    /// Re-discover the target after rollback and compare with `before`.
+    async fn diff_resources(
+        agent_lock: &Arc<RwLock<Box<dyn Agent>>>,
+        before: &[Box<dyn DiscoveredResource>],
+    ) -> Option<ResourceDiff> {
+        let mut agent = agent_lock.write().await;
+        match agent.discover().await {
+            // What couldn't be seen again would show as disappeared
+            Ok(after) if !after.errors.is_empty() => {
+                tracing::warn!(
+                    errors = after.errors.len(),
+                    "Re-discovery after rollback was partial, no resource diff"
+                );
+                None
+            }
+            Ok(after) => {
+                let diff = ResourceDiff::between(before, &after.resources);
+                tracing::info!(
+                    disappeared = diff.disappeared.len(),
+                    appeared = diff.appeared.len(),
+                    changed = diff.changed.len(),
+                    "Compared resources after rollback"
+                );
+                Some(diff)
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Re-discovery after rollback failed, no resource diff");
+                None
+            }
+        }
+    }
+
+    /// Poll the target's health check until it passes after having failed,
+    /// filling in `record` along the way. A check that takes longer than the
+    /// poll interval counts as failed. Never returns if the target stays
+    /// healthy or never recovers; the caller bounds it by the soak duration.
+    async fn wait_for_recovery(
+        &self,
+        agent_lock: &Arc<RwLock<Box<dyn Agent>>>,
+        config: &ExperimentConfig,
+        experiment_id: Uuid,
+        soak_start: Instant,
+        record: &mut RecoveryRecord,
+    ) {
+        let interval = config
+            .health_poll_interval
+            .unwrap_or(DEFAULT_HEALTH_POLL_INTERVAL);
+        loop {
+            tokio::time::sleep(interval).await;
+            // A target too slow to answer within one interval counts as down.
+            let result = match tokio::time::timeout(
+                interval,
+                async { agent_lock.read().await.health_check().await },
+            )
+            .await
+            {
+                Ok(result) => result,
+                Err(_) => Err(ChaosError::Unhealthy(format!(
+                    "health check did not answer within {}",
+                    format_duration(interval)
+                ))),
+            };
+            match (result, record.degraded_after) {
+                (Err(e), None) => {
+                    let after = soak_start.elapsed();
+                    tracing::info!(error = %e, after = ?after, "Target degraded");
+                    record.degraded_after = Some(after);
+                    self.emit(ExperimentEvent::Degraded {
+                        experiment_id,
+                        error: e.to_string(),
+                    })
+                    .await;
+                }
+                (Ok(()), Some(_)) => {
+                    record.recovered_after = Some(soak_start.elapsed());
+                    let time_to_recovery = record.time_to_recovery().unwrap_or_default();
+                    tracing::info!(
+                        time_to_recovery = ?time_to_recovery,
+                        "Target recovered, proceeding to rollback"
+                    );
+                    self.emit(ExperimentEvent::Recovered {
+                        experiment_id,
+                        time_to_recovery,
+                    })
+                    .await;
+                    return;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Names of the experiment's skills that declare `reversible: false`.
+    async fn irreversible_skills(
+        agent_lock: &Arc<RwLock<Box<dyn Agent>>>,
+        config: &ExperimentConfig,
+    ) -> Vec<String> {
+        let agent = agent_lock.read().await;
+        let mut names = Vec::new();
+        for invocation in &config.skills {
+            let irreversible = agent
+                .skill_by_name(&invocation.skill_name)
+                .is_some_and(|skill| !skill.descriptor().reversible);
+            if irreversible && !names.contains(&invocation.skill_name) {
+                names.push(invocation.skill_name.clone());
+            }
+        }
+        names
+    }
+
+    async fn all_skills_require_resources(
+        agent_lock: &Arc<RwLock<Box<dyn Agent>>>,
+        config: &ExperimentConfig,
+    ) -> bool {
+        let agent = agent_lock.read().await;
+        !config.skills.is_empty()
+            && config.skills.iter().all(|invocation| {
+                agent
+                    .skill_by_name(&invocation.skill_name)
+                    .is_some_and(|skill| skill.requires_resources())
+            })
+    }
+
+    /// Agent context for one invocation, stamped with the experiment it belongs to.
+    async fn skill_context(
+        agent: &dyn Agent,
+        experiment_id: Uuid,
+        config: &ExperimentConfig,
+        skill_name: &str,
+        params: &serde_yaml::Value,
+        parsed_params: &ParsedParams,
+    ) -> ChaosResult<SkillContext> {
+        let mut ctx = agent.build_context().await?;
+        ctx.params = params.clone();
+        ctx.parsed_params = parsed_params.clone();
+        ctx.experiment_id = experiment_id;
+        ctx.experiment_name = config.name.clone();
+        ctx.skill_name = skill_name.to_string();
+        ctx.dry_run = config.dry_run;
+        ctx.limits = config.limits;
+        Ok(ctx)
+    }
+
+    async fn preflight_skills(
+        agent_lock: &Arc<RwLock<Box<dyn Agent>>>,
+        config: &ExperimentConfig,
+        experiment_id: Uuid,
+        parsed_params: &[ParsedParams],
+    ) -> ChaosResult<()> {
+        let agent = agent_lock.read().await;
+        for (invocation, parsed) in config.skills.iter().zip(parsed_params) {
+            let skill = agent.skill_by_name(&invocation.skill_name).ok_or_else(|| {
+                ChaosError::Config(format!("Unknown skill: {}", invocation.skill_name))
+            })?;
+            skill.validate_params(&invocation.params)?;
+            if let Some(duration) = config.duration {
+                skill.validate_duration(&invocation.params, duration)?;
+            }
+
+            let ctx = Self::skill_context(
+                agent.as_ref(),
+                experiment_id,
+                config,
+                &invocation.skill_name,
+                &invocation.params,
+                parsed,
+            )
+            .await?;
+            skill.preflight(&ctx).await?;
+        }
+        Ok(())
+    }
+
     async fn execute_skills(
         &self,
         agent_lock: &Arc<RwLock<Box<dyn Agent>>>,
         experiment: &mut Experiment,
+        parsed_params: &[ParsedParams],
         records: &mut Vec<SkillExecutionRecord>,
     ) -> ChaosResult<()> {
         let agent = agent_lock.read().await;
 
-        for invocation in &experiment.config.skills {
-            if self.cancelled.load(Ordering::Relaxed) {
+        for (invocation, parsed) in experiment.config.skills.iter().zip(parsed_params) {
+            if self.cancel.is_cancelled() {
                 tracing::info!("Experiment cancelled, skipping remaining skills");
                 break;
             }
@@ -219,13 +672,23 @@ impl Orchestrator {
                 ChaosError::Config(format!("Unknown skill: {}", invocation.skill_name))
             })?;
 
-            skill.validate_params(&invocation.params)?;
-
+            // Params were validated in preflight
             for _ in 0..invocation.count {
-                let ctx = agent.build_context().await?;
+                let ctx = Self::skill_context(
+                    agent.as_ref(),
+                    experiment.id,
+                    &experiment.config,
+                    &invocation.skill_name,
+                    &invocation.params,
+                    parsed,
+                )
+                .await?;
                 let start = Instant::now();
                 match skill.execute(&ctx).await {
-                    Ok(handle) => {
+                    Ok(mut handle) => {
+                        handle.rollback_priority = invocation
+                            .rollback_priority
+                            .unwrap_or_else(|| skill.rollback_priority());
                         let elapsed = start.elapsed();
                         tracing::info!(skill = %invocation.skill_name, "Skill executed successfully");
                         self.emit(ExperimentEvent::SkillExecuted {
@@ -234,12 +697,16 @@ impl Orchestrator {
                             success: true,
                         })
                         .await;
+                        let output = ctx.output.take();
+                        handle.clamped = output.clamped.clone();
                         experiment.rollback_log.push(handle);
                         records.push(SkillExecutionRecord {
                             skill_name: invocation.skill_name.clone(),
+                            params: invocation.params.clone(),
                             success: true,
                             duration: elapsed,
                             error: None,
+                            output,
                         });
                     }
                     Err(e) => {
@@ -252,9 +719,11 @@ impl Orchestrator {
                         .await;
                         records.push(SkillExecutionRecord {
                             skill_name: invocation.skill_name.clone(),
+                            params: invocation.params.clone(),
                             success: false,
                             duration: elapsed,
                             error: Some(e.to_string()),
+                            output: ctx.output.take(),
                         });
                         return Err(ChaosError::SkillExecution {
                             skill_name: invocation.skill_name.clone(),
@@ -268,7 +737,8 @@ impl Orchestrator {
         Ok(())
     }
 
-    /// Rollback in LIFO order. Best-effort: continues even if individual rollbacks fail.
+    /// Rollback by descending priority, LIFO within a priority. Best-effort:
+    /// continues even if individual rollbacks fail.
     async fn rollback_experiment(
         &self,
         agent_lock: &Arc<RwLock<Box<dyn Agent>>>,
@@ -277,7 +747,12 @@ impl Orchestrator {
     ) {
         let agent = agent_lock.read().await;
 
-        let handles: Vec<_> = experiment.rollback_log.iter_reverse().cloned().collect();
+        let handles: Vec<_> = experiment
+            .rollback_log
+            .rollback_order()
+            .into_iter()
+            .cloned()
+            .collect();
         for handle in &handles {
             let skill = match agent.skill_by_name(&handle.skill_name) {
                 Some(s) => s,
@@ -288,12 +763,22 @@ impl Orchestrator {
                         success: false,
                         duration: std::time::Duration::ZERO,
                         error: Some("skill not found".to_string()),
+                        output: Default::default(),
                     });
                     continue;
                 }
             };
 
-            let ctx = match agent.build_context().await {
+            let ctx = match Self::skill_context(
+                agent.as_ref(),
+                experiment.id,
+                &experiment.config,
+                &handle.skill_name,
+                &serde_yaml::Value::Null,
+                &ParsedParams::default(),
+            )
+            .await
+            {
                 Ok(ctx) => ctx,
                 Err(e) => {
                     tracing::error!(error = %e, "Failed to build context for rollback");
@@ -302,6 +787,7 @@ impl Orchestrator {
                         success: false,
                         duration: std::time::Duration::ZERO,
                         error: Some(format!("context build failed: {e}")),
+                        output: Default::default(),
                     });
                     continue;
                 }
@@ -325,6 +811,7 @@ impl Orchestrator {
                 success,
                 duration: elapsed,
                 error,
+                output: ctx.output.take(),
             });
 
             self.emit(ExperimentEvent::RollbackStepCompleted {
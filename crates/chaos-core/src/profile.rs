@@ -0,0 +1,115 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::DefaultDurations;
+use crate::duration::format_duration;
+use crate::error::{ChaosError, ChaosResult};
+use crate::experiment::ExperimentConfig;
+
+/// Guards for one environment (e.g. "dev is wide open, staging is
+/// restricted"), defined once under `profiles:` and picked with `--profile`.
+/// The orchestrator blocks experiments that break them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Profile {
+    /// The profile's key in `profiles:`, for messages.
+    #[serde(skip)]
+    pub name: String,
+    /// Skills that may run; empty allows every skill. A trailing `*` matches
+    /// a prefix, e.g. `db.*`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_skills: Vec<String>,
+    /// Skills that may never run, even if `allowed_skills` matches them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub denied_skills: Vec<String>,
+    /// Longest soak an experiment may ask for.
+    #[serde(default, with = "crate::duration::option", skip_serializing_if = "Option::is_none")]
+    pub max_duration: Option<Duration>,
+    /// Blast radius: most skill invocations (the sum of their `count`s) in
+    /// one experiment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_skill_invocations: Option<u32>,
+    /// `true` runs irreversible skills in every experiment, like
+    /// `--allow-irreversible`; `false` blocks them even in experiments with
+    /// `allow_irreversible: true` and despite the flag. Unset leaves it to
+    /// those.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_irreversible: Option<bool>,
+}
+
+impl Profile {
+    pub fn allows_skill(&self, skill_name: &str) -> bool {
+        let matches = |pattern: &String| match pattern.strip_suffix('*') {
+            Some(prefix) => skill_name.starts_with(prefix),
+            None => skill_name == pattern,
+        };
+        !self.denied_skills.iter().any(matches)
+            && (self.allowed_skills.is_empty() || self.allowed_skills.iter().any(matches))
+    }
+
+    /// Every way `experiment` breaks this profile, joined, or `Ok` if none.
+    /// An experiment without a duration is checked against the one it would
+    /// get from `defaults`. Irreversible skills are checked by the
+    /// orchestrator, which knows them.
+    pub fn check(
+        &self,
+        experiment: &ExperimentConfig,
+        defaults: &DefaultDurations,
+    ) -> Result<(), String> {
+        let mut problems = Vec::new();
+        for invocation in &experiment.skills {
+            if !self.allows_skill(&invocation.skill_name) {
+                problems.push(format!("skill {} is not allowed", invocation.skill_name));
+            }
+        }
+        let duration = experiment.duration.or_else(|| defaults.for_target(experiment.target));
+        if let (Some(max), Some(duration)) = (self.max_duration, duration) {
+            if duration > max {
+                problems.push(format!(
+                    "duration {} exceeds max_duration {}",
+                    format_duration(duration),
+                    format_duration(max)
+                ));
+            }
+        }
+        if let Some(max) = self.max_skill_invocations {
+            let invocations: u32 = experiment.skills.iter().map(|s| s.count).sum();
+            if invocations > max {
+                problems.push(format!(
+                    "{invocations} skill invocations exceed max_skill_invocations {max}"
+                ));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("profile '{}': {}", self.name, problems.join("; ")))
+        }
+    }
+}
+
+/// The `profiles:` section of a config file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ProfileSet(pub BTreeMap<String, Profile>);
+
+impl ProfileSet {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The profile called `name`, for `--profile`.
+    pub fn select(&self, name: &str) -> ChaosResult<Profile> {
+        let mut profile = self.0.get(name).cloned().ok_or_else(|| {
+            let known: Vec<&str> = self.0.keys().map(String::as_str).collect();
+            ChaosError::Config(format!(
+                "Unknown profile '{name}' (known: {})",
+                if known.is_empty() { "none".to_string() } else { known.join(", ") }
+            ))
+        })?;
+        profile.name = name.to_string();
+        Ok(profile)
+    }
+}
@@ -0,0 +1,102 @@
+//! Blanking out secrets before config or tool arguments are shown: values
+//! of secret-looking keys, everything under `headers` and `env`, and
+//! passwords in URLs.
+
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Keys whose string values are always secret, matched case-insensitively
+/// as substrings.
+const SECRET_KEYS: &[&str] = &[
+    "password",
+    "passphrase",
+    "secret",
+    "token",
+    "api_key",
+    "apikey",
+    "authorization",
+    "cookie",
+];
+
+/// Maps whose every value is redacted: request headers and MCP server env.
+const SECRET_MAPS: &[&str] = &["headers", "env"];
+
+fn is_secret_key(key: &str) -> bool {
+    let key = key.to_lowercase();
+    SECRET_KEYS.iter().any(|s| key.contains(s))
+}
+
+fn is_secret_map(key: &str) -> bool {
+    SECRET_MAPS.contains(&key.to_lowercase().as_str())
+}
+
+/// Redact `value` in place.
+pub fn redact_yaml(value: &mut serde_yaml::Value) {
+    use serde_yaml::Value;
+    match value {
+        Value::Mapping(map) => {
+            for (key, value) in map.iter_mut() {
+                let key = key.as_str().unwrap_or_default();
+                if is_secret_map(key) {
+                    if let Value::Mapping(entries) = value {
+                        for (_, v) in entries.iter_mut() {
+                            *v = REDACTED.into();
+                        }
+                        continue;
+                    }
+                }
+                if value.is_string() && is_secret_key(key) {
+                    *value = REDACTED.into();
+                } else {
+                    redact_yaml(value);
+                }
+            }
+        }
+        Value::Sequence(items) => items.iter_mut().for_each(redact_yaml),
+        Value::String(s) => *s = redact_url(s),
+        _ => {}
+    }
+}
+
+/// Redact `value` in place.
+pub fn redact_json(value: &mut serde_json::Value) {
+    use serde_json::Value;
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if is_secret_map(key) {
+                    if let Value::Object(entries) = value {
+                        for v in entries.values_mut() {
+                            *v = REDACTED.into();
+                        }
+                        continue;
+                    }
+                }
+                if value.is_string() && is_secret_key(key) {
+                    *value = REDACTED.into();
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_json),
+        Value::String(s) => *s = redact_url(s),
+        _ => {}
+    }
+}
+
+/// `url` with the password of any `user:password@` credentials replaced,
+/// e.g. `postgres://chaos:[REDACTED]@db:5432/app`.
+pub fn redact_url(url: &str) -> String {
+    let Some((scheme, rest)) = url.split_once("://") else {
+        return url.to_string();
+    };
+    let authority_end = rest.find(['/', '?']).unwrap_or(rest.len());
+    let (authority, path) = rest.split_at(authority_end);
+    let Some((credentials, hosts)) = authority.rsplit_once('@') else {
+        return url.to_string();
+    };
+    match credentials.split_once(':') {
+        Some((user, _)) => format!("{scheme}://{user}:{REDACTED}@{hosts}{path}"),
+        None => url.to_string(),
+    }
+}
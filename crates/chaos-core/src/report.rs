@@ -1,37 +1,229 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::time::Duration;
 use uuid::Uuid;
 
-use crate::skill::TargetDomain;
+use crate::discovery::{DiscoveredResource, DiscoveryError};
+use crate::duration::format_duration;
+use crate::skill::{StepOutput, TargetDomain};
 
 /// Lightweight summary of a discovered resource.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiscoveredResourceSummary {
     pub resource_type: String,
     pub name: String,
 }
 
+/// Longest metadata value shown in a [`FieldChange`].
+const MAX_FIELD_VALUE_CHARS: usize = 80;
+
+/// A metadata field that differs between two discoveries of a resource.
+/// Nested fields use dotted paths (e.g. `labels.pod-template-hash`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// A resource present in both discoveries with different metadata.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceChange {
+    pub resource_type: String,
+    pub name: String,
+    pub fields: Vec<FieldChange>,
+}
+
+/// How the target's resources differ between discovery before execution
+/// and re-discovery after rollback. Resources are matched by type and
+/// [`key`](DiscoveredResource::key), which is also the name reported here.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResourceDiff {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disappeared: Vec<DiscoveredResourceSummary>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub appeared: Vec<DiscoveredResourceSummary>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changed: Vec<ResourceChange>,
+}
+
+impl ResourceDiff {
+    pub fn between(
+        before: &[Box<dyn DiscoveredResource>],
+        after: &[Box<dyn DiscoveredResource>],
+    ) -> Self {
+        let index = |resources: &[Box<dyn DiscoveredResource>]| {
+            resources
+                .iter()
+                .map(|r| {
+                    let mut fields = BTreeMap::new();
+                    flatten_metadata("", &r.metadata(), &mut fields);
+                    ((r.resource_type().to_string(), r.key()), fields)
+                })
+                .collect::<BTreeMap<_, _>>()
+        };
+        let before = index(before);
+        let after = index(after);
+        let summary = |(resource_type, name): &(String, String)| DiscoveredResourceSummary {
+            resource_type: resource_type.clone(),
+            name: name.clone(),
+        };
+
+        let mut diff = Self::default();
+        for (id, old) in &before {
+            let Some(new) = after.get(id) else {
+                diff.disappeared.push(summary(id));
+                continue;
+            };
+            let mut names: Vec<&String> = old.keys().chain(new.keys()).collect();
+            names.sort();
+            names.dedup();
+            let fields: Vec<FieldChange> = names
+                .into_iter()
+                .filter_map(|field| {
+                    let (before, after) = (old.get(field), new.get(field));
+                    (before != after).then(|| FieldChange {
+                        field: field.clone(),
+                        before: before.cloned().unwrap_or_else(|| "(none)".into()),
+                        after: after.cloned().unwrap_or_else(|| "(none)".into()),
+                    })
+                })
+                .collect();
+            if !fields.is_empty() {
+                diff.changed.push(ResourceChange {
+                    resource_type: id.0.clone(),
+                    name: id.1.clone(),
+                    fields,
+                });
+            }
+        }
+        diff.appeared = after
+            .keys()
+            .filter(|id| !before.contains_key(*id))
+            .map(summary)
+            .collect();
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.disappeared.is_empty() && self.appeared.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Flatten nested mappings into dotted paths; other values are rendered
+/// as text (sequences as compact JSON).
+fn flatten_metadata(path: &str, value: &serde_yaml::Value, out: &mut BTreeMap<String, String>) {
+    use serde_yaml::Value;
+    let text = match value {
+        Value::Mapping(map) => {
+            for (key, value) in map {
+                let key = match key {
+                    Value::String(s) => s.clone(),
+                    other => serde_json::to_string(other).unwrap_or_default(),
+                };
+                let path = if path.is_empty() { key } else { format!("{path}.{key}") };
+                flatten_metadata(&path, value, out);
+            }
+            return;
+        }
+        Value::String(s) => s.clone(),
+        Value::Null => "null".to_string(),
+        other => serde_json::to_string(other).unwrap_or_default(),
+    };
+    let text = if text.chars().count() > MAX_FIELD_VALUE_CHARS {
+        let cut: String = text.chars().take(MAX_FIELD_VALUE_CHARS).collect();
+        format!("{cut}...")
+    } else {
+        text
+    };
+    out.insert(path.to_string(), text);
+}
+
 /// Record of a single skill execution.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillExecutionRecord {
     pub skill_name: String,
+    /// Params the skill ran with.
+    #[serde(default)]
+    pub params: serde_yaml::Value,
     pub success: bool,
+    #[serde(with = "humantime_serde")]
     pub duration: Duration,
     pub error: Option<String>,
+    /// Warnings, actions and text blocks (e.g. captured logs) the skill attached.
+    pub output: StepOutput,
 }
 
 /// Record of a single rollback step.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RollbackStepRecord {
     pub skill_name: String,
     pub success: bool,
+    #[serde(with = "humantime_serde")]
     pub duration: Duration,
     pub error: Option<String>,
+    /// Warnings, actions and text blocks (e.g. captured logs) the skill attached.
+    pub output: StepOutput,
+}
+
+/// Health timeline of an `until_recovered` soak, measured from the end of
+/// skill execution.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecoveryRecord {
+    /// When the health check first failed; `None` if it never did.
+    #[serde(default, with = "humantime_serde::option")]
+    pub degraded_after: Option<Duration>,
+    /// When the health check passed again; `None` if the target was still
+    /// unhealthy when the soak ran out (or never degraded).
+    #[serde(default, with = "humantime_serde::option")]
+    pub recovered_after: Option<Duration>,
+}
+
+impl RecoveryRecord {
+    /// Time to recovery: from the first failed health check to the first
+    /// passing one after it.
+    pub fn time_to_recovery(&self) -> Option<Duration> {
+        Some(self.recovered_after?.saturating_sub(self.degraded_after?))
+    }
+}
+
+/// When a hook ran relative to its experiment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookStage {
+    Pre,
+    Post,
+}
+
+impl fmt::Display for HookStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HookStage::Pre => write!(f, "pre_hook"),
+            HookStage::Post => write!(f, "post_hook"),
+        }
+    }
+}
+
+/// Record of a `pre_hook`/`post_hook` command.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HookRecord {
+    pub stage: HookStage,
+    pub command: String,
+    /// `None` if the command couldn't be started or was killed by a signal.
+    pub exit_code: Option<i32>,
+    pub success: bool,
+    #[serde(with = "humantime_serde")]
+    pub duration: Duration,
+    /// Tail of the command's combined stdout and stderr, or why it couldn't
+    /// be started.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub output: String,
 }
 
 /// Complete post-experiment report.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExperimentReport {
     pub experiment_id: Uuid,
     pub experiment_name: String,
@@ -40,33 +232,50 @@ pub struct ExperimentReport {
 
     pub started_at: DateTime<Utc>,
     pub completed_at: DateTime<Utc>,
+    #[serde(with = "humantime_serde")]
     pub total_duration: Duration,
+    #[serde(with = "humantime_serde")]
     pub soak_duration: Duration,
+    /// Set for `until_recovered` soaks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recovery: Option<RecoveryRecord>,
 
     pub discovered_resources: Vec<DiscoveredResourceSummary>,
+    /// Parts of the target discovery couldn't see; the experiment went on
+    /// with what was found.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub discovery_errors: Vec<DiscoveryError>,
     pub skill_executions: Vec<SkillExecutionRecord>,
     pub rollback_steps: Vec<RollbackStepRecord>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hooks: Vec<HookRecord>,
+    /// Resources before execution vs. after rollback; `None` when nothing
+    /// ran or re-discovery failed or was partial.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_diff: Option<ResourceDiff>,
+    /// A copy of an earlier run's report, served by the daemon's `cache_ttl`
+    /// instead of running the experiment again.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub from_cache: bool,
 }
 
-fn format_duration(d: Duration) -> String {
-    let total_secs = d.as_secs();
-    if total_secs >= 60 {
-        let mins = total_secs / 60;
-        let secs = total_secs % 60;
-        let millis = d.subsec_millis();
-        if millis > 0 {
-            format!("{mins}m {secs}.{millis:03}s")
-        } else {
-            format!("{mins}m {secs}s")
-        }
-    } else {
-        let millis = d.as_millis();
-        if millis < 1000 {
-            format!("{millis}ms")
-        } else {
-            format!("{}.{}s", total_secs, d.subsec_millis() / 100)
+fn write_output(f: &mut fmt::Formatter<'_>, output: &StepOutput) -> fmt::Result {
+    for action in &output.actions {
+        writeln!(f, "       * {action}")?;
+    }
+    for planned in &output.planned {
+        writeln!(f, "       ~ would: {planned}")?;
+    }
+    for warning in &output.warnings {
+        writeln!(f, "       ! {warning}")?;
+    }
+    for block in &output.blocks {
+        writeln!(f, "       [{}]", block.label)?;
+        for line in block.content.lines() {
+            writeln!(f, "       | {line}")?;
         }
     }
+    Ok(())
 }
 
 impl fmt::Display for ExperimentReport {
@@ -81,7 +290,11 @@ impl fmt::Display for ExperimentReport {
         writeln!(f, "  Name:     {}", self.experiment_name)?;
         writeln!(f, "  ID:       {}", self.experiment_id)?;
         writeln!(f, "  Target:   {}", self.target_domain)?;
-        writeln!(f, "  Status:   {}", self.status)?;
+        if self.from_cache {
+            writeln!(f, "  Status:   {} (from cache)", self.status)?;
+        } else {
+            writeln!(f, "  Status:   {}", self.status)?;
+        }
         writeln!(f, "  Duration: {}", format_duration(self.total_duration))?;
 
         // Discovery
@@ -95,11 +308,17 @@ impl fmt::Display for ExperimentReport {
         if self.discovered_resources.is_empty() {
             writeln!(f, "  (none)")?;
         } else {
-            writeln!(f, "  {:<15} {}", "TYPE", "NAME")?;
+            writeln!(f, "  {:<15} NAME", "TYPE")?;
             for r in &self.discovered_resources {
                 writeln!(f, "  {:<15} {}", r.resource_type, r.name)?;
             }
         }
+        if !self.discovery_errors.is_empty() {
+            writeln!(f, "\n  Not discovered ({}):", self.discovery_errors.len())?;
+            for e in &self.discovery_errors {
+                writeln!(f, "    ! {e}")?;
+            }
+        }
 
         // Skills executed
         writeln!(f, "\n{thin}")?;
@@ -114,8 +333,8 @@ impl fmt::Display for ExperimentReport {
         } else {
             writeln!(
                 f,
-                "  {:<4} {:<25} {:<10} {}",
-                "#", "SKILL", "RESULT", "DURATION"
+                "  {:<4} {:<25} {:<10} DURATION",
+                "#", "SKILL", "RESULT"
             )?;
             for (i, s) in self.skill_executions.iter().enumerate() {
                 let result = if s.success { "OK" } else { "FAILED" };
@@ -130,6 +349,7 @@ impl fmt::Display for ExperimentReport {
                 if let Some(ref err) = s.error {
                     writeln!(f, "       -> {err}")?;
                 }
+                write_output(f, &s.output)?;
             }
         }
 
@@ -142,8 +362,8 @@ impl fmt::Display for ExperimentReport {
         } else {
             writeln!(
                 f,
-                "  {:<4} {:<25} {:<10} {}",
-                "#", "SKILL", "RESULT", "DURATION"
+                "  {:<4} {:<25} {:<10} DURATION",
+                "#", "SKILL", "RESULT"
             )?;
             for (i, r) in self.rollback_steps.iter().enumerate() {
                 let result = if r.success { "OK" } else { "FAILED" };
@@ -158,6 +378,55 @@ impl fmt::Display for ExperimentReport {
                 if let Some(ref err) = r.error {
                     writeln!(f, "       -> {err}")?;
                 }
+                write_output(f, &r.output)?;
+            }
+        }
+
+        // Resource diff
+        if let Some(ref diff) = self.resource_diff {
+            writeln!(f, "\n{thin}")?;
+            writeln!(f, "  RESOURCE DIFF (before execution vs. after rollback)")?;
+            writeln!(f, "{thin}\n")?;
+            if diff.is_empty() {
+                writeln!(f, "  (no changes)")?;
+            }
+            for r in &diff.disappeared {
+                writeln!(f, "  - {:<15} {} (disappeared)", r.resource_type, r.name)?;
+            }
+            for r in &diff.appeared {
+                writeln!(f, "  + {:<15} {} (appeared)", r.resource_type, r.name)?;
+            }
+            for r in &diff.changed {
+                writeln!(f, "  ~ {:<15} {}", r.resource_type, r.name)?;
+                for c in &r.fields {
+                    writeln!(f, "       {}: {} -> {}", c.field, c.before, c.after)?;
+                }
+            }
+        }
+
+        // Hooks
+        if !self.hooks.is_empty() {
+            writeln!(f, "\n{thin}")?;
+            writeln!(f, "  HOOKS ({})", self.hooks.len())?;
+            writeln!(f, "{thin}\n")?;
+            writeln!(f, "  {:<10} {:<19} {:<10} COMMAND", "HOOK", "RESULT", "DURATION")?;
+            for h in &self.hooks {
+                let result = match (h.success, h.exit_code) {
+                    (true, _) => "OK".to_string(),
+                    (false, Some(code)) => format!("FAILED (exit {code})"),
+                    (false, None) => "FAILED".to_string(),
+                };
+                writeln!(
+                    f,
+                    "  {:<10} {:<19} {:<10} {}",
+                    h.stage.to_string(),
+                    result,
+                    format_duration(h.duration),
+                    h.command
+                )?;
+                for line in h.output.lines() {
+                    writeln!(f, "       | {line}")?;
+                }
             }
         }
 
@@ -180,6 +449,20 @@ impl fmt::Display for ExperimentReport {
             "  Soak time:  {}",
             format_duration(self.soak_duration)
         )?;
+        if let Some(ref recovery) = self.recovery {
+            let recovery = match (recovery.degraded_after, recovery.time_to_recovery()) {
+                (None, _) => "never degraded".to_string(),
+                (Some(degraded), None) => {
+                    format!("degraded after {}, not recovered", format_duration(degraded))
+                }
+                (Some(degraded), Some(ttr)) => format!(
+                    "{} (degraded after {})",
+                    format_duration(ttr),
+                    format_duration(degraded)
+                ),
+            };
+            writeln!(f, "  Recovery:   {recovery}")?;
+        }
         writeln!(
             f,
             "  Total:      {}",
@@ -10,6 +10,14 @@ pub struct RollbackHandle {
     pub created_at: DateTime<Utc>,
     /// Skill-specific serialized undo state.
     pub undo_state: serde_yaml::Value,
+    /// Higher priorities are rolled back first; set by the orchestrator from
+    /// the skill or its invocation.
+    #[serde(default)]
+    pub rollback_priority: i32,
+    /// Where `execute` cut its targets down to the blast-radius limits; set
+    /// by the orchestrator.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub clamped: Vec<String>,
 }
 
 impl RollbackHandle {
@@ -19,12 +27,19 @@ impl RollbackHandle {
             skill_name: skill_name.into(),
             created_at: Utc::now(),
             undo_state,
+            rollback_priority: 0,
+            clamped: Vec::new(),
         }
     }
+
+    /// A handle with nothing to undo, returned by `execute` in a dry run.
+    pub fn dry_run(skill_name: impl Into<String>) -> Self {
+        Self::new(skill_name, serde_yaml::Value::Null)
+    }
 }
 
 /// Ordered log of rollback handles for an experiment.
-/// Rollback pops in LIFO (reverse) order.
+/// Rollback runs by descending priority, LIFO among equal priorities.
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct RollbackLog {
     entries: Vec<RollbackHandle>,
@@ -46,6 +61,14 @@ impl RollbackLog {
         self.entries.iter().rev()
     }
 
+    /// Handles in rollback order: highest `rollback_priority` first, and
+    /// most-recent first within a priority.
+    pub fn rollback_order(&self) -> Vec<&RollbackHandle> {
+        let mut handles: Vec<_> = self.iter_reverse().collect();
+        handles.sort_by_key(|h| std::cmp::Reverse(h.rollback_priority));
+        handles
+    }
+
     pub fn len(&self) -> usize {
         self.entries.len()
     }
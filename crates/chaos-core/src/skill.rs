@@ -1,10 +1,14 @@
 use async_trait::async_trait;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::any::Any;
 use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock};
+use uuid::Uuid;
 
-use crate::error::ChaosResult;
+use crate::error::{ChaosError, ChaosResult};
 use crate::rollback::RollbackHandle;
+use crate::safety::SafetyLimits;
 
 /// Metadata describing a skill.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,8 +41,160 @@ impl fmt::Display for TargetDomain {
 pub struct SkillContext {
     /// Agent-specific shared state (downcast by the skill).
     pub shared: Box<dyn Any + Send + Sync>,
-    /// Parameters from the YAML config for this skill invocation.
+    /// Parameters from the YAML config for this skill invocation. Read them
+    /// with [`SkillContext::typed_params`].
     pub params: serde_yaml::Value,
+    /// Typed `params`, parsed once and shared by every context built for the
+    /// same invocation.
+    pub parsed_params: ParsedParams,
+    /// Output the orchestrator attaches to this step's report record.
+    pub output: OutputSink,
+    /// Experiment this invocation belongs to. Skills stamp it on anything they
+    /// create (see [`crate::cleanup`]) so orphans can be traced back.
+    pub experiment_id: Uuid,
+    /// Name of that experiment, for log lines and labels humans read.
+    pub experiment_name: String,
+    /// Skill this context was built for, named in param errors.
+    pub skill_name: String,
+    /// Report what `execute` would change with [`SkillContext::record_planned`]
+    /// instead of changing it, and return [`RollbackHandle::dry_run`].
+    pub dry_run: bool,
+    /// Caps on what `execute` may touch; apply them with
+    /// [`SkillContext::clamp`].
+    pub limits: SafetyLimits,
+}
+
+/// Deserialize `params` into a skill's params struct, naming `skill` in the
+/// error. Use it in `validate_params` with the struct `execute` reads
+/// through [`SkillContext::typed_params`], so params that validate also
+/// parse at execution.
+pub fn parse_params<P: DeserializeOwned>(skill: &str, params: &serde_yaml::Value) -> ChaosResult<P> {
+    serde_yaml::from_value(params.clone())
+        .map_err(|e| ChaosError::Config(format!("Invalid {skill} params: {e}")))
+}
+
+/// Cache behind [`SkillContext::typed_params`]. Clones share the cache; the
+/// orchestrator hands one per invocation to its preflight and every `count`
+/// repetition.
+#[derive(Clone, Default)]
+pub struct ParsedParams(Arc<OnceLock<Arc<dyn Any + Send + Sync>>>);
+
+/// A labelled block of text a skill attaches to the report, e.g. captured logs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillOutput {
+    pub label: String,
+    pub content: String,
+}
+
+/// Everything a skill reported during one execute or rollback call.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StepOutput {
+    pub blocks: Vec<SkillOutput>,
+    /// Problems worth a human's attention that didn't fail the step.
+    pub warnings: Vec<String>,
+    /// What the skill actually changed, in order.
+    pub actions: Vec<String>,
+    /// What the skill would have changed, in a dry run.
+    pub planned: Vec<String>,
+    /// Targets cut down to the experiment's [`SafetyLimits`].
+    pub clamped: Vec<String>,
+}
+
+/// Collects [`StepOutput`] during one execute or rollback call.
+#[derive(Debug, Default)]
+pub struct OutputSink(Mutex<StepOutput>);
+
+impl OutputSink {
+    pub fn push(&self, label: impl Into<String>, content: impl Into<String>) {
+        self.lock().blocks.push(SkillOutput {
+            label: label.into(),
+            content: content.into(),
+        });
+    }
+
+    pub fn warn(&self, message: impl Into<String>) {
+        self.lock().warnings.push(message.into());
+    }
+
+    pub fn record_action(&self, message: impl Into<String>) {
+        self.lock().actions.push(message.into());
+    }
+
+    pub fn record_planned(&self, message: impl Into<String>) {
+        self.lock().planned.push(message.into());
+    }
+
+    pub fn record_clamp(&self, message: impl Into<String>) {
+        self.lock().clamped.push(message.into());
+    }
+
+    /// Drain everything pushed so far.
+    pub fn take(&self) -> StepOutput {
+        std::mem::take(&mut *self.lock())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, StepOutput> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl SkillContext {
+    /// `params` as `P`, parsed on first use and cached for the invocation.
+    pub fn typed_params<P>(&self) -> ChaosResult<Arc<P>>
+    where
+        P: DeserializeOwned + Send + Sync + 'static,
+    {
+        if let Some(cached) = self.parsed_params.0.get() {
+            if let Ok(params) = cached.clone().downcast::<P>() {
+                return Ok(params);
+            }
+        }
+        let params: Arc<P> = Arc::new(
+            serde_yaml::from_value(self.params.clone())
+                .map_err(|e| ChaosError::Config(format!("Invalid {} params: {e}", self.skill_name)))?,
+        );
+        let _ = self.parsed_params.0.set(params.clone());
+        Ok(params)
+    }
+
+    /// Log a warning and attach it to this step's report record.
+    pub fn warn(&self, message: impl Into<String>) {
+        let message = message.into();
+        tracing::warn!("{message}");
+        self.output.warn(message);
+    }
+
+    /// Log a change the skill made and attach it to this step's report record.
+    pub fn record_action(&self, message: impl Into<String>) {
+        let message = message.into();
+        tracing::info!("{message}");
+        self.output.record_action(message);
+    }
+
+    /// Log a change a dry run would have made and attach it to this step's
+    /// report record.
+    pub fn record_planned(&self, message: impl Into<String>) {
+        let message = message.into();
+        tracing::info!("Dry run, would: {message}");
+        self.output.record_planned(message);
+    }
+
+    /// `requested` cut down to `max` (a field of [`SkillContext::limits`]),
+    /// e.g. `ctx.clamp("pods", count, ctx.limits.max_pods)`. A cut is
+    /// logged, added to the step's warnings and recorded on its rollback
+    /// handle.
+    pub fn clamp<T: Copy + Ord + fmt::Display>(&self, what: &str, requested: T, max: Option<T>) -> T {
+        match max {
+            Some(max) if requested > max => {
+                let message = format!("Clamped {what} from {requested} to {max} by the blast-radius limit");
+                self.warn(message.clone());
+                self.output.record_clamp(message);
+                max
+            }
+            _ => requested,
+        }
+    }
 }
 
 /// A single reversible chaos action.
@@ -46,9 +202,51 @@ pub struct SkillContext {
 pub trait Skill: Send + Sync {
     fn descriptor(&self) -> SkillDescriptor;
 
+    /// JSON Schema describing the accepted `params`.
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({ "type": "object" })
+    }
+
+    /// Whether the skill only makes sense against discovered resources (tables,
+    /// pods, services). Experiments made up solely of such skills are skipped
+    /// when discovery finds nothing.
+    fn requires_resources(&self) -> bool {
+        false
+    }
+
+    /// Where this skill's undo goes in an experiment's rollback: higher runs
+    /// earlier, equal priorities unwind LIFO. Override when undoing must not
+    /// simply mirror execution order, e.g. a service restart that has to
+    /// happen before its config is restored.
+    fn rollback_priority(&self) -> i32 {
+        0
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()>;
 
-    /// Execute the chaos action. Returns a handle for rollback.
+    /// Check `params` against the experiment's `duration`, e.g. that
+    /// something the skill runs ends before rollback. Called after
+    /// `validate_params` wherever the duration is known.
+    fn validate_duration(&self, _params: &serde_yaml::Value, _duration: std::time::Duration) -> ChaosResult<()> {
+        Ok(())
+    }
+
+    /// Check `ctx.params` against the live target before any skill in the
+    /// experiment executes, e.g. that a selector actually matches something.
+    async fn preflight(&self, _ctx: &SkillContext) -> ChaosResult<()> {
+        Ok(())
+    }
+
+    /// Describe what `execute` would act on with `ctx.params`, one line per
+    /// target, without changing anything. Empty if the skill has nothing
+    /// beyond its params to show.
+    async fn inspect(&self, _ctx: &SkillContext) -> ChaosResult<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    /// Execute the chaos action. Returns a handle for rollback. With
+    /// `ctx.dry_run`, only read the target: record each change it would make
+    /// and return [`RollbackHandle::dry_run`].
     async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle>;
 
     /// Reverse a previously executed action.
@@ -0,0 +1,43 @@
+use std::sync::{Arc, Mutex};
+
+use crate::skill::{Skill, TargetDomain};
+
+/// Supplies skills that live outside this repository. Register one with
+/// [`register_skill_provider`] before any agent is created; every agent built
+/// afterwards (for planning, validation and execution alike) asks each
+/// provider for extra skills and offers them next to its built-in ones.
+pub trait SkillProvider: Send + Sync {
+    /// Skills for the agent named `agent` (e.g. `database-chaos-agent`,
+    /// `mongodb-chaos-agent`, `kubernetes-chaos-agent`, `server-chaos-agent`)
+    /// targeting `domain`. Return nothing for agents the provider doesn't
+    /// extend. Skills run with that agent's [`SkillContext`](crate::skill::SkillContext).
+    fn skills(&self, domain: TargetDomain, agent: &str) -> Vec<Box<dyn Skill>>;
+}
+
+static PROVIDERS: Mutex<Vec<Arc<dyn SkillProvider>>> = Mutex::new(Vec::new());
+
+/// Add `provider` to every agent created from now on.
+pub fn register_skill_provider(provider: impl SkillProvider + 'static) {
+    lock().push(Arc::new(provider));
+}
+
+/// Append the registered providers' skills for `agent` to `skills`. A skill
+/// whose name is already taken is skipped, so built-in skills can't be
+/// shadowed.
+pub fn add_registered_skills(skills: &mut Vec<Box<dyn Skill>>, domain: TargetDomain, agent: &str) {
+    let providers = lock().clone();
+    for provider in providers {
+        for skill in provider.skills(domain, agent) {
+            let name = skill.descriptor().name;
+            if skills.iter().any(|s| s.descriptor().name == name) {
+                tracing::warn!(skill = %name, agent, "Skipping registered skill: name already taken");
+                continue;
+            }
+            skills.push(skill);
+        }
+    }
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Vec<Arc<dyn SkillProvider>>> {
+    PROVIDERS.lock().unwrap_or_else(|e| e.into_inner())
+}
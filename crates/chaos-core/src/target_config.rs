@@ -0,0 +1,155 @@
+//! Field-level checks of an experiment's `target_config`, run before it is
+//! deserialized so a mistake names the field instead of surfacing a raw
+//! serde error.
+
+use serde_yaml::{Mapping, Value};
+
+use crate::skill::TargetDomain;
+
+/// Accepted `db_type` values, aliases included.
+const DB_TYPES: &[&str] = &[
+    "postgres",
+    "mysql",
+    "cockroach_db",
+    "cockroach",
+    "yugabyte_db",
+    "yugabyte",
+    "mongodb",
+    "mongo",
+    "mongo_d_b",
+];
+
+/// `db_type` values that select the MongoDB agent.
+const MONGO_DB_TYPES: &[&str] = &["mongodb", "mongo", "mongo_d_b"];
+
+/// Check `config` for a `domain` target. The error lists every problem
+/// found, e.g. "server target: `hosts` is required and must be a non-empty
+/// list".
+pub fn validate_target_config(domain: TargetDomain, config: &Value) -> Result<(), String> {
+    let mut errors = Vec::new();
+    match (domain, config) {
+        // Every Kubernetes field has a default
+        (TargetDomain::Kubernetes, Value::Null) => {}
+        (_, Value::Mapping(map)) => match domain {
+            TargetDomain::Database => check_database(map, &mut errors),
+            TargetDomain::Kubernetes => check_kubernetes(map, &mut errors),
+            TargetDomain::Server => check_server(map, &mut errors),
+        },
+        _ => errors.push("`target_config` must be a mapping of fields".to_string()),
+    }
+    if errors.is_empty() {
+        return Ok(());
+    }
+    Err(errors
+        .iter()
+        .map(|e| format!("{domain} target: {e}"))
+        .collect::<Vec<_>>()
+        .join("; "))
+}
+
+fn check_database(map: &Mapping, errors: &mut Vec<String>) {
+    required_string(map, "connection_url", "connection_url", errors);
+    // A mongodb:// URL selects the MongoDB agent without a db_type
+    let mongo_url = map
+        .get("connection_url")
+        .and_then(Value::as_str)
+        .is_some_and(|u| u.starts_with("mongodb://") || u.starts_with("mongodb+srv://"));
+    let mongo = match map.get("db_type") {
+        None | Some(Value::Null) if mongo_url => true,
+        None | Some(Value::Null) => {
+            errors.push(
+                "`db_type` is required: one of postgres, mysql, cockroach_db, yugabyte_db, mongodb"
+                    .to_string(),
+            );
+            return;
+        }
+        Some(Value::String(db_type)) if !DB_TYPES.contains(&db_type.as_str()) => {
+            errors.push(format!(
+                "`db_type` '{db_type}' is not one of postgres, mysql, cockroach_db, yugabyte_db, mongodb"
+            ));
+            return;
+        }
+        Some(Value::String(db_type)) => MONGO_DB_TYPES.contains(&db_type.as_str()),
+        Some(_) => {
+            errors.push("`db_type` must be a string".to_string());
+            return;
+        }
+    };
+    if mongo {
+        string_list(map, "databases", errors);
+    } else {
+        string_list(map, "schemas", errors);
+    }
+}
+
+fn check_kubernetes(map: &Mapping, errors: &mut Vec<String>) {
+    if map.contains_key("namespace") {
+        required_string(map, "namespace", "namespace", errors);
+    }
+    optional_string(map, "kubeconfig", errors);
+    optional_string(map, "label_selector", errors);
+}
+
+fn check_server(map: &Mapping, errors: &mut Vec<String>) {
+    let hosts = match map.get("hosts") {
+        Some(Value::Sequence(hosts)) if !hosts.is_empty() => hosts,
+        _ => {
+            errors.push("`hosts` is required and must be a non-empty list".to_string());
+            return;
+        }
+    };
+    for (i, host) in hosts.iter().enumerate() {
+        let Value::Mapping(host) = host else {
+            errors.push(format!("`hosts[{i}]` must be a mapping with `host` and `username`"));
+            continue;
+        };
+        required_string(host, "host", &format!("hosts[{i}].host"), errors);
+        required_string(host, "username", &format!("hosts[{i}].username"), errors);
+        match host.get("port") {
+            None => {}
+            Some(Value::Number(port)) if port.as_u64().is_some_and(|p| (1..=65535).contains(&p)) => {}
+            Some(_) => errors.push(format!("`hosts[{i}].port` must be a port number (1-65535)")),
+        }
+        match host.get("auth") {
+            None => {}
+            Some(Value::Mapping(auth)) => match auth.get("type").and_then(Value::as_str) {
+                Some("key") => required_string(
+                    auth,
+                    "private_key_path",
+                    &format!("hosts[{i}].auth.private_key_path"),
+                    errors,
+                ),
+                Some("password") => {
+                    required_string(auth, "password", &format!("hosts[{i}].auth.password"), errors)
+                }
+                _ => errors.push(format!("`hosts[{i}].auth.type` must be `key` or `password`")),
+            },
+            Some(_) => errors.push(format!(
+                "`hosts[{i}].auth` must be a mapping with `type: key` or `type: password`"
+            )),
+        }
+    }
+}
+
+/// `field` of `map` must be a non-empty string; `path` names it in the error.
+fn required_string(map: &Mapping, field: &str, path: &str, errors: &mut Vec<String>) {
+    match map.get(field) {
+        Some(Value::String(s)) if !s.trim().is_empty() => {}
+        _ => errors.push(format!("`{path}` is required and must be a non-empty string")),
+    }
+}
+
+fn optional_string(map: &Mapping, field: &str, errors: &mut Vec<String>) {
+    match map.get(field) {
+        None | Some(Value::Null) | Some(Value::String(_)) => {}
+        Some(_) => errors.push(format!("`{field}` must be a string")),
+    }
+}
+
+fn string_list(map: &Mapping, field: &str, errors: &mut Vec<String>) {
+    match map.get(field) {
+        None | Some(Value::Null) => {}
+        Some(Value::Sequence(items)) if items.iter().all(Value::is_string) => {}
+        Some(_) => errors.push(format!("`{field}` must be a list of strings")),
+    }
+}
@@ -2,22 +2,34 @@ use async_trait::async_trait;
 use sqlx::AnyPool;
 
 use chaos_core::agent::{Agent, AgentStatus};
-use chaos_core::discovery::DiscoveredResource;
+use chaos_core::cleanup::CleanupItem;
+use chaos_core::discovery::{DiscoveredResource, Discovery};
 use chaos_core::error::ChaosResult;
 use chaos_core::skill::{Skill, SkillContext, TargetDomain};
+use chaos_core::skill_registry::add_registered_skills;
+use chaos_core::target_config::validate_target_config;
 
+use crate::cleanup::cleanup_sql;
 use crate::config::{DbTargetConfig, DbType};
 use crate::connection::create_pool;
 use crate::schema_discovery::discover_schema;
+use crate::skills::autovacuum_disrupt::AutovacuumDisruptSkill;
 use crate::skills::config_change::ConfigChangeSkill;
 use crate::skills::crdb_zone_config::CrdbZoneConfigSkill;
+use crate::skills::deadlock::DeadlockSkill;
+use crate::skills::index_hotspot::IndexHotspotSkill;
 use crate::skills::insert_load::InsertLoadSkill;
 use crate::skills::row_lock::RowLockSkill;
 use crate::skills::select_load::SelectLoadSkill;
+use crate::skills::slow_query::SlowQuerySkill;
 use crate::skills::table_lock::TableLockSkill;
 use crate::skills::update_load::UpdateLoadSkill;
+use crate::skills::wal_fill::WalFillSkill;
+use crate::skills::xid_consume::XidConsumeSkill;
 use crate::skills::ysql_follower_reads::YsqlFollowerReadsSkill;
 
+const AGENT_NAME: &str = "database-chaos-agent";
+
 pub struct DbAgent {
     config: DbTargetConfig,
     pool: Option<AnyPool>,
@@ -28,27 +40,41 @@ pub struct DbAgent {
 impl DbAgent {
     pub fn new(config: DbTargetConfig) -> Self {
         let db_type = config.db_type;
+        let schemas = config.schemas.clone();
         let mut skills: Vec<Box<dyn Skill>> = vec![
-            Box::new(InsertLoadSkill),
-            Box::new(UpdateLoadSkill),
-            Box::new(SelectLoadSkill),
+            Box::new(InsertLoadSkill { db_type, schemas: schemas.clone() }),
+            Box::new(IndexHotspotSkill { db_type, schemas: schemas.clone() }),
+            Box::new(UpdateLoadSkill { db_type, schemas: schemas.clone() }),
+            Box::new(SelectLoadSkill { db_type, schemas: schemas.clone() }),
             Box::new(ConfigChangeSkill { db_type }),
+            Box::new(SlowQuerySkill { db_type }),
         ];
 
         // Add lock skills for all SQL databases
-        skills.push(Box::new(TableLockSkill { db_type }));
-        skills.push(Box::new(RowLockSkill { db_type }));
+        skills.push(Box::new(TableLockSkill { db_type, schemas: schemas.clone() }));
+        skills.push(Box::new(RowLockSkill { db_type, schemas: schemas.clone() }));
+        skills.push(Box::new(DeadlockSkill { db_type, schemas: schemas.clone() }));
 
         // Add database-specific skills
         match db_type {
             DbType::CockroachDb => {
                 skills.push(Box::new(CrdbZoneConfigSkill));
             }
+            DbType::Postgres => {
+                skills.push(Box::new(AutovacuumDisruptSkill { db_type, schemas: schemas.clone() }));
+                skills.push(Box::new(XidConsumeSkill));
+                skills.push(Box::new(WalFillSkill { db_type }));
+            }
+            DbType::Mysql => {
+                skills.push(Box::new(WalFillSkill { db_type }));
+            }
             DbType::YugabyteDb => {
                 skills.push(Box::new(YsqlFollowerReadsSkill));
+                skills.push(Box::new(AutovacuumDisruptSkill { db_type, schemas: schemas.clone() }));
             }
             _ => {}
         }
+        add_registered_skills(&mut skills, TargetDomain::Database, AGENT_NAME);
         Self {
             config,
             pool: None,
@@ -58,6 +84,8 @@ impl DbAgent {
     }
 
     pub fn from_yaml(value: &serde_yaml::Value) -> ChaosResult<Self> {
+        validate_target_config(TargetDomain::Database, value)
+            .map_err(chaos_core::error::ChaosError::Config)?;
         let config: DbTargetConfig = serde_yaml::from_value(value.clone())
             .map_err(|e| chaos_core::error::ChaosError::Config(format!("Invalid DB config: {e}")))?;
         Ok(Self::new(config))
@@ -71,7 +99,7 @@ impl Agent for DbAgent {
     }
 
     fn name(&self) -> &str {
-        "database-chaos-agent"
+        AGENT_NAME
     }
 
     fn status(&self) -> AgentStatus {
@@ -82,31 +110,35 @@ impl Agent for DbAgent {
         self.status = AgentStatus::Initializing;
         let pool = create_pool(&self.config)
             .await
-            .map_err(|e| chaos_core::error::ChaosError::Connection(e))?;
+            .map_err(chaos_core::error::ChaosError::Connection)?;
         self.pool = Some(pool);
         self.status = AgentStatus::Ready;
         tracing::info!(db_type = ?self.config.db_type, "Database agent initialized");
         Ok(())
     }
 
-    async fn discover(&mut self) -> ChaosResult<Vec<Box<dyn DiscoveredResource>>> {
+    async fn discover(&mut self) -> ChaosResult<Discovery> {
         self.status = AgentStatus::Discovering;
         let pool = self
             .pool
             .as_ref()
             .ok_or_else(|| chaos_core::error::ChaosError::Connection(anyhow::anyhow!("Not initialized")))?;
 
-        let resources = discover_schema(pool)
+        let (resources, errors) = discover_schema(pool, self.config.db_type, &self.config.schemas)
             .await
             .map_err(|e| chaos_core::error::ChaosError::Discovery(e.to_string()))?;
 
-        tracing::info!(tables = resources.len(), "Schema discovery complete");
+        tracing::info!(tables = resources.len(), failed = errors.len(), "Schema discovery complete");
         self.status = AgentStatus::Ready;
 
-        Ok(resources
-            .into_iter()
-            .map(|r| Box::new(r) as Box<dyn DiscoveredResource>)
-            .collect())
+        Discovery {
+            resources: resources
+                .into_iter()
+                .map(|r| Box::new(r) as Box<dyn DiscoveredResource>)
+                .collect(),
+            errors,
+        }
+        .into_result()
     }
 
     fn skills(&self) -> Vec<&dyn Skill> {
@@ -130,9 +162,38 @@ impl Agent for DbAgent {
         Ok(SkillContext {
             shared: Box::new(pool),
             params: serde_yaml::Value::Null,
+            parsed_params: Default::default(),
+            output: Default::default(),
+            experiment_id: uuid::Uuid::nil(),
+            experiment_name: String::new(),
+            skill_name: String::new(),
+            dry_run: false,
+            limits: Default::default(),
         })
     }
 
+    async fn health_check(&self) -> ChaosResult<()> {
+        let pool = self
+            .pool
+            .as_ref()
+            .ok_or_else(|| chaos_core::error::ChaosError::Connection(anyhow::anyhow!("Not initialized")))?;
+        sqlx::query("SELECT 1")
+            .execute(pool)
+            .await
+            .map_err(|e| chaos_core::error::ChaosError::Unhealthy(format!("SELECT 1 failed: {e}")))?;
+        Ok(())
+    }
+
+    async fn cleanup(&mut self, dry_run: bool) -> ChaosResult<Vec<CleanupItem>> {
+        let pool = self
+            .pool
+            .as_ref()
+            .ok_or_else(|| chaos_core::error::ChaosError::Connection(anyhow::anyhow!("Not initialized")))?;
+        cleanup_sql(pool, self.config.db_type, dry_run)
+            .await
+            .map_err(chaos_core::error::ChaosError::Other)
+    }
+
     async fn shutdown(&mut self) -> ChaosResult<()> {
         if let Some(pool) = self.pool.take() {
             pool.close().await;
@@ -0,0 +1,204 @@
+use chaos_core::cleanup::{CleanupAction, CleanupItem};
+use mongodb::bson::{doc, Document};
+use sqlx::{AnyPool, Row};
+
+use crate::config::DbType;
+use crate::skills::sql_utils::{qualified_table, quote_ident};
+
+/// Prefix `db.insert_load` writes into text columns.
+const TEST_ROW_PREFIX: &str = "chaos_agent_test_";
+/// Value `db.update_load` overwrites text columns with.
+const MODIFIED_VALUE: &str = "chaos_modified";
+/// Throwaway tables created by `db.wal_fill` / `db.xid_consume`.
+const CHAOS_TABLES: &[&str] = &["chaos_wal_fill", "chaos_xid_burn"];
+/// Replication slot created by `db.wal_fill` with `hold_with_slot`.
+const CHAOS_SLOT: &str = "chaos_wal_hold";
+
+/// Find and remove leftover chaos artifacts in a SQL database.
+pub async fn cleanup_sql(
+    pool: &AnyPool,
+    db_type: DbType,
+    dry_run: bool,
+) -> anyhow::Result<Vec<CleanupItem>> {
+    let mut items = Vec::new();
+
+    if db_type == DbType::Postgres {
+        let slot = sqlx::query(&format!(
+            "SELECT slot_name FROM pg_replication_slots WHERE slot_name = '{CHAOS_SLOT}'"
+        ))
+        .fetch_optional(pool)
+        .await?;
+        if slot.is_some() {
+            let action = if dry_run {
+                CleanupAction::WouldRemove
+            } else {
+                CleanupAction::from_result(
+                    sqlx::query(&format!("SELECT pg_drop_replication_slot('{CHAOS_SLOT}')"))
+                        .execute(pool)
+                        .await
+                        .map(|_| ()),
+                )
+            };
+            items.push(CleanupItem::new(
+                "replication slot",
+                CHAOS_SLOT,
+                "pins WAL until dropped",
+                action,
+            ));
+        }
+    }
+
+    let table_list = CHAOS_TABLES
+        .iter()
+        .map(|t| format!("'{t}'"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let tables = sqlx::query(&format!(
+        "SELECT table_schema, table_name FROM information_schema.tables \
+         WHERE table_name IN ({table_list})"
+    ))
+    .fetch_all(pool)
+    .await?;
+    for row in &tables {
+        let schema: String = row.get("table_schema");
+        let table: String = row.get("table_name");
+        let action = if dry_run {
+            CleanupAction::WouldRemove
+        } else {
+            CleanupAction::from_result(
+                sqlx::query(&format!(
+                    "DROP TABLE IF EXISTS {}",
+                    qualified_table(db_type, &schema, &table)
+                ))
+                    .execute(pool)
+                    .await
+                    .map(|_| ()),
+            )
+        };
+        items.push(CleanupItem::new("table", format!("{schema}.{table}"), "chaos scratch table", action));
+    }
+
+    let columns = sqlx::query(
+        "SELECT table_schema, table_name, column_name FROM information_schema.columns \
+         WHERE table_schema NOT IN ('information_schema', 'pg_catalog', 'mysql', 'performance_schema', 'sys', 'crdb_internal', 'pg_extension') \
+         AND (data_type LIKE '%char%' OR data_type LIKE '%text%')",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for row in &columns {
+        let schema: String = row.get("table_schema");
+        let table: String = row.get("table_name");
+        let column: String = row.get("column_name");
+        if CHAOS_TABLES.contains(&table.as_str()) {
+            continue;
+        }
+        let location = format!("{schema}.{table}.{column}");
+        let qualified = qualified_table(db_type, &schema, &table);
+        let column = quote_ident(db_type, &column);
+        let test_rows = format!("{column} LIKE '{}%'", TEST_ROW_PREFIX.replace('_', "\\_"));
+
+        match count_where(pool, &qualified, &test_rows).await {
+            Ok(0) => {}
+            Ok(n) => {
+                let action = if dry_run {
+                    CleanupAction::WouldRemove
+                } else {
+                    CleanupAction::from_result(
+                        sqlx::query(&format!("DELETE FROM {qualified} WHERE {test_rows}"))
+                            .execute(pool)
+                            .await
+                            .map(|_| ()),
+                    )
+                };
+                items.push(CleanupItem::new(
+                    "rows",
+                    location.clone(),
+                    format!("{n} rows with '{TEST_ROW_PREFIX}' values"),
+                    action,
+                ));
+            }
+            Err(e) => {
+                tracing::debug!(column = %location, error = %e, "Skipping column during cleanup scan");
+                continue;
+            }
+        }
+
+        if let Ok(n @ 1..) =
+            count_where(pool, &qualified, &format!("{column} = '{MODIFIED_VALUE}'")).await
+        {
+            items.push(CleanupItem::new(
+                "rows",
+                location,
+                format!("{n} rows set to '{MODIFIED_VALUE}'"),
+                CleanupAction::ReportOnly("original values unknown, restore from backup".into()),
+            ));
+        }
+    }
+
+    Ok(items)
+}
+
+/// Rows of `table` (already quoted) matching `condition`.
+async fn count_where(pool: &AnyPool, table: &str, condition: &str) -> Result<i64, sqlx::Error> {
+    let row = sqlx::query(&format!("SELECT COUNT(*) AS n FROM {table} WHERE {condition}"))
+    .fetch_one(pool)
+    .await?;
+    Ok(row.try_get::<i64, _>("n").unwrap_or_default())
+}
+
+/// Find and remove documents left by the Mongo load skills.
+pub async fn cleanup_mongo(
+    client: &mongodb::Client,
+    databases: &[String],
+    dry_run: bool,
+) -> anyhow::Result<Vec<CleanupItem>> {
+    let db_names = if databases.is_empty() {
+        client
+            .list_database_names()
+            .await?
+            .into_iter()
+            .filter(|name| !matches!(name.as_str(), "admin" | "local" | "config"))
+            .collect()
+    } else {
+        databases.to_vec()
+    };
+
+    let mut items = Vec::new();
+    for db_name in &db_names {
+        let db = client.database(db_name);
+        for coll_name in db.list_collection_names().await? {
+            let coll = db.collection::<Document>(&coll_name);
+            let location = format!("{db_name}.{coll_name}");
+
+            let inserted = coll.count_documents(doc! { "chaos_test": true }).await?;
+            if inserted > 0 {
+                let action = if dry_run {
+                    CleanupAction::WouldRemove
+                } else {
+                    CleanupAction::from_result(
+                        coll.delete_many(doc! { "chaos_test": true }).await.map(|_| ()),
+                    )
+                };
+                items.push(CleanupItem::new(
+                    "documents",
+                    location.clone(),
+                    format!("{inserted} documents with chaos_test: true"),
+                    action,
+                ));
+            }
+
+            let modified = coll.count_documents(doc! { "chaos_modified": true }).await?;
+            if modified > 0 {
+                items.push(CleanupItem::new(
+                    "documents",
+                    location,
+                    format!("{modified} documents with chaos_modified: true"),
+                    CleanupAction::ReportOnly("original documents unknown, restore from backup".into()),
+                ));
+            }
+        }
+    }
+
+    Ok(items)
+}
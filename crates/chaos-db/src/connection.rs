@@ -1,9 +1,18 @@
+use sqlx::any::AnyPoolOptions;
 use sqlx::AnyPool;
 
 use crate::config::DbTargetConfig;
 
+/// Connections in the agent's pool. Skills that pin connections for the
+/// whole experiment must stay below this, leaving room for the queries
+/// that terminate them at rollback.
+pub const POOL_MAX_CONNECTIONS: u32 = 80;
+
 pub async fn create_pool(config: &DbTargetConfig) -> anyhow::Result<AnyPool> {
     sqlx::any::install_default_drivers();
-    let pool = AnyPool::connect(&config.connection_url).await?;
+    let pool = AnyPoolOptions::new()
+        .max_connections(POOL_MAX_CONNECTIONS)
+        .connect(&config.connection_url)
+        .await?;
     Ok(pool)
 }
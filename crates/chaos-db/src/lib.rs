@@ -1,4 +1,5 @@
 pub mod agent;
+pub mod cleanup;
 pub mod config;
 pub mod connection;
 pub mod mongo_agent;
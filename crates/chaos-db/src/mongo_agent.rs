@@ -2,10 +2,14 @@ use async_trait::async_trait;
 use mongodb::Client;
 
 use chaos_core::agent::{Agent, AgentStatus};
-use chaos_core::discovery::DiscoveredResource;
+use chaos_core::cleanup::CleanupItem;
+use chaos_core::discovery::{DiscoveredResource, Discovery};
 use chaos_core::error::ChaosResult;
 use chaos_core::skill::{Skill, SkillContext, TargetDomain};
+use chaos_core::skill_registry::add_registered_skills;
+use chaos_core::target_config::validate_target_config;
 
+use crate::cleanup::cleanup_mongo;
 use crate::mongo_config::MongoTargetConfig;
 use crate::mongo_discovery::discover_mongo;
 use crate::skills::mongo_connection_stress::MongoConnectionStressSkill;
@@ -15,6 +19,8 @@ use crate::skills::mongo_insert_load::MongoInsertLoadSkill;
 use crate::skills::mongo_profiling_change::MongoProfilingChangeSkill;
 use crate::skills::mongo_update_load::MongoUpdateLoadSkill;
 
+const AGENT_NAME: &str = "mongodb-chaos-agent";
+
 pub struct MongoAgent {
     config: MongoTargetConfig,
     client: Option<Client>,
@@ -24,7 +30,7 @@ pub struct MongoAgent {
 
 impl MongoAgent {
     pub fn new(config: MongoTargetConfig) -> Self {
-        let skills: Vec<Box<dyn Skill>> = vec![
+        let mut skills: Vec<Box<dyn Skill>> = vec![
             Box::new(MongoInsertLoadSkill),
             Box::new(MongoUpdateLoadSkill),
             Box::new(MongoFindLoadSkill),
@@ -32,6 +38,7 @@ impl MongoAgent {
             Box::new(MongoProfilingChangeSkill),
             Box::new(MongoConnectionStressSkill),
         ];
+        add_registered_skills(&mut skills, TargetDomain::Database, AGENT_NAME);
         Self {
             config,
             client: None,
@@ -41,6 +48,8 @@ impl MongoAgent {
     }
 
     pub fn from_yaml(value: &serde_yaml::Value) -> ChaosResult<Self> {
+        validate_target_config(TargetDomain::Database, value)
+            .map_err(chaos_core::error::ChaosError::Config)?;
         let config: MongoTargetConfig = serde_yaml::from_value(value.clone()).map_err(|e| {
             chaos_core::error::ChaosError::Config(format!("Invalid MongoDB config: {e}"))
         })?;
@@ -55,7 +64,7 @@ impl Agent for MongoAgent {
     }
 
     fn name(&self) -> &str {
-        "mongodb-chaos-agent"
+        AGENT_NAME
     }
 
     fn status(&self) -> AgentStatus {
@@ -85,7 +94,7 @@ impl Agent for MongoAgent {
         Ok(())
     }
 
-    async fn discover(&mut self) -> ChaosResult<Vec<Box<dyn DiscoveredResource>>> {
+    async fn discover(&mut self) -> ChaosResult<Discovery> {
         self.status = AgentStatus::Discovering;
         let client = self
             .client
@@ -94,17 +103,21 @@ impl Agent for MongoAgent {
                 chaos_core::error::ChaosError::Connection(anyhow::anyhow!("Not initialized"))
             })?;
 
-        let resources = discover_mongo(client, &self.config.databases)
+        let (resources, errors) = discover_mongo(client, &self.config.databases)
             .await
             .map_err(|e| chaos_core::error::ChaosError::Discovery(e.to_string()))?;
 
-        tracing::info!(collections = resources.len(), "MongoDB discovery complete");
+        tracing::info!(collections = resources.len(), failed = errors.len(), "MongoDB discovery complete");
         self.status = AgentStatus::Ready;
 
-        Ok(resources
-            .into_iter()
-            .map(|r| Box::new(r) as Box<dyn DiscoveredResource>)
-            .collect())
+        Discovery {
+            resources: resources
+                .into_iter()
+                .map(|r| Box::new(r) as Box<dyn DiscoveredResource>)
+                .collect(),
+            errors,
+        }
+        .into_result()
     }
 
     fn skills(&self) -> Vec<&dyn Skill> {
@@ -130,9 +143,38 @@ impl Agent for MongoAgent {
         Ok(SkillContext {
             shared: Box::new(client),
             params: serde_yaml::Value::Null,
+            parsed_params: Default::default(),
+            output: Default::default(),
+            experiment_id: uuid::Uuid::nil(),
+            experiment_name: String::new(),
+            skill_name: String::new(),
+            dry_run: false,
+            limits: Default::default(),
         })
     }
 
+    async fn health_check(&self) -> ChaosResult<()> {
+        let client = self.client.as_ref().ok_or_else(|| {
+            chaos_core::error::ChaosError::Connection(anyhow::anyhow!("Not initialized"))
+        })?;
+        client
+            .database("admin")
+            .run_command(mongodb::bson::doc! { "ping": 1 })
+            .await
+            .map_err(|e| chaos_core::error::ChaosError::Unhealthy(format!("ping failed: {e}")))?;
+        Ok(())
+    }
+
+    async fn cleanup(&mut self, dry_run: bool) -> ChaosResult<Vec<CleanupItem>> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| chaos_core::error::ChaosError::Connection(anyhow::anyhow!("Not initialized")))?;
+        cleanup_mongo(client, &self.config.databases, dry_run)
+            .await
+            .map_err(chaos_core::error::ChaosError::Other)
+    }
+
     async fn shutdown(&mut self) -> ChaosResult<()> {
         self.client = None;
         self.status = AgentStatus::Idle;
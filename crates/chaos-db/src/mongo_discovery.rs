@@ -1,12 +1,14 @@
-use chaos_core::discovery::MongoResource;
+use chaos_core::discovery::{DiscoveryError, MongoResource};
 use mongodb::Client;
 
-/// Discover MongoDB databases and collections.
+/// Discover MongoDB databases and collections. Databases whose collections
+/// can't be listed are returned as errors alongside the others.
 pub async fn discover_mongo(
     client: &Client,
     filter_databases: &[String],
-) -> anyhow::Result<Vec<MongoResource>> {
+) -> anyhow::Result<(Vec<MongoResource>, Vec<DiscoveryError>)> {
     let mut resources = Vec::new();
+    let mut errors = Vec::new();
 
     let db_names = client.list_database_names().await?;
 
@@ -22,7 +24,16 @@ pub async fn discover_mongo(
         }
 
         let db = client.database(db_name);
-        let collection_names = db.list_collection_names().await?;
+        let collection_names = match db.list_collection_names().await {
+            Ok(names) => names,
+            Err(e) => {
+                errors.push(DiscoveryError {
+                    scope: db_name.clone(),
+                    error: e.to_string(),
+                });
+                continue;
+            }
+        };
 
         for coll_name in &collection_names {
             // Skip system collections
@@ -41,5 +52,5 @@ pub async fn discover_mongo(
         }
     }
 
-    Ok(resources)
+    Ok((resources, errors))
 }
@@ -1,12 +1,22 @@
-use chaos_core::discovery::{ColumnInfo, DbResource};
+use chaos_core::discovery::{ColumnInfo, DbResource, DiscoveryError};
 use sqlx::AnyPool;
 use sqlx::Row;
 
-/// Introspect database schema using information_schema (works for both Pg and MySQL).
-pub async fn discover_schema(pool: &AnyPool) -> anyhow::Result<Vec<DbResource>> {
+use crate::config::DbType;
+use crate::skills::sql_utils::placeholders;
+
+/// Introspect database schema using information_schema (works for both Pg and
+/// MySQL), limited to `schemas` unless it's empty. Columns are aliased
+/// because MySQL 8 reports them in upper case. Tables whose columns can't
+/// be read are returned as errors alongside the others.
+pub async fn discover_schema(
+    pool: &AnyPool,
+    db_type: DbType,
+    schemas: &[String],
+) -> anyhow::Result<(Vec<DbResource>, Vec<DiscoveryError>)> {
     let tables = sqlx::query(
         r#"
-        SELECT table_schema, table_name
+        SELECT table_schema AS table_schema, table_name AS table_name
         FROM information_schema.tables
         WHERE table_schema NOT IN ('information_schema', 'pg_catalog', 'mysql', 'performance_schema', 'sys', 'crdb_internal')
           AND table_type = 'BASE TABLE'
@@ -17,17 +27,22 @@ pub async fn discover_schema(pool: &AnyPool) -> anyhow::Result<Vec<DbResource>>
     .await?;
 
     let mut resources = Vec::new();
+    let mut errors = Vec::new();
 
     for table_row in &tables {
         let schema: String = table_row.get("table_schema");
         let table_name: String = table_row.get("table_name");
+        if !schemas.is_empty() && !schemas.contains(&schema) {
+            continue;
+        }
 
-        let columns = sqlx::query(
+        let columns = sqlx::query(&placeholders(
+            db_type,
             r#"
             SELECT
-                c.column_name,
-                c.data_type,
-                c.is_nullable,
+                c.column_name AS column_name,
+                c.data_type AS data_type,
+                c.is_nullable AS is_nullable,
                 CASE WHEN tc.constraint_type = 'PRIMARY KEY' THEN 'YES' ELSE 'NO' END as is_pk
             FROM information_schema.columns c
             LEFT JOIN information_schema.key_column_usage kcu
@@ -37,15 +52,26 @@ pub async fn discover_schema(pool: &AnyPool) -> anyhow::Result<Vec<DbResource>>
             LEFT JOIN information_schema.table_constraints tc
                 ON kcu.constraint_name = tc.constraint_name
                 AND kcu.table_schema = tc.table_schema
+                AND kcu.table_name = tc.table_name
                 AND tc.constraint_type = 'PRIMARY KEY'
             WHERE c.table_schema = $1 AND c.table_name = $2
             ORDER BY c.ordinal_position
             "#,
-        )
+        ))
         .bind(&schema)
         .bind(&table_name)
         .fetch_all(pool)
-        .await?;
+        .await;
+        let columns = match columns {
+            Ok(columns) => columns,
+            Err(e) => {
+                errors.push(DiscoveryError {
+                    scope: format!("{schema}.{table_name}"),
+                    error: e.to_string(),
+                });
+                continue;
+            }
+        };
 
         let column_infos: Vec<ColumnInfo> = columns
             .iter()
@@ -65,5 +91,5 @@ pub async fn discover_schema(pool: &AnyPool) -> anyhow::Result<Vec<DbResource>>
         });
     }
 
-    Ok(resources)
+    Ok((resources, errors))
 }
@@ -0,0 +1,241 @@
+use async_trait::async_trait;
+use chaos_core::error::{ChaosError, ChaosResult};
+use chaos_core::rollback::RollbackHandle;
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use serde::{Deserialize, Serialize};
+use sqlx::AnyPool;
+use sqlx::Row;
+
+use crate::config::DbType;
+use crate::skills::sql_utils::{resolve_tables, validate_table_patterns};
+
+/// PostgreSQL/YugabyteDB: disable autovacuum on target tables and optionally
+/// churn rows to build up dead tuples, simulating slow-burn table bloat.
+/// The prior per-table `autovacuum_enabled` setting is restored on rollback.
+pub struct AutovacuumDisruptSkill {
+    pub db_type: DbType,
+    /// The target's `schemas`; empty means all.
+    pub schemas: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AutovacuumParams {
+    /// Tables to target, as `table` (in each target schema, or `public`) or
+    /// `schema.table`.
+    /// Default: up to 5 user tables.
+    #[serde(default)]
+    tables: Vec<String>,
+    /// Rows per table to rewrite in place (no data change) to create dead
+    /// tuples. Default: 0 (only disable autovacuum).
+    #[serde(default)]
+    churn_rows: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AutovacuumUndoEntry {
+    schema: String,
+    table: String,
+    /// Explicit `autovacuum_enabled` reloption before the change; `None` means
+    /// the table inherited the server default and the option should be reset.
+    original: Option<String>,
+}
+
+#[async_trait]
+impl Skill for AutovacuumDisruptSkill {
+    fn descriptor(&self) -> SkillDescriptor {
+        SkillDescriptor {
+            name: "db.autovacuum_disrupt".into(),
+            description: "Disable autovacuum on target tables and optionally churn rows to accumulate bloat".into(),
+            target: TargetDomain::Database,
+            reversible: true,
+        }
+    }
+
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "tables": { "type": "array", "items": { "type": "string" } },
+                "churn_rows": { "type": "integer", "default": 0 }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
+    fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
+        let params: AutovacuumParams = parse_params("db.autovacuum_disrupt", params)?;
+        validate_table_patterns(&params.tables)?;
+        Ok(())
+    }
+
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool")))?;
+
+        let params = ctx.typed_params::<AutovacuumParams>()?;
+
+        let mut tables_to_target = resolve_tables(pool, self.db_type, &self.schemas, &params.tables, 5).await?;
+        tables_to_target.truncate(ctx.clamp("tables", tables_to_target.len(), ctx.limits.max_tables));
+        let churn_rows_per_table = ctx.clamp(
+            "churned rows per table",
+            params.churn_rows,
+            ctx.limits.max_rows_per_table(tables_to_target.len()),
+        );
+
+        let mut undo_entries = Vec::new();
+
+        for (schema, table) in &tables_to_target {
+            let original = match read_autovacuum_option(pool, schema, table).await {
+                Ok(original) => original,
+                Err(e) => {
+                    tracing::warn!(table = %table, error = %e, "Failed to read reloptions, skipping table");
+                    continue;
+                }
+            };
+
+            let alter = format!(
+                "ALTER TABLE {schema}.{table} SET (autovacuum_enabled = false)"
+            );
+            if ctx.dry_run {
+                let churn = if churn_rows_per_table > 0 {
+                    format!(", then churn {churn_rows_per_table} rows")
+                } else {
+                    String::new()
+                };
+                ctx.record_planned(format!("{alter} (currently {original:?}){churn}"));
+                continue;
+            }
+            if let Err(e) = sqlx::query(&alter).execute(pool).await {
+                tracing::warn!(table = %table, error = %e, "Failed to disable autovacuum");
+                continue;
+            }
+
+            tracing::info!(
+                table = %table,
+                original = ?original,
+                "Disabled autovacuum"
+            );
+            undo_entries.push(AutovacuumUndoEntry {
+                schema: schema.clone(),
+                table: table.clone(),
+                original,
+            });
+
+            if churn_rows_per_table > 0 {
+                churn_rows(pool, schema, table, churn_rows_per_table).await;
+            }
+        }
+
+        if ctx.dry_run {
+            return Ok(RollbackHandle::dry_run("db.autovacuum_disrupt"));
+        }
+
+        if undo_entries.is_empty() && !tables_to_target.is_empty() {
+            return Err(ChaosError::Other(anyhow::anyhow!(
+                "Failed to disable autovacuum on any target table"
+            )));
+        }
+
+        let undo_state = serde_yaml::to_value(&undo_entries)
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
+
+        Ok(RollbackHandle::new("db.autovacuum_disrupt", undo_state))
+    }
+
+    async fn rollback(&self, ctx: &SkillContext, handle: &RollbackHandle) -> ChaosResult<()> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool")))?;
+
+        let entries: Vec<AutovacuumUndoEntry> = serde_yaml::from_value(handle.undo_state.clone())
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
+
+        for entry in &entries {
+            let query = match &entry.original {
+                Some(value) => format!(
+                    "ALTER TABLE {}.{} SET (autovacuum_enabled = {value})",
+                    entry.schema, entry.table
+                ),
+                None => format!(
+                    "ALTER TABLE {}.{} RESET (autovacuum_enabled)",
+                    entry.schema, entry.table
+                ),
+            };
+            match sqlx::query(&query).execute(pool).await {
+                Ok(_) => tracing::info!(table = %entry.table, "Restored autovacuum setting"),
+                Err(e) => {
+                    tracing::error!(table = %entry.table, error = %e, "Rollback autovacuum failed")
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Read the table's explicit `autovacuum_enabled` reloption, if any.
+async fn read_autovacuum_option(
+    pool: &AnyPool,
+    schema: &str,
+    table: &str,
+) -> Result<Option<String>, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT COALESCE(array_to_string(c.reloptions, ','), '') AS options \
+         FROM pg_class c JOIN pg_namespace n ON n.oid = c.relnamespace \
+         WHERE n.nspname = $1 AND c.relname = $2",
+    )
+    .bind(schema)
+    .bind(table)
+    .fetch_one(pool)
+    .await?;
+
+    let options: String = row.try_get("options").unwrap_or_default();
+    Ok(options
+        .split(',')
+        .find_map(|opt| opt.strip_prefix("autovacuum_enabled="))
+        .map(|v| v.to_string()))
+}
+
+/// Rewrite rows onto themselves via the primary key. Every update leaves a dead
+/// tuple behind while the visible data stays unchanged.
+async fn churn_rows(pool: &AnyPool, schema: &str, table: &str, rows: u32) {
+    let pk_col = sqlx::query(
+        "SELECT kcu.column_name FROM information_schema.key_column_usage kcu \
+         JOIN information_schema.table_constraints tc \
+           ON kcu.constraint_name = tc.constraint_name AND kcu.table_schema = tc.table_schema \
+         WHERE tc.constraint_type = 'PRIMARY KEY' AND kcu.table_schema = $1 AND kcu.table_name = $2 LIMIT 1",
+    )
+    .bind(schema)
+    .bind(table)
+    .fetch_optional(pool)
+    .await;
+
+    let pk_column: String = match pk_col {
+        Ok(Some(row)) => row.get("column_name"),
+        Ok(None) => {
+            tracing::warn!(table = %table, "No primary key, skipping churn");
+            return;
+        }
+        Err(e) => {
+            tracing::warn!(table = %table, error = %e, "PK fetch failed, skipping churn");
+            return;
+        }
+    };
+
+    let query = format!(
+        "UPDATE {schema}.{table} SET {pk_column} = {pk_column} \
+         WHERE {pk_column} IN (SELECT {pk_column} FROM {schema}.{table} LIMIT $1)"
+    );
+    match sqlx::query(&query).bind(rows as i64).execute(pool).await {
+        Ok(result) => {
+            tracing::info!(table = %table, churned = result.rows_affected(), "Generated dead tuples")
+        }
+        Err(e) => tracing::warn!(table = %table, error = %e, "Row churn failed"),
+    }
+}
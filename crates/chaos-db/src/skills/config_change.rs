@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use serde::{Deserialize, Serialize};
 use sqlx::AnyPool;
 use sqlx::Row;
@@ -12,6 +12,43 @@ pub struct ConfigChangeSkill {
     pub db_type: DbType,
 }
 
+/// PostgreSQL parameters that only take effect after a server restart;
+/// `pg_reload_conf()` neither applies nor reverts them.
+const PG_RESTART_PARAMS: &[&str] = &[
+    "max_connections",
+    "shared_buffers",
+    "superuser_reserved_connections",
+    "max_worker_processes",
+    "max_wal_senders",
+    "max_replication_slots",
+    "max_prepared_transactions",
+    "max_locks_per_transaction",
+    "autovacuum_max_workers",
+    "wal_level",
+    "wal_buffers",
+    "huge_pages",
+    "shared_preload_libraries",
+    "listen_addresses",
+    "port",
+    "track_activity_query_size",
+];
+
+/// MySQL system variables that are read-only at runtime, so `SET GLOBAL`
+/// fails and only a restart with a new config changes them.
+const MYSQL_RESTART_PARAMS: &[&str] = &[
+    "innodb_buffer_pool_instances",
+    "innodb_page_size",
+    "innodb_data_file_path",
+    "innodb_flush_method",
+    "innodb_read_io_threads",
+    "innodb_write_io_threads",
+    "lower_case_table_names",
+    "performance_schema",
+    "bind_address",
+    "port",
+    "skip_networking",
+];
+
 #[derive(Debug, Deserialize)]
 struct ConfigChangeParams {
     changes: Vec<ConfigEntry>,
@@ -30,6 +67,34 @@ struct ConfigUndoEntry {
     db_type: String,
 }
 
+impl ConfigChangeSkill {
+    async fn current_value(&self, pool: &AnyPool, param: &str) -> ChaosResult<String> {
+        let (query, what) = match self.db_type {
+            DbType::Postgres | DbType::YugabyteDb => (format!("SHOW {param}"), "config"),
+            DbType::CockroachDb => (format!("SHOW CLUSTER SETTING {param}"), "cluster setting"),
+            DbType::Mysql => (format!("SELECT @@{param}"), "config"),
+            DbType::MongoDB => unreachable!(),
+        };
+        let row = sqlx::query(&query).fetch_one(pool).await.map_err(|e| {
+            ChaosError::Other(anyhow::anyhow!("Failed to read {what} {param}: {e}"))
+        })?;
+        Ok(row.try_get::<String, _>(0).unwrap_or_default())
+    }
+
+    /// Whether a PostgreSQL-compatible parameter only takes effect after a
+    /// server restart, so a reload leaves the running value behind.
+    async fn pending_restart(&self, pool: &AnyPool, param: &str) -> bool {
+        if !matches!(self.db_type, DbType::Postgres | DbType::YugabyteDb) {
+            return false;
+        }
+        let query = format!("SELECT pending_restart FROM pg_settings WHERE name = '{param}'");
+        match sqlx::query(&query).fetch_optional(pool).await {
+            Ok(Some(row)) => row.try_get::<bool, _>(0).unwrap_or(false),
+            _ => false,
+        }
+    }
+}
+
 #[async_trait]
 impl Skill for ConfigChangeSkill {
     fn descriptor(&self) -> SkillDescriptor {
@@ -41,9 +106,42 @@ impl Skill for ConfigChangeSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "required": ["changes"],
+            "properties": {
+                "changes": {
+                    "type": "array",
+                    "description": "Server settings to change",
+                    "items": {
+                        "type": "object",
+                        "required": ["param", "value"],
+                        "properties": {
+                            "param": { "type": "string" },
+                            "value": { "type": "string" }
+                        }
+                    }
+                }
+            }
+        })
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: ConfigChangeParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid config_change params: {e}")))?;
+        let params: ConfigChangeParams = parse_params("config_change", params)?;
+        let restart_params = match self.db_type {
+            DbType::Postgres | DbType::YugabyteDb => PG_RESTART_PARAMS,
+            DbType::Mysql => MYSQL_RESTART_PARAMS,
+            DbType::CockroachDb | DbType::MongoDB => &[],
+        };
+        for change in &params.changes {
+            if restart_params.contains(&change.param.to_lowercase().as_str()) {
+                tracing::warn!(
+                    param = %change.param,
+                    "config_change parameter requires a server restart; the change may not apply or roll back"
+                );
+            }
+        }
         Ok(())
     }
 
@@ -53,60 +151,20 @@ impl Skill for ConfigChangeSkill {
             .downcast_ref::<AnyPool>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool")))?;
 
-        let params: ConfigChangeParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<ConfigChangeParams>()?;
 
         let mut undo_entries = Vec::new();
 
         for change in &params.changes {
+            if matches!(self.db_type, DbType::MongoDB) {
+                return Err(ChaosError::Config(
+                    "config_change skill not supported for MongoDB; use mongo-specific skills"
+                        .into(),
+                ));
+            }
+
             // Get current value
-            let original_value = match self.db_type {
-                DbType::Postgres | DbType::YugabyteDb => {
-                    let query = format!("SHOW {}", change.param);
-                    let row = sqlx::query(&query)
-                        .fetch_one(pool)
-                        .await
-                        .map_err(|e| {
-                            ChaosError::Other(anyhow::anyhow!(
-                                "Failed to read config {}: {e}",
-                                change.param
-                            ))
-                        })?;
-                    row.try_get::<String, _>(0).unwrap_or_default()
-                }
-                DbType::CockroachDb => {
-                    let query = format!("SHOW CLUSTER SETTING {}", change.param);
-                    let row = sqlx::query(&query)
-                        .fetch_one(pool)
-                        .await
-                        .map_err(|e| {
-                            ChaosError::Other(anyhow::anyhow!(
-                                "Failed to read cluster setting {}: {e}",
-                                change.param
-                            ))
-                        })?;
-                    row.try_get::<String, _>(0).unwrap_or_default()
-                }
-                DbType::Mysql => {
-                    let query = format!("SELECT @@{}", change.param);
-                    let row = sqlx::query(&query)
-                        .fetch_one(pool)
-                        .await
-                        .map_err(|e| {
-                            ChaosError::Other(anyhow::anyhow!(
-                                "Failed to read config {}: {e}",
-                                change.param
-                            ))
-                        })?;
-                    row.try_get::<String, _>(0).unwrap_or_default()
-                }
-                DbType::MongoDB => {
-                    return Err(ChaosError::Config(
-                        "config_change skill not supported for MongoDB; use mongo-specific skills"
-                            .into(),
-                    ));
-                }
-            };
+            let original_value = self.current_value(pool, &change.param).await?;
 
             // Apply new value
             let alter_query = match self.db_type {
@@ -122,6 +180,11 @@ impl Skill for ConfigChangeSkill {
                 DbType::MongoDB => unreachable!(),
             };
 
+            if ctx.dry_run {
+                ctx.record_planned(format!("{alter_query} (currently {original_value})"));
+                continue;
+            }
+
             sqlx::query(&alter_query)
                 .execute(pool)
                 .await
@@ -151,6 +214,10 @@ impl Skill for ConfigChangeSkill {
             });
         }
 
+        if ctx.dry_run {
+            return Ok(RollbackHandle::dry_run("db.config_change"));
+        }
+
         let undo_state = serde_yaml::to_value(&undo_entries)
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
 
@@ -200,6 +267,33 @@ impl Skill for ConfigChangeSkill {
             }
         }
 
+        // Re-read every parameter: a failed restore or one that needs a
+        // restart must fail the rollback step rather than pass silently.
+        let mut mismatches = Vec::new();
+        for entry in &entries {
+            match self.current_value(pool, &entry.param).await {
+                Ok(current) if current != entry.original_value => {
+                    mismatches.push(format!(
+                        "{} is '{current}', expected '{}'",
+                        entry.param, entry.original_value
+                    ));
+                }
+                Ok(_) if self.pending_restart(pool, &entry.param).await => {
+                    mismatches.push(format!("{} needs a server restart to apply", entry.param));
+                }
+                Ok(_) => {}
+                Err(e) => mismatches.push(e.to_string()),
+            }
+        }
+
+        if !mismatches.is_empty() {
+            tracing::error!(mismatches = ?mismatches, "Config not fully restored");
+            return Err(ChaosError::RollbackFailed {
+                skill_name: "db.config_change".into(),
+                source: anyhow::anyhow!("config not restored: {}", mismatches.join("; ")),
+            });
+        }
+
         Ok(())
     }
 }
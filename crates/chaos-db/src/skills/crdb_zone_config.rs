@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use serde::{Deserialize, Serialize};
 use sqlx::AnyPool;
 use sqlx::Row;
@@ -44,9 +44,29 @@ impl Skill for CrdbZoneConfigSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "required": ["target", "changes"],
+            "properties": {
+                "target": { "type": "string", "description": "\"DATABASE dbname\", \"TABLE schema.table\" or \"RANGE default\"" },
+                "changes": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "required": ["param", "value"],
+                        "properties": {
+                            "param": { "type": "string", "description": "e.g. num_replicas, gc.ttlseconds" },
+                            "value": { "type": "string" }
+                        }
+                    }
+                }
+            }
+        })
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: ZoneConfigParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid crdb.zone_config_change params: {e}")))?;
+        let _: ZoneConfigParams = parse_params("crdb.zone_config_change", params)?;
         Ok(())
     }
 
@@ -56,8 +76,7 @@ impl Skill for CrdbZoneConfigSkill {
             .downcast_ref::<AnyPool>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool")))?;
 
-        let params: ZoneConfigParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<ZoneConfigParams>()?;
 
         // Get the current zone configuration for rollback
         let show_query = format!("SHOW ZONE CONFIGURATION FOR {}", params.target);
@@ -89,6 +108,11 @@ impl Skill for CrdbZoneConfigSkill {
             overrides.join(", ")
         );
 
+        if ctx.dry_run {
+            ctx.record_planned(alter_query);
+            return Ok(RollbackHandle::dry_run("crdb.zone_config_change"));
+        }
+
         sqlx::query(&alter_query)
             .execute(pool)
             .await
@@ -109,7 +133,7 @@ impl Skill for CrdbZoneConfigSkill {
         }
 
         let undo = ZoneConfigUndoState {
-            target: params.target,
+            target: params.target.clone(),
             original_config,
         };
 
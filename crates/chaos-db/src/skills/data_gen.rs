@@ -0,0 +1,283 @@
+//! Column values for generated rows, as SQL literals. `sequential` values
+//! follow the row number; `realistic` ones are weighted-random strings,
+//! numbers, emails and UUIDs that spread across indexes and column limits
+//! the way real traffic does.
+
+use std::ops::RangeInclusive;
+
+use chaos_core::error::{ChaosError, ChaosResult};
+use rand::distributions::WeightedIndex;
+use rand::prelude::*;
+use rand::rngs::StdRng;
+use serde::{Deserialize, Serialize};
+use sqlx::{AnyPool, Row};
+
+use crate::config::DbType;
+use crate::skills::sql_utils::placeholders;
+
+/// How generated rows are filled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataGeneration {
+    /// Patterned values derived from the row number.
+    #[default]
+    Sequential,
+    /// Weighted-random values shaped by column type, name and limits.
+    Realistic,
+}
+
+/// A column to generate values for.
+#[derive(Debug, Clone)]
+pub struct ColumnSpec {
+    pub name: String,
+    pub data_type: String,
+    /// `character_maximum_length`, for length-limited strings.
+    pub max_length: Option<u32>,
+    /// `numeric_precision` and `numeric_scale`, for fixed-point numbers.
+    pub precision: Option<u32>,
+    pub scale: Option<u32>,
+}
+
+/// Columns of `schema.table` other than `pk_column`, in table order.
+pub async fn column_specs(
+    pool: &AnyPool,
+    db_type: DbType,
+    schema: &str,
+    table: &str,
+    pk_column: &str,
+) -> ChaosResult<Vec<ColumnSpec>> {
+    let columns = sqlx::query(&placeholders(
+        db_type,
+        "SELECT column_name AS column_name, data_type AS data_type, \
+         character_maximum_length + 0 AS max_length, \
+         numeric_precision + 0 AS numeric_precision, numeric_scale + 0 AS numeric_scale \
+         FROM information_schema.columns \
+         WHERE table_schema = $1 AND table_name = $2 \
+         AND column_name != $3 \
+         ORDER BY ordinal_position",
+    ))
+    .bind(schema)
+    .bind(table)
+    .bind(pk_column)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| ChaosError::Discovery(format!("Failed to get columns: {e}")))?;
+
+    Ok(columns
+        .iter()
+        .map(|c| ColumnSpec {
+            name: c.get("column_name"),
+            data_type: c.get("data_type"),
+            max_length: column_limit(c, "max_length"),
+            precision: column_limit(c, "numeric_precision"),
+            scale: column_limit(c, "numeric_scale"),
+        })
+        .collect())
+}
+
+/// An integer column of `information_schema.columns`, if set. The width
+/// differs per database, so try both.
+fn column_limit(row: &sqlx::any::AnyRow, column: &str) -> Option<u32> {
+    row.try_get::<Option<i64>, _>(column)
+        .or_else(|_| row.try_get::<Option<i32>, _>(column).map(|v| v.map(i64::from)))
+        .ok()
+        .flatten()
+        .and_then(|v| u32::try_from(v).ok())
+}
+
+pub trait ValueGenerator: Send {
+    /// A SQL literal for `column` in the `row`th generated row.
+    fn value(&mut self, column: &ColumnSpec, row: u32) -> String;
+}
+
+/// The generator for `kind`.
+pub fn generator(kind: DataGeneration) -> Box<dyn ValueGenerator> {
+    match kind {
+        DataGeneration::Sequential => Box::new(SequentialValues),
+        DataGeneration::Realistic => Box::new(RealisticValues {
+            rng: StdRng::from_entropy(),
+        }),
+    }
+}
+
+pub struct SequentialValues;
+
+impl ValueGenerator for SequentialValues {
+    fn value(&mut self, column: &ColumnSpec, seed: u32) -> String {
+        let dt = column.data_type.to_lowercase();
+        if dt.contains("int") || dt.contains("serial") {
+            format!("{}", seed + 1000)
+        } else if dt.contains("float") || dt.contains("double") || dt.contains("numeric") || dt.contains("decimal") {
+            format!("{}.{}", seed, seed % 100)
+        } else if dt.contains("bool") {
+            if seed.is_multiple_of(2) { "true".into() } else { "false".into() }
+        } else if dt.contains("timestamp") || dt.contains("datetime") {
+            "'2024-01-01 00:00:00'".into()
+        } else if dt.contains("date") {
+            "'2024-01-01'".into()
+        } else if dt.contains("json") {
+            format!("'{}'", serde_json::json!({"chaos": seed}))
+        } else {
+            // Default to text/varchar
+            format!("'chaos_agent_test_{seed}'")
+        }
+    }
+}
+
+pub struct RealisticValues {
+    rng: StdRng,
+}
+
+const FIRST_NAMES: &[&str] = &[
+    "Ada", "Alan", "Grace", "Linus", "Margaret", "Dennis", "Barbara", "Ken", "Frances", "Edsger",
+    "Radia", "Donald", "Hedy", "Tim", "Sophie", "John",
+];
+const LAST_NAMES: &[&str] = &[
+    "Lovelace", "Turing", "Hopper", "Torvalds", "Hamilton", "Ritchie", "Liskov", "Thompson", "Allen",
+    "Dijkstra", "Perlman", "Knuth", "Lamarr", "Berners", "Wilson", "Backus",
+];
+const EMAIL_DOMAINS: &[&str] = &["example.com", "example.org", "example.net", "mail.test"];
+
+/// Random characters that keep generated names and emails unique.
+const UNIQUE_TAIL: u32 = 10;
+
+/// Most strings are short, a few are long.
+const STRING_LENGTHS: &[(RangeInclusive<u32>, u32)] = &[(1..=8, 50), (9..=32, 35), (33..=255, 15)];
+/// Most counts and ids are small, with a long tail.
+const INTEGER_SIZES: &[(RangeInclusive<i64>, u32)] = &[
+    (0..=100, 60),
+    (101..=10_000, 30),
+    (10_001..=i64::MAX, 10),
+];
+
+impl ValueGenerator for RealisticValues {
+    fn value(&mut self, column: &ColumnSpec, _row: u32) -> String {
+        let dt = column.data_type.to_lowercase();
+        let name = column.name.to_lowercase();
+        if dt == "uuid" {
+            format!("'{}'", uuid::Uuid::new_v4())
+        } else if dt.contains("int") || dt.contains("serial") {
+            self.integer(&dt).to_string()
+        } else if dt.contains("float") || dt.contains("double") || dt.contains("real") {
+            format!("{:.4}", self.amount(None))
+        } else if dt.contains("numeric") || dt.contains("decimal") {
+            let scale = column.scale.unwrap_or(2).min(6);
+            // numeric(p, s) holds at most p - s integer digits
+            let max = column
+                .precision
+                .map(|p| 10f64.powi(p.saturating_sub(scale).min(15) as i32) - 1.0);
+            format!("{:.*}", scale as usize, self.amount(max))
+        } else if dt.contains("bool") {
+            self.rng.gen_bool(0.7).to_string()
+        } else if dt.contains("timestamp") || dt.contains("datetime") {
+            format!("'{}'", self.recent().format("%Y-%m-%d %H:%M:%S"))
+        } else if dt.contains("date") {
+            format!("'{}'", self.recent().format("%Y-%m-%d"))
+        } else if dt.starts_with("time") {
+            format!("'{}'", self.recent().format("%H:%M:%S"))
+        } else if dt.contains("json") {
+            format!(
+                "'{}'",
+                serde_json::json!({
+                    "id": uuid::Uuid::new_v4().to_string(),
+                    "score": self.rng.gen_range(0..1000),
+                    "tags": (0..self.rng.gen_range(0..4)).map(|_| self.word(3..=10)).collect::<Vec<_>>(),
+                })
+            )
+        } else {
+            // Names and emails often carry unique constraints: a random tail
+            // keeps them apart, and survives truncation to the column limit
+            let (mut text, unique) = if has_token(&name, "uuid") {
+                (uuid::Uuid::new_v4().to_string(), String::new())
+            } else if has_token(&name, "email") {
+                self.email()
+            } else if has_token(&name, "name") {
+                self.full_name()
+            } else {
+                let length = self.weighted(STRING_LENGTHS).clone();
+                let length = self.rng.gen_range(length);
+                (self.word(length..=length), String::new())
+            };
+            if let Some(max) = column.max_length {
+                let max = max.max(1) as usize;
+                text.truncate(max.saturating_sub(unique.len()));
+                text.push_str(&unique);
+                text.truncate(max);
+            } else {
+                text.push_str(&unique);
+            }
+            // Generated text is alphanumeric apart from `@`, `.`, `-` and spaces
+            format!("'{text}'")
+        }
+    }
+}
+
+impl RealisticValues {
+    fn weighted<'a, T>(&mut self, choices: &'a [(T, u32)]) -> &'a T {
+        let index = WeightedIndex::new(choices.iter().map(|(_, weight)| *weight))
+            .expect("weights are non-zero constants");
+        &choices[index.sample(&mut self.rng)].0
+    }
+
+    /// A skewed integer within the range of `data_type`.
+    fn integer(&mut self, data_type: &str) -> i64 {
+        let max = if data_type.contains("tiny") {
+            i8::MAX as i64
+        } else if data_type.contains("small") {
+            i16::MAX as i64
+        } else if data_type.contains("medium") {
+            (1 << 23) - 1
+        } else if data_type.contains("big") {
+            i64::MAX
+        } else {
+            i32::MAX as i64
+        };
+        let range = self.weighted(INTEGER_SIZES).clone();
+        self.rng.gen_range(*range.start().min(&max)..=*range.end().min(&max))
+    }
+
+    /// A log-uniform amount between 0.1 and 100,000, like prices or
+    /// durations, capped at `max`.
+    fn amount(&mut self, max: Option<f64>) -> f64 {
+        let amount = 10f64.powf(self.rng.gen_range(-1.0..5.0));
+        max.map_or(amount, |max| amount.min(max))
+    }
+
+    /// A time within the last year.
+    fn recent(&mut self) -> chrono::NaiveDateTime {
+        let seconds = self.rng.gen_range(0..365 * 24 * 3600);
+        (chrono::Utc::now() - chrono::Duration::seconds(seconds)).naive_utc()
+    }
+
+    fn word(&mut self, length: RangeInclusive<u32>) -> String {
+        let length = self.rng.gen_range(length);
+        (0..length)
+            .map(|_| self.rng.sample(rand::distributions::Alphanumeric) as char)
+            .collect()
+    }
+
+    /// A name and its unique tail.
+    fn full_name(&mut self) -> (String, String) {
+        let name = format!(
+            "{} {}",
+            FIRST_NAMES.choose(&mut self.rng).unwrap_or(&"Ada"),
+            LAST_NAMES.choose(&mut self.rng).unwrap_or(&"Lovelace")
+        );
+        (name, format!(" {}", self.word(UNIQUE_TAIL..=UNIQUE_TAIL)))
+    }
+
+    /// An email's local part and its unique tail, which carries the domain.
+    fn email(&mut self) -> (String, String) {
+        let first = FIRST_NAMES.choose(&mut self.rng).unwrap_or(&"ada").to_lowercase();
+        let last = LAST_NAMES.choose(&mut self.rng).unwrap_or(&"lovelace").to_lowercase();
+        let domain = EMAIL_DOMAINS.choose(&mut self.rng).unwrap_or(&"example.com");
+        let tail = self.word(UNIQUE_TAIL..=UNIQUE_TAIL).to_lowercase();
+        (format!("{first}.{last}."), format!("{tail}@{domain}"))
+    }
+}
+
+/// Whether `name` has `token` as a whole word, so `name` matches
+/// `first_name` but not `username` or `hostname`.
+fn has_token(name: &str, token: &str) -> bool {
+    name.split(|c: char| !c.is_ascii_alphanumeric()).any(|t| t == token)
+}
@@ -0,0 +1,361 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chaos_core::error::{ChaosError, ChaosResult};
+use chaos_core::rollback::RollbackHandle;
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use serde::{Deserialize, Serialize};
+use sqlx::any::Any;
+use sqlx::pool::PoolConnection;
+use sqlx::{AnyPool, Row};
+
+use crate::config::DbType;
+use crate::skills::lock_utils::{find_pk_column, get_backend_pid, terminate_backend};
+use crate::skills::sql_utils::{qualified_table, quote_ident, resolve_tables, validate_table_patterns};
+
+/// Produce a real deadlock: two dedicated connections each lock one row, then
+/// the other's, in opposite order, so the database's deadlock detector aborts
+/// one of them. The survivor keeps both row locks until rollback terminates it.
+///
+/// PostgreSQL-compatible targets only: MySQL's InnoDB picks its victim
+/// differently and can have deadlock detection turned off.
+pub struct DeadlockSkill {
+    pub db_type: DbType,
+    /// The target's `schemas`; empty means all.
+    pub schemas: Vec<String>,
+}
+
+/// How long to wait for the deadlock detector (Postgres checks after
+/// `deadlock_timeout`, 1s by default) before giving up.
+const DEADLOCK_WAIT: Duration = Duration::from_secs(30);
+
+/// SQLSTATE `deadlock_detected`.
+const DEADLOCK_DETECTED: &str = "40P01";
+
+#[derive(Debug, Deserialize)]
+struct DeadlockParams {
+    /// Tables to take the two rows from: the first two rows of one table, or
+    /// the first row of each of two. Default: discovered tables.
+    #[serde(default)]
+    tables: Vec<String>,
+}
+
+/// A row locked by its primary key, kept as text and cast back on lookup.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct LockedRow {
+    schema: String,
+    table: String,
+    pk_column: String,
+    pk_type: String,
+    key: String,
+}
+
+impl std::fmt::Display for LockedRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{} ({} = {})", self.schema, self.table, self.pk_column, self.key)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DeadlockUndoState {
+    /// The aborted connection first, then the one holding both locks.
+    backend_pids: Vec<i32>,
+    rows: Vec<LockedRow>,
+    db_type: String,
+}
+
+#[async_trait]
+impl Skill for DeadlockSkill {
+    fn descriptor(&self) -> SkillDescriptor {
+        SkillDescriptor {
+            name: "db.deadlock".into(),
+            description: "Lock two rows from two connections in opposite order to trigger a real deadlock, then hold the surviving locks".into(),
+            target: TargetDomain::Database,
+            reversible: true,
+        }
+    }
+
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "tables": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "One table with at least two rows, or two tables with at least one row each; tables need a primary key"
+                }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
+    fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
+        if self.db_type == DbType::Mysql {
+            return Err(ChaosError::Config(
+                "db.deadlock is not supported on MySQL: InnoDB chooses deadlock victims differently \
+                 and innodb_deadlock_detect may be off; use a PostgreSQL-compatible target"
+                    .into(),
+            ));
+        }
+        let params: DeadlockParams = parse_params("db.deadlock", params)?;
+        validate_table_patterns(&params.tables)?;
+        Ok(())
+    }
+
+    async fn preflight(&self, ctx: &SkillContext) -> ChaosResult<()> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
+
+        let params = ctx.typed_params::<DeadlockParams>()?;
+        let tables = resolve_tables(pool, self.db_type, &self.schemas, &params.tables, 5).await?;
+        self.pick_rows(pool, &tables).await?;
+        Ok(())
+    }
+
+    async fn inspect(&self, ctx: &SkillContext) -> ChaosResult<Vec<String>> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
+
+        let params = ctx.typed_params::<DeadlockParams>()?;
+        let tables = resolve_tables(pool, self.db_type, &self.schemas, &params.tables, 5).await?;
+        let (a, b) = self.pick_rows(pool, &tables).await?;
+        Ok(vec![
+            format!("Connection 1: locks {a}, then {b}"),
+            format!("Connection 2: locks {b}, then {a}"),
+        ])
+    }
+
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
+
+        let params = ctx.typed_params::<DeadlockParams>()?;
+        let mut tables = resolve_tables(pool, self.db_type, &self.schemas, &params.tables, 5).await?;
+        tables.truncate(ctx.clamp("tables", tables.len(), ctx.limits.max_tables));
+        let (a, b) = self.pick_rows(pool, &tables).await?;
+        let db_type = format!("{:?}", self.db_type);
+
+        if ctx.dry_run {
+            ctx.record_planned(format!(
+                "Lock {a} and {b} from two connections in opposite order, deadlocking them"
+            ));
+            return Ok(RollbackHandle::dry_run("db.deadlock"));
+        }
+
+        // Two dedicated connections, each locking its first row
+        let mut conn_a = self.begin_with_lock(pool, &a).await?;
+        let mut conn_b = match self.begin_with_lock(pool, &b).await {
+            Ok(conn) => conn,
+            Err(e) => {
+                let _ = sqlx::query("ROLLBACK").execute(&mut *conn_a).await;
+                return Err(e);
+            }
+        };
+        let pid_a = get_backend_pid(&mut conn_a, self.db_type).await?;
+        let pid_b = get_backend_pid(&mut conn_b, self.db_type).await?;
+        ctx.record_action(format!("Backend {pid_a} locked {a}, backend {pid_b} locked {b}"));
+
+        // Cross over: each now waits on the other's row
+        let crossed = tokio::time::timeout(DEADLOCK_WAIT, async {
+            tokio::join!(
+                self.lock_or_abort(&mut conn_a, &b),
+                self.lock_or_abort(&mut conn_b, &a),
+            )
+        })
+        .await;
+
+        let (victim_pid, holder_pid, holder_conn, victim_conn, error) = match crossed {
+            Ok((Ok(()), Err(e))) => (pid_b, pid_a, conn_a, conn_b, e),
+            Ok((Err(e), Ok(()))) => (pid_a, pid_b, conn_b, conn_a, e),
+            outcome => {
+                // Leave nothing holding locks behind
+                for pid in [pid_a, pid_b] {
+                    let _ = terminate_backend(pool, pid, &db_type).await;
+                }
+                drop((conn_a, conn_b));
+                let reason = match outcome {
+                    Err(_) => format!("no deadlock was detected within {}s", DEADLOCK_WAIT.as_secs()),
+                    Ok((Ok(()), Ok(()))) => "both connections got both locks".to_string(),
+                    Ok((Err(e), _) | (_, Err(e))) => format!("both connections failed: {e}"),
+                };
+                return Err(ChaosError::Other(anyhow::anyhow!(
+                    "db.deadlock {reason}; both connections were terminated"
+                )));
+            }
+        };
+
+        let code = error
+            .as_database_error()
+            .and_then(|e| e.code())
+            .map(|c| c.into_owned())
+            .unwrap_or_default();
+        if code == DEADLOCK_DETECTED {
+            ctx.record_action(format!("Deadlock detected, backend {victim_pid} aborted: {error}"));
+        } else {
+            ctx.warn(format!(
+                "Backend {victim_pid} failed with {} rather than a deadlock error: {error}",
+                if code.is_empty() { "no SQLSTATE" } else { code.as_str() }
+            ));
+        }
+        ctx.record_action(format!("Backend {holder_pid} holds the locks on {a} and {b}"));
+
+        // Hold the surviving transaction (and its locks) until rollback
+        tokio::spawn(async move {
+            let mut holder_conn = holder_conn;
+            tracing::debug!(pid = holder_pid, "Deadlock holder task started");
+            loop {
+                tokio::time::sleep(std::time::Duration::from_secs(30)).await;
+                // Periodic keepalive to prevent idle timeout
+                if let Err(e) = sqlx::query("SELECT 1").execute(&mut *holder_conn).await {
+                    tracing::info!(
+                        pid = holder_pid,
+                        error = %e,
+                        "Deadlock holder connection terminated"
+                    );
+                    break;
+                }
+            }
+            let _ = victim_conn.close().await;
+        });
+
+        let undo = DeadlockUndoState {
+            backend_pids: vec![victim_pid, holder_pid],
+            rows: vec![a, b],
+            db_type,
+        };
+
+        let undo_state = serde_yaml::to_value(&undo)
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
+
+        tracing::info!(
+            victim = victim_pid,
+            holder = holder_pid,
+            "Deadlock induced, surviving locks held by background connection"
+        );
+
+        Ok(RollbackHandle::new("db.deadlock", undo_state))
+    }
+
+    async fn rollback(&self, ctx: &SkillContext, handle: &RollbackHandle) -> ChaosResult<()> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
+
+        let undo: DeadlockUndoState = serde_yaml::from_value(handle.undo_state.clone())
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
+
+        for pid in &undo.backend_pids {
+            terminate_backend(pool, *pid, &undo.db_type).await?;
+        }
+
+        tracing::info!(
+            pids = ?undo.backend_pids,
+            rows = ?undo.rows,
+            "Deadlock locks released via backend termination"
+        );
+
+        Ok(())
+    }
+}
+
+impl DeadlockSkill {
+    /// The two rows to deadlock on, from the resolved `tables`, taking rows
+    /// in primary key order from tables that have one.
+    async fn pick_rows(&self, pool: &AnyPool, tables: &[(String, String)]) -> ChaosResult<(LockedRow, LockedRow)> {
+        let mut conn = pool.acquire().await.map_err(|e| {
+            ChaosError::Connection(anyhow::anyhow!("Failed to acquire connection: {e}"))
+        })?;
+
+        let mut rows = Vec::new();
+        for (schema, table) in tables {
+            if rows.len() == 2 {
+                break;
+            }
+            let Some(pk_column) = find_pk_column(&mut conn, self.db_type, schema, table).await else {
+                continue;
+            };
+            let pk = quote_ident(self.db_type, &pk_column);
+            let sql = format!(
+                "SELECT CAST({pk} AS TEXT) AS key, pg_typeof({pk})::text AS pk_type FROM {} ORDER BY {pk} LIMIT {}",
+                qualified_table(self.db_type, schema, table),
+                2 - rows.len(),
+            );
+            let found = match sqlx::query(&sql).fetch_all(&mut *conn).await {
+                Ok(found) => found,
+                Err(e) => {
+                    tracing::warn!(table = %format!("{schema}.{table}"), error = %e, "Failed to read rows, skipped");
+                    continue;
+                }
+            };
+            rows.extend(found.iter().map(|row| LockedRow {
+                schema: schema.clone(),
+                table: table.clone(),
+                pk_column: pk_column.clone(),
+                pk_type: row.get("pk_type"),
+                key: row.get("key"),
+            }));
+        }
+
+        let mut rows = rows.into_iter();
+        match (rows.next(), rows.next()) {
+            (Some(a), Some(b)) => Ok((a, b)),
+            (found, _) => Err(ChaosError::Config(format!(
+                "db.deadlock needs two rows in tables with a primary key, found {} in {}",
+                usize::from(found.is_some()),
+                tables
+                    .iter()
+                    .map(|(schema, table)| format!("{schema}.{table}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))),
+        }
+    }
+
+    /// A dedicated connection with an open transaction holding `row`'s lock.
+    async fn begin_with_lock(&self, pool: &AnyPool, row: &LockedRow) -> ChaosResult<PoolConnection<Any>> {
+        let mut conn = pool.acquire().await.map_err(|e| {
+            ChaosError::Connection(anyhow::anyhow!("Failed to acquire connection: {e}"))
+        })?;
+        sqlx::query("BEGIN")
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("BEGIN failed: {e}")))?;
+        if let Err(e) = self.lock(&mut conn, row).await {
+            let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+            return Err(ChaosError::Other(anyhow::anyhow!("Failed to lock {row}: {e}")));
+        }
+        Ok(conn)
+    }
+
+    /// Lock `row`, and on failure (the deadlock victim) roll back at once:
+    /// the aborted transaction keeps its locks until then, and the other
+    /// connection is waiting on them.
+    async fn lock_or_abort(&self, conn: &mut PoolConnection<Any>, row: &LockedRow) -> Result<(), sqlx::Error> {
+        let result = self.lock(conn, row).await;
+        if result.is_err() {
+            let _ = sqlx::query("ROLLBACK").execute(&mut **conn).await;
+        }
+        result
+    }
+
+    async fn lock(&self, conn: &mut PoolConnection<Any>, row: &LockedRow) -> Result<(), sqlx::Error> {
+        let sql = format!(
+            "SELECT 1 FROM {} WHERE {} = CAST($1 AS {}) FOR UPDATE",
+            qualified_table(self.db_type, &row.schema, &row.table),
+            quote_ident(self.db_type, &row.pk_column),
+            row.pk_type,
+        );
+        sqlx::query(&sql).bind(&row.key).execute(&mut **conn).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,488 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chaos_core::error::{ChaosError, ChaosResult};
+use chaos_core::rollback::RollbackHandle;
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use serde::{Deserialize, Serialize};
+use sqlx::{AnyPool, Row};
+
+use crate::config::DbType;
+use crate::skills::data_gen::{column_specs, generator, ColumnSpec, DataGeneration};
+use crate::skills::sql_utils::{
+    insert_returning_key, placeholders, primary_key_column, qualified_table, quote_ident, resolve_tables,
+    validate_table_patterns,
+};
+
+/// Concentrate inserts from several connections on one value of a
+/// non-unique index, so they all land on the same B-tree leaf pages: a hot
+/// partition, unlike the uniform spread of `db.insert_load`. Lock and wait
+/// counters are reported before and after where the database exposes them.
+pub struct IndexHotspotSkill {
+    pub db_type: DbType,
+    /// The target's `schemas`; empty means all.
+    pub schemas: Vec<String>,
+}
+
+const MAX_CONCURRENCY: u32 = 64;
+
+/// How often waiting sessions are counted while the writers run.
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Deserialize)]
+struct IndexHotspotParams {
+    /// Table to write to. Default: the first with a non-unique index.
+    #[serde(default)]
+    table: Option<String>,
+    /// Non-unique index to concentrate on. Default: the table's first.
+    #[serde(default)]
+    index: Option<String>,
+    /// Value every row gets in the index's leading column. Default: its most
+    /// common existing value.
+    #[serde(default)]
+    value: Option<String>,
+    /// Rows inserted in total. Default: 2000.
+    #[serde(default = "default_rows")]
+    rows: u32,
+    /// Connections writing at once. Default: 8.
+    #[serde(default = "default_concurrency")]
+    concurrency: u32,
+}
+
+fn default_rows() -> u32 {
+    2000
+}
+
+fn default_concurrency() -> u32 {
+    8
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct IndexHotspotUndoState {
+    schema: String,
+    table: String,
+    index: String,
+    pk_column: String,
+    inserted_ids: Vec<String>,
+}
+
+/// The index writes concentrate on and the value they share.
+struct Hotspot {
+    schema: String,
+    table: String,
+    index: String,
+    column: String,
+    /// SQL literal for the shared value.
+    value: String,
+}
+
+#[async_trait]
+impl Skill for IndexHotspotSkill {
+    fn descriptor(&self) -> SkillDescriptor {
+        SkillDescriptor {
+            name: "db.index_hotspot".into(),
+            description: "Concentrate concurrent inserts on one value of a non-unique index to create B-tree hot-spot contention".into(),
+            target: TargetDomain::Database,
+            reversible: true,
+        }
+    }
+
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "table": { "type": "string", "description": "Table to write to (default: first discovered with a non-unique index)" },
+                "index": { "type": "string", "description": "Non-unique index to concentrate on (default: the table's first)" },
+                "value": { "type": "string", "description": "Shared value for the index's leading column (default: its most common value)" },
+                "rows": { "type": "integer", "default": 2000 },
+                "concurrency": { "type": "integer", "default": 8, "maximum": MAX_CONCURRENCY }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
+    fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
+        let p: IndexHotspotParams = parse_params("db.index_hotspot", params)?;
+        if p.concurrency == 0 || p.concurrency > MAX_CONCURRENCY {
+            return Err(ChaosError::Config(format!(
+                "db.index_hotspot concurrency must be between 1 and {MAX_CONCURRENCY}, got {}",
+                p.concurrency
+            )));
+        }
+        if p.rows == 0 {
+            return Err(ChaosError::Config("db.index_hotspot rows must be at least 1".into()));
+        }
+        if p.index.is_some() && p.table.is_none() {
+            return Err(ChaosError::Config("db.index_hotspot index needs table".into()));
+        }
+        validate_table_patterns(p.table.as_slice())?;
+        Ok(())
+    }
+
+    async fn preflight(&self, ctx: &SkillContext) -> ChaosResult<()> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
+        let params = ctx.typed_params::<IndexHotspotParams>()?;
+        self.pick_hotspot(pool, &params).await.map(|_| ())
+    }
+
+    async fn inspect(&self, ctx: &SkillContext) -> ChaosResult<Vec<String>> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
+        let params = ctx.typed_params::<IndexHotspotParams>()?;
+        let hotspot = self.pick_hotspot(pool, &params).await?;
+        let mut lines = vec![format!(
+            "{} rows into {}.{} from {} connection(s), all with {} = {} (index {})",
+            params.rows, hotspot.schema, hotspot.table, params.concurrency, hotspot.column, hotspot.value, hotspot.index
+        )];
+        lines.extend(
+            self.contention_stats(pool, &hotspot)
+                .await
+                .into_iter()
+                .map(|(name, value)| format!("now: {name} = {value}")),
+        );
+        Ok(lines)
+    }
+
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
+
+        let params = ctx.typed_params::<IndexHotspotParams>()?;
+        let hotspot = self.pick_hotspot(pool, &params).await?;
+        let Hotspot { ref schema, ref table, ref index, ref column, ref value } = hotspot;
+        let total_rows = ctx.clamp("rows", params.rows, ctx.limits.max_rows_per_table(1));
+
+        if ctx.dry_run {
+            ctx.record_planned(format!(
+                "INSERT {total_rows} rows into {schema}.{table} from {} connection(s), all with {column} = {value} (index {index})",
+                params.concurrency
+            ));
+            return Ok(RollbackHandle::dry_run("db.index_hotspot"));
+        }
+
+        let pk_column = primary_key_column(pool, self.db_type, schema, table)
+            .await?
+            .ok_or_else(|| ChaosError::Config(format!("{schema}.{table} has no primary key to clean up by")))?;
+        let specs = column_specs(pool, self.db_type, schema, table, &pk_column).await?;
+
+        let before = self.contention_stats(pool, &hotspot).await;
+
+        // Split the rows across the writers
+        let mut writers = Vec::new();
+        for writer in 0..params.concurrency {
+            let rows = total_rows / params.concurrency + u32::from(writer < total_rows % params.concurrency);
+            writers.push(tokio::spawn(write_rows(
+                pool.clone(),
+                self.db_type,
+                qualified_table(self.db_type, schema, table),
+                pk_column.clone(),
+                specs.clone(),
+                (column.clone(), value.clone()),
+                rows,
+            )));
+        }
+
+        // Waits only show while they happen; keep the worst seen
+        let mut peak_waiting = None;
+        while !writers.iter().all(|w| w.is_finished()) {
+            if let Some(waiting) = self.waiting_sessions(pool).await {
+                peak_waiting = Some(peak_waiting.unwrap_or(0).max(waiting));
+            }
+            tokio::time::sleep(SAMPLE_INTERVAL).await;
+        }
+
+        let mut inserted_ids = Vec::new();
+        for writer in writers {
+            match writer.await {
+                Ok((ids, None)) => inserted_ids.extend(ids),
+                Ok((ids, Some(problem))) => {
+                    inserted_ids.extend(ids);
+                    ctx.warn(format!("A writer on {schema}.{table} stopped early: {problem}"));
+                }
+                Err(e) => ctx.warn(format!("A writer on {schema}.{table} panicked: {e}")),
+            }
+        }
+
+        let after = self.contention_stats(pool, &hotspot).await;
+
+        if inserted_ids.is_empty() {
+            return Err(ChaosError::Other(anyhow::anyhow!(
+                "No rows could be inserted into {schema}.{table}"
+            )));
+        }
+        ctx.record_action(format!(
+            "Inserted {} rows into {schema}.{table} from {} connection(s), all with {column} = {value} (index {index})",
+            inserted_ids.len(),
+            params.concurrency
+        ));
+
+        let mut stats = format!("{:<32} {:>14} {:>14}\n", "", "before", "after");
+        for (name, was) in &before {
+            let now = after
+                .iter()
+                .find(|(n, _)| n == name)
+                .map_or_else(|| "-".to_string(), |(_, v)| v.to_string());
+            stats.push_str(&format!("{name:<32} {was:>14} {now:>14}\n"));
+        }
+        if let Some(peak) = peak_waiting {
+            stats.push_str(&format!("{:<32} {:>14} {peak:>14}\n", "peak sessions waiting", "-"));
+        }
+        if before.is_empty() && peak_waiting.is_none() {
+            stats.push_str("(not exposed by this database)\n");
+        }
+        ctx.output.push("contention", stats);
+
+        let undo = IndexHotspotUndoState {
+            schema: schema.clone(),
+            table: table.clone(),
+            index: index.clone(),
+            pk_column,
+            inserted_ids,
+        };
+        let undo_state = serde_yaml::to_value(&undo)
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
+
+        Ok(RollbackHandle::new("db.index_hotspot", undo_state))
+    }
+
+    async fn rollback(&self, ctx: &SkillContext, handle: &RollbackHandle) -> ChaosResult<()> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
+
+        let undo: IndexHotspotUndoState = serde_yaml::from_value(handle.undo_state.clone())
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
+
+        let query = format!(
+            "DELETE FROM {} WHERE {} IN ({})",
+            qualified_table(self.db_type, &undo.schema, &undo.table),
+            quote_ident(self.db_type, &undo.pk_column),
+            undo.inserted_ids.join(", ")
+        );
+        let result = sqlx::query(&query).execute(pool).await.map_err(|e| {
+            ChaosError::Other(anyhow::anyhow!(
+                "Failed to delete hot-spot rows from {}.{}: {e}",
+                undo.schema,
+                undo.table
+            ))
+        })?;
+
+        tracing::info!(
+            table = %undo.table,
+            index = %undo.index,
+            deleted = result.rows_affected(),
+            "Rollback: deleted hot-spot rows"
+        );
+        Ok(())
+    }
+}
+
+/// Insert `rows` rows into `table`, each with the hot column set to the hot
+/// value. Returns the new keys, and why it stopped early if it did.
+async fn write_rows(
+    pool: AnyPool,
+    db_type: DbType,
+    table: String,
+    pk_column: String,
+    specs: Vec<ColumnSpec>,
+    (hot_column, hot_value): (String, String),
+    rows: u32,
+) -> (Vec<String>, Option<String>) {
+    let mut values = generator(DataGeneration::Realistic);
+    let col_list = specs
+        .iter()
+        .map(|c| quote_ident(db_type, &c.name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut ids = Vec::new();
+    for i in 0..rows {
+        let row: Vec<String> = specs
+            .iter()
+            .map(|c| if c.name == hot_column { hot_value.clone() } else { values.value(c, i) })
+            .collect();
+        let insert = format!("INSERT INTO {table} ({col_list}) VALUES ({})", row.join(", "));
+        match insert_returning_key(&pool, db_type, &insert, &quote_ident(db_type, &pk_column)).await {
+            Ok(Some(id)) => ids.push(id),
+            Ok(None) => {
+                return (ids, Some("no auto-increment key, so inserted rows can't be rolled back".into()));
+            }
+            Err(e) => return (ids, Some(e.to_string())),
+        }
+    }
+    (ids, None)
+}
+
+impl IndexHotspotSkill {
+    /// The `table`/`index`/`value` params resolved against the database.
+    async fn pick_hotspot(&self, pool: &AnyPool, params: &IndexHotspotParams) -> ChaosResult<Hotspot> {
+        let named: Vec<String> = params.table.iter().cloned().collect();
+        let tables = resolve_tables(pool, self.db_type, &self.schemas, &named, 20).await?;
+
+        let mut found = None;
+        for (schema, table) in &tables {
+            let indexes = self.non_unique_indexes(pool, schema, table).await?;
+            let pick = match params.index {
+                Some(ref wanted) => indexes.into_iter().find(|(index, _)| index == wanted),
+                None => indexes.into_iter().next(),
+            };
+            if let Some((index, column)) = pick {
+                found = Some((schema.clone(), table.clone(), index, column));
+                break;
+            }
+        }
+        let (schema, table, index, column) = found.ok_or_else(|| {
+            ChaosError::Config(match (&params.table, &params.index) {
+                (Some(table), Some(index)) => format!("{table} has no non-unique index named {index}"),
+                (Some(table), None) => format!("{table} has no non-unique index"),
+                _ => "No table with a non-unique index found".to_string(),
+            })
+        })?;
+
+        let value = match params.value {
+            Some(ref value) => format!("'{}'", value.replace('\'', "''")),
+            None => match self.most_common_value(pool, &schema, &table, &column).await? {
+                Some(value) => format!("'{}'", value.replace('\'', "''")),
+                // An empty table: any value will do, as long as it's the same
+                None => {
+                    let spec = column_specs(pool, self.db_type, &schema, &table, "")
+                        .await?
+                        .into_iter()
+                        .find(|c| c.name == column)
+                        .ok_or_else(|| ChaosError::Discovery(format!("Column {column} not found on {table}")))?;
+                    generator(DataGeneration::Realistic).value(&spec, 0)
+                }
+            },
+        };
+
+        Ok(Hotspot { schema, table, index, column, value })
+    }
+
+    /// `(index, leading column)` of each non-unique index on `schema.table`.
+    async fn non_unique_indexes(&self, pool: &AnyPool, schema: &str, table: &str) -> ChaosResult<Vec<(String, String)>> {
+        let query = match self.db_type {
+            DbType::Mysql => {
+                "SELECT index_name AS index_name, column_name AS column_name FROM information_schema.statistics \
+                 WHERE table_schema = $1 AND table_name = $2 AND non_unique = 1 AND seq_in_index = 1 \
+                 ORDER BY index_name"
+            }
+            _ => {
+                "SELECT i.relname::text AS index_name, a.attname::text AS column_name FROM pg_index x \
+                 JOIN pg_class t ON t.oid = x.indrelid \
+                 JOIN pg_namespace n ON n.oid = t.relnamespace \
+                 JOIN pg_class i ON i.oid = x.indexrelid \
+                 JOIN pg_attribute a ON a.attrelid = t.oid AND a.attnum = x.indkey[0] \
+                 WHERE n.nspname = $1 AND t.relname = $2 AND NOT x.indisunique AND NOT x.indisprimary \
+                 ORDER BY i.relname"
+            }
+        };
+        let rows = sqlx::query(&placeholders(self.db_type, query))
+            .bind(schema)
+            .bind(table)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| ChaosError::Discovery(format!("Failed to list indexes on {schema}.{table}: {e}")))?;
+        Ok(rows
+            .iter()
+            .map(|r| (r.get("index_name"), r.get("column_name")))
+            .collect())
+    }
+
+    async fn most_common_value(
+        &self,
+        pool: &AnyPool,
+        schema: &str,
+        table: &str,
+        column: &str,
+    ) -> ChaosResult<Option<String>> {
+        let text = if self.db_type == DbType::Mysql { "CHAR" } else { "TEXT" };
+        let col = quote_ident(self.db_type, column);
+        let query = format!(
+            "SELECT CAST({col} AS {text}) FROM {} WHERE {col} IS NOT NULL \
+             GROUP BY {col} ORDER BY COUNT(*) DESC LIMIT 1",
+            qualified_table(self.db_type, schema, table)
+        );
+        let row = sqlx::query(&query)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| ChaosError::Discovery(format!("Failed to read {column} of {schema}.{table}: {e}")))?;
+        Ok(row.and_then(|r| r.try_get::<String, _>(0).ok()))
+    }
+
+    /// Cumulative lock counters and the index's size, where exposed.
+    async fn contention_stats(&self, pool: &AnyPool, hotspot: &Hotspot) -> Vec<(String, i64)> {
+        let mut stats = Vec::new();
+        match self.db_type {
+            DbType::Mysql => {
+                let rows = sqlx::query(
+                    "SHOW GLOBAL STATUS WHERE Variable_name IN \
+                     ('Innodb_row_lock_waits', 'Innodb_row_lock_time', 'Innodb_row_lock_current_waits')",
+                )
+                .fetch_all(pool)
+                .await
+                .unwrap_or_default();
+                for row in rows {
+                    let name = row.try_get::<String, _>(0).unwrap_or_default();
+                    if let Some(value) = row.try_get::<String, _>(1).ok().and_then(|v| v.parse().ok()) {
+                        stats.push((name, value));
+                    }
+                }
+            }
+            DbType::Postgres | DbType::YugabyteDb => {
+                let queries = [
+                    ("lock requests not granted", "SELECT count(*) FROM pg_locks WHERE NOT granted".to_string()),
+                    (
+                        "deadlocks (database)",
+                        "SELECT deadlocks FROM pg_stat_database WHERE datname = current_database()".to_string(),
+                    ),
+                    (
+                        "index size (bytes)",
+                        format!(
+                            "SELECT pg_relation_size(to_regclass('{}'))",
+                            qualified_table(self.db_type, &hotspot.schema, &hotspot.index).replace('\'', "''")
+                        ),
+                    ),
+                ];
+                for (name, query) in queries {
+                    if let Ok(value) = sqlx::query_scalar::<_, i64>(&query).fetch_one(pool).await {
+                        stats.push((name.to_string(), value));
+                    }
+                }
+            }
+            _ => {}
+        }
+        stats
+    }
+
+    /// Sessions waiting on a lock right now, where exposed.
+    async fn waiting_sessions(&self, pool: &AnyPool) -> Option<i64> {
+        match self.db_type {
+            DbType::Mysql => sqlx::query("SHOW GLOBAL STATUS LIKE 'Innodb_row_lock_current_waits'")
+                .fetch_one(pool)
+                .await
+                .ok()?
+                .try_get::<String, _>(1)
+                .ok()?
+                .parse()
+                .ok(),
+            DbType::Postgres | DbType::YugabyteDb => sqlx::query_scalar::<_, i64>(
+                "SELECT count(*) FROM pg_stat_activity \
+                 WHERE wait_event_type IN ('Lock', 'LWLock') AND datname = current_database()",
+            )
+            .fetch_one(pool)
+            .await
+            .ok(),
+            _ => None,
+        }
+    }
+}
@@ -1,12 +1,19 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use serde::{Deserialize, Serialize};
 use sqlx::AnyPool;
-use sqlx::Row;
 
-pub struct InsertLoadSkill;
+use crate::config::DbType;
+use crate::skills::data_gen::{column_specs, generator, DataGeneration};
+use crate::skills::sql_utils::{insert_returning_key, primary_key_column, resolve_tables, validate_table_patterns};
+
+pub struct InsertLoadSkill {
+    pub db_type: DbType,
+    /// The target's `schemas`; empty means all.
+    pub schemas: Vec<String>,
+}
 
 #[derive(Debug, Deserialize)]
 struct InsertParams {
@@ -14,6 +21,9 @@ struct InsertParams {
     rows_per_table: u32,
     #[serde(default)]
     tables: Vec<String>,
+    /// How inserted rows are filled. Default: sequential.
+    #[serde(default)]
+    data: DataGeneration,
 }
 
 fn default_rows() -> u32 {
@@ -39,9 +49,29 @@ impl Skill for InsertLoadSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "rows_per_table": { "type": "integer", "default": 1000 },
+                "tables": { "type": "array", "items": { "type": "string" }, "description": "Tables to target (default: all discovered)" },
+                "data": {
+                    "type": "string",
+                    "enum": ["sequential", "realistic"],
+                    "default": "sequential",
+                    "description": "sequential: patterned values from the row number; realistic: weighted-random strings of varied length, skewed numbers, emails and UUIDs within column limits"
+                }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: InsertParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid insert_load params: {e}")))?;
+        let params: InsertParams = parse_params("insert_load", params)?;
+        validate_table_patterns(&params.tables)?;
         Ok(())
     }
 
@@ -51,111 +81,70 @@ impl Skill for InsertLoadSkill {
             .downcast_ref::<AnyPool>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
 
-        let params: InsertParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
-
-        // Discover tables if none specified
-        let tables_to_target = if params.tables.is_empty() {
-            let rows = sqlx::query(
-                "SELECT table_schema, table_name FROM information_schema.tables \
-                 WHERE table_schema NOT IN ('information_schema', 'pg_catalog', 'mysql', 'performance_schema', 'sys') \
-                 AND table_type = 'BASE TABLE' LIMIT 10"
-            )
-            .fetch_all(pool)
-            .await
-            .map_err(|e| ChaosError::Discovery(format!("Failed to list tables: {e}")))?;
-
-            rows.iter()
-                .map(|r| {
-                    let schema: String = r.get("table_schema");
-                    let table: String = r.get("table_name");
-                    (schema, table)
-                })
-                .collect::<Vec<_>>()
-        } else {
-            params
-                .tables
-                .iter()
-                .map(|t| ("public".to_string(), t.clone()))
-                .collect()
-        };
+        let params = ctx.typed_params::<InsertParams>()?;
+
+        let mut tables_to_target = resolve_tables(pool, self.db_type, &self.schemas, &params.tables, 10).await?;
+        tables_to_target.truncate(ctx.clamp("tables", tables_to_target.len(), ctx.limits.max_tables));
+        let rows_per_table = ctx.clamp(
+            "rows per table",
+            params.rows_per_table,
+            ctx.limits.max_rows_per_table(tables_to_target.len()),
+        );
+        let mut values = generator(params.data);
 
         let mut all_undo = Vec::new();
 
         for (schema, table) in &tables_to_target {
-            // Find the primary key column
-            let pk_row = sqlx::query(
-                "SELECT c.column_name FROM information_schema.columns c \
-                 JOIN information_schema.key_column_usage kcu \
-                   ON c.table_schema = kcu.table_schema AND c.table_name = kcu.table_name AND c.column_name = kcu.column_name \
-                 JOIN information_schema.table_constraints tc \
-                   ON kcu.constraint_name = tc.constraint_name AND kcu.table_schema = tc.table_schema \
-                 WHERE tc.constraint_type = 'PRIMARY KEY' AND c.table_schema = $1 AND c.table_name = $2 \
-                 LIMIT 1",
-            )
-            .bind(schema)
-            .bind(table)
-            .fetch_optional(pool)
-            .await
-            .map_err(|e| ChaosError::Discovery(format!("Failed to find PK for {table}: {e}")))?;
+            let pk_row = primary_key_column(pool, self.db_type, schema, table).await?;
 
             let pk_column: String = match pk_row {
-                Some(row) => row.get("column_name"),
+                Some(column) => column,
                 None => {
-                    tracing::warn!(table = %table, "No primary key found, skipping");
+                    ctx.warn(format!("{schema}.{table} has no primary key, skipped"));
                     continue;
                 }
             };
 
-            // Get column info for generating data
-            let columns = sqlx::query(
-                "SELECT column_name, data_type FROM information_schema.columns \
-                 WHERE table_schema = $1 AND table_name = $2 \
-                 AND column_name != $3 \
-                 ORDER BY ordinal_position",
-            )
-            .bind(schema)
-            .bind(table)
-            .bind(&pk_column)
-            .fetch_all(pool)
-            .await
-            .map_err(|e| ChaosError::Discovery(format!("Failed to get columns: {e}")))?;
-
-            let col_names: Vec<String> = columns.iter().map(|c| c.get("column_name")).collect();
-            let col_types: Vec<String> = columns.iter().map(|c| c.get("data_type")).collect();
+            let col_specs = column_specs(pool, self.db_type, schema, table, &pk_column).await?;
+            let col_names: Vec<&str> = col_specs.iter().map(|c| c.name.as_str()).collect();
 
             if col_names.is_empty() {
-                tracing::warn!(table = %table, "No non-PK columns found, skipping");
+                ctx.warn(format!("{schema}.{table} has no non-PK columns, skipped"));
+                continue;
+            }
+
+            let col_list = col_names.join(", ");
+            if ctx.dry_run {
+                let row: Vec<String> = col_specs.iter().map(|c| values.value(c, 0)).collect();
+                ctx.record_planned(format!(
+                    "INSERT {} rows into {schema}.{table}, like: INSERT INTO {schema}.{table} ({col_list}) VALUES ({})",
+                    rows_per_table,
+                    row.join(", ")
+                ));
                 continue;
             }
 
             let mut inserted_ids = Vec::new();
 
-            for i in 0..params.rows_per_table {
-                let values: Vec<String> = col_types
+            for i in 0..rows_per_table {
+                let row: Vec<String> = col_specs
                     .iter()
-                    .map(|dt| generate_value(dt, i))
+                    .map(|c| values.value(c, i))
                     .collect();
 
-                let col_list = col_names.join(", ");
-                let val_list = values.join(", ");
-                let query = format!(
-                    "INSERT INTO {schema}.{table} ({col_list}) VALUES ({val_list}) RETURNING {pk_column}"
-                );
-
-                match sqlx::query(&query).fetch_one(pool).await {
-                    Ok(row) => {
-                        // Try to get the ID as a string
-                        let id: String = row
-                            .try_get::<i64, _>(0)
-                            .map(|v| v.to_string())
-                            .or_else(|_| row.try_get::<i32, _>(0).map(|v| v.to_string()))
-                            .or_else(|_| row.try_get::<String, _>(0))
-                            .unwrap_or_else(|_| format!("unknown_{i}"));
-                        inserted_ids.push(id);
+                let val_list = row.join(", ");
+                let insert = format!("INSERT INTO {schema}.{table} ({col_list}) VALUES ({val_list})");
+
+                match insert_returning_key(pool, self.db_type, &insert, &pk_column).await {
+                    Ok(Some(id)) => inserted_ids.push(id),
+                    Ok(None) => {
+                        ctx.warn(format!(
+                            "{schema}.{table} has no auto-increment key, so the row just inserted can't be rolled back; stopped load for this table"
+                        ));
+                        break;
                     }
                     Err(e) => {
-                        tracing::warn!(table = %table, error = %e, "Insert failed, stopping load for this table");
+                        ctx.warn(format!("Insert into {schema}.{table} failed, stopped load for this table: {e}"));
                         break;
                     }
                 }
@@ -172,6 +161,10 @@ impl Skill for InsertLoadSkill {
             }
         }
 
+        if ctx.dry_run {
+            return Ok(RollbackHandle::dry_run("db.insert_load"));
+        }
+
         let undo_state = serde_yaml::to_value(&all_undo)
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("Failed to serialize undo state: {e}")))?;
 
@@ -210,23 +203,3 @@ impl Skill for InsertLoadSkill {
         Ok(())
     }
 }
-
-fn generate_value(data_type: &str, seed: u32) -> String {
-    let dt = data_type.to_lowercase();
-    if dt.contains("int") || dt.contains("serial") {
-        format!("{}", seed + 1000)
-    } else if dt.contains("float") || dt.contains("double") || dt.contains("numeric") || dt.contains("decimal") {
-        format!("{}.{}", seed, seed % 100)
-    } else if dt.contains("bool") {
-        if seed % 2 == 0 { "true".into() } else { "false".into() }
-    } else if dt.contains("timestamp") || dt.contains("datetime") {
-        "'2024-01-01 00:00:00'".into()
-    } else if dt.contains("date") {
-        "'2024-01-01'".into()
-    } else if dt.contains("json") {
-        format!("'{}'", serde_json::json!({"chaos": seed}))
-    } else {
-        // Default to text/varchar
-        format!("'chaos_agent_test_{seed}'")
-    }
-}
@@ -1,9 +1,10 @@
 use chaos_core::error::{ChaosError, ChaosResult};
 use sqlx::any::Any;
 use sqlx::pool::PoolConnection;
-use sqlx::{AnyPool, Row};
+use sqlx::{Executor, Row};
 
 use crate::config::DbType;
+use crate::skills::sql_utils::primary_key_query;
 
 const VALID_TABLE_LOCK_MODES: &[&str] = &[
     "ACCESS SHARE",
@@ -45,26 +46,6 @@ pub fn validate_row_lock_type(lock_type: &str) -> ChaosResult<()> {
     Ok(())
 }
 
-pub async fn discover_user_tables(pool: &AnyPool) -> ChaosResult<Vec<(String, String)>> {
-    let rows = sqlx::query(
-        "SELECT table_schema, table_name FROM information_schema.tables \
-         WHERE table_schema NOT IN ('information_schema', 'pg_catalog', 'mysql', 'performance_schema', 'sys', 'crdb_internal') \
-         AND table_type = 'BASE TABLE' LIMIT 5",
-    )
-    .fetch_all(pool)
-    .await
-    .map_err(|e| ChaosError::Discovery(format!("Failed to list tables: {e}")))?;
-
-    Ok(rows
-        .iter()
-        .map(|r| {
-            let schema: String = r.get("table_schema");
-            let table: String = r.get("table_name");
-            (schema, table)
-        })
-        .collect())
-}
-
 pub async fn get_backend_pid(
     conn: &mut PoolConnection<Any>,
     db_type: DbType,
@@ -95,12 +76,15 @@ pub async fn get_backend_pid(
     }
 }
 
-pub async fn terminate_backend(pool: &AnyPool, pid: i32, db_type_str: &str) -> ChaosResult<()> {
+pub async fn terminate_backend<'c, E>(executor: E, pid: i32, db_type_str: &str) -> ChaosResult<()>
+where
+    E: Executor<'c, Database = Any>,
+{
     let db_lower = db_type_str.to_lowercase();
 
     if db_lower.contains("mysql") {
         let kill_query = format!("KILL {}", pid);
-        sqlx::query(&kill_query).execute(pool).await.map_err(|e| {
+        sqlx::query(&kill_query).execute(executor).await.map_err(|e| {
             ChaosError::Other(anyhow::anyhow!(
                 "Failed to KILL MySQL connection {}: {e}",
                 pid
@@ -110,7 +94,7 @@ pub async fn terminate_backend(pool: &AnyPool, pid: i32, db_type_str: &str) -> C
         // PostgreSQL, CockroachDB, YugabyteDB all support pg_terminate_backend
         let result = sqlx::query("SELECT pg_terminate_backend($1)")
             .bind(pid)
-            .fetch_one(pool)
+            .fetch_one(executor)
             .await
             .map_err(|e| {
                 ChaosError::Other(anyhow::anyhow!(
@@ -133,24 +117,17 @@ pub async fn terminate_backend(pool: &AnyPool, pid: i32, db_type_str: &str) -> C
 
 pub async fn find_pk_column(
     conn: &mut PoolConnection<Any>,
+    db_type: DbType,
     schema: &str,
     table: &str,
 ) -> Option<String> {
-    let pk_row = sqlx::query(
-        "SELECT c.column_name FROM information_schema.columns c \
-         JOIN information_schema.key_column_usage kcu \
-           ON c.table_schema = kcu.table_schema AND c.table_name = kcu.table_name AND c.column_name = kcu.column_name \
-         JOIN information_schema.table_constraints tc \
-           ON kcu.constraint_name = tc.constraint_name AND kcu.table_schema = tc.table_schema \
-         WHERE tc.constraint_type = 'PRIMARY KEY' AND c.table_schema = $1 AND c.table_name = $2 \
-         LIMIT 1",
-    )
-    .bind(schema)
-    .bind(table)
-    .fetch_optional(&mut **conn)
-    .await
-    .ok()
-    .flatten();
+    let pk_row = sqlx::query(&primary_key_query(db_type))
+        .bind(schema)
+        .bind(table)
+        .fetch_optional(&mut **conn)
+        .await
+        .ok()
+        .flatten();
 
     pk_row.map(|row| row.get("column_name"))
 }
@@ -1,6 +1,10 @@
+pub mod autovacuum_disrupt;
 pub mod config_change;
+pub mod data_gen;
+pub mod index_hotspot;
 pub mod insert_load;
 pub mod select_load;
+pub mod slow_query;
 pub mod update_load;
 pub mod mongo_insert_load;
 pub mod mongo_update_load;
@@ -9,7 +13,11 @@ pub mod mongo_index_drop;
 pub mod mongo_profiling_change;
 pub mod mongo_connection_stress;
 pub mod crdb_zone_config;
+pub mod deadlock;
 pub mod lock_utils;
+pub mod sql_utils;
 pub mod row_lock;
 pub mod table_lock;
 pub mod ysql_follower_reads;
+pub mod wal_fill;
+pub mod xid_consume;
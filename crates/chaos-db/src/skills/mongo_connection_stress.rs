@@ -1,10 +1,15 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
 use async_trait::async_trait;
+use chaos_core::duration::parse_duration;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use mongodb::bson::doc;
 use mongodb::Client;
 use serde::{Deserialize, Serialize};
+use tokio::task::AbortHandle;
 
 pub struct MongoConnectionStressSkill;
 
@@ -16,19 +21,47 @@ struct ConnectionStressParams {
     /// Number of extra clients to open. Default: 50.
     #[serde(default = "default_count")]
     count: u32,
+    /// Safety cap; the extra clients are closed after this even if rollback
+    /// never runs. Default: 1h.
+    #[serde(default = "default_max_duration")]
+    max_duration: String,
 }
 
 fn default_count() -> u32 {
     50
 }
 
+fn default_max_duration() -> String {
+    "1h".to_string()
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct ConnectionStressUndoState {
-    /// We store the count so rollback knows how many were opened.
-    /// The actual clients are held in a leaked Vec during the experiment
-    /// and cleaned up when the process drops them.
     opened: u32,
-    note: String,
+    /// Key into [`OPEN`], when extra clients were opened.
+    #[serde(default)]
+    run_id: Option<String>,
+}
+
+struct OpenClients {
+    clients: Vec<Client>,
+    /// Closes the clients at `max_duration`.
+    expiry: AbortHandle,
+}
+
+/// Extra clients opened by `execute`, until rollback or their expiry closes
+/// them.
+static OPEN: Mutex<BTreeMap<String, OpenClients>> = Mutex::new(BTreeMap::new());
+
+/// Take the clients of `run_id` out of [`OPEN`], if they're still there.
+fn take_clients(run_id: &str) -> Option<OpenClients> {
+    OPEN.lock().unwrap_or_else(|e| e.into_inner()).remove(run_id)
+}
+
+async fn close_clients(clients: Vec<Client>) {
+    for client in clients {
+        client.shutdown().immediate(true).await;
+    }
 }
 
 #[async_trait]
@@ -42,9 +75,20 @@ impl Skill for MongoConnectionStressSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "connection_url": { "type": "string", "description": "Defaults to the agent's connection_url" },
+                "count": { "type": "integer", "default": 50, "description": "Number of extra clients to open" },
+                "max_duration": { "type": "string", "default": "1h", "description": "Safety cap; the extra clients are closed after this" }
+            }
+        })
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: ConnectionStressParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid mongo.connection_pool_stress params: {e}")))?;
+        let params: ConnectionStressParams = parse_params("mongo.connection_pool_stress", params)?;
+        parse_duration(&params.max_duration)?;
         Ok(())
     }
 
@@ -54,8 +98,8 @@ impl Skill for MongoConnectionStressSkill {
             .downcast_ref::<Client>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected mongodb::Client")))?;
 
-        let params: ConnectionStressParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<ConnectionStressParams>()?;
+        let max_duration = parse_duration(&params.max_duration)?;
 
         // Get the connection URL from params or from the agent's existing client
         // We extract the connection string by using serverStatus
@@ -71,6 +115,14 @@ impl Skill for MongoConnectionStressSkill {
             .and_then(|c| c.get_i32("current").ok())
             .unwrap_or(0);
 
+        if ctx.dry_run {
+            ctx.record_planned(format!(
+                "Open {} extra connections ({current_connections} open now)",
+                params.count
+            ));
+            return Ok(RollbackHandle::dry_run("mongo.connection_pool_stress"));
+        }
+
         tracing::info!(
             current_connections,
             target_new = params.count,
@@ -93,6 +145,7 @@ impl Skill for MongoConnectionStressSkill {
         };
 
         let mut opened = 0u32;
+        let mut extra_clients = Vec::new();
 
         if uri.is_empty() {
             // Use the existing client — spawn concurrent pings to force
@@ -123,9 +176,7 @@ impl Skill for MongoConnectionStressSkill {
                             .database("admin")
                             .run_command(doc! { "ping": 1 })
                             .await;
-                        // Leak the client so it stays alive during the experiment.
-                        // It will be cleaned up when the process exits or soak ends.
-                        std::mem::forget(new_client);
+                        extra_clients.push(new_client);
                         opened += 1;
                     }
                     Err(e) => {
@@ -156,10 +207,30 @@ impl Skill for MongoConnectionStressSkill {
             "Connection pool stress applied"
         );
 
-        let undo = ConnectionStressUndoState {
-            opened,
-            note: "Connections will be released when the experiment process ends or soak duration expires".into(),
-        };
+        // Hold the clients until rollback, or close them at max_duration if
+        // rollback never comes
+        let run_id = (!extra_clients.is_empty()).then(|| {
+            let run_id = uuid::Uuid::new_v4().as_simple().to_string();
+            let expiring = run_id.clone();
+            let expiry = tokio::spawn(async move {
+                tokio::time::sleep(max_duration).await;
+                if let Some(open) = take_clients(&expiring) {
+                    tracing::info!(clients = open.clients.len(), "Connection pool stress reached max_duration, closing clients");
+                    close_clients(open.clients).await;
+                }
+            })
+            .abort_handle();
+            OPEN.lock().unwrap_or_else(|e| e.into_inner()).insert(
+                run_id.clone(),
+                OpenClients {
+                    clients: extra_clients,
+                    expiry,
+                },
+            );
+            run_id
+        });
+
+        let undo = ConnectionStressUndoState { opened, run_id };
 
         let undo_state = serde_yaml::to_value(&undo)
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
@@ -170,15 +241,29 @@ impl Skill for MongoConnectionStressSkill {
         ))
     }
 
-    async fn rollback(&self, ctx: &SkillContext, _handle: &RollbackHandle) -> ChaosResult<()> {
-        // Leaked clients are cleaned up by process termination.
-        // For non-leaked (concurrent ping) mode, the connections return
-        // to the pool automatically.
+    async fn rollback(&self, ctx: &SkillContext, handle: &RollbackHandle) -> ChaosResult<()> {
+        // Connections from concurrent pings on the agent's client return to
+        // its pool by themselves; extra clients are closed here.
         let client = ctx
             .shared
             .downcast_ref::<Client>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected mongodb::Client")))?;
 
+        let undo: ConnectionStressUndoState = serde_yaml::from_value(handle.undo_state.clone())
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
+        if let Some(ref run_id) = undo.run_id {
+            match take_clients(run_id) {
+                Some(open) => {
+                    open.expiry.abort();
+                    let count = open.clients.len();
+                    close_clients(open.clients).await;
+                    ctx.record_action(format!("Closed {count} extra MongoDB clients"));
+                }
+                // Already expired, or opened by a process that has since exited
+                None => tracing::info!(run_id = %run_id, "Extra MongoDB clients already closed"),
+            }
+        }
+
         let status = client
             .database("admin")
             .run_command(doc! { "serverStatus": 1, "connections": 1 })
@@ -193,7 +278,7 @@ impl Skill for MongoConnectionStressSkill {
 
         tracing::info!(
             current_connections = current,
-            "Connection pool stress rollback: connections will drain as clients are dropped"
+            "Connection pool stress rolled back"
         );
 
         Ok(())
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use futures::TryStreamExt;
 use mongodb::bson::{doc, Document};
 use mongodb::Client;
@@ -38,9 +38,23 @@ impl Skill for MongoFindLoadSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "database": { "type": "string", "default": "test" },
+                "collections": { "type": "array", "items": { "type": "string" } },
+                "query_count": { "type": "integer", "default": 500 }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: FindParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid mongo.find_load params: {e}")))?;
+        let _: FindParams = parse_params("mongo.find_load", params)?;
         Ok(())
     }
 
@@ -50,13 +64,12 @@ impl Skill for MongoFindLoadSkill {
             .downcast_ref::<Client>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected mongodb::Client")))?;
 
-        let params: FindParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<FindParams>()?;
 
         let db = client.database(&params.database);
 
         // Discover collections if none specified
-        let collections = if params.collections.is_empty() {
+        let mut collections = if params.collections.is_empty() {
             db.list_collection_names()
                 .await
                 .map_err(|e| ChaosError::Discovery(format!("Failed to list collections: {e}")))?
@@ -67,12 +80,20 @@ impl Skill for MongoFindLoadSkill {
         } else {
             params.collections.clone()
         };
+        collections.truncate(ctx.clamp("collections", collections.len(), ctx.limits.max_tables));
 
         let mut total_queries = 0u32;
 
         for coll_name in &collections {
             let coll = db.collection::<Document>(coll_name);
             let per_coll = params.query_count / collections.len().max(1) as u32;
+            if ctx.dry_run {
+                ctx.record_planned(format!(
+                    "Run {per_coll} find/count/aggregate queries on {}.{coll_name}",
+                    params.database
+                ));
+                continue;
+            }
 
             for i in 0..per_coll {
                 let query_result = match i % 4 {
@@ -120,6 +141,10 @@ impl Skill for MongoFindLoadSkill {
             }
         }
 
+        if ctx.dry_run {
+            return Ok(RollbackHandle::dry_run("mongo.find_load"));
+        }
+
         tracing::info!(total_queries, "MongoDB find load completed");
 
         let undo_state = serde_yaml::to_value(serde_json::json!({
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use mongodb::bson::{doc, Document};
 use mongodb::Client;
 use serde::{Deserialize, Serialize};
@@ -53,9 +53,23 @@ impl Skill for MongoIndexDropSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "database": { "type": "string", "default": "test" },
+                "collections": { "type": "array", "items": { "type": "string" } },
+                "max_per_collection": { "type": "integer", "default": 3 }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: IndexDropParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid mongo.index_drop params: {e}")))?;
+        let _: IndexDropParams = parse_params("mongo.index_drop", params)?;
         Ok(())
     }
 
@@ -65,12 +79,11 @@ impl Skill for MongoIndexDropSkill {
             .downcast_ref::<Client>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected mongodb::Client")))?;
 
-        let params: IndexDropParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<IndexDropParams>()?;
 
         let db = client.database(&params.database);
 
-        let collections = if params.collections.is_empty() {
+        let mut collections = if params.collections.is_empty() {
             db.list_collection_names()
                 .await
                 .map_err(|e| ChaosError::Discovery(format!("Failed to list collections: {e}")))?
@@ -81,6 +94,7 @@ impl Skill for MongoIndexDropSkill {
         } else {
             params.collections.clone()
         };
+        collections.truncate(ctx.clamp("collections", collections.len(), ctx.limits.max_tables));
 
         let mut all_undo = Vec::new();
 
@@ -124,6 +138,16 @@ impl Skill for MongoIndexDropSkill {
                 });
             }
 
+            if ctx.dry_run {
+                for entry in droppable.iter().take(params.max_per_collection) {
+                    ctx.record_planned(format!(
+                        "Drop index {} on {}.{coll_name} (keys {})",
+                        entry.index_name, params.database, entry.key
+                    ));
+                }
+                continue;
+            }
+
             // Drop up to max_per_collection indexes
             for entry in droppable.into_iter().take(params.max_per_collection) {
                 match coll.drop_index(&entry.index_name).await {
@@ -147,6 +171,10 @@ impl Skill for MongoIndexDropSkill {
             }
         }
 
+        if ctx.dry_run {
+            return Ok(RollbackHandle::dry_run("mongo.index_drop"));
+        }
+
         tracing::info!(dropped = all_undo.len(), "Index drop complete");
 
         let undo_state = serde_yaml::to_value(&all_undo)
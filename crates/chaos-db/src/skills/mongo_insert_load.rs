@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use mongodb::bson::{doc, oid::ObjectId, Bson, Document};
 use mongodb::Client;
 use serde::{Deserialize, Serialize};
@@ -44,9 +44,23 @@ impl Skill for MongoInsertLoadSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "database": { "type": "string", "default": "test" },
+                "collections": { "type": "array", "items": { "type": "string" } },
+                "docs_per_collection": { "type": "integer", "default": 1000 }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: InsertParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid mongo.insert_load params: {e}")))?;
+        let _: InsertParams = parse_params("mongo.insert_load", params)?;
         Ok(())
     }
 
@@ -56,13 +70,12 @@ impl Skill for MongoInsertLoadSkill {
             .downcast_ref::<Client>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected mongodb::Client")))?;
 
-        let params: InsertParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<InsertParams>()?;
 
         let db = client.database(&params.database);
 
         // Discover collections if none specified
-        let collections = if params.collections.is_empty() {
+        let mut collections = if params.collections.is_empty() {
             db.list_collection_names()
                 .await
                 .map_err(|e| ChaosError::Discovery(format!("Failed to list collections: {e}")))?
@@ -73,16 +86,25 @@ impl Skill for MongoInsertLoadSkill {
         } else {
             params.collections.clone()
         };
+        collections.truncate(ctx.clamp("collections", collections.len(), ctx.limits.max_tables));
+        let docs_per_collection = ctx.clamp("documents", params.docs_per_collection, ctx.limits.max_rows_per_table(collections.len()));
 
         let mut all_undo = Vec::new();
 
         for coll_name in &collections {
+            if ctx.dry_run {
+                ctx.record_planned(format!(
+                    "Insert {} test documents into {}.{coll_name}",
+                    docs_per_collection, params.database
+                ));
+                continue;
+            }
             let coll = db.collection::<Document>(coll_name);
             let mut inserted_ids = Vec::new();
 
             // Build batch of documents
             let mut docs = Vec::new();
-            for i in 0..params.docs_per_collection {
+            for i in 0..docs_per_collection {
                 let doc = doc! {
                     "chaos_test": true,
                     "index": i as i64,
@@ -99,7 +121,7 @@ impl Skill for MongoInsertLoadSkill {
 
             match coll.insert_many(&docs).await {
                 Ok(result) => {
-                    for (_, id) in &result.inserted_ids {
+                    for id in result.inserted_ids.values() {
                         if let Bson::ObjectId(oid) = id {
                             inserted_ids.push(oid.to_hex());
                         }
@@ -128,6 +150,10 @@ impl Skill for MongoInsertLoadSkill {
             }
         }
 
+        if ctx.dry_run {
+            return Ok(RollbackHandle::dry_run("mongo.insert_load"));
+        }
+
         let undo_state = serde_yaml::to_value(&all_undo)
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
 
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use mongodb::bson::doc;
 use mongodb::Client;
 use serde::{Deserialize, Serialize};
@@ -51,9 +51,19 @@ impl Skill for MongoProfilingChangeSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "database": { "type": "string", "default": "test" },
+                "level": { "type": "integer", "enum": [0, 1, 2], "default": 2 },
+                "slow_ms": { "type": "integer", "default": 100, "description": "Only used when level=1" }
+            }
+        })
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let p: ProfilingParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid mongo.profiling_change params: {e}")))?;
+        let p: ProfilingParams = parse_params("mongo.profiling_change", params)?;
         if !(0..=2).contains(&p.level) {
             return Err(ChaosError::Config("profiling level must be 0, 1, or 2".into()));
         }
@@ -66,8 +76,7 @@ impl Skill for MongoProfilingChangeSkill {
             .downcast_ref::<Client>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected mongodb::Client")))?;
 
-        let params: ProfilingParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<ProfilingParams>()?;
 
         let db = client.database(&params.database);
 
@@ -88,6 +97,14 @@ impl Skill for MongoProfilingChangeSkill {
             cmd.insert("slowms", params.slow_ms);
         }
 
+        if ctx.dry_run {
+            ctx.record_planned(format!(
+                "Set profiling level of {} to {} (currently {original_level})",
+                params.database, params.level
+            ));
+            return Ok(RollbackHandle::dry_run("mongo.profiling_change"));
+        }
+
         db.run_command(cmd).await.map_err(|e| {
             ChaosError::Other(anyhow::anyhow!("Failed to set profiling level: {e}"))
         })?;
@@ -100,7 +117,7 @@ impl Skill for MongoProfilingChangeSkill {
         );
 
         let undo = ProfilingUndoState {
-            database: params.database,
+            database: params.database.clone(),
             original_level,
             original_slow_ms,
         };
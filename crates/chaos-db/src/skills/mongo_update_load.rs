@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use futures::TryStreamExt;
 use mongodb::bson::{doc, oid::ObjectId, Bson, Document};
 use mongodb::Client;
@@ -46,9 +46,23 @@ impl Skill for MongoUpdateLoadSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "database": { "type": "string", "default": "test" },
+                "collections": { "type": "array", "items": { "type": "string" } },
+                "docs": { "type": "integer", "default": 100 }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: UpdateParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid mongo.update_load params: {e}")))?;
+        let _: UpdateParams = parse_params("mongo.update_load", params)?;
         Ok(())
     }
 
@@ -58,13 +72,12 @@ impl Skill for MongoUpdateLoadSkill {
             .downcast_ref::<Client>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected mongodb::Client")))?;
 
-        let params: UpdateParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<UpdateParams>()?;
 
         let db = client.database(&params.database);
 
         // Discover collections if none specified
-        let collections = if params.collections.is_empty() {
+        let mut collections = if params.collections.is_empty() {
             db.list_collection_names()
                 .await
                 .map_err(|e| ChaosError::Discovery(format!("Failed to list collections: {e}")))?
@@ -75,16 +88,25 @@ impl Skill for MongoUpdateLoadSkill {
         } else {
             params.collections.clone()
         };
+        collections.truncate(ctx.clamp("collections", collections.len(), ctx.limits.max_tables));
+        let docs = ctx.clamp("documents", params.docs, ctx.limits.max_rows_per_table(collections.len()));
 
         let mut all_undo = Vec::new();
 
         for coll_name in &collections {
+            if ctx.dry_run {
+                ctx.record_planned(format!(
+                    "Set chaos_modified on up to {} documents of {}.{coll_name}",
+                    docs, params.database
+                ));
+                continue;
+            }
             let coll = db.collection::<Document>(coll_name);
 
             // Fetch documents to update
             let mut cursor = coll
                 .find(doc! {})
-                .limit(params.docs as i64)
+                .limit(docs as i64)
                 .await
                 .map_err(|e| {
                     ChaosError::Other(anyhow::anyhow!("Failed to query {coll_name}: {e}"))
@@ -128,6 +150,10 @@ impl Skill for MongoUpdateLoadSkill {
             tracing::info!(collection = %coll_name, updated, "Updated documents");
         }
 
+        if ctx.dry_run {
+            return Ok(RollbackHandle::dry_run("mongo.update_load"));
+        }
+
         let undo_state = serde_yaml::to_value(&all_undo)
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
 
@@ -1,18 +1,20 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use serde::{Deserialize, Serialize};
 use sqlx::AnyPool;
 
 use crate::config::DbType;
 use crate::skills::lock_utils::{
-    discover_user_tables, find_pk_column, get_backend_pid, terminate_backend,
-    validate_row_lock_type,
+    find_pk_column, get_backend_pid, terminate_backend, validate_row_lock_type,
 };
+use crate::skills::sql_utils::{qualified_table, quote_ident, resolve_tables, validate_table_patterns};
 
 pub struct RowLockSkill {
     pub db_type: DbType,
+    /// The target's `schemas`; empty means all.
+    pub schemas: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -59,31 +61,80 @@ impl Skill for RowLockSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "tables": { "type": "array", "items": { "type": "string" } },
+                "rows_per_table": { "type": "integer", "default": 100 },
+                "lock_type": { "type": "string", "default": "FOR UPDATE" }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let p: RowLockParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid db.row_lock params: {e}")))?;
+        let p: RowLockParams = parse_params("db.row_lock", params)?;
         validate_row_lock_type(&p.lock_type)?;
+        validate_table_patterns(&p.tables)?;
         Ok(())
     }
 
+    async fn inspect(&self, ctx: &SkillContext) -> ChaosResult<Vec<String>> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
+
+        let params = ctx.typed_params::<RowLockParams>()?;
+
+        Ok(resolve_tables(pool, self.db_type, &self.schemas, &params.tables, 5)
+            .await?
+            .into_iter()
+            .map(|(schema, table)| {
+                format!(
+                    "{schema}.{table}: first {} rows {} NOWAIT",
+                    params.rows_per_table,
+                    params.lock_type.to_uppercase()
+                )
+            })
+            .collect())
+    }
+
     async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
         let pool = ctx
             .shared
             .downcast_ref::<AnyPool>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
 
-        let params: RowLockParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
-
-        let tables = if params.tables.is_empty() {
-            discover_user_tables(pool).await?
-        } else {
-            params
-                .tables
-                .iter()
-                .map(|t| ("public".to_string(), t.clone()))
-                .collect()
+        let params = ctx.typed_params::<RowLockParams>()?;
+
+        let mut tables = resolve_tables(pool, self.db_type, &self.schemas, &params.tables, 5).await?;
+        tables.truncate(ctx.clamp("tables", tables.len(), ctx.limits.max_tables));
+
+        let lock_type_upper = params.lock_type.to_uppercase();
+        // MySQL has no key-only row locks; take the nearest stronger one.
+        let lock_clause = match (self.db_type, lock_type_upper.as_str()) {
+            (DbType::Mysql, "FOR NO KEY UPDATE") => "FOR UPDATE",
+            (DbType::Mysql, "FOR KEY SHARE") => "FOR SHARE",
+            (_, other) => other,
         };
+        if lock_clause != lock_type_upper {
+            ctx.warn(format!("MySQL has no {lock_type_upper}, using {lock_clause}"));
+        }
+
+        if ctx.dry_run {
+            for (schema, table) in &tables {
+                ctx.record_planned(format!(
+                    "Lock the first {} rows of {schema}.{table} ({lock_clause}) until rollback",
+                    params.rows_per_table
+                ));
+            }
+            return Ok(RollbackHandle::dry_run("db.row_lock"));
+        }
 
         // Acquire a dedicated connection and hold it for the lock duration
         let mut conn = pool.acquire().await.map_err(|e| {
@@ -97,31 +148,29 @@ impl Skill for RowLockSkill {
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("BEGIN failed: {e}")))?;
 
         let mut locked_rows = Vec::new();
-        let lock_type_upper = params.lock_type.to_uppercase();
 
         for (schema, table) in &tables {
-            let pk_col = match find_pk_column(&mut conn, schema, table).await {
+            let pk_col = match find_pk_column(&mut conn, self.db_type, schema, table).await {
                 Some(col) => col,
                 None => {
-                    tracing::warn!(table = %table, "No primary key found, skipping row lock");
+                    ctx.warn(format!("{schema}.{table} has no primary key, skipped row lock"));
                     continue;
                 }
             };
 
             let lock_sql = format!(
-                "SELECT * FROM \"{schema}\".\"{table}\" ORDER BY \"{pk_col}\" LIMIT {} {lock_type_upper} NOWAIT",
+                "SELECT * FROM {} ORDER BY {} LIMIT {} {lock_clause} NOWAIT",
+                qualified_table(self.db_type, schema, table),
+                quote_ident(self.db_type, &pk_col),
                 params.rows_per_table,
             );
 
             match sqlx::query(&lock_sql).fetch_all(&mut *conn).await {
                 Ok(rows) => {
                     let count = rows.len() as u32;
-                    tracing::info!(
-                        table = %table,
-                        rows_locked = count,
-                        lock_type = %lock_type_upper,
-                        "Row locks acquired"
-                    );
+                    ctx.record_action(format!(
+                        "Locked {count} rows in {schema}.{table} ({lock_clause})"
+                    ));
                     locked_rows.push(LockedTableSummary {
                         table: table.clone(),
                         schema: schema.clone(),
@@ -129,7 +178,7 @@ impl Skill for RowLockSkill {
                     });
                 }
                 Err(e) => {
-                    tracing::warn!(table = %table, error = %e, "Failed to lock rows, skipping");
+                    ctx.warn(format!("Failed to lock rows in {schema}.{table}, skipped: {e}"));
                 }
             }
         }
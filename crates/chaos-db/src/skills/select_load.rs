@@ -1,12 +1,18 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use serde::Deserialize;
 use sqlx::AnyPool;
-use sqlx::Row;
 
-pub struct SelectLoadSkill;
+use crate::config::DbType;
+use crate::skills::sql_utils::{resolve_tables, validate_table_patterns};
+
+pub struct SelectLoadSkill {
+    pub db_type: DbType,
+    /// The target's `schemas`; empty means all.
+    pub schemas: Vec<String>,
+}
 
 #[derive(Debug, Deserialize)]
 struct SelectParams {
@@ -31,9 +37,23 @@ impl Skill for SelectLoadSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "query_count": { "type": "integer", "default": 500 },
+                "tables": { "type": "array", "items": { "type": "string" } }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: SelectParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid select_load params: {e}")))?;
+        let params: SelectParams = parse_params("select_load", params)?;
+        validate_table_patterns(&params.tables)?;
         Ok(())
     }
 
@@ -43,43 +63,25 @@ impl Skill for SelectLoadSkill {
             .downcast_ref::<AnyPool>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool")))?;
 
-        let params: SelectParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
-
-        let tables_to_target = if params.tables.is_empty() {
-            let rows = sqlx::query(
-                "SELECT table_schema, table_name FROM information_schema.tables \
-                 WHERE table_schema NOT IN ('information_schema', 'pg_catalog', 'mysql', 'performance_schema', 'sys') \
-                 AND table_type = 'BASE TABLE' LIMIT 10",
-            )
-            .fetch_all(pool)
-            .await
-            .map_err(|e| ChaosError::Discovery(format!("Table list failed: {e}")))?;
-
-            rows.iter()
-                .map(|r| {
-                    let schema: String = r.get("table_schema");
-                    let table: String = r.get("table_name");
-                    (schema, table)
-                })
-                .collect::<Vec<_>>()
-        } else {
-            params
-                .tables
-                .iter()
-                .map(|t| ("public".to_string(), t.clone()))
-                .collect()
-        };
+        let params = ctx.typed_params::<SelectParams>()?;
+
+        let mut tables_to_target = resolve_tables(pool, self.db_type, &self.schemas, &params.tables, 10).await?;
+        tables_to_target.truncate(ctx.clamp("tables", tables_to_target.len(), ctx.limits.max_tables));
+        let random = if self.db_type == DbType::Mysql { "RAND()" } else { "random()" };
 
         let mut total_queries = 0u32;
 
         for (schema, table) in &tables_to_target {
             let per_table = params.query_count / tables_to_target.len().max(1) as u32;
+            if ctx.dry_run {
+                ctx.record_planned(format!("Run {per_table} heavy SELECTs on {schema}.{table}"));
+                continue;
+            }
 
             for _ in 0..per_table {
                 // Run various heavy queries
                 let queries = [
-                    format!("SELECT * FROM {schema}.{table} ORDER BY random() LIMIT 100"),
+                    format!("SELECT * FROM {schema}.{table} ORDER BY {random} LIMIT 100"),
                     format!("SELECT COUNT(*) FROM {schema}.{table}"),
                     format!(
                         "SELECT * FROM {schema}.{table} t1 CROSS JOIN (SELECT 1) t2 LIMIT 1000"
@@ -97,6 +99,10 @@ impl Skill for SelectLoadSkill {
             }
         }
 
+        if ctx.dry_run {
+            return Ok(RollbackHandle::dry_run("db.select_load"));
+        }
+
         tracing::info!(total_queries, "Select load completed");
 
         // Select load is read-only, no real rollback needed
@@ -0,0 +1,283 @@
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use chaos_core::duration::format_duration;
+use chaos_core::error::{ChaosError, ChaosResult};
+use chaos_core::rollback::RollbackHandle;
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use serde::{Deserialize, Serialize};
+use sqlx::any::Any;
+use sqlx::pool::PoolConnection;
+use sqlx::AnyPool;
+
+use crate::config::DbType;
+use crate::connection::POOL_MAX_CONNECTIONS;
+use crate::skills::lock_utils::{get_backend_pid, terminate_backend};
+
+/// Keep `concurrency` dedicated connections busy with deliberately slow
+/// queries, each taking `query_seconds`, until rollback terminates them.
+/// Exercises slow-query logging, query-timeout alerting and connection
+/// headroom.
+pub struct SlowQuerySkill {
+    pub db_type: DbType,
+}
+
+/// Each slow query pins a pooled connection; keep enough of the pool free
+/// for the terminations at rollback and for other skills.
+const MAX_CONCURRENCY: u32 = POOL_MAX_CONNECTIONS - 16;
+
+#[derive(Debug, Deserialize)]
+struct SlowQueryParams {
+    /// Connections running slow queries at once. Default: 4.
+    #[serde(default = "default_concurrency")]
+    concurrency: u32,
+    /// How long each query takes. Default: 30.
+    #[serde(default = "default_query_seconds")]
+    query_seconds: u64,
+    #[serde(default)]
+    mode: SlowQueryMode,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum SlowQueryMode {
+    /// `pg_sleep` / `SLEEP`: slow but idle on the server.
+    #[default]
+    Sleep,
+    /// A cross join cut off at `query_seconds`: slow and burning CPU.
+    Cartesian,
+}
+
+fn default_concurrency() -> u32 {
+    4
+}
+
+fn default_query_seconds() -> u64 {
+    30
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SlowQueryUndoState {
+    backend_pids: Vec<i32>,
+    mode: SlowQueryMode,
+    query_seconds: u64,
+    db_type: String,
+}
+
+#[async_trait]
+impl Skill for SlowQuerySkill {
+    fn descriptor(&self) -> SkillDescriptor {
+        SkillDescriptor {
+            name: "db.slow_query".into(),
+            description: "Run deliberately slow queries (sleep or cartesian join) on several connections to test query-timeout alerting".into(),
+            target: TargetDomain::Database,
+            reversible: true,
+        }
+    }
+
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "concurrency": { "type": "integer", "default": 4, "maximum": MAX_CONCURRENCY },
+                "query_seconds": {
+                    "type": "integer",
+                    "default": 30,
+                    "description": "Duration of each query; at most the experiment duration"
+                },
+                "mode": { "type": "string", "enum": ["sleep", "cartesian"], "default": "sleep" }
+            }
+        })
+    }
+
+    fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
+        let p: SlowQueryParams = parse_params("db.slow_query", params)?;
+        if p.concurrency == 0 || p.concurrency > MAX_CONCURRENCY {
+            return Err(ChaosError::Config(format!(
+                "db.slow_query concurrency must be between 1 and {MAX_CONCURRENCY}, got {}",
+                p.concurrency
+            )));
+        }
+        if p.query_seconds == 0 {
+            return Err(ChaosError::Config("db.slow_query query_seconds must be at least 1".into()));
+        }
+        Ok(())
+    }
+
+    fn validate_duration(&self, params: &serde_yaml::Value, duration: Duration) -> ChaosResult<()> {
+        let p: SlowQueryParams = parse_params("db.slow_query", params)?;
+        if Duration::from_secs(p.query_seconds) > duration {
+            return Err(ChaosError::Config(format!(
+                "db.slow_query query_seconds {} is longer than the experiment duration ({}); \
+                 the queries would be terminated at rollback before they finish",
+                p.query_seconds,
+                format_duration(duration)
+            )));
+        }
+        Ok(())
+    }
+
+    async fn inspect(&self, ctx: &SkillContext) -> ChaosResult<Vec<String>> {
+        let params = ctx.typed_params::<SlowQueryParams>()?;
+        Ok(vec![format!(
+            "{} connection(s), each repeating: {}",
+            params.concurrency,
+            self.query(params.mode, params.query_seconds)
+        )])
+    }
+
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
+
+        let params = ctx.typed_params::<SlowQueryParams>()?;
+        let query = self.query(params.mode, params.query_seconds);
+        let query_time = Duration::from_secs(params.query_seconds);
+        let db_type = format!("{:?}", self.db_type);
+
+        if ctx.dry_run {
+            ctx.record_planned(format!(
+                "Open {} connection(s), each repeating: {query}",
+                params.concurrency
+            ));
+            return Ok(RollbackHandle::dry_run("db.slow_query"));
+        }
+
+        // Set aside before the workers take theirs, so a partial failure
+        // can still terminate the ones already running
+        let mut control = acquire(pool).await?;
+
+        let mut backend_pids = Vec::new();
+        for _ in 0..params.concurrency {
+            let (pid, mut conn) = match self.connect(pool).await {
+                Ok(started) => started,
+                Err(e) => {
+                    // Don't leave the ones already running behind
+                    for pid in &backend_pids {
+                        let _ = terminate_backend(&mut *control, *pid, &db_type).await;
+                    }
+                    return Err(e);
+                }
+            };
+            if self.db_type != DbType::Mysql && params.mode == SlowQueryMode::Cartesian {
+                let timeout = format!("SET statement_timeout = '{}s'", params.query_seconds);
+                if let Err(e) = sqlx::query(&timeout).execute(&mut *conn).await {
+                    ctx.warn(format!("Backend {pid}: could not set statement_timeout, the query may run longer: {e}"));
+                }
+            }
+
+            let query = query.clone();
+            tokio::spawn(async move {
+                tracing::debug!(pid, "Slow query task started");
+                loop {
+                    let start = Instant::now();
+                    if let Err(e) = sqlx::query(&query).execute(&mut *conn).await {
+                        // A cartesian join cut off by its timeout ran its
+                        // full time; anything quicker is a real failure
+                        if start.elapsed() < query_time / 2 {
+                            tracing::info!(pid, error = %e, "Slow query connection stopped");
+                            break;
+                        }
+                    }
+                }
+            });
+            backend_pids.push(pid);
+        }
+        drop(control);
+
+        ctx.record_action(format!(
+            "{} connection(s) running {}s {} queries (backends {})",
+            backend_pids.len(),
+            params.query_seconds,
+            match params.mode {
+                SlowQueryMode::Sleep => "sleep",
+                SlowQueryMode::Cartesian => "cartesian join",
+            },
+            backend_pids
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+
+        let undo = SlowQueryUndoState {
+            backend_pids,
+            mode: params.mode,
+            query_seconds: params.query_seconds,
+            db_type,
+        };
+
+        let undo_state = serde_yaml::to_value(&undo)
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
+
+        tracing::info!(
+            pids = ?undo.backend_pids,
+            query_seconds = undo.query_seconds,
+            "Slow queries running on background connections"
+        );
+
+        Ok(RollbackHandle::new("db.slow_query", undo_state))
+    }
+
+    async fn rollback(&self, ctx: &SkillContext, handle: &RollbackHandle) -> ChaosResult<()> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
+
+        let undo: SlowQueryUndoState = serde_yaml::from_value(handle.undo_state.clone())
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
+
+        // Terminate every backend even if one fails
+        let mut control = acquire(pool).await?;
+        let mut first_error = None;
+        for pid in &undo.backend_pids {
+            if let Err(e) = terminate_backend(&mut *control, *pid, &undo.db_type).await {
+                tracing::warn!(pid, error = %e, "Failed to terminate slow query backend");
+                first_error.get_or_insert(e);
+            }
+        }
+
+        tracing::info!(pids = ?undo.backend_pids, "Slow queries stopped via backend termination");
+
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+impl SlowQuerySkill {
+    /// A dedicated connection and its backend PID.
+    async fn connect(&self, pool: &AnyPool) -> ChaosResult<(i32, PoolConnection<Any>)> {
+        let mut conn = acquire(pool).await?;
+        let pid = get_backend_pid(&mut conn, self.db_type).await?;
+        Ok((pid, conn))
+    }
+
+    /// One query taking about `seconds`.
+    fn query(&self, mode: SlowQueryMode, seconds: u64) -> String {
+        match (mode, self.db_type) {
+            (SlowQueryMode::Sleep, DbType::Mysql) => format!("SELECT SLEEP({seconds})"),
+            (SlowQueryMode::Sleep, _) => format!("SELECT pg_sleep({seconds})"),
+            // Cut off by MAX_EXECUTION_TIME, in milliseconds
+            (SlowQueryMode::Cartesian, DbType::Mysql) => format!(
+                "SELECT /*+ MAX_EXECUTION_TIME({}) */ COUNT(*) FROM information_schema.columns a, \
+                 information_schema.columns b, information_schema.columns c",
+                seconds * 1000
+            ),
+            // Cut off by the connection's statement_timeout
+            (SlowQueryMode::Cartesian, _) => {
+                "SELECT count(*) FROM generate_series(1, 100000) a, generate_series(1, 100000) b".to_string()
+            }
+        }
+    }
+}
+
+async fn acquire(pool: &AnyPool) -> ChaosResult<PoolConnection<Any>> {
+    pool.acquire()
+        .await
+        .map_err(|e| ChaosError::Connection(anyhow::anyhow!("Failed to acquire connection: {e}")))
+}
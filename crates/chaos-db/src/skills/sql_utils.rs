@@ -0,0 +1,323 @@
+use std::borrow::Cow;
+
+use chaos_core::error::{ChaosError, ChaosResult};
+use chaos_core::name_pattern::{is_pattern, is_regex, NamePattern};
+use sqlx::{AnyPool, Row};
+
+use crate::config::DbType;
+
+/// `query` with Postgres-style `$1, $2, ...` placeholders rewritten to `?` for
+/// MySQL. Parameters must be bound in placeholder order.
+pub fn placeholders(db_type: DbType, query: &str) -> Cow<'_, str> {
+    if db_type != DbType::Mysql {
+        return Cow::Borrowed(query);
+    }
+    let mut out = String::with_capacity(query.len());
+    let mut chars = query.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '$' && chars.peek().is_some_and(char::is_ascii_digit) {
+            while chars.peek().is_some_and(char::is_ascii_digit) {
+                chars.next();
+            }
+            out.push('?');
+        } else {
+            out.push(c);
+        }
+    }
+    Cow::Owned(out)
+}
+
+/// Quote an identifier: backticks for MySQL, double quotes elsewhere.
+pub fn quote_ident(db_type: DbType, name: &str) -> String {
+    match db_type {
+        DbType::Mysql => format!("`{}`", name.replace('`', "``")),
+        _ => format!("\"{}\"", name.replace('"', "\"\"")),
+    }
+}
+
+/// `schema.table`, both quoted for `db_type`.
+pub fn qualified_table(db_type: DbType, schema: &str, table: &str) -> String {
+    format!("{}.{}", quote_ident(db_type, schema), quote_ident(db_type, table))
+}
+
+/// Up to `limit` user tables as `(schema, table)` pairs, or with `schemas`
+/// set, up to `limit` from each of those schemas.
+pub async fn discover_user_tables(
+    pool: &AnyPool,
+    db_type: DbType,
+    schemas: &[String],
+    limit: u32,
+) -> ChaosResult<Vec<(String, String)>> {
+    // Aliased because MySQL 8 reports information_schema columns in upper case.
+    let rows = if schemas.is_empty() {
+        sqlx::query(&format!(
+            "SELECT table_schema AS table_schema, table_name AS table_name FROM information_schema.tables \
+             WHERE table_schema NOT IN ('information_schema', 'pg_catalog', 'mysql', 'performance_schema', 'sys', 'crdb_internal') \
+             AND table_type = 'BASE TABLE' LIMIT {limit}"
+        ))
+        .fetch_all(pool)
+        .await
+        .map_err(|e| ChaosError::Discovery(format!("Failed to list tables: {e}")))?
+    } else {
+        let query = placeholders(
+            db_type,
+            "SELECT table_schema AS table_schema, table_name AS table_name FROM information_schema.tables \
+             WHERE table_schema = $1 AND table_type = 'BASE TABLE' ORDER BY table_name",
+        );
+        let mut rows = Vec::new();
+        for schema in schemas {
+            rows.extend(
+                sqlx::query(&format!("{query} LIMIT {limit}"))
+                    .bind(schema)
+                    .fetch_all(pool)
+                    .await
+                    .map_err(|e| ChaosError::Discovery(format!("Failed to list tables in {schema}: {e}")))?,
+            );
+        }
+        rows
+    };
+
+    Ok(rows
+        .iter()
+        .map(|r| {
+            let schema: String = r.get("table_schema");
+            let table: String = r.get("table_name");
+            (schema, table)
+        })
+        .collect())
+}
+
+/// The `tables` param as `(schema, table)` pairs, or up to `limit` discovered
+/// user tables (per schema, with `schemas` set) when it's empty.
+///
+/// A table may be named `schema.table`; the schema must then be one of
+/// `schemas`, if any are set. An unqualified name is looked up in every one
+/// of `schemas`, or without them in `public` (on MySQL, the connection's
+/// database).
+///
+/// A glob (`orders_*`, `sales.orders_2024_??`) or `re:` regex stands for
+/// every discovered table it matches, with no `limit`; it's an error if it
+/// matches none.
+pub async fn resolve_tables(
+    pool: &AnyPool,
+    db_type: DbType,
+    schemas: &[String],
+    tables: &[String],
+    limit: u32,
+) -> ChaosResult<Vec<(String, String)>> {
+    if tables.is_empty() {
+        return discover_user_tables(pool, db_type, schemas, limit).await;
+    }
+    let default_schema = if schemas.is_empty() && tables.iter().any(|t| split_table_name(t).0.is_none()) {
+        current_schema(pool, db_type).await?
+    } else {
+        String::new()
+    };
+    let mut discovered = None;
+    let mut resolved = Vec::new();
+    for name in tables {
+        if is_pattern(name) {
+            if discovered.is_none() {
+                discovered = Some(discover_user_tables(pool, db_type, schemas, u32::MAX).await?);
+            }
+            let matched = match_tables(discovered.as_deref().unwrap_or_default(), name, schemas, &default_schema)?;
+            if matched.is_empty() {
+                return Err(ChaosError::Config(format!("Table pattern {name} matches no tables")));
+            }
+            resolved.extend(matched);
+            continue;
+        }
+        match split_table_name(name) {
+            (Some(schema), table) => {
+                if !schemas.is_empty() && !schemas.iter().any(|s| s == schema) {
+                    return Err(ChaosError::Config(format!(
+                        "Table {name} is outside the target's schemas ({})",
+                        schemas.join(", ")
+                    )));
+                }
+                resolved.push((schema.to_string(), table.to_string()));
+            }
+            (None, _) if !schemas.is_empty() => {
+                resolved.extend(schemas.iter().map(|s| (s.clone(), name.clone())));
+            }
+            (None, _) => resolved.push((default_schema.clone(), name.clone())),
+        }
+    }
+    // Patterns may overlap each other or named tables
+    let mut seen = std::collections::HashSet::new();
+    resolved.retain(|t| seen.insert(t.clone()));
+    Ok(resolved)
+}
+
+/// `name` split into its schema, if qualified, and table. A regex is never
+/// qualified, as its dots are part of it.
+fn split_table_name(name: &str) -> (Option<&str>, &str) {
+    match name.split_once('.') {
+        Some((schema, table)) if !is_regex(name) => (Some(schema), table),
+        _ => (None, name),
+    }
+}
+
+/// The `discovered` tables matching the glob or regex `pattern`. Unqualified
+/// ones match in `schemas`, or without them in `default_schema`.
+fn match_tables(
+    discovered: &[(String, String)],
+    pattern: &str,
+    schemas: &[String],
+    default_schema: &str,
+) -> ChaosResult<Vec<(String, String)>> {
+    let (schema_pattern, table_pattern) = split_table_name(pattern);
+    let schema_pattern = schema_pattern.map(NamePattern::parse).transpose()?;
+    let table_pattern = NamePattern::parse(table_pattern)?;
+    Ok(discovered
+        .iter()
+        .filter(|(schema, table)| {
+            let schema_matches = match schema_pattern {
+                Some(ref pattern) => pattern.matches(schema),
+                None => !schemas.is_empty() || schema == default_schema,
+            };
+            schema_matches && table_pattern.matches(table)
+        })
+        .cloned()
+        .collect())
+}
+
+/// Check the globs and regexes among `tables` compile, for `validate_params`.
+pub fn validate_table_patterns(tables: &[String]) -> ChaosResult<()> {
+    for name in tables.iter().filter(|t| is_pattern(t)) {
+        let (schema, table) = split_table_name(name);
+        if let Some(schema) = schema {
+            NamePattern::parse(schema)?;
+        }
+        NamePattern::parse(table)?;
+    }
+    Ok(())
+}
+
+/// Schema of unqualified table names: `public`, or on MySQL the connection's
+/// database.
+async fn current_schema(pool: &AnyPool, db_type: DbType) -> ChaosResult<String> {
+    match db_type {
+        DbType::Mysql => sqlx::query("SELECT DATABASE()")
+            .fetch_one(pool)
+            .await
+            .map_err(|e| ChaosError::Discovery(format!("Failed to get current database: {e}")))?
+            .try_get::<String, _>(0)
+            .map_err(|_| {
+                ChaosError::Config("MySQL connection_url must name a database to use `tables`".into())
+            }),
+        _ => Ok("public".to_string()),
+    }
+}
+
+/// Query for the first primary key column of a table; binds schema, table.
+pub fn primary_key_query(db_type: DbType) -> Cow<'static, str> {
+    placeholders(
+        db_type,
+        "SELECT c.column_name AS column_name FROM information_schema.columns c \
+         JOIN information_schema.key_column_usage kcu \
+           ON c.table_schema = kcu.table_schema AND c.table_name = kcu.table_name AND c.column_name = kcu.column_name \
+         JOIN information_schema.table_constraints tc \
+           ON kcu.constraint_name = tc.constraint_name AND kcu.table_schema = tc.table_schema \
+           AND kcu.table_name = tc.table_name \
+         WHERE tc.constraint_type = 'PRIMARY KEY' AND c.table_schema = $1 AND c.table_name = $2 \
+         LIMIT 1",
+    )
+}
+
+/// First primary key column of `schema.table`, if it has one.
+pub async fn primary_key_column(
+    pool: &AnyPool,
+    db_type: DbType,
+    schema: &str,
+    table: &str,
+) -> ChaosResult<Option<String>> {
+    let row = sqlx::query(&primary_key_query(db_type))
+        .bind(schema)
+        .bind(table)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| ChaosError::Discovery(format!("Failed to find PK for {table}: {e}")))?;
+    Ok(row.map(|r| r.get("column_name")))
+}
+
+/// Run `insert` and return the new row's key. Postgres-family databases
+/// use `RETURNING`; MySQL has none, so the key is the statement's
+/// `LAST_INSERT_ID()`, which only exists for auto-increment keys.
+pub async fn insert_returning_key(
+    pool: &AnyPool,
+    db_type: DbType,
+    insert: &str,
+    pk_column: &str,
+) -> Result<Option<String>, sqlx::Error> {
+    if db_type == DbType::Mysql {
+        let result = sqlx::query(insert).execute(pool).await?;
+        return Ok(result
+            .last_insert_id()
+            .filter(|id| *id != 0)
+            .map(|id| id.to_string()));
+    }
+    let row = sqlx::query(&format!("{insert} RETURNING {pk_column}"))
+        .fetch_one(pool)
+        .await?;
+    Ok(Some(
+        row.try_get::<i64, _>(0)
+            .map(|v| v.to_string())
+            .or_else(|_| row.try_get::<i32, _>(0).map(|v| v.to_string()))
+            .or_else(|_| row.try_get::<String, _>(0))
+            .unwrap_or_else(|_| "unknown".to_string()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tables(names: &[(&str, &str)]) -> Vec<(String, String)> {
+        names.iter().map(|(s, t)| (s.to_string(), t.to_string())).collect()
+    }
+
+    fn discovered() -> Vec<(String, String)> {
+        tables(&[
+            ("public", "orders_2023"),
+            ("public", "orders_2024"),
+            ("public", "users"),
+            ("archive", "orders_2022"),
+        ])
+    }
+
+    #[test]
+    fn unqualified_patterns_match_in_the_default_schema() {
+        let matched = match_tables(&discovered(), "orders_*", &[], "public").unwrap();
+        assert_eq!(matched, tables(&[("public", "orders_2023"), ("public", "orders_2024")]));
+    }
+
+    #[test]
+    fn unqualified_patterns_match_in_every_configured_schema() {
+        let schemas = ["public".to_string(), "archive".to_string()];
+        let matched = match_tables(&discovered(), "orders_*", &schemas, "public").unwrap();
+        assert_eq!(matched.len(), 3);
+    }
+
+    #[test]
+    fn schema_qualified_patterns() {
+        let matched = match_tables(&discovered(), "arch*.orders_*", &[], "public").unwrap();
+        assert_eq!(matched, tables(&[("archive", "orders_2022")]));
+        let matched = match_tables(&discovered(), "*.users", &[], "public").unwrap();
+        assert_eq!(matched, tables(&[("public", "users")]));
+    }
+
+    #[test]
+    fn regexes_are_never_schema_qualified() {
+        let matched = match_tables(&discovered(), "re:orders_\\d+", &[], "public").unwrap();
+        assert_eq!(matched.len(), 2);
+        assert!(match_tables(&discovered(), "re:public.users", &[], "public").unwrap().is_empty());
+    }
+
+    #[test]
+    fn validates_patterns_only() {
+        assert!(validate_table_patterns(&["orders".into(), "public.users".into()]).is_ok());
+        assert!(validate_table_patterns(&["orders_[z-a]".into()]).is_err());
+        assert!(validate_table_patterns(&["re:(".into()]).is_err());
+    }
+}
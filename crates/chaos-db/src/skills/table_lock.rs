@@ -1,17 +1,20 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use serde::{Deserialize, Serialize};
+use sqlx::any::Any;
+use sqlx::pool::PoolConnection;
 use sqlx::AnyPool;
 
 use crate::config::DbType;
-use crate::skills::lock_utils::{
-    discover_user_tables, get_backend_pid, terminate_backend, validate_lock_mode,
-};
+use crate::skills::lock_utils::{get_backend_pid, terminate_backend, validate_lock_mode};
+use crate::skills::sql_utils::{qualified_table, resolve_tables, validate_table_patterns};
 
 pub struct TableLockSkill {
     pub db_type: DbType,
+    /// The target's `schemas`; empty means all.
+    pub schemas: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -45,31 +48,71 @@ impl Skill for TableLockSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "tables": { "type": "array", "items": { "type": "string" } },
+                "lock_mode": { "type": "string", "default": "ACCESS EXCLUSIVE" }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let p: TableLockParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid db.table_lock params: {e}")))?;
+        let p: TableLockParams = parse_params("db.table_lock", params)?;
         validate_lock_mode(&p.lock_mode)?;
+        validate_table_patterns(&p.tables)?;
         Ok(())
     }
 
+    async fn inspect(&self, ctx: &SkillContext) -> ChaosResult<Vec<String>> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
+
+        let params = ctx.typed_params::<TableLockParams>()?;
+
+        Ok(resolve_tables(pool, self.db_type, &self.schemas, &params.tables, 5)
+            .await?
+            .into_iter()
+            .map(|(schema, table)| match self.db_type {
+                DbType::Mysql => format!(
+                    "{schema}.{table}: LOCK TABLES {}",
+                    mysql_lock_mode(&params.lock_mode.to_uppercase())
+                ),
+                _ => format!(
+                    "{schema}.{table}: LOCK TABLE IN {} MODE",
+                    params.lock_mode.to_uppercase()
+                ),
+            })
+            .collect())
+    }
+
     async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
         let pool = ctx
             .shared
             .downcast_ref::<AnyPool>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool in context")))?;
 
-        let params: TableLockParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<TableLockParams>()?;
 
-        let tables = if params.tables.is_empty() {
-            discover_user_tables(pool).await?
-        } else {
-            params
-                .tables
-                .iter()
-                .map(|t| ("public".to_string(), t.clone()))
-                .collect()
-        };
+        let mut tables = resolve_tables(pool, self.db_type, &self.schemas, &params.tables, 5).await?;
+        tables.truncate(ctx.clamp("tables", tables.len(), ctx.limits.max_tables));
+
+        if ctx.dry_run {
+            for (schema, table) in &tables {
+                ctx.record_planned(format!(
+                    "Lock {schema}.{table} in {} mode until rollback",
+                    params.lock_mode.to_uppercase()
+                ));
+            }
+            return Ok(RollbackHandle::dry_run("db.table_lock"));
+        }
 
         // Acquire a dedicated connection and hold it for the lock duration
         let mut conn = pool.acquire().await.map_err(|e| {
@@ -82,33 +125,28 @@ impl Skill for TableLockSkill {
             .await
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("BEGIN failed: {e}")))?;
 
-        let mut locked_tables = Vec::new();
         let lock_mode_upper = params.lock_mode.to_uppercase();
-
-        for (schema, table) in &tables {
-            let lock_sql = if self.db_type == DbType::Mysql {
-                let mysql_mode = if lock_mode_upper.contains("EXCLUSIVE") {
-                    "WRITE"
-                } else {
-                    "READ"
-                };
-                format!("LOCK TABLES `{table}` {mysql_mode}")
-            } else {
-                format!(
-                    "LOCK TABLE \"{schema}\".\"{table}\" IN {lock_mode_upper} MODE NOWAIT"
-                )
-            };
-
-            match sqlx::query(&lock_sql).execute(&mut *conn).await {
-                Ok(_) => {
-                    tracing::info!(table = %table, mode = %lock_mode_upper, "Table lock acquired");
-                    locked_tables.push(format!("{schema}.{table}"));
-                }
-                Err(e) => {
-                    tracing::warn!(table = %table, error = %e, "Failed to lock table, skipping");
+        let locked_tables = if self.db_type == DbType::Mysql {
+            lock_mysql_tables(ctx, &mut conn, &tables, &lock_mode_upper).await
+        } else {
+            let mut locked_tables = Vec::new();
+            for (schema, table) in &tables {
+                let lock_sql = format!(
+                    "LOCK TABLE {} IN {lock_mode_upper} MODE NOWAIT",
+                    qualified_table(self.db_type, schema, table)
+                );
+                match sqlx::query(&lock_sql).execute(&mut *conn).await {
+                    Ok(_) => {
+                        ctx.record_action(format!("Locked {schema}.{table} in {lock_mode_upper} mode"));
+                        locked_tables.push(format!("{schema}.{table}"));
+                    }
+                    Err(e) => {
+                        ctx.warn(format!("Failed to lock {schema}.{table}, skipped: {e}"));
+                    }
                 }
             }
-        }
+            locked_tables
+        };
 
         if locked_tables.is_empty() {
             let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
@@ -178,3 +216,64 @@ impl Skill for TableLockSkill {
         Ok(())
     }
 }
+
+/// MySQL only has READ and WRITE table locks; any EXCLUSIVE mode maps to WRITE.
+fn mysql_lock_mode(lock_mode_upper: &str) -> &'static str {
+    if lock_mode_upper.contains("EXCLUSIVE") {
+        "WRITE"
+    } else {
+        "READ"
+    }
+}
+
+/// `LOCK TABLES` releases everything the session already holds, so each table
+/// is probed on its own and the ones that can be locked are then locked in a
+/// single statement. There is no NOWAIT; a short `lock_wait_timeout` stands in.
+async fn lock_mysql_tables(
+    ctx: &SkillContext,
+    conn: &mut PoolConnection<Any>,
+    tables: &[(String, String)],
+    lock_mode_upper: &str,
+) -> Vec<String> {
+    let mode = mysql_lock_mode(lock_mode_upper);
+    if let Err(e) = sqlx::query("SET SESSION lock_wait_timeout = 5")
+        .execute(&mut **conn)
+        .await
+    {
+        ctx.warn(format!("Failed to shorten lock_wait_timeout: {e}"));
+    }
+
+    let mut lockable = Vec::new();
+    for (schema, table) in tables {
+        let name = qualified_table(DbType::Mysql, schema, table);
+        match sqlx::query(&format!("LOCK TABLES {name} {mode}"))
+            .execute(&mut **conn)
+            .await
+        {
+            Ok(_) => lockable.push((format!("{schema}.{table}"), name)),
+            Err(e) => ctx.warn(format!("Failed to lock {schema}.{table}, skipped: {e}")),
+        }
+    }
+    if lockable.is_empty() {
+        return Vec::new();
+    }
+
+    let list: Vec<String> = lockable.iter().map(|(_, name)| format!("{name} {mode}")).collect();
+    match sqlx::query(&format!("LOCK TABLES {}", list.join(", ")))
+        .execute(&mut **conn)
+        .await
+    {
+        Ok(_) => lockable
+            .into_iter()
+            .map(|(label, _)| {
+                ctx.record_action(format!("Locked {label} with LOCK TABLES {mode}"));
+                label
+            })
+            .collect(),
+        Err(e) => {
+            ctx.warn(format!("Failed to lock tables together: {e}"));
+            let _ = sqlx::query("UNLOCK TABLES").execute(&mut **conn).await;
+            Vec::new()
+        }
+    }
+}
@@ -1,12 +1,19 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use serde::{Deserialize, Serialize};
 use sqlx::AnyPool;
 use sqlx::Row;
 
-pub struct UpdateLoadSkill;
+use crate::config::DbType;
+use crate::skills::sql_utils::{placeholders, primary_key_column, resolve_tables, validate_table_patterns};
+
+pub struct UpdateLoadSkill {
+    pub db_type: DbType,
+    /// The target's `schemas`; empty means all.
+    pub schemas: Vec<String>,
+}
 
 #[derive(Debug, Deserialize)]
 struct UpdateParams {
@@ -41,9 +48,23 @@ impl Skill for UpdateLoadSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "rows": { "type": "integer", "default": 100 },
+                "tables": { "type": "array", "items": { "type": "string" } }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: UpdateParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid update_load params: {e}")))?;
+        let params: UpdateParams = parse_params("update_load", params)?;
+        validate_table_patterns(&params.tables)?;
         Ok(())
     }
 
@@ -53,64 +74,35 @@ impl Skill for UpdateLoadSkill {
             .downcast_ref::<AnyPool>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool")))?;
 
-        let params: UpdateParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<UpdateParams>()?;
 
-        let tables_to_target = if params.tables.is_empty() {
-            let rows = sqlx::query(
-                "SELECT table_schema, table_name FROM information_schema.tables \
-                 WHERE table_schema NOT IN ('information_schema', 'pg_catalog', 'mysql', 'performance_schema', 'sys') \
-                 AND table_type = 'BASE TABLE' LIMIT 5",
-            )
-            .fetch_all(pool)
-            .await
-            .map_err(|e| ChaosError::Discovery(format!("Failed to list tables: {e}")))?;
-
-            rows.iter()
-                .map(|r| {
-                    let schema: String = r.get("table_schema");
-                    let table: String = r.get("table_name");
-                    (schema, table)
-                })
-                .collect::<Vec<_>>()
-        } else {
-            params
-                .tables
-                .iter()
-                .map(|t| ("public".to_string(), t.clone()))
-                .collect()
-        };
+        let mut tables_to_target = resolve_tables(pool, self.db_type, &self.schemas, &params.tables, 5).await?;
+        tables_to_target.truncate(ctx.clamp("tables", tables_to_target.len(), ctx.limits.max_tables));
+        let rows_per_table = ctx.clamp(
+            "rows per table",
+            params.rows,
+            ctx.limits.max_rows_per_table(tables_to_target.len()),
+        );
 
         let mut all_undo = Vec::new();
 
         for (schema, table) in &tables_to_target {
             // Find PK and a text-like column to update
-            let cols = sqlx::query(
-                "SELECT column_name, data_type FROM information_schema.columns \
+            let cols = sqlx::query(&placeholders(
+                self.db_type,
+                "SELECT column_name AS column_name, data_type AS data_type FROM information_schema.columns \
                  WHERE table_schema = $1 AND table_name = $2 ORDER BY ordinal_position",
-            )
+            ))
             .bind(schema)
             .bind(table)
             .fetch_all(pool)
             .await
             .map_err(|e| ChaosError::Discovery(format!("Column fetch failed: {e}")))?;
 
-            let pk_col = sqlx::query(
-                "SELECT c.column_name FROM information_schema.columns c \
-                 JOIN information_schema.key_column_usage kcu \
-                   ON c.table_schema = kcu.table_schema AND c.table_name = kcu.table_name AND c.column_name = kcu.column_name \
-                 JOIN information_schema.table_constraints tc \
-                   ON kcu.constraint_name = tc.constraint_name AND kcu.table_schema = tc.table_schema \
-                 WHERE tc.constraint_type = 'PRIMARY KEY' AND c.table_schema = $1 AND c.table_name = $2 LIMIT 1",
-            )
-            .bind(schema)
-            .bind(table)
-            .fetch_optional(pool)
-            .await
-            .map_err(|e| ChaosError::Discovery(format!("PK fetch failed: {e}")))?;
+            let pk_col = primary_key_column(pool, self.db_type, schema, table).await?;
 
             let pk_column: String = match pk_col {
-                Some(row) => row.get("column_name"),
+                Some(column) => column,
                 None => continue,
             };
 
@@ -131,19 +123,27 @@ impl Skill for UpdateLoadSkill {
             let query = format!(
                 "SELECT {pk_column}, {update_column} FROM {schema}.{table} LIMIT $1"
             );
-            let rows = sqlx::query(&query)
-                .bind(params.rows as i64)
+            let rows = sqlx::query(&placeholders(self.db_type, &query))
+                .bind(rows_per_table as i64)
                 .fetch_all(pool)
                 .await;
 
             let rows = match rows {
                 Ok(r) => r,
                 Err(e) => {
-                    tracing::warn!(table = %table, error = %e, "Failed to fetch rows for update");
+                    ctx.warn(format!("Failed to fetch rows for update from {schema}.{table}: {e}"));
                     continue;
                 }
             };
 
+            if ctx.dry_run {
+                ctx.record_planned(format!(
+                    "UPDATE {} rows of {schema}.{table}: SET {update_column} = 'chaos_modified'",
+                    rows.len()
+                ));
+                continue;
+            }
+
             for row in &rows {
                 let pk_value: String = row
                     .try_get::<i64, _>(0)
@@ -173,6 +173,10 @@ impl Skill for UpdateLoadSkill {
             tracing::info!(table = %table, updated = all_undo.len(), "Updated rows");
         }
 
+        if ctx.dry_run {
+            return Ok(RollbackHandle::dry_run("db.update_load"));
+        }
+
         let undo_state = serde_yaml::to_value(&all_undo)
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
 
@@ -0,0 +1,289 @@
+use async_trait::async_trait;
+use chaos_core::error::{ChaosError, ChaosResult};
+use chaos_core::rollback::RollbackHandle;
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use serde::{Deserialize, Serialize};
+use sqlx::AnyPool;
+use sqlx::Row;
+
+use crate::config::DbType;
+
+/// Generate sustained write volume to grow the write-ahead log (PostgreSQL)
+/// or InnoDB redo log (MySQL), testing disk-pressure alerting. On PostgreSQL
+/// a replication slot can pin the WAL, modelling a stuck replica that stops
+/// the server from recycling segments.
+pub struct WalFillSkill {
+    pub db_type: DbType,
+}
+
+const FILL_TABLE: &str = "chaos_wal_fill";
+const SLOT_NAME: &str = "chaos_wal_hold";
+
+/// MySQL's recursive CTE depth limit defaults to 1000.
+const MYSQL_MAX_BATCH_ROWS: u32 = 1000;
+
+#[derive(Debug, Deserialize)]
+struct WalFillParams {
+    /// Log volume to generate, in MB. Default: 256.
+    #[serde(default = "default_target_mb")]
+    target_mb: u64,
+    /// Rows per INSERT batch (~1KB each). Default: 1000.
+    #[serde(default = "default_batch_rows")]
+    batch_rows: u32,
+    /// PostgreSQL only: create a physical replication slot so the generated
+    /// WAL cannot be recycled until rollback. Default: false.
+    #[serde(default)]
+    hold_with_slot: bool,
+}
+
+fn default_target_mb() -> u64 {
+    256
+}
+
+fn default_batch_rows() -> u32 {
+    1000
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct WalFillUndoState {
+    table: String,
+    slot: Option<String>,
+    generated_bytes: u64,
+}
+
+#[async_trait]
+impl Skill for WalFillSkill {
+    fn descriptor(&self) -> SkillDescriptor {
+        SkillDescriptor {
+            name: "db.wal_fill".into(),
+            description: "Generate write volume to grow the WAL / redo log and test disk-pressure alerting".into(),
+            target: TargetDomain::Database,
+            reversible: true,
+        }
+    }
+
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "target_mb": { "type": "integer", "default": 256, "description": "Log volume to generate in MB" },
+                "batch_rows": { "type": "integer", "default": 1000 },
+                "hold_with_slot": {
+                    "type": "boolean",
+                    "default": false,
+                    "description": "PostgreSQL only: pin WAL with a replication slot until rollback"
+                }
+            }
+        })
+    }
+
+    fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
+        let params: WalFillParams = parse_params("db.wal_fill", params)?;
+        if params.batch_rows == 0 {
+            return Err(ChaosError::Config("db.wal_fill batch_rows must be greater than 0".into()));
+        }
+        if params.hold_with_slot && self.db_type != DbType::Postgres {
+            return Err(ChaosError::Config(
+                "db.wal_fill hold_with_slot is only supported on PostgreSQL".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool")))?;
+
+        let params = ctx.typed_params::<WalFillParams>()?;
+
+        let (create_table, insert_batch) = match self.db_type {
+            DbType::Postgres => (
+                format!("CREATE TABLE IF NOT EXISTS {FILL_TABLE} (id bigserial PRIMARY KEY, payload text)"),
+                format!(
+                    "INSERT INTO {FILL_TABLE} (payload) \
+                     SELECT repeat(md5(random()::text), 32) FROM generate_series(1, {})",
+                    params.batch_rows
+                ),
+            ),
+            DbType::Mysql => (
+                format!(
+                    "CREATE TABLE IF NOT EXISTS {FILL_TABLE} \
+                     (id BIGINT AUTO_INCREMENT PRIMARY KEY, payload TEXT)"
+                ),
+                format!(
+                    "INSERT INTO {FILL_TABLE} (payload) \
+                     WITH RECURSIVE seq (n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM seq WHERE n < {}) \
+                     SELECT REPEAT(MD5(RAND()), 32) FROM seq",
+                    params.batch_rows.min(MYSQL_MAX_BATCH_ROWS)
+                ),
+            ),
+            other => {
+                return Err(ChaosError::Config(format!(
+                    "db.wal_fill is not supported for {other:?}"
+                )));
+            }
+        };
+
+        if ctx.dry_run {
+            if params.hold_with_slot {
+                ctx.record_planned(format!("Create replication slot {SLOT_NAME} to pin WAL"));
+            }
+            ctx.record_planned(format!(
+                "Create {FILL_TABLE} and insert into it until {} MB of log is written",
+                params.target_mb
+            ));
+            return Ok(RollbackHandle::dry_run("db.wal_fill"));
+        }
+
+        let slot = if params.hold_with_slot {
+            sqlx::query(&format!(
+                "SELECT pg_create_physical_replication_slot('{SLOT_NAME}', true)"
+            ))
+            .execute(pool)
+            .await
+            .map_err(|e| {
+                ChaosError::Other(anyhow::anyhow!("Failed to create replication slot: {e}"))
+            })?;
+            tracing::warn!(slot = SLOT_NAME, "Created replication slot to pin WAL");
+            Some(SLOT_NAME.to_string())
+        } else {
+            None
+        };
+
+        if let Err(e) = sqlx::query(&create_table).execute(pool).await {
+            if let Some(ref slot) = slot {
+                let _ = drop_slot(pool, slot).await;
+            }
+            return Err(ChaosError::Other(anyhow::anyhow!(
+                "Failed to create {FILL_TABLE}: {e}"
+            )));
+        }
+
+        let start = match self.log_position(pool).await {
+            Ok(start) => start,
+            Err(e) => {
+                if let Some(ref slot) = slot {
+                    let _ = drop_slot(pool, slot).await;
+                }
+                let _ = sqlx::query(&format!("DROP TABLE IF EXISTS {FILL_TABLE}"))
+                    .execute(pool)
+                    .await;
+                return Err(e);
+            }
+        };
+        let target_bytes = params.target_mb * 1024 * 1024;
+        tracing::info!(
+            db_type = ?self.db_type,
+            target_mb = params.target_mb,
+            "Starting WAL fill"
+        );
+
+        let mut generated = 0u64;
+        while generated < target_bytes {
+            if let Err(e) = sqlx::query(&insert_batch).execute(pool).await {
+                ctx.warn(format!("WAL fill insert failed, stopping early: {e}"));
+                break;
+            }
+            match self.log_position(pool).await {
+                Ok(position) => generated = position.saturating_sub(start),
+                Err(e) => {
+                    ctx.warn(format!("Lost track of the log position, stopping early: {e}"));
+                    break;
+                }
+            }
+        }
+
+        ctx.record_action(format!(
+            "Generated {} MB of {} (log position {} -> {})",
+            generated / (1024 * 1024),
+            if self.db_type == DbType::Postgres { "WAL" } else { "redo log" },
+            self.format_position(start),
+            self.format_position(start + generated)
+        ));
+
+        let undo = WalFillUndoState {
+            table: FILL_TABLE.to_string(),
+            slot,
+            generated_bytes: generated,
+        };
+        let undo_state = serde_yaml::to_value(&undo)
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
+
+        Ok(RollbackHandle::new("db.wal_fill", undo_state))
+    }
+
+    async fn rollback(&self, ctx: &SkillContext, handle: &RollbackHandle) -> ChaosResult<()> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool")))?;
+
+        let undo: WalFillUndoState = serde_yaml::from_value(handle.undo_state.clone())
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
+
+        // Release the slot first so the server can start recycling WAL.
+        if let Some(ref slot) = undo.slot {
+            match drop_slot(pool, slot).await {
+                Ok(()) => tracing::info!(slot = %slot, "Dropped replication slot"),
+                Err(e) => tracing::error!(slot = %slot, error = %e, "Failed to drop replication slot"),
+            }
+        }
+
+        match sqlx::query(&format!("DROP TABLE IF EXISTS {}", undo.table))
+            .execute(pool)
+            .await
+        {
+            Ok(_) => tracing::info!(
+                table = %undo.table,
+                generated_mb = undo.generated_bytes / (1024 * 1024),
+                "Dropped WAL fill table"
+            ),
+            Err(e) => tracing::error!(table = %undo.table, error = %e, "Failed to drop WAL fill table"),
+        }
+
+        Ok(())
+    }
+}
+
+impl WalFillSkill {
+    /// A position from `log_position` as the database shows it: an LSN
+    /// such as `0/16B3748` on Postgres, a byte count on MySQL.
+    fn format_position(&self, bytes: u64) -> String {
+        match self.db_type {
+            DbType::Postgres => format!("{:X}/{:X}", bytes >> 32, bytes & 0xFFFF_FFFF),
+            _ => bytes.to_string(),
+        }
+    }
+
+    /// Current log write position in bytes: the WAL LSN offset on PostgreSQL,
+    /// total redo bytes written on MySQL.
+    async fn log_position(&self, pool: &AnyPool) -> ChaosResult<u64> {
+        let bytes = match self.db_type {
+            DbType::Postgres => sqlx::query(
+                "SELECT pg_wal_lsn_diff(pg_current_wal_lsn(), '0/0')::bigint AS bytes",
+            )
+            .fetch_one(pool)
+            .await
+            .map(|row| row.try_get::<i64, _>("bytes").unwrap_or_default().max(0) as u64),
+            _ => sqlx::query("SHOW GLOBAL STATUS LIKE 'Innodb_os_log_written'")
+                .fetch_one(pool)
+                .await
+                .map(|row| {
+                    row.try_get::<String, _>(1)
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or_default()
+                }),
+        };
+        bytes.map_err(|e| ChaosError::Other(anyhow::anyhow!("Failed to read log position: {e}")))
+    }
+}
+
+async fn drop_slot(pool: &AnyPool, slot: &str) -> Result<(), sqlx::Error> {
+    sqlx::query(&format!("SELECT pg_drop_replication_slot('{slot}')"))
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
@@ -0,0 +1,211 @@
+use async_trait::async_trait;
+use chaos_core::error::{ChaosError, ChaosResult};
+use chaos_core::rollback::RollbackHandle;
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use serde::{Deserialize, Serialize};
+use sqlx::AnyPool;
+use sqlx::Row;
+
+/// PostgreSQL-specific: burn transaction IDs with many small committed
+/// transactions against a throwaway table, pushing `age(datfrozenxid)` toward
+/// the XID wraparound warning thresholds so monitoring and emergency-vacuum
+/// runbooks can be exercised.
+///
+/// Consumed XIDs cannot be given back: rollback drops the throwaway table, but
+/// the database age only drops after a (manual or auto) `VACUUM FREEZE`.
+pub struct XidConsumeSkill;
+
+/// XID age at which the database wraps around.
+const XID_WRAPAROUND_AGE: u64 = 1 << 31;
+
+/// Postgres starts warning 40M XIDs before wraparound (10M before 14).
+const XID_WARN_AGE: u64 = XID_WRAPAROUND_AGE - 40_000_000;
+
+/// Hard ceiling on the database XID age this skill will push to: 10M past
+/// the warning threshold, so the warnings fire, and still 27M short of the
+/// point where Postgres refuses writes (3M before wraparound).
+const MAX_XID_AGE_CAP: u64 = XID_WARN_AGE + 10_000_000;
+
+/// How many transactions to run between age checks.
+const AGE_CHECK_INTERVAL: u64 = 10_000;
+
+const BURN_TABLE: &str = "chaos_xid_burn";
+
+#[derive(Debug, Deserialize)]
+struct XidConsumeParams {
+    /// Number of transactions (and so XIDs) to consume. Default: 100000.
+    #[serde(default = "default_transactions")]
+    transactions: u64,
+    /// Stop early once the oldest database reaches this XID age.
+    /// Default: 1000000000, capped at 2117483648.
+    #[serde(default = "default_max_xid_age")]
+    max_xid_age: u64,
+}
+
+fn default_transactions() -> u64 {
+    100_000
+}
+
+fn default_max_xid_age() -> u64 {
+    1_000_000_000
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct XidConsumeUndoState {
+    table: String,
+    consumed: u64,
+    start_age: i64,
+    end_age: i64,
+}
+
+#[async_trait]
+impl Skill for XidConsumeSkill {
+    fn descriptor(&self) -> SkillDescriptor {
+        SkillDescriptor {
+            name: "db.xid_consume".into(),
+            description: "Consume transaction IDs to push PostgreSQL toward XID wraparound warnings (capped; needs VACUUM FREEZE to recover)".into(),
+            target: TargetDomain::Database,
+            reversible: false,
+        }
+    }
+
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "transactions": { "type": "integer", "default": 100000 },
+                "max_xid_age": {
+                    "type": "integer",
+                    "default": 1000000000,
+                    "maximum": MAX_XID_AGE_CAP,
+                    "description": "Stop once age(datfrozenxid) reaches this value"
+                }
+            }
+        })
+    }
+
+    fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
+        let params: XidConsumeParams = parse_params("db.xid_consume", params)?;
+        if params.max_xid_age > MAX_XID_AGE_CAP {
+            return Err(ChaosError::Config(format!(
+                "db.xid_consume max_xid_age {} exceeds the safety cap of {MAX_XID_AGE_CAP}",
+                params.max_xid_age
+            )));
+        }
+        Ok(())
+    }
+
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool")))?;
+
+        let params = ctx.typed_params::<XidConsumeParams>()?;
+        let max_age = params.max_xid_age.min(MAX_XID_AGE_CAP) as i64;
+
+        let start_age = database_xid_age(pool).await?;
+        if start_age >= max_age {
+            return Err(ChaosError::Other(anyhow::anyhow!(
+                "Database XID age {start_age} already at or above max_xid_age {max_age}, refusing to consume more"
+            )));
+        }
+
+        if ctx.dry_run {
+            ctx.record_planned(format!(
+                "Consume up to {} transaction IDs via {BURN_TABLE}, stopping at XID age {max_age} (now {start_age})",
+                params.transactions
+            ));
+            return Ok(RollbackHandle::dry_run("db.xid_consume"));
+        }
+
+        sqlx::query(&format!(
+            "CREATE UNLOGGED TABLE IF NOT EXISTS {BURN_TABLE} (id bigserial PRIMARY KEY)"
+        ))
+        .execute(pool)
+        .await
+        .map_err(|e| ChaosError::Other(anyhow::anyhow!("Failed to create {BURN_TABLE}: {e}")))?;
+
+        tracing::warn!(
+            transactions = params.transactions,
+            start_age,
+            max_age,
+            "Consuming transaction IDs"
+        );
+
+        // Each autocommitted INSERT is its own transaction and takes one XID.
+        let insert = format!("INSERT INTO {BURN_TABLE} DEFAULT VALUES");
+        let mut consumed = 0u64;
+        let mut end_age = start_age;
+        while consumed < params.transactions {
+            if let Err(e) = sqlx::query(&insert).execute(pool).await {
+                tracing::error!(consumed, error = %e, "XID burn insert failed, stopping");
+                break;
+            }
+            consumed += 1;
+
+            if consumed.is_multiple_of(AGE_CHECK_INTERVAL) {
+                // Keep the throwaway table small; the XIDs stay consumed.
+                let _ = sqlx::query(&format!("TRUNCATE {BURN_TABLE}")).execute(pool).await;
+                end_age = database_xid_age(pool).await?;
+                tracing::info!(consumed, age = end_age, "XID consumption progress");
+                if end_age >= max_age {
+                    tracing::warn!(age = end_age, max_age, "Reached max_xid_age, stopping");
+                    break;
+                }
+            }
+        }
+
+        if let Ok(age) = database_xid_age(pool).await {
+            end_age = age;
+        }
+        tracing::info!(consumed, start_age, end_age, "XID consumption finished");
+
+        let undo = XidConsumeUndoState {
+            table: BURN_TABLE.to_string(),
+            consumed,
+            start_age,
+            end_age,
+        };
+        let undo_state = serde_yaml::to_value(&undo)
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
+
+        Ok(RollbackHandle::new("db.xid_consume", undo_state))
+    }
+
+    async fn rollback(&self, ctx: &SkillContext, handle: &RollbackHandle) -> ChaosResult<()> {
+        let pool = ctx
+            .shared
+            .downcast_ref::<AnyPool>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool")))?;
+
+        let undo: XidConsumeUndoState = serde_yaml::from_value(handle.undo_state.clone())
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
+
+        match sqlx::query(&format!("DROP TABLE IF EXISTS {}", undo.table))
+            .execute(pool)
+            .await
+        {
+            Ok(_) => tracing::info!(table = %undo.table, "Dropped XID burn table"),
+            Err(e) => tracing::error!(table = %undo.table, error = %e, "Failed to drop XID burn table"),
+        }
+
+        tracing::warn!(
+            consumed = undo.consumed,
+            age = undo.end_age,
+            "Consumed XIDs are not reclaimed until VACUUM FREEZE runs"
+        );
+
+        Ok(())
+    }
+}
+
+/// Oldest `age(datfrozenxid)` across all databases, the figure wraparound
+/// warnings are based on.
+async fn database_xid_age(pool: &AnyPool) -> ChaosResult<i64> {
+    let row = sqlx::query("SELECT max(age(datfrozenxid))::bigint AS age FROM pg_database")
+        .fetch_one(pool)
+        .await
+        .map_err(|e| ChaosError::Other(anyhow::anyhow!("Failed to read XID age: {e}")))?;
+    Ok(row.try_get::<i64, _>("age").unwrap_or_default())
+}
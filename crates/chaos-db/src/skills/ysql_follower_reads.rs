@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use serde::{Deserialize, Serialize};
 use sqlx::AnyPool;
 use sqlx::Row;
@@ -46,9 +46,18 @@ impl Skill for YsqlFollowerReadsSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "enable": { "type": "boolean", "default": true },
+                "staleness": { "type": "string", "default": "30000ms" }
+            }
+        })
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: FollowerReadsParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid ysql.follower_reads params: {e}")))?;
+        let _: FollowerReadsParams = parse_params("ysql.follower_reads", params)?;
         Ok(())
     }
 
@@ -58,8 +67,7 @@ impl Skill for YsqlFollowerReadsSkill {
             .downcast_ref::<AnyPool>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected AnyPool")))?;
 
-        let params: FollowerReadsParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<FollowerReadsParams>()?;
 
         // Read current values
         let orig_follower = sqlx::query("SHOW yb_read_from_followers")
@@ -77,6 +85,15 @@ impl Skill for YsqlFollowerReadsSkill {
         // Apply new settings
         let enable_str = if params.enable { "on" } else { "off" };
 
+        if ctx.dry_run {
+            ctx.record_planned(format!(
+                "SET yb_read_from_followers = '{enable_str}' (currently {orig_follower}), \
+                 yb_follower_read_staleness_ms = '{}' (currently {orig_staleness})",
+                params.staleness
+            ));
+            return Ok(RollbackHandle::dry_run("ysql.follower_reads"));
+        }
+
         sqlx::query(&format!(
             "SET yb_read_from_followers = '{}'",
             enable_str
@@ -1,19 +1,36 @@
 use async_trait::async_trait;
-use k8s_openapi::api::core::v1::Pod;
-use kube::api::{Api, ListParams};
+use k8s_openapi::api::core::v1::{ConfigMap, Pod};
+use k8s_openapi::api::networking::v1::NetworkPolicy;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::api::{Api, DeleteParams, ListParams};
 use kube::Client;
 
 use chaos_core::agent::{Agent, AgentStatus};
-use chaos_core::discovery::{DiscoveredResource, K8sResource};
+use chaos_core::cleanup::{CleanupAction, CleanupItem, EXPERIMENT_ID_LABEL};
+use chaos_core::discovery::{DiscoveredResource, Discovery, K8sResource};
 use chaos_core::error::ChaosResult;
 use chaos_core::skill::{Skill, SkillContext, TargetDomain};
+use chaos_core::skill_registry::add_registered_skills;
+use chaos_core::target_config::validate_target_config;
 
 use crate::client::create_client;
 use crate::config::K8sTargetConfig;
+use crate::selector::validate_label_selector;
+use crate::skills::api_pressure::ApiPressureSkill;
+use crate::skills::config_corrupt::ConfigMapCorruptSkill;
+use crate::skills::dns_failure::DnsFailureSkill;
 use crate::skills::network_chaos::NetworkChaosSkill;
+use crate::skills::network_latency::NetworkLatencySkill;
 use crate::skills::node_drain::NodeDrainSkill;
+use crate::skills::pod_evict::PodEvictSkill;
 use crate::skills::pod_kill::PodKillSkill;
 use crate::skills::resource_stress::ResourceStressSkill;
+use crate::skills::scale_deployment::ScaleDeploymentSkill;
+
+/// Label every chaos-created k8s object carries.
+const CHAOS_MANAGED_SELECTOR: &str = "app.kubernetes.io/managed-by=chaos-agents";
+
+const AGENT_NAME: &str = "kubernetes-chaos-agent";
 
 pub struct K8sAgent {
     config: K8sTargetConfig,
@@ -24,12 +41,19 @@ pub struct K8sAgent {
 
 impl K8sAgent {
     pub fn new(config: K8sTargetConfig) -> Self {
-        let skills: Vec<Box<dyn Skill>> = vec![
+        let mut skills: Vec<Box<dyn Skill>> = vec![
             Box::new(PodKillSkill),
+            Box::new(PodEvictSkill),
             Box::new(NodeDrainSkill),
             Box::new(NetworkChaosSkill),
+            Box::new(NetworkLatencySkill),
             Box::new(ResourceStressSkill),
+            Box::new(ScaleDeploymentSkill),
+            Box::new(ConfigMapCorruptSkill),
+            Box::new(DnsFailureSkill),
+            Box::new(ApiPressureSkill),
         ];
+        add_registered_skills(&mut skills, TargetDomain::Kubernetes, AGENT_NAME);
         Self {
             config,
             client: None,
@@ -39,8 +63,13 @@ impl K8sAgent {
     }
 
     pub fn from_yaml(value: &serde_yaml::Value) -> ChaosResult<Self> {
+        validate_target_config(TargetDomain::Kubernetes, value)
+            .map_err(chaos_core::error::ChaosError::Config)?;
         let config: K8sTargetConfig = serde_yaml::from_value(value.clone())
             .map_err(|e| chaos_core::error::ChaosError::Config(format!("Invalid K8s config: {e}")))?;
+        if let Some(ref selector) = config.label_selector {
+            validate_label_selector(selector).map_err(chaos_core::error::ChaosError::Config)?;
+        }
         Ok(Self::new(config))
     }
 }
@@ -52,7 +81,7 @@ impl Agent for K8sAgent {
     }
 
     fn name(&self) -> &str {
-        "kubernetes-chaos-agent"
+        AGENT_NAME
     }
 
     fn status(&self) -> AgentStatus {
@@ -70,7 +99,7 @@ impl Agent for K8sAgent {
         Ok(())
     }
 
-    async fn discover(&mut self) -> ChaosResult<Vec<Box<dyn DiscoveredResource>>> {
+    async fn discover(&mut self) -> ChaosResult<Discovery> {
         self.status = AgentStatus::Discovering;
         let client = self
             .client
@@ -107,7 +136,7 @@ impl Agent for K8sAgent {
         tracing::info!(pods = resources.len(), "Kubernetes discovery complete");
         self.status = AgentStatus::Ready;
 
-        Ok(resources)
+        Ok(Discovery::complete(resources))
     }
 
     fn skills(&self) -> Vec<&dyn Skill> {
@@ -131,9 +160,131 @@ impl Agent for K8sAgent {
         Ok(SkillContext {
             shared: Box::new(client),
             params: serde_yaml::Value::Null,
+            parsed_params: Default::default(),
+            output: Default::default(),
+            experiment_id: uuid::Uuid::nil(),
+            experiment_name: String::new(),
+            skill_name: String::new(),
+            dry_run: false,
+            limits: Default::default(),
         })
     }
 
+    async fn health_check(&self) -> ChaosResult<()> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| chaos_core::error::ChaosError::Connection(anyhow::anyhow!("Not initialized")))?;
+
+        let pods: Api<Pod> = Api::namespaced(client.clone(), &self.config.namespace);
+        let mut lp = ListParams::default();
+        if let Some(ref selector) = self.config.label_selector {
+            lp = lp.labels(selector);
+        }
+        let pod_list = pods.list(&lp).await.map_err(|e| {
+            chaos_core::error::ChaosError::Unhealthy(format!("cannot list pods: {e}"))
+        })?;
+
+        let failing: Vec<String> = pod_list
+            .items
+            .iter()
+            .filter_map(|pod| Some(format!("{} ({})", pod.metadata.name.as_ref()?, failing_reason(pod)?)))
+            .collect();
+        if failing.is_empty() {
+            Ok(())
+        } else {
+            Err(chaos_core::error::ChaosError::Unhealthy(format!(
+                "{} pod(s) already failing in {}: {}",
+                failing.len(),
+                self.config.namespace,
+                failing.join(", ")
+            )))
+        }
+    }
+
+    async fn cleanup(&mut self, dry_run: bool) -> ChaosResult<Vec<CleanupItem>> {
+        let client = self
+            .client
+            .as_ref()
+            .ok_or_else(|| chaos_core::error::ChaosError::Connection(anyhow::anyhow!("Not initialized")))?;
+
+        // Chaos objects may live outside the configured namespace (skills take
+        // their own `namespace` param), so scan cluster-wide.
+        let lp = ListParams::default().labels(CHAOS_MANAGED_SELECTOR);
+        let mut items = Vec::new();
+
+        let policies: Api<NetworkPolicy> = Api::all(client.clone());
+        let policy_list = policies.list(&lp).await.map_err(|e| {
+            chaos_core::error::ChaosError::Discovery(format!("NetworkPolicy list failed: {e}"))
+        })?;
+        for policy in policy_list.items {
+            let (namespace, name, detail) =
+                describe_leftover(&policy.metadata, "deny-all policy from k8s.network_chaos");
+            let action = if dry_run {
+                CleanupAction::WouldRemove
+            } else {
+                let api: Api<NetworkPolicy> = Api::namespaced(client.clone(), &namespace);
+                CleanupAction::from_result(
+                    api.delete(&name, &DeleteParams::default()).await.map(|_| ()),
+                )
+            };
+            items.push(CleanupItem::new(
+                "NetworkPolicy",
+                format!("{namespace}/{name}"),
+                detail,
+                action,
+            ));
+        }
+
+        let pods: Api<Pod> = Api::all(client.clone());
+        let pod_list = pods.list(&lp).await.map_err(|e| {
+            chaos_core::error::ChaosError::Discovery(format!("Pod list failed: {e}"))
+        })?;
+        for pod in pod_list.items {
+            let (namespace, name, detail) =
+                describe_leftover(&pod.metadata, "stress pod from k8s.resource_stress");
+            let action = if dry_run {
+                CleanupAction::WouldRemove
+            } else {
+                let api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+                CleanupAction::from_result(
+                    api.delete(&name, &DeleteParams::default()).await.map(|_| ()),
+                )
+            };
+            items.push(CleanupItem::new(
+                "Pod",
+                format!("{namespace}/{name}"),
+                detail,
+                action,
+            ));
+        }
+
+        let config_maps: Api<ConfigMap> = Api::all(client.clone());
+        let config_map_list = config_maps.list(&lp).await.map_err(|e| {
+            chaos_core::error::ChaosError::Discovery(format!("ConfigMap list failed: {e}"))
+        })?;
+        for config_map in config_map_list.items {
+            let (namespace, name, detail) =
+                describe_leftover(&config_map.metadata, "churn object from k8s.api_pressure");
+            let action = if dry_run {
+                CleanupAction::WouldRemove
+            } else {
+                let api: Api<ConfigMap> = Api::namespaced(client.clone(), &namespace);
+                CleanupAction::from_result(
+                    api.delete(&name, &DeleteParams::default()).await.map(|_| ()),
+                )
+            };
+            items.push(CleanupItem::new(
+                "ConfigMap",
+                format!("{namespace}/{name}"),
+                detail,
+                action,
+            ));
+        }
+
+        Ok(items)
+    }
+
     async fn shutdown(&mut self) -> ChaosResult<()> {
         self.client = None;
         self.status = AgentStatus::Idle;
@@ -141,3 +292,46 @@ impl Agent for K8sAgent {
         Ok(())
     }
 }
+
+/// Namespace, name and a detail line naming the experiment that created it.
+fn describe_leftover(meta: &ObjectMeta, what: &str) -> (String, String, String) {
+    let experiment = meta
+        .labels
+        .as_ref()
+        .and_then(|l| l.get(EXPERIMENT_ID_LABEL))
+        .map(String::as_str)
+        .unwrap_or("unknown");
+    (
+        meta.namespace.clone().unwrap_or_default(),
+        meta.name.clone().unwrap_or_default(),
+        format!("{what} (experiment {experiment})"),
+    )
+}
+
+/// Why `pod` counts as already broken, if it does. Chaos-created pods are ignored.
+fn failing_reason(pod: &Pod) -> Option<String> {
+    let managed = pod
+        .metadata
+        .labels
+        .as_ref()
+        .is_some_and(|l| l.get("app.kubernetes.io/managed-by").is_some_and(|v| v == "chaos-agents"));
+    if managed {
+        return None;
+    }
+
+    let status = pod.status.as_ref()?;
+    if status.phase.as_deref() == Some("Failed") {
+        return Some("Failed".into());
+    }
+    status
+        .container_statuses
+        .iter()
+        .flatten()
+        .filter_map(|c| c.state.as_ref()?.waiting.as_ref()?.reason.clone())
+        .find(|reason| {
+            matches!(
+                reason.as_str(),
+                "CrashLoopBackOff" | "ImagePullBackOff" | "ErrImagePull" | "CreateContainerConfigError"
+            )
+        })
+}
@@ -12,6 +12,20 @@ pub struct K8sTargetConfig {
     pub label_selector: Option<String>,
 }
 
+impl K8sTargetConfig {
+    /// JSON Schema for this target config.
+    pub fn schema() -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "kubeconfig": { "type": "string", "description": "Path to kubeconfig (default: in-cluster or ~/.kube/config)" },
+                "namespace": { "type": "string", "default": "default" },
+                "label_selector": { "type": "string", "description": "e.g. app=web" }
+            }
+        })
+    }
+}
+
 fn default_namespace() -> String {
     "default".to_string()
 }
@@ -0,0 +1,144 @@
+//! Label selector syntax checks, so typos fail at validation time instead of
+//! surfacing as an empty pod list mid-experiment, and matching of structured
+//! selectors such as a PodDisruptionBudget's.
+
+use std::collections::BTreeMap;
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+
+/// Validate a Kubernetes label selector string such as
+/// `app=web,tier!=db,env in (prod, staging),!canary`.
+pub fn validate_label_selector(selector: &str) -> Result<(), String> {
+    if selector.trim().is_empty() {
+        return Err("label selector is empty".into());
+    }
+    for requirement in split_requirements(selector)? {
+        validate_requirement(requirement.trim())
+            .map_err(|reason| format!("invalid label selector '{selector}': {reason}"))?;
+    }
+    Ok(())
+}
+
+/// Split on commas that are not inside a `( ... )` value set.
+fn split_requirements(selector: &str) -> Result<Vec<&str>, String> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in selector.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&selector[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        if !(0..=1).contains(&depth) {
+            return Err(format!("invalid label selector '{selector}': unbalanced parentheses"));
+        }
+    }
+    if depth != 0 {
+        return Err(format!("invalid label selector '{selector}': unbalanced parentheses"));
+    }
+    parts.push(&selector[start..]);
+    Ok(parts)
+}
+
+fn validate_requirement(requirement: &str) -> Result<(), String> {
+    if requirement.is_empty() {
+        return Err("empty requirement".into());
+    }
+
+    if let Some(key) = requirement.strip_prefix('!') {
+        return validate_key(key.trim());
+    }
+
+    for op in ["==", "!=", "="] {
+        if let Some((key, value)) = requirement.split_once(op) {
+            validate_key(key.trim())?;
+            return validate_value(value.trim());
+        }
+    }
+
+    for op in [" notin ", " in "] {
+        if let Some((key, values)) = requirement.split_once(op) {
+            validate_key(key.trim())?;
+            let values = values.trim();
+            let inner = values
+                .strip_prefix('(')
+                .and_then(|v| v.strip_suffix(')'))
+                .ok_or_else(|| format!("expected '(a, b)' after '{}'", op.trim()))?;
+            return inner.split(',').try_for_each(|v| validate_value(v.trim()));
+        }
+    }
+
+    // Bare key: existence check
+    validate_key(requirement)
+}
+
+/// Keys are an optional DNS-subdomain prefix plus a name of at most 63
+/// alphanumerics, '-', '_' or '.', starting and ending alphanumeric.
+fn validate_key(key: &str) -> Result<(), String> {
+    let name = match key.rsplit_once('/') {
+        Some((prefix, name)) => {
+            if prefix.is_empty()
+                || prefix.len() > 253
+                || !prefix
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '.')
+            {
+                return Err(format!("invalid key prefix '{prefix}'"));
+            }
+            name
+        }
+        None => key,
+    };
+    if name.is_empty() {
+        return Err("missing label key".into());
+    }
+    if !is_label_name(name) {
+        return Err(format!("invalid label key '{key}'"));
+    }
+    Ok(())
+}
+
+fn validate_value(value: &str) -> Result<(), String> {
+    if value.is_empty() || is_label_name(value) {
+        Ok(())
+    } else {
+        Err(format!("invalid label value '{value}'"))
+    }
+}
+
+fn is_label_name(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    s.len() <= 63
+        && bytes.first().is_some_and(u8::is_ascii_alphanumeric)
+        && bytes.last().is_some_and(u8::is_ascii_alphanumeric)
+        && bytes
+            .iter()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.'))
+}
+
+/// Whether `labels` satisfy `selector`. An empty selector matches everything.
+pub fn selector_matches(selector: &LabelSelector, labels: &BTreeMap<String, String>) -> bool {
+    let labels_match = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .all(|(key, value)| labels.get(key) == Some(value));
+    let expressions_match = selector.match_expressions.iter().flatten().all(|req| {
+        let values = req.values.as_deref().unwrap_or_default();
+        match (req.operator.as_str(), labels.get(&req.key)) {
+            ("In", Some(value)) => values.contains(value),
+            ("In", None) => false,
+            ("NotIn", Some(value)) => !values.contains(value),
+            ("NotIn", None) => true,
+            ("Exists", label) => label.is_some(),
+            ("DoesNotExist", label) => label.is_none(),
+            _ => false,
+        }
+    });
+    labels_match && expressions_match
+}
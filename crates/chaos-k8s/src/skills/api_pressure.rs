@@ -0,0 +1,483 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use chaos_core::cleanup::EXPERIMENT_ID_LABEL;
+use chaos_core::duration::parse_duration;
+use chaos_core::error::{ChaosError, ChaosResult};
+use chaos_core::rollback::RollbackHandle;
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use futures::StreamExt;
+use k8s_openapi::api::core::v1::{ConfigMap, Endpoints, Pod, Service};
+use kube::api::{Api, DeleteParams, ListParams, PostParams, WatchParams};
+use kube::Client;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
+use tokio::task::AbortHandle;
+
+/// Put the API server (and etcd behind it) under controlled load, either with
+/// a steady stream of list calls plus open watches, or by churning lightweight
+/// ConfigMaps. Load runs in the background until rollback stops it and
+/// deletes every object it created.
+pub struct ApiPressureSkill;
+
+#[derive(Debug, Deserialize)]
+struct ApiPressureParams {
+    #[serde(default)]
+    mode: PressureMode,
+    #[serde(default = "default_namespace")]
+    namespace: String,
+    /// List across all namespaces instead of just `namespace` (list mode).
+    #[serde(default)]
+    all_namespaces: bool,
+    /// Request rate across all workers.
+    #[serde(default = "default_requests_per_second")]
+    requests_per_second: u32,
+    /// Requests allowed in flight at once; ticks beyond it are skipped.
+    #[serde(default = "default_concurrency")]
+    concurrency: u32,
+    /// Long-lived pod watches to hold open (list mode).
+    #[serde(default)]
+    watches: u32,
+    /// ConfigMaps kept alive at once; each new one past this deletes the oldest
+    /// (objects mode).
+    #[serde(default = "default_objects")]
+    objects: u32,
+    /// Size of each ConfigMap's payload (objects mode).
+    #[serde(default = "default_payload_bytes")]
+    payload_bytes: usize,
+    /// Load stops by itself after this long even if rollback never runs.
+    #[serde(default = "default_max_duration")]
+    max_duration: String,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum PressureMode {
+    /// List pods, ConfigMaps, services and endpoints in rotation.
+    #[default]
+    List,
+    /// Create and delete ConfigMaps.
+    Objects,
+}
+
+fn default_namespace() -> String {
+    "default".to_string()
+}
+fn default_requests_per_second() -> u32 {
+    20
+}
+fn default_concurrency() -> u32 {
+    10
+}
+fn default_objects() -> u32 {
+    100
+}
+fn default_payload_bytes() -> usize {
+    1024
+}
+fn default_max_duration() -> String {
+    "1h".to_string()
+}
+
+/// ConfigMaps cannot exceed 1MiB; leave room for metadata.
+const MAX_PAYLOAD_BYTES: usize = 900 * 1024;
+
+/// Far beyond what one client can push; keeps the tick period above zero.
+const MAX_REQUESTS_PER_SECOND: u32 = 10_000;
+const PRESSURE_TYPE_LABEL: &str = "chaos-agents/type";
+const PRESSURE_TYPE: &str = "api-pressure";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ApiPressureUndoState {
+    /// Key into [`RUNNING`].
+    run_id: String,
+    mode: PressureMode,
+    namespace: String,
+    experiment_id: String,
+}
+
+#[derive(Default)]
+struct LoadStats {
+    requests: AtomicU64,
+    errors: AtomicU64,
+    skipped: AtomicU64,
+    latency_ms: AtomicU64,
+}
+
+impl LoadStats {
+    fn record<T, E>(&self, started: Instant, result: &Result<T, E>) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        self.latency_ms
+            .fetch_add(started.elapsed().as_millis() as u64, Ordering::Relaxed);
+        if result.is_err() {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn summary(&self) -> String {
+        let requests = self.requests.load(Ordering::Relaxed);
+        let mean = self
+            .latency_ms
+            .load(Ordering::Relaxed)
+            .checked_div(requests)
+            .unwrap_or(0);
+        format!(
+            "{requests} API requests, {} errors, {} skipped at the concurrency cap, mean latency {mean}ms",
+            self.errors.load(Ordering::Relaxed),
+            self.skipped.load(Ordering::Relaxed),
+        )
+    }
+}
+
+struct RunningLoad {
+    tasks: Vec<AbortHandle>,
+    stats: Arc<LoadStats>,
+    in_flight: Arc<Semaphore>,
+    concurrency: u32,
+}
+
+/// Background load started by `execute`, until rollback stops it.
+static RUNNING: Mutex<BTreeMap<String, RunningLoad>> = Mutex::new(BTreeMap::new());
+
+#[async_trait]
+impl Skill for ApiPressureSkill {
+    fn descriptor(&self) -> SkillDescriptor {
+        SkillDescriptor {
+            name: "k8s.api_pressure".into(),
+            description: "Load the API server with list/watch calls or ConfigMap churn, rollback stops the load and deletes created objects".into(),
+            target: TargetDomain::Kubernetes,
+            reversible: true,
+        }
+    }
+
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "mode": { "type": "string", "enum": ["list", "objects"], "default": "list" },
+                "namespace": { "type": "string", "default": "default" },
+                "all_namespaces": { "type": "boolean", "default": false, "description": "List cluster-wide (list mode)" },
+                "requests_per_second": { "type": "integer", "default": 20, "maximum": MAX_REQUESTS_PER_SECOND },
+                "concurrency": { "type": "integer", "default": 10, "description": "Max requests in flight" },
+                "watches": { "type": "integer", "default": 0, "description": "Pod watches to hold open (list mode)" },
+                "objects": { "type": "integer", "default": 100, "description": "ConfigMaps alive at once (objects mode)" },
+                "payload_bytes": { "type": "integer", "default": 1024, "description": "Per-ConfigMap payload (objects mode)" },
+                "max_duration": { "type": "string", "default": "1h", "description": "Safety cap; load stops by itself after this" }
+            }
+        })
+    }
+
+    fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
+        let params: ApiPressureParams = parse_params("api_pressure", params)?;
+        parse_duration(&params.max_duration)?;
+        if params.requests_per_second == 0 || params.concurrency == 0 {
+            return Err(ChaosError::Config(
+                "api_pressure requests_per_second and concurrency must be at least 1".into(),
+            ));
+        }
+        if params.requests_per_second > MAX_REQUESTS_PER_SECOND {
+            return Err(ChaosError::Config(format!(
+                "api_pressure requests_per_second must be at most {MAX_REQUESTS_PER_SECOND}"
+            )));
+        }
+        if params.mode == PressureMode::Objects && params.objects == 0 {
+            return Err(ChaosError::Config("api_pressure objects must be at least 1".into()));
+        }
+        if params.payload_bytes > MAX_PAYLOAD_BYTES {
+            return Err(ChaosError::Config(format!(
+                "api_pressure payload_bytes must be at most {MAX_PAYLOAD_BYTES}"
+            )));
+        }
+        Ok(())
+    }
+
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<ApiPressureParams>()?;
+        let max_duration = parse_duration(&params.max_duration)?;
+
+        let load = match params.mode {
+            PressureMode::List => format!(
+                "list load at {}/s on {}{}",
+                params.requests_per_second,
+                if params.all_namespaces { "all namespaces" } else { params.namespace.as_str() },
+                if params.watches > 0 { format!(" with {} open watches", params.watches) } else { String::new() },
+            ),
+            PressureMode::Objects => format!(
+                "ConfigMap churn at {}/s in {}, up to {} objects of {} bytes",
+                params.requests_per_second, params.namespace, params.objects, params.payload_bytes
+            ),
+        };
+        if ctx.dry_run {
+            ctx.record_planned(format!("Start {load} for up to {}", params.max_duration));
+            return Ok(RollbackHandle::dry_run("k8s.api_pressure"));
+        }
+
+        let run_id = uuid::Uuid::new_v4().as_simple().to_string();
+        let stats = Arc::new(LoadStats::default());
+        let mut tasks = Vec::new();
+
+        let in_flight = Arc::new(Semaphore::new(params.concurrency as usize));
+        let deadline = tokio::time::Instant::now() + max_duration;
+        let driver = Driver {
+            client: client.clone(),
+            namespace: params.namespace.clone(),
+            all_namespaces: params.all_namespaces,
+            stats: stats.clone(),
+            in_flight: in_flight.clone(),
+            deadline,
+        };
+        let period = Duration::from_secs(1) / params.requests_per_second;
+        tasks.push(match params.mode {
+            PressureMode::List => tokio::spawn(driver.list_load(period)).abort_handle(),
+            PressureMode::Objects => {
+                let template = ObjectTemplate {
+                    prefix: format!("chaos-api-pressure-{}", &run_id[..8]),
+                    experiment_id: ctx.experiment_id.to_string(),
+                    payload: "x".repeat(params.payload_bytes),
+                    keep: params.objects as u64,
+                };
+                tokio::spawn(driver.object_churn(period, template)).abort_handle()
+            }
+        });
+        if params.mode == PressureMode::List {
+            for _ in 0..params.watches {
+                let namespace = (!params.all_namespaces).then(|| params.namespace.clone());
+                let watch = hold_watch(client.clone(), namespace);
+                tasks.push(tokio::spawn(tokio::time::timeout_at(deadline, watch)).abort_handle());
+            }
+        }
+
+        RUNNING
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(
+                run_id.clone(),
+                RunningLoad {
+                    tasks,
+                    stats,
+                    in_flight,
+                    concurrency: params.concurrency,
+                },
+            );
+
+        ctx.record_action(format!("Started {load}"));
+
+        let undo = ApiPressureUndoState {
+            run_id,
+            mode: params.mode,
+            namespace: params.namespace.clone(),
+            experiment_id: ctx.experiment_id.to_string(),
+        };
+        let undo_state = serde_yaml::to_value(&undo)
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
+
+        Ok(RollbackHandle::new("k8s.api_pressure", undo_state))
+    }
+
+    async fn rollback(&self, ctx: &SkillContext, handle: &RollbackHandle) -> ChaosResult<()> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let undo: ApiPressureUndoState = serde_yaml::from_value(handle.undo_state.clone())
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
+
+        let running = RUNNING
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&undo.run_id);
+        match running {
+            Some(load) => {
+                load.tasks.iter().for_each(AbortHandle::abort);
+                // Let requests already in flight land, so none create an object
+                // after the cleanup below.
+                let drained = tokio::time::timeout(
+                    Duration::from_secs(30),
+                    load.in_flight.acquire_many(load.concurrency),
+                )
+                .await;
+                if drained.is_err() {
+                    ctx.warn("api_pressure requests still in flight after 30s; some objects may be left");
+                }
+                ctx.record_action(format!("Stopped API load: {}", load.stats.summary()));
+            }
+            // A different process started it; its load died with it.
+            None => tracing::info!(run_id = %undo.run_id, "API load not running in this process"),
+        }
+
+        if undo.mode == PressureMode::Objects {
+            let api: Api<ConfigMap> = Api::namespaced(client.clone(), &undo.namespace);
+            let lp = ListParams::default().labels(&format!(
+                "{PRESSURE_TYPE_LABEL}={PRESSURE_TYPE},{EXPERIMENT_ID_LABEL}={}",
+                undo.experiment_id
+            ));
+            match api.delete_collection(&DeleteParams::default(), &lp).await {
+                Ok(_) => ctx.record_action(format!(
+                    "Deleted api_pressure ConfigMaps in {}",
+                    undo.namespace
+                )),
+                Err(e) => ctx.warn(format!(
+                    "Failed to delete api_pressure ConfigMaps in {}: {e}",
+                    undo.namespace
+                )),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Issues one request per tick, as long as a concurrency permit is free.
+struct Driver {
+    client: Client,
+    namespace: String,
+    all_namespaces: bool,
+    stats: Arc<LoadStats>,
+    in_flight: Arc<Semaphore>,
+    deadline: tokio::time::Instant,
+}
+
+impl Driver {
+    fn api<K>(&self) -> Api<K>
+    where
+        K: kube::Resource<Scope = k8s_openapi::NamespaceResourceScope>,
+        K::DynamicType: Default,
+    {
+        if self.all_namespaces {
+            Api::all(self.client.clone())
+        } else {
+            Api::namespaced(self.client.clone(), &self.namespace)
+        }
+    }
+
+    /// Wait for the next tick and a free permit. `None` once past the deadline.
+    async fn next_slot(&self, ticker: &mut tokio::time::Interval) -> Option<tokio::sync::OwnedSemaphorePermit> {
+        loop {
+            ticker.tick().await;
+            if tokio::time::Instant::now() >= self.deadline {
+                tracing::info!("api_pressure reached max_duration, stopping load");
+                return None;
+            }
+            match self.in_flight.clone().try_acquire_owned() {
+                Ok(permit) => return Some(permit),
+                Err(_) => {
+                    self.stats.skipped.fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    async fn list_load(self, period: Duration) {
+        let mut ticker = tokio::time::interval(period);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let (pods, config_maps, services, endpoints) =
+            (self.api::<Pod>(), self.api::<ConfigMap>(), self.api::<Service>(), self.api::<Endpoints>());
+        let mut seq: u64 = 0;
+        while let Some(permit) = self.next_slot(&mut ticker).await {
+            let stats = self.stats.clone();
+            let lp = ListParams::default();
+            let (pods, config_maps, services, endpoints) =
+                (pods.clone(), config_maps.clone(), services.clone(), endpoints.clone());
+            let kind = seq % 4;
+            seq += 1;
+            tokio::spawn(async move {
+                let started = Instant::now();
+                let result = match kind {
+                    0 => pods.list(&lp).await.map(|_| ()),
+                    1 => config_maps.list(&lp).await.map(|_| ()),
+                    2 => services.list(&lp).await.map(|_| ()),
+                    _ => endpoints.list(&lp).await.map(|_| ()),
+                };
+                stats.record(started, &result);
+                drop(permit);
+            });
+        }
+    }
+
+    async fn object_churn(self, period: Duration, template: ObjectTemplate) {
+        let mut ticker = tokio::time::interval(period);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        let api: Api<ConfigMap> = Api::namespaced(self.client.clone(), &self.namespace);
+        let mut seq: u64 = 0;
+        while let Some(permit) = self.next_slot(&mut ticker).await {
+            let stats = self.stats.clone();
+            let api = api.clone();
+            let create = template.build(seq);
+            let evict = seq.checked_sub(template.keep).map(|old| template.name(old));
+            seq += 1;
+            tokio::spawn(async move {
+                if let Ok(cm) = create {
+                    let started = Instant::now();
+                    let result = api.create(&PostParams::default(), &cm).await;
+                    stats.record(started, &result);
+                }
+                if let Some(name) = evict {
+                    let started = Instant::now();
+                    let result = api.delete(&name, &DeleteParams::default()).await;
+                    stats.record(started, &result);
+                }
+                drop(permit);
+            });
+        }
+    }
+}
+
+struct ObjectTemplate {
+    prefix: String,
+    experiment_id: String,
+    payload: String,
+    /// How many objects stay alive before the oldest is deleted.
+    keep: u64,
+}
+
+impl ObjectTemplate {
+    fn name(&self, seq: u64) -> String {
+        format!("{}-{seq}", self.prefix)
+    }
+
+    fn build(&self, seq: u64) -> serde_json::Result<ConfigMap> {
+        serde_json::from_value(serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "ConfigMap",
+            "metadata": {
+                "name": self.name(seq),
+                "labels": {
+                    "app.kubernetes.io/managed-by": "chaos-agents",
+                    EXPERIMENT_ID_LABEL: self.experiment_id,
+                    PRESSURE_TYPE_LABEL: PRESSURE_TYPE,
+                }
+            },
+            "data": { "payload": self.payload }
+        }))
+    }
+}
+
+/// Keep a pod watch open on `namespace`, or cluster-wide, reopening it
+/// whenever the server closes it.
+async fn hold_watch(client: Client, namespace: Option<String>) {
+    let pods: Api<Pod> = match namespace {
+        Some(namespace) => Api::namespaced(client, &namespace),
+        None => Api::all(client),
+    };
+    loop {
+        match pods.watch(&WatchParams::default(), "0").await {
+            Ok(stream) => {
+                let mut stream = std::pin::pin!(stream);
+                while stream.next().await.is_some() {}
+            }
+            Err(e) => {
+                tracing::debug!(error = %e, "api_pressure watch failed, retrying");
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        }
+    }
+}
@@ -0,0 +1,310 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use chaos_core::error::{ChaosError, ChaosResult};
+use chaos_core::rollback::RollbackHandle;
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::core::v1::{ConfigMap, Secret};
+use k8s_openapi::ByteString;
+use kube::api::{Api, ListParams, Patch, PatchParams, PostParams};
+use kube::Client;
+use serde::{Deserialize, Serialize};
+
+/// Overwrite a key in a ConfigMap or Secret with a bad value to test how
+/// workloads react to invalid config on reload or restart. The full original
+/// `data` is snapshotted and written back on rollback.
+pub struct ConfigMapCorruptSkill;
+
+#[derive(Debug, Deserialize)]
+struct ConfigCorruptParams {
+    /// Name of the ConfigMap or Secret.
+    name: String,
+    /// Key to overwrite (added if missing).
+    key: String,
+    #[serde(default = "default_value")]
+    value: String,
+    #[serde(default)]
+    kind: ConfigKind,
+    #[serde(default = "default_namespace")]
+    namespace: String,
+    /// Rollout-restart deployments that reference the object, after the
+    /// corruption and again after restore.
+    #[serde(default)]
+    restart_dependents: bool,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ConfigKind {
+    #[default]
+    ConfigMap,
+    Secret,
+}
+
+fn default_value() -> String {
+    "chaos-invalid-value: {{".to_string()
+}
+
+fn default_namespace() -> String {
+    "default".to_string()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ConfigCorruptUndoState {
+    name: String,
+    namespace: String,
+    kind: ConfigKind,
+    /// Original `data` field as serialized by the API (Secret values base64).
+    original_data: serde_json::Value,
+    restarted_deployments: Vec<String>,
+}
+
+#[async_trait]
+impl Skill for ConfigMapCorruptSkill {
+    fn descriptor(&self) -> SkillDescriptor {
+        SkillDescriptor {
+            name: "k8s.config_corrupt".into(),
+            description: "Overwrite a ConfigMap or Secret key with an invalid value, rollback restores the original data".into(),
+            target: TargetDomain::Kubernetes,
+            reversible: true,
+        }
+    }
+
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "required": ["name", "key"],
+            "properties": {
+                "name": { "type": "string", "description": "ConfigMap or Secret name" },
+                "key": { "type": "string" },
+                "value": { "type": "string", "default": default_value() },
+                "kind": { "type": "string", "enum": ["config_map", "secret"], "default": "config_map" },
+                "namespace": { "type": "string", "default": "default" },
+                "restart_dependents": { "type": "boolean", "default": false }
+            }
+        })
+    }
+
+    fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
+        let _: ConfigCorruptParams = parse_params("config_corrupt", params)?;
+        Ok(())
+    }
+
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<ConfigCorruptParams>()?;
+
+        if ctx.dry_run {
+            // Secret values stay out of the report
+            ctx.record_planned(match params.kind {
+                ConfigKind::ConfigMap => format!(
+                    "Set key '{}' of ConfigMap {}/{} to '{}'",
+                    params.key, params.namespace, params.name, params.value
+                ),
+                ConfigKind::Secret => format!(
+                    "Overwrite key '{}' of Secret {}/{}",
+                    params.key, params.namespace, params.name
+                ),
+            });
+            if params.restart_dependents {
+                for deployment in dependent_deployments(client, &params.namespace, params.kind, &params.name).await {
+                    ctx.record_planned(format!("Restart deployment {}/{deployment}", params.namespace));
+                }
+            }
+            return Ok(RollbackHandle::dry_run("k8s.config_corrupt"));
+        }
+
+        let original_data = match params.kind {
+            ConfigKind::ConfigMap => {
+                let api: Api<ConfigMap> = Api::namespaced(client.clone(), &params.namespace);
+                let mut cm = api.get(&params.name).await.map_err(|e| {
+                    ChaosError::Other(anyhow::anyhow!("Failed to get ConfigMap {}: {e}", params.name))
+                })?;
+                let original = serde_json::to_value(&cm.data)
+                    .map_err(|e| ChaosError::Other(anyhow::anyhow!("Snapshot ConfigMap: {e}")))?;
+                cm.data
+                    .get_or_insert_with(BTreeMap::new)
+                    .insert(params.key.clone(), params.value.clone());
+                api.replace(&params.name, &PostParams::default(), &cm)
+                    .await
+                    .map_err(|e| {
+                        ChaosError::Other(anyhow::anyhow!("Failed to update ConfigMap: {e}"))
+                    })?;
+                original
+            }
+            ConfigKind::Secret => {
+                let api: Api<Secret> = Api::namespaced(client.clone(), &params.namespace);
+                let mut secret = api.get(&params.name).await.map_err(|e| {
+                    ChaosError::Other(anyhow::anyhow!("Failed to get Secret {}: {e}", params.name))
+                })?;
+                let original = serde_json::to_value(&secret.data)
+                    .map_err(|e| ChaosError::Other(anyhow::anyhow!("Snapshot Secret: {e}")))?;
+                secret
+                    .data
+                    .get_or_insert_with(BTreeMap::new)
+                    .insert(params.key.clone(), ByteString(params.value.clone().into_bytes()));
+                api.replace(&params.name, &PostParams::default(), &secret)
+                    .await
+                    .map_err(|e| ChaosError::Other(anyhow::anyhow!("Failed to update Secret: {e}")))?;
+                original
+            }
+        };
+
+        tracing::info!(
+            kind = ?params.kind,
+            name = %params.name,
+            key = %params.key,
+            namespace = %params.namespace,
+            "Config corrupted"
+        );
+
+        let restarted_deployments = if params.restart_dependents {
+            let dependents =
+                dependent_deployments(client, &params.namespace, params.kind, &params.name).await;
+            restart_deployments(client, &params.namespace, &dependents).await;
+            dependents
+        } else {
+            Vec::new()
+        };
+
+        let undo = ConfigCorruptUndoState {
+            name: params.name.clone(),
+            namespace: params.namespace.clone(),
+            kind: params.kind,
+            original_data,
+            restarted_deployments,
+        };
+        let undo_state = serde_yaml::to_value(&undo)
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
+
+        Ok(RollbackHandle::new("k8s.config_corrupt", undo_state))
+    }
+
+    async fn rollback(&self, ctx: &SkillContext, handle: &RollbackHandle) -> ChaosResult<()> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let undo: ConfigCorruptUndoState = serde_yaml::from_value(handle.undo_state.clone())
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
+
+        let restored = match undo.kind {
+            ConfigKind::ConfigMap => restore_config_map(client, &undo).await,
+            ConfigKind::Secret => restore_secret(client, &undo).await,
+        };
+
+        match restored {
+            Ok(()) => {
+                tracing::info!(name = %undo.name, kind = ?undo.kind, "Config restored (rollback)");
+                // Restart again so dependents pick up the good config.
+                restart_deployments(client, &undo.namespace, &undo.restarted_deployments).await;
+            }
+            Err(e) => {
+                tracing::error!(name = %undo.name, error = %e, "Failed to restore config");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+async fn restore_config_map(client: &Client, undo: &ConfigCorruptUndoState) -> anyhow::Result<()> {
+    let api: Api<ConfigMap> = Api::namespaced(client.clone(), &undo.namespace);
+    let mut cm = api.get(&undo.name).await?;
+    cm.data = serde_json::from_value(undo.original_data.clone())?;
+    api.replace(&undo.name, &PostParams::default(), &cm).await?;
+    Ok(())
+}
+
+async fn restore_secret(client: &Client, undo: &ConfigCorruptUndoState) -> anyhow::Result<()> {
+    let api: Api<Secret> = Api::namespaced(client.clone(), &undo.namespace);
+    let mut secret = api.get(&undo.name).await?;
+    secret.data = serde_json::from_value(undo.original_data.clone())?;
+    // Drop any stringData so it can't override the restored values.
+    secret.string_data = None;
+    api.replace(&undo.name, &PostParams::default(), &secret).await?;
+    Ok(())
+}
+
+/// Deployments whose pod template mounts or references the object via
+/// volumes, `envFrom` or `env[].valueFrom`.
+async fn dependent_deployments(
+    client: &Client,
+    namespace: &str,
+    kind: ConfigKind,
+    name: &str,
+) -> Vec<String> {
+    let api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let deployments = match api.list(&ListParams::default()).await {
+        Ok(list) => list.items,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to list deployments, skipping restart");
+            return Vec::new();
+        }
+    };
+
+    deployments
+        .into_iter()
+        .filter(|d| {
+            d.spec
+                .as_ref()
+                .and_then(|s| serde_json::to_value(&s.template).ok())
+                .is_some_and(|template| references(&template, kind, name))
+        })
+        .filter_map(|d| d.metadata.name)
+        .collect()
+}
+
+fn references(value: &serde_json::Value, kind: ConfigKind, name: &str) -> bool {
+    let (ref_keys, volume_key, volume_name_field): (&[&str], &str, &str) = match kind {
+        ConfigKind::ConfigMap => (&["configMapRef", "configMapKeyRef"], "configMap", "name"),
+        ConfigKind::Secret => (&["secretRef", "secretKeyRef"], "secret", "secretName"),
+    };
+
+    match value {
+        serde_json::Value::Object(map) => map.iter().any(|(key, child)| {
+            let direct = if ref_keys.contains(&key.as_str()) {
+                child["name"].as_str() == Some(name)
+            } else if key == volume_key {
+                // Projected volume sources use `name` for secrets too.
+                child[volume_name_field].as_str() == Some(name) || child["name"].as_str() == Some(name)
+            } else {
+                false
+            };
+            direct || references(child, kind, name)
+        }),
+        serde_json::Value::Array(items) => items.iter().any(|item| references(item, kind, name)),
+        _ => false,
+    }
+}
+
+async fn restart_deployments(client: &Client, namespace: &str, names: &[String]) {
+    let api: Api<Deployment> = Api::namespaced(client.clone(), namespace);
+    let patch = serde_json::json!({
+        "spec": {
+            "template": {
+                "metadata": {
+                    "annotations": {
+                        "kubectl.kubernetes.io/restartedAt": k8s_openapi::chrono::Utc::now().to_rfc3339()
+                    }
+                }
+            }
+        }
+    });
+
+    for name in names {
+        match api
+            .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await
+        {
+            Ok(_) => tracing::info!(deployment = %name, "Rollout restart triggered"),
+            Err(e) => tracing::warn!(deployment = %name, error = %e, "Rollout restart failed"),
+        }
+    }
+}
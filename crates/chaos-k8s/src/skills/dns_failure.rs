@@ -0,0 +1,275 @@
+use async_trait::async_trait;
+use chaos_core::error::{ChaosError, ChaosResult};
+use chaos_core::rollback::RollbackHandle;
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use k8s_openapi::api::apps::v1::Deployment;
+use kube::api::{Api, Patch, PatchParams};
+use kube::Client;
+use serde::{Deserialize, Serialize};
+
+/// Break DNS resolution, either for one deployment (pod template pointed at a
+/// blackhole nameserver) or cluster-wide (CoreDNS scaled to zero). Rollback
+/// restores the prior DNS settings or replica count.
+pub struct DnsFailureSkill;
+
+#[derive(Debug, Deserialize)]
+struct DnsFailureParams {
+    #[serde(default)]
+    mode: DnsFailureMode,
+    /// Deployment to break DNS for (`deployment_dns_config` mode).
+    #[serde(default)]
+    deployment: Option<String>,
+    #[serde(default = "default_namespace")]
+    namespace: String,
+    /// Unreachable nameserver to inject. Default: 192.0.2.1 (TEST-NET-1).
+    #[serde(default = "default_nameserver")]
+    nameserver: String,
+    #[serde(default = "default_coredns_namespace")]
+    coredns_namespace: String,
+    #[serde(default = "default_coredns_deployment")]
+    coredns_deployment: String,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum DnsFailureMode {
+    /// Patch the deployment's pod template with `dnsPolicy: None` and a
+    /// blackhole nameserver. Triggers a rollout.
+    #[default]
+    DeploymentDnsConfig,
+    /// Scale the CoreDNS deployment to zero replicas. Affects the whole cluster.
+    CorednsScaleDown,
+}
+
+fn default_namespace() -> String {
+    "default".to_string()
+}
+
+fn default_nameserver() -> String {
+    "192.0.2.1".to_string()
+}
+
+fn default_coredns_namespace() -> String {
+    "kube-system".to_string()
+}
+
+fn default_coredns_deployment() -> String {
+    "coredns".to_string()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+enum DnsFailureUndoState {
+    DeploymentDnsConfig {
+        deployment: String,
+        namespace: String,
+        /// Original `dnsPolicy` / `dnsConfig` (JSON null when unset).
+        dns_policy: serde_json::Value,
+        dns_config: serde_json::Value,
+    },
+    CorednsScaleDown {
+        deployment: String,
+        namespace: String,
+        replicas: i32,
+    },
+}
+
+#[async_trait]
+impl Skill for DnsFailureSkill {
+    fn descriptor(&self) -> SkillDescriptor {
+        SkillDescriptor {
+            name: "k8s.dns_failure".into(),
+            description: "Break DNS resolution for a deployment or scale CoreDNS down, rollback restores it".into(),
+            target: TargetDomain::Kubernetes,
+            reversible: true,
+        }
+    }
+
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "mode": {
+                    "type": "string",
+                    "enum": ["deployment_dns_config", "coredns_scale_down"],
+                    "default": "deployment_dns_config"
+                },
+                "deployment": { "type": "string", "description": "Required for deployment_dns_config" },
+                "namespace": { "type": "string", "default": "default" },
+                "nameserver": { "type": "string", "default": "192.0.2.1" },
+                "coredns_namespace": { "type": "string", "default": "kube-system" },
+                "coredns_deployment": { "type": "string", "default": "coredns" }
+            }
+        })
+    }
+
+    fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
+        let params: DnsFailureParams = parse_params("dns_failure", params)?;
+        if matches!(params.mode, DnsFailureMode::DeploymentDnsConfig) && params.deployment.is_none() {
+            return Err(ChaosError::Config(
+                "dns_failure mode deployment_dns_config requires 'deployment'".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<DnsFailureParams>()?;
+
+        if ctx.dry_run {
+            ctx.record_planned(match params.mode {
+                DnsFailureMode::DeploymentDnsConfig => format!(
+                    "Point DNS of deployment {}/{} at nameserver {}",
+                    params.namespace,
+                    params.deployment.as_deref().unwrap_or_default(),
+                    params.nameserver
+                ),
+                DnsFailureMode::CorednsScaleDown => format!(
+                    "Scale {}/{} to 0 replicas (cluster-wide DNS outage)",
+                    params.coredns_namespace, params.coredns_deployment
+                ),
+            });
+            return Ok(RollbackHandle::dry_run("k8s.dns_failure"));
+        }
+
+        let undo = match params.mode {
+            DnsFailureMode::DeploymentDnsConfig => {
+                let name = params.deployment.clone().ok_or_else(|| {
+                    ChaosError::Config("dns_failure requires 'deployment'".into())
+                })?;
+                let api: Api<Deployment> = Api::namespaced(client.clone(), &params.namespace);
+                let deployment = api.get(&name).await.map_err(|e| {
+                    ChaosError::Other(anyhow::anyhow!("Failed to get deployment {name}: {e}"))
+                })?;
+                let pod_spec = deployment.spec.and_then(|s| s.template.spec);
+                let dns_policy = serde_json::to_value(pod_spec.as_ref().and_then(|s| s.dns_policy.clone()))
+                    .unwrap_or_default();
+                let dns_config = serde_json::to_value(pod_spec.as_ref().and_then(|s| s.dns_config.clone()))
+                    .unwrap_or_default();
+
+                let patch = serde_json::json!({
+                    "spec": { "template": { "spec": {
+                        "dnsPolicy": "None",
+                        "dnsConfig": {
+                            "nameservers": [params.nameserver],
+                            "searches": [],
+                            "options": [{ "name": "timeout", "value": "1" }, { "name": "attempts", "value": "1" }]
+                        }
+                    }}}
+                });
+                api.patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
+                    .await
+                    .map_err(|e| {
+                        ChaosError::Other(anyhow::anyhow!("Failed to patch deployment DNS: {e}"))
+                    })?;
+
+                tracing::info!(
+                    deployment = %name,
+                    namespace = %params.namespace,
+                    nameserver = %params.nameserver,
+                    "Deployment DNS pointed at blackhole nameserver"
+                );
+
+                DnsFailureUndoState::DeploymentDnsConfig {
+                    deployment: name,
+                    namespace: params.namespace.clone(),
+                    dns_policy,
+                    dns_config,
+                }
+            }
+            DnsFailureMode::CorednsScaleDown => {
+                let api: Api<Deployment> =
+                    Api::namespaced(client.clone(), &params.coredns_namespace);
+                let coredns = api.get(&params.coredns_deployment).await.map_err(|e| {
+                    ChaosError::Other(anyhow::anyhow!(
+                        "Failed to get {}/{}: {e}",
+                        params.coredns_namespace,
+                        params.coredns_deployment
+                    ))
+                })?;
+                let replicas = coredns.spec.and_then(|s| s.replicas).unwrap_or(1);
+
+                set_replicas(&api, &params.coredns_deployment, 0)
+                    .await
+                    .map_err(|e| ChaosError::Other(anyhow::anyhow!("Failed to scale CoreDNS: {e}")))?;
+
+                tracing::warn!(
+                    deployment = %params.coredns_deployment,
+                    original_replicas = replicas,
+                    "CoreDNS scaled to zero (cluster-wide DNS outage)"
+                );
+
+                DnsFailureUndoState::CorednsScaleDown {
+                    deployment: params.coredns_deployment.clone(),
+                    namespace: params.coredns_namespace.clone(),
+                    replicas,
+                }
+            }
+        };
+
+        let undo_state = serde_yaml::to_value(&undo)
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
+
+        Ok(RollbackHandle::new("k8s.dns_failure", undo_state))
+    }
+
+    async fn rollback(&self, ctx: &SkillContext, handle: &RollbackHandle) -> ChaosResult<()> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let undo: DnsFailureUndoState = serde_yaml::from_value(handle.undo_state.clone())
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
+
+        match undo {
+            DnsFailureUndoState::DeploymentDnsConfig {
+                deployment,
+                namespace,
+                dns_policy,
+                dns_config,
+            } => {
+                let api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+                // Null fields are removed by the merge patch, restoring defaults.
+                let patch = serde_json::json!({
+                    "spec": { "template": { "spec": {
+                        "dnsPolicy": dns_policy,
+                        "dnsConfig": dns_config
+                    }}}
+                });
+                match api
+                    .patch(&deployment, &PatchParams::default(), &Patch::Merge(&patch))
+                    .await
+                {
+                    Ok(_) => tracing::info!(deployment = %deployment, "Deployment DNS restored (rollback)"),
+                    Err(e) => tracing::error!(deployment = %deployment, error = %e, "Failed to restore deployment DNS"),
+                }
+            }
+            DnsFailureUndoState::CorednsScaleDown {
+                deployment,
+                namespace,
+                replicas,
+            } => {
+                let api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+                match set_replicas(&api, &deployment, replicas).await {
+                    Ok(()) => tracing::info!(deployment = %deployment, replicas, "CoreDNS scaled back up (rollback)"),
+                    Err(e) => tracing::error!(deployment = %deployment, error = %e, "Failed to restore CoreDNS replicas"),
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) async fn set_replicas(api: &Api<Deployment>, name: &str, replicas: i32) -> Result<(), kube::Error> {
+    let patch = serde_json::json!({ "spec": { "replicas": replicas } });
+    api.patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map(|_| ())
+}
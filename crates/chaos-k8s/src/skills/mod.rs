@@ -1,4 +1,10 @@
+pub mod api_pressure;
+pub mod config_corrupt;
+pub mod dns_failure;
 pub mod network_chaos;
+pub mod network_latency;
 pub mod node_drain;
+pub mod pod_evict;
 pub mod pod_kill;
 pub mod resource_stress;
+pub mod scale_deployment;
@@ -1,9 +1,11 @@
 use async_trait::async_trait;
+use chaos_core::cleanup::EXPERIMENT_ID_LABEL;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use k8s_openapi::api::core::v1::Pod;
 use k8s_openapi::api::networking::v1::NetworkPolicy;
-use kube::api::{Api, DeleteParams, PostParams};
+use kube::api::{Api, DeleteParams, ListParams, PostParams};
 use kube::Client;
 use serde::{Deserialize, Serialize};
 
@@ -38,20 +40,85 @@ impl Skill for NetworkChaosSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "namespace": { "type": "string", "default": "default" },
+                "pod_selector": { "type": "object", "additionalProperties": { "type": "string" } }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: NetworkChaosParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid network_chaos params: {e}")))?;
+        let _: NetworkChaosParams = parse_params("network_chaos", params)?;
         Ok(())
     }
 
+    async fn inspect(&self, ctx: &SkillContext) -> ChaosResult<Vec<String>> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<NetworkChaosParams>()?;
+
+        // An empty podSelector matches every pod in the namespace.
+        let selector = params
+            .pod_selector
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        let mut lp = ListParams::default();
+        if !selector.is_empty() {
+            lp = lp.labels(&selector);
+        }
+        let pods: Api<Pod> = Api::namespaced(client.clone(), &params.namespace);
+        let pod_list = pods
+            .list(&lp)
+            .await
+            .map_err(|e| ChaosError::Discovery(format!("Failed to list pods: {e}")))?;
+
+        let mut lines = vec![format!(
+            "would cut all ingress and egress for {} pods in '{}':",
+            pod_list.items.len(),
+            params.namespace
+        )];
+        lines.extend(
+            pod_list
+                .items
+                .iter()
+                .map(|p| format!("  {}", p.metadata.name.as_deref().unwrap_or("unknown"))),
+        );
+        Ok(lines)
+    }
+
     async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
         let client = ctx
             .shared
             .downcast_ref::<Client>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
 
-        let params: NetworkChaosParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<NetworkChaosParams>()?;
+
+        if ctx.dry_run {
+            let selector = params
+                .pod_selector
+                .iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            ctx.record_planned(format!(
+                "Create a deny-all NetworkPolicy in '{}' for pods matching '{selector}'",
+                params.namespace
+            ));
+            return Ok(RollbackHandle::dry_run("k8s.network_chaos"));
+        }
 
         let policy_name = format!("chaos-deny-{}", uuid::Uuid::new_v4().as_simple());
 
@@ -63,7 +130,8 @@ impl Skill for NetworkChaosSkill {
                 "name": policy_name,
                 "namespace": params.namespace,
                 "labels": {
-                    "app.kubernetes.io/managed-by": "chaos-agents"
+                    "app.kubernetes.io/managed-by": "chaos-agents",
+                    EXPERIMENT_ID_LABEL: ctx.experiment_id.to_string()
                 }
             },
             "spec": {
@@ -93,7 +161,7 @@ impl Skill for NetworkChaosSkill {
 
         let undo = NetworkChaosUndoState {
             policy_name,
-            namespace: params.namespace,
+            namespace: params.namespace.clone(),
         };
         let undo_state = serde_yaml::to_value(&undo)
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
@@ -0,0 +1,549 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chaos_core::cleanup::EXPERIMENT_ID_LABEL;
+use chaos_core::duration::parse_duration;
+use chaos_core::error::{ChaosError, ChaosResult};
+use chaos_core::rollback::RollbackHandle;
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::api::networking::v1::NetworkPolicy;
+use kube::api::{Api, DeleteParams, LogParams, Patch, PatchParams, PostParams};
+use kube::Client;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use crate::selector::validate_label_selector;
+use crate::skills::pod_kill::list_running_pods;
+
+/// Degrade pod networking with a netem qdisc, added by an ephemeral
+/// container with `NET_ADMIN` in the pod's network namespace. Where `tc`
+/// can't run (no ephemeral containers, a restrictive pod security policy,
+/// no `tc` in the image), the pods can instead be cut off from ingress with
+/// a NetworkPolicy.
+pub struct NetworkLatencySkill;
+
+#[derive(Debug, Deserialize)]
+struct NetworkLatencyParams {
+    #[serde(default)]
+    label_selector: Option<String>,
+    #[serde(default = "default_namespace")]
+    namespace: String,
+    #[serde(default = "default_count")]
+    count: usize,
+    #[serde(default = "default_latency_ms")]
+    latency_ms: u32,
+    #[serde(default)]
+    jitter_ms: u32,
+    #[serde(default)]
+    loss_percent: f64,
+    /// Network interface inside the pods.
+    #[serde(default = "default_interface")]
+    interface: String,
+    /// Image with `tc` (iproute2).
+    #[serde(default = "default_image")]
+    image: String,
+    /// Safety cap; the qdisc is removed by itself after this.
+    #[serde(default = "default_max_duration")]
+    max_duration: String,
+    /// Deny ingress to pods where `tc` couldn't run, instead of skipping them.
+    #[serde(default = "default_true")]
+    fallback_policy: bool,
+}
+
+fn default_namespace() -> String {
+    "default".to_string()
+}
+fn default_count() -> usize {
+    1
+}
+fn default_latency_ms() -> u32 {
+    100
+}
+fn default_interface() -> String {
+    "eth0".to_string()
+}
+fn default_image() -> String {
+    "nicolaka/netshoot:latest".to_string()
+}
+fn default_max_duration() -> String {
+    "1h".to_string()
+}
+fn default_true() -> bool {
+    true
+}
+
+/// Label on pods isolated by the fallback NetworkPolicy, set to the run id.
+const ISOLATED_LABEL: &str = "chaos-agents/network-latency";
+/// How long to wait for an ephemeral container to start or finish.
+const CONTAINER_TIMEOUT: Duration = Duration::from_secs(60);
+const CONTAINER_POLL: Duration = Duration::from_secs(1);
+
+impl NetworkLatencyParams {
+    /// e.g. `delay 100ms 20ms loss 1%`.
+    fn netem(&self) -> String {
+        let mut netem = format!("delay {}ms", self.latency_ms);
+        if self.jitter_ms > 0 {
+            netem.push_str(&format!(" {}ms", self.jitter_ms));
+        }
+        if self.loss_percent > 0.0 {
+            netem.push_str(&format!(" loss {}%", self.loss_percent));
+        }
+        netem
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct NetworkLatencyUndoState {
+    namespace: String,
+    interface: String,
+    image: String,
+    run_id: String,
+    /// Pods with the netem qdisc.
+    shaped_pods: Vec<String>,
+    /// Pods given the tc container, shaped or not: one that timed out may
+    /// still start later and add the qdisc.
+    #[serde(default)]
+    injected_pods: Vec<String>,
+    /// The fallback NetworkPolicy, if any pod needed it.
+    #[serde(default)]
+    policy_name: Option<String>,
+    /// Pods labelled for the fallback NetworkPolicy.
+    #[serde(default)]
+    isolated_pods: Vec<String>,
+}
+
+#[async_trait]
+impl Skill for NetworkLatencySkill {
+    fn descriptor(&self) -> SkillDescriptor {
+        SkillDescriptor {
+            name: "k8s.network_latency".into(),
+            description: "Add latency, jitter and packet loss to pods with tc netem, rollback removes it".into(),
+            target: TargetDomain::Kubernetes,
+            reversible: true,
+        }
+    }
+
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "label_selector": { "type": "string" },
+                "namespace": { "type": "string", "default": "default" },
+                "count": { "type": "integer", "default": 1 },
+                "latency_ms": { "type": "integer", "default": 100 },
+                "jitter_ms": { "type": "integer", "default": 0 },
+                "loss_percent": { "type": "number", "default": 0 },
+                "interface": { "type": "string", "default": "eth0" },
+                "image": { "type": "string", "default": "nicolaka/netshoot:latest", "description": "Image with tc (iproute2)" },
+                "max_duration": { "type": "string", "default": "1h", "description": "Safety cap; the qdisc is removed by itself after this" },
+                "fallback_policy": { "type": "boolean", "default": true, "description": "Deny ingress to pods where tc can't run, instead of skipping them" }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
+    fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
+        let params: NetworkLatencyParams = parse_params("network_latency", params)?;
+        if let Some(ref selector) = params.label_selector {
+            validate_label_selector(selector).map_err(ChaosError::Config)?;
+        }
+        parse_duration(&params.max_duration)?;
+        if !(0.0..=100.0).contains(&params.loss_percent) {
+            return Err(ChaosError::Config(
+                "network_latency loss_percent must be between 0 and 100".into(),
+            ));
+        }
+        if params.latency_ms == 0 && params.loss_percent == 0.0 {
+            return Err(ChaosError::Config(
+                "network_latency needs a latency_ms or loss_percent above 0".into(),
+            ));
+        }
+        // Spliced into a shell command
+        if !params
+            .interface
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '@'))
+        {
+            return Err(ChaosError::Config(format!(
+                "network_latency interface '{}' is not a valid interface name",
+                params.interface
+            )));
+        }
+        Ok(())
+    }
+
+    async fn preflight(&self, ctx: &SkillContext) -> ChaosResult<()> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<NetworkLatencyParams>()?;
+
+        let running = list_running_pods(client, &params.namespace, params.label_selector.as_deref()).await?;
+        if running.is_empty() {
+            return Err(ChaosError::Config(match &params.label_selector {
+                Some(selector) => format!(
+                    "network_latency label_selector '{selector}' matches no running pods in namespace '{}'",
+                    params.namespace
+                ),
+                None => format!("network_latency found no running pods in namespace '{}'", params.namespace),
+            }));
+        }
+        Ok(())
+    }
+
+    async fn inspect(&self, ctx: &SkillContext) -> ChaosResult<Vec<String>> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<NetworkLatencyParams>()?;
+
+        let running_pods = list_running_pods(client, &params.namespace, params.label_selector.as_deref()).await?;
+        let mut lines = vec![format!(
+            "would add {} on {} to {} of {} running pods, chosen at random:",
+            params.netem(),
+            params.interface,
+            params.count.min(running_pods.len()),
+            running_pods.len()
+        )];
+        lines.extend(running_pods.iter().map(|p| {
+            format!(
+                "  {}/{}",
+                p.metadata.namespace.as_deref().unwrap_or(&params.namespace),
+                p.metadata.name.as_deref().unwrap_or("unknown")
+            )
+        }));
+        Ok(lines)
+    }
+
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<NetworkLatencyParams>()?;
+        let max_duration = parse_duration(&params.max_duration)?;
+
+        let pods: Api<Pod> = Api::namespaced(client.clone(), &params.namespace);
+        let running_pods = list_running_pods(client, &params.namespace, params.label_selector.as_deref()).await?;
+
+        if running_pods.is_empty() {
+            return Err(ChaosError::Discovery("No running pods found".into()));
+        }
+
+        let count = ctx.clamp("pods", params.count, ctx.limits.max_pods);
+        let targets: Vec<String> = {
+            let mut rng = rand::thread_rng();
+            running_pods
+                .choose_multiple(&mut rng, count.min(running_pods.len()))
+                .filter_map(|p| p.metadata.name.clone())
+                .collect()
+        };
+
+        if ctx.dry_run {
+            for pod in &targets {
+                ctx.record_planned(format!(
+                    "Add {} on {} in pod {}/{pod} via an ephemeral tc container",
+                    params.netem(),
+                    params.interface,
+                    params.namespace
+                ));
+            }
+            return Ok(RollbackHandle::dry_run("k8s.network_latency"));
+        }
+
+        let run_id = uuid::Uuid::new_v4().as_simple().to_string()[..8].to_string();
+        let container = format!("chaos-netem-{run_id}");
+        // Removes the qdisc by itself at max_duration, in case rollback never runs
+        let script = format!(
+            "tc qdisc add dev {iface} root netem {netem} || exit 1; sleep {secs}; tc qdisc del dev {iface} root netem",
+            iface = params.interface,
+            netem = params.netem(),
+            secs = max_duration.as_secs().max(1),
+        );
+
+        let mut shaped = Vec::new();
+        let mut unshaped = Vec::new();
+        let mut injected = Vec::new();
+        for pod in &targets {
+            let result = match add_container(&pods, pod, &container, &params.image, &script).await {
+                Ok(()) => {
+                    injected.push(pod.clone());
+                    wait_for_container(&pods, pod, &container, false).await
+                }
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(()) => {
+                    ctx.record_action(format!(
+                        "Added {} on {} in pod {}/{pod}",
+                        params.netem(),
+                        params.interface,
+                        params.namespace
+                    ));
+                    shaped.push(pod.clone());
+                }
+                Err(e) => {
+                    ctx.warn(format!("tc couldn't shape pod {}/{pod}: {e}", params.namespace));
+                    unshaped.push(pod.clone());
+                }
+            }
+        }
+
+        let mut undo = NetworkLatencyUndoState {
+            namespace: params.namespace.clone(),
+            interface: params.interface.clone(),
+            image: params.image.clone(),
+            run_id: run_id.clone(),
+            shaped_pods: shaped,
+            injected_pods: injected,
+            policy_name: None,
+            isolated_pods: Vec::new(),
+        };
+
+        if !unshaped.is_empty() && params.fallback_policy {
+            match isolate_pods(client, ctx, &params.namespace, &run_id, &unshaped, &mut undo).await {
+                Ok(policy_name) => ctx.warn(format!(
+                    "Fell back to NetworkPolicy {policy_name}, denying all ingress to {} pod(s) where tc couldn't run",
+                    undo.isolated_pods.len()
+                )),
+                Err(e) => ctx.warn(format!("Fallback NetworkPolicy failed: {e}")),
+            }
+        }
+
+        if undo.shaped_pods.is_empty() && undo.policy_name.is_none() {
+            return Err(ChaosError::Other(anyhow::anyhow!(
+                "network_latency couldn't degrade any of the chosen pods"
+            )));
+        }
+
+        let undo_state = serde_yaml::to_value(&undo)
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
+
+        Ok(RollbackHandle::new("k8s.network_latency", undo_state))
+    }
+
+    async fn rollback(&self, ctx: &SkillContext, handle: &RollbackHandle) -> ChaosResult<()> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let undo: NetworkLatencyUndoState = serde_yaml::from_value(handle.undo_state.clone())
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
+
+        let pods: Api<Pod> = Api::namespaced(client.clone(), &undo.namespace);
+
+        // Ephemeral containers can't be stopped, so a second one deletes the
+        // qdisc from the same network namespace. Pods whose tc container
+        // hadn't started in time are checked too, in case it started since.
+        let container = format!("chaos-netem-undo-{}", undo.run_id);
+        let script = format!(
+            "tc qdisc show dev {iface} | grep -q netem || exit 0; tc qdisc del dev {iface} root netem",
+            iface = undo.interface
+        );
+        let mut targets = undo.shaped_pods.clone();
+        for pod in &undo.injected_pods {
+            if !targets.contains(pod) {
+                targets.push(pod.clone());
+            }
+        }
+        for pod in &targets {
+            match pods.get_opt(pod).await {
+                Ok(None) => {
+                    tracing::info!(pod = %pod, "Shaped pod is gone, nothing to remove");
+                    continue;
+                }
+                Ok(Some(_)) => {}
+                Err(e) => {
+                    tracing::error!(pod = %pod, error = %e, "Failed to get shaped pod");
+                    continue;
+                }
+            }
+            match run_tc(&pods, pod, &container, &undo.image, &script, true).await {
+                Ok(()) if undo.shaped_pods.contains(pod) => ctx.record_action(format!(
+                    "Removed the netem qdisc on {} in pod {}/{pod}",
+                    undo.interface, undo.namespace
+                )),
+                Ok(()) => ctx.record_action(format!(
+                    "Removed any late netem qdisc on {} in pod {}/{pod}",
+                    undo.interface, undo.namespace
+                )),
+                Err(e) => ctx.warn(format!(
+                    "Failed to remove the netem qdisc in pod {}/{pod}: {e}; it goes away at max_duration or when the pod is replaced",
+                    undo.namespace
+                )),
+            }
+        }
+
+        if let Some(ref policy_name) = undo.policy_name {
+            let np_api: Api<NetworkPolicy> = Api::namespaced(client.clone(), &undo.namespace);
+            match np_api.delete(policy_name, &DeleteParams::default()).await {
+                Ok(_) => {
+                    tracing::info!(policy = %policy_name, "NetworkPolicy deleted (rollback)");
+                }
+                Err(e) => {
+                    tracing::error!(policy = %policy_name, error = %e, "Failed to delete NetworkPolicy");
+                }
+            }
+        }
+        // Labelled pods may outlive a policy that was never created
+        remove_isolation_labels(&pods, &undo.isolated_pods).await;
+
+        Ok(())
+    }
+}
+
+/// Run `script` in a new ephemeral `container` of `pod` with `NET_ADMIN`.
+/// Succeeds once it's running, or with `until_exit`, once it exits cleanly.
+async fn run_tc(
+    pods: &Api<Pod>,
+    pod: &str,
+    container: &str,
+    image: &str,
+    script: &str,
+    until_exit: bool,
+) -> Result<(), String> {
+    add_container(pods, pod, container, image, script).await?;
+    wait_for_container(pods, pod, container, until_exit).await
+}
+
+/// Add the ephemeral `container` running `script` to `pod`.
+async fn add_container(
+    pods: &Api<Pod>,
+    pod: &str,
+    container: &str,
+    image: &str,
+    script: &str,
+) -> Result<(), String> {
+    let patch = serde_json::json!({
+        "spec": { "ephemeralContainers": [{
+            "name": container,
+            "image": image,
+            "command": ["sh", "-c", script],
+            "securityContext": { "capabilities": { "add": ["NET_ADMIN"] } }
+        }]}
+    });
+    pods.patch_ephemeral_containers(pod, &PatchParams::default(), &Patch::Strategic(patch))
+        .await
+        .map_err(|e| format!("couldn't add an ephemeral container: {e}"))?;
+    Ok(())
+}
+
+/// Wait for `container` to be running, or with `until_exit`, to exit cleanly.
+async fn wait_for_container(
+    pods: &Api<Pod>,
+    pod: &str,
+    container: &str,
+    until_exit: bool,
+) -> Result<(), String> {
+    let deadline = tokio::time::Instant::now() + CONTAINER_TIMEOUT;
+    // `tc qdisc add` runs first, so a container still up a poll later has passed it
+    let mut seen_running = 0;
+    loop {
+        let state = pods
+            .get(pod)
+            .await
+            .map_err(|e| e.to_string())?
+            .status
+            .and_then(|s| s.ephemeral_container_statuses)
+            .unwrap_or_default()
+            .into_iter()
+            .find(|s| s.name == container)
+            .and_then(|s| s.state);
+        if let Some(terminated) = state.as_ref().and_then(|s| s.terminated.as_ref()) {
+            if terminated.exit_code == 0 {
+                return Ok(());
+            }
+            let lp = LogParams {
+                container: Some(container.to_string()),
+                tail_lines: Some(1),
+                ..LogParams::default()
+            };
+            let output = pods.logs(pod, &lp).await.unwrap_or_default();
+            return Err(format!("tc exited with code {}: {}", terminated.exit_code, output.trim()));
+        }
+        if !until_exit && state.as_ref().is_some_and(|s| s.running.is_some()) {
+            seen_running += 1;
+            if seen_running >= 2 {
+                return Ok(());
+            }
+        }
+        if tokio::time::Instant::now() >= deadline {
+            let waiting = state
+                .and_then(|s| s.waiting)
+                .and_then(|w| w.reason)
+                .unwrap_or_else(|| "not started".into());
+            return Err(format!("container still {waiting} after {}s", CONTAINER_TIMEOUT.as_secs()));
+        }
+        tokio::time::sleep(CONTAINER_POLL).await;
+    }
+}
+
+/// Label `pods` and deny them all ingress with a NetworkPolicy selecting
+/// that label. Returns the policy's name.
+async fn isolate_pods(
+    client: &Client,
+    ctx: &SkillContext,
+    namespace: &str,
+    run_id: &str,
+    pods_to_isolate: &[String],
+    undo: &mut NetworkLatencyUndoState,
+) -> anyhow::Result<String> {
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let patch = serde_json::json!({ "metadata": { "labels": { ISOLATED_LABEL: run_id } } });
+    for pod in pods_to_isolate {
+        match pods.patch(pod, &PatchParams::default(), &Patch::Merge(&patch)).await {
+            Ok(_) => undo.isolated_pods.push(pod.clone()),
+            Err(e) => ctx.warn(format!("Failed to label pod {namespace}/{pod} for isolation: {e}")),
+        }
+    }
+    if undo.isolated_pods.is_empty() {
+        anyhow::bail!("no pod could be labelled");
+    }
+
+    let policy_name = format!("chaos-latency-{run_id}");
+    let policy: NetworkPolicy = serde_json::from_value(serde_json::json!({
+        "apiVersion": "networking.k8s.io/v1",
+        "kind": "NetworkPolicy",
+        "metadata": {
+            "name": policy_name,
+            "namespace": namespace,
+            "labels": {
+                "app.kubernetes.io/managed-by": "chaos-agents",
+                EXPERIMENT_ID_LABEL: ctx.experiment_id.to_string()
+            }
+        },
+        "spec": {
+            "podSelector": { "matchLabels": { ISOLATED_LABEL: run_id } },
+            "policyTypes": ["Ingress"],
+            "ingress": []
+        }
+    }))?;
+    let np_api: Api<NetworkPolicy> = Api::namespaced(client.clone(), namespace);
+    if let Err(e) = np_api.create(&PostParams::default(), &policy).await {
+        remove_isolation_labels(&pods, &undo.isolated_pods).await;
+        undo.isolated_pods.clear();
+        return Err(e.into());
+    }
+    ctx.record_action(format!("Created NetworkPolicy {namespace}/{policy_name} denying ingress"));
+    undo.policy_name = Some(policy_name.clone());
+    Ok(policy_name)
+}
+
+async fn remove_isolation_labels(pods: &Api<Pod>, isolated: &[String]) {
+    let patch = serde_json::json!({ "metadata": { "labels": { ISOLATED_LABEL: null } } });
+    for pod in isolated {
+        if let Err(e) = pods.patch(pod, &PatchParams::default(), &Patch::Merge(&patch)).await {
+            tracing::warn!(pod = %pod, error = %e, "Failed to remove isolation label");
+        }
+    }
+}
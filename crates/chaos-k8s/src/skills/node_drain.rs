@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use k8s_openapi::api::core::v1::Node;
 use kube::api::{Api, ListParams, Patch, PatchParams};
 use kube::Client;
@@ -32,55 +32,55 @@ impl Skill for NodeDrainSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "node_name": { "type": "string", "description": "Node to drain (default: picked from discovered nodes)" }
+            }
+        })
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: NodeDrainParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid node_drain params: {e}")))?;
+        let _: NodeDrainParams = parse_params("node_drain", params)?;
         Ok(())
     }
 
+    async fn inspect(&self, ctx: &SkillContext) -> ChaosResult<Vec<String>> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<NodeDrainParams>()?;
+
+        if let Some(name) = &params.node_name {
+            return Ok(vec![format!("would cordon node {name}")]);
+        }
+        let nodes: Api<Node> = Api::all(client.clone());
+        let schedulable = schedulable_workers(&nodes).await?;
+        let mut lines = vec![format!(
+            "would cordon 1 of {} schedulable worker nodes, chosen at random:",
+            schedulable.len()
+        )];
+        lines.extend(schedulable.iter().map(|n| format!("  {n}")));
+        Ok(lines)
+    }
+
     async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
         let client = ctx
             .shared
             .downcast_ref::<Client>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
 
-        let params: NodeDrainParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<NodeDrainParams>()?;
 
         let nodes: Api<Node> = Api::all(client.clone());
 
         let target_node = if let Some(ref name) = params.node_name {
             name.clone()
         } else {
-            // Pick a random schedulable worker node
-            let node_list = nodes
-                .list(&ListParams::default())
-                .await
-                .map_err(|e| ChaosError::Discovery(format!("Failed to list nodes: {e}")))?;
-
-            let schedulable: Vec<_> = node_list
-                .items
-                .iter()
-                .filter(|n| {
-                    let unschedulable = n
-                        .spec
-                        .as_ref()
-                        .and_then(|s| s.unschedulable)
-                        .unwrap_or(false);
-                    !unschedulable
-                })
-                .filter(|n| {
-                    // Skip control plane nodes
-                    let labels = n.metadata.labels.as_ref();
-                    !labels
-                        .map(|l| {
-                            l.contains_key("node-role.kubernetes.io/control-plane")
-                                || l.contains_key("node-role.kubernetes.io/master")
-                        })
-                        .unwrap_or(false)
-                })
-                .collect();
-
+            let schedulable = schedulable_workers(&nodes).await?;
             if schedulable.is_empty() {
                 return Err(ChaosError::Discovery(
                     "No schedulable worker nodes found".into(),
@@ -88,15 +88,14 @@ impl Skill for NodeDrainSkill {
             }
 
             let mut rng = rand::thread_rng();
-            schedulable
-                .choose(&mut rng)
-                .unwrap()
-                .metadata
-                .name
-                .clone()
-                .unwrap_or_default()
+            schedulable.choose(&mut rng).cloned().unwrap_or_default()
         };
 
+        if ctx.dry_run {
+            ctx.record_planned(format!("Cordon node {target_node}"));
+            return Ok(RollbackHandle::dry_run("k8s.node_drain"));
+        }
+
         // Cordon: set spec.unschedulable = true
         let patch = serde_json::json!({
             "spec": {
@@ -164,3 +163,35 @@ impl Skill for NodeDrainSkill {
         Ok(())
     }
 }
+
+/// Names of schedulable, non-control-plane nodes `execute` picks from.
+async fn schedulable_workers(nodes: &Api<Node>) -> ChaosResult<Vec<String>> {
+    let node_list = nodes
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| ChaosError::Discovery(format!("Failed to list nodes: {e}")))?;
+
+    Ok(node_list
+        .items
+        .into_iter()
+        .filter(|n| {
+            let unschedulable = n
+                .spec
+                .as_ref()
+                .and_then(|s| s.unschedulable)
+                .unwrap_or(false);
+            !unschedulable
+        })
+        .filter(|n| {
+            // Skip control plane nodes
+            let labels = n.metadata.labels.as_ref();
+            !labels
+                .map(|l| {
+                    l.contains_key("node-role.kubernetes.io/control-plane")
+                        || l.contains_key("node-role.kubernetes.io/master")
+                })
+                .unwrap_or(false)
+        })
+        .filter_map(|n| n.metadata.name)
+        .collect())
+}
@@ -0,0 +1,364 @@
+use async_trait::async_trait;
+use chaos_core::error::{ChaosError, ChaosResult};
+use chaos_core::rollback::RollbackHandle;
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use kube::api::{Api, EvictParams, ListParams};
+use kube::Client;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use crate::selector::{selector_matches, validate_label_selector};
+use crate::skills::pod_kill::list_running_pods;
+
+/// Evict pods through the eviction API, which, unlike `k8s.pod_kill`'s
+/// delete, honors PodDisruptionBudgets. Tests whether the budgets actually
+/// stop a voluntary disruption from taking out too many replicas.
+pub struct PodEvictSkill;
+
+#[derive(Debug, Deserialize)]
+struct PodEvictParams {
+    #[serde(default)]
+    label_selector: Option<String>,
+    #[serde(default = "default_namespace")]
+    namespace: String,
+    #[serde(default = "default_count")]
+    count: usize,
+}
+
+fn default_namespace() -> String {
+    "default".to_string()
+}
+fn default_count() -> usize {
+    1
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PodEvictUndoState {
+    namespace: String,
+    label_selector: Option<String>,
+    evicted_pods: Vec<EvictedPodInfo>,
+    /// Pods whose eviction a disruption budget refused.
+    blocked_pods: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EvictedPodInfo {
+    name: String,
+    namespace: String,
+    owner_kind: Option<String>,
+    owner_name: Option<String>,
+}
+
+#[async_trait]
+impl Skill for PodEvictSkill {
+    fn descriptor(&self) -> SkillDescriptor {
+        SkillDescriptor {
+            name: "k8s.pod_evict".into(),
+            description: "Evict random pods via the eviction API, respecting PodDisruptionBudgets".into(),
+            target: TargetDomain::Kubernetes,
+            reversible: true,
+        }
+    }
+
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "label_selector": { "type": "string" },
+                "namespace": { "type": "string", "default": "default" },
+                "count": { "type": "integer", "default": 1 }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
+    fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
+        let params: PodEvictParams = parse_params("pod_evict", params)?;
+        if let Some(ref selector) = params.label_selector {
+            validate_label_selector(selector).map_err(ChaosError::Config)?;
+        }
+        Ok(())
+    }
+
+    async fn preflight(&self, ctx: &SkillContext) -> ChaosResult<()> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<PodEvictParams>()?;
+
+        let running = list_running_pods(client, &params.namespace, params.label_selector.as_deref()).await?;
+        if running.is_empty() {
+            return Err(ChaosError::Config(match &params.label_selector {
+                Some(selector) => format!(
+                    "pod_evict label_selector '{selector}' matches no running pods in namespace '{}'",
+                    params.namespace
+                ),
+                None => format!("pod_evict found no running pods in namespace '{}'", params.namespace),
+            }));
+        }
+        Ok(())
+    }
+
+    async fn inspect(&self, ctx: &SkillContext) -> ChaosResult<Vec<String>> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<PodEvictParams>()?;
+
+        let running_pods = list_running_pods(client, &params.namespace, params.label_selector.as_deref()).await?;
+        let budgets = list_budgets(client, &params.namespace).await?;
+        let mut lines = vec![format!(
+            "would evict {} of {} running pods, chosen at random:",
+            params.count.min(running_pods.len()),
+            running_pods.len()
+        )];
+        lines.extend(running_pods.iter().map(|p| {
+            format!(
+                "  {}/{} ({})",
+                p.metadata.namespace.as_deref().unwrap_or(&params.namespace),
+                p.metadata.name.as_deref().unwrap_or("unknown"),
+                budgets_summary(&covering_budgets(&budgets, p))
+            )
+        }));
+        Ok(lines)
+    }
+
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<PodEvictParams>()?;
+
+        let pods: Api<Pod> = Api::namespaced(client.clone(), &params.namespace);
+        let running_pods = list_running_pods(client, &params.namespace, params.label_selector.as_deref()).await?;
+
+        if running_pods.is_empty() {
+            return Err(ChaosError::Discovery("No running pods found".into()));
+        }
+
+        let count = ctx.clamp("pods", params.count, ctx.limits.max_pods);
+        let targets: Vec<_> = {
+            let mut rng = rand::thread_rng();
+            running_pods
+                .choose_multiple(&mut rng, count.min(running_pods.len()))
+                .cloned()
+                .collect()
+        };
+
+        // The budgets as they stood before any eviction
+        let budgets = list_budgets(client, &params.namespace).await?;
+        let mut covered = Vec::new();
+        for pod in &targets {
+            for budget in covering_budgets(&budgets, pod) {
+                let name = budget.metadata.name.clone().unwrap_or_default();
+                if !covered.contains(&name) {
+                    ctx.output.push(format!("disruption budget {name}"), budget_summary(budget));
+                    covered.push(name);
+                }
+            }
+        }
+        if covered.is_empty() {
+            ctx.warn(format!(
+                "No PodDisruptionBudget covers the chosen pods in namespace '{}'; eviction won't be limited",
+                params.namespace
+            ));
+        }
+
+        if ctx.dry_run {
+            for pod in &targets {
+                ctx.record_planned(format!(
+                    "Evict pod {}/{} ({})",
+                    pod.metadata.namespace.as_deref().unwrap_or(&params.namespace),
+                    pod.metadata.name.as_deref().unwrap_or("unknown"),
+                    budgets_summary(&covering_budgets(&budgets, pod))
+                ));
+            }
+            return Ok(RollbackHandle::dry_run("k8s.pod_evict"));
+        }
+
+        let mut evicted = Vec::new();
+        let mut blocked = Vec::new();
+        for pod in &targets {
+            let pod_name = pod.metadata.name.as_deref().unwrap_or("unknown");
+            let namespace = pod
+                .metadata
+                .namespace
+                .as_deref()
+                .unwrap_or(&params.namespace);
+            let owner_ref = pod
+                .metadata
+                .owner_references
+                .as_ref()
+                .and_then(|refs| refs.first());
+
+            match pods.evict(pod_name, &EvictParams::default()).await {
+                Ok(_) => {
+                    ctx.record_action(format!("Evicted pod {namespace}/{pod_name}"));
+                    evicted.push(EvictedPodInfo {
+                        name: pod_name.to_string(),
+                        namespace: namespace.to_string(),
+                        owner_kind: owner_ref.map(|r| r.kind.clone()),
+                        owner_name: owner_ref.map(|r| r.name.clone()),
+                    });
+                }
+                // The API answers 429 when the eviction would break a budget
+                Err(kube::Error::Api(e)) if e.code == 429 => {
+                    ctx.record_action(format!(
+                        "Eviction of pod {namespace}/{pod_name} refused by disruption budget: {}",
+                        e.message
+                    ));
+                    blocked.push(format!("{namespace}/{pod_name}"));
+                }
+                Err(e) => {
+                    tracing::error!(pod = %pod_name, error = %e, "Failed to evict pod");
+                    ctx.warn(format!("Failed to evict pod {namespace}/{pod_name}: {e}"));
+                }
+            }
+        }
+
+        tracing::info!(
+            evicted = evicted.len(),
+            blocked = blocked.len(),
+            "Pod eviction finished"
+        );
+
+        let undo = PodEvictUndoState {
+            namespace: params.namespace.clone(),
+            label_selector: params.label_selector.clone(),
+            evicted_pods: evicted,
+            blocked_pods: blocked,
+        };
+        let undo_state = serde_yaml::to_value(&undo)
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
+
+        Ok(RollbackHandle::new("k8s.pod_evict", undo_state))
+    }
+
+    async fn rollback(&self, ctx: &SkillContext, handle: &RollbackHandle) -> ChaosResult<()> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let undo: PodEvictUndoState = serde_yaml::from_value(handle.undo_state.clone())
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
+
+        if undo.evicted_pods.is_empty() {
+            return Ok(());
+        }
+
+        // Owned pods are recreated by their controller; check they came back
+        for pod_info in undo.evicted_pods.iter().filter(|p| p.owner_kind.is_none()) {
+            ctx.warn(format!(
+                "Pod {}/{} had no owner and won't be recreated; manual intervention may be needed",
+                pod_info.namespace, pod_info.name
+            ));
+        }
+        match list_running_pods(client, &undo.namespace, undo.label_selector.as_deref()).await {
+            Ok(running) => {
+                let replaced = running
+                    .iter()
+                    .filter(|p| {
+                        !undo
+                            .evicted_pods
+                            .iter()
+                            .any(|e| p.metadata.name.as_deref() == Some(e.name.as_str()))
+                    })
+                    .count();
+                tracing::info!(
+                    evicted = undo.evicted_pods.len(),
+                    blocked = undo.blocked_pods.len(),
+                    running_pods = replaced,
+                    "Verified pods running after eviction"
+                );
+                ctx.output.push(
+                    "recovery",
+                    format!(
+                        "{} pod(s) evicted, {} refused by a disruption budget; {replaced} matching pod(s) running now",
+                        undo.evicted_pods.len(),
+                        undo.blocked_pods.len()
+                    ),
+                );
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to verify pod recovery");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+async fn list_budgets(client: &Client, namespace: &str) -> ChaosResult<Vec<PodDisruptionBudget>> {
+    let budgets: Api<PodDisruptionBudget> = Api::namespaced(client.clone(), namespace);
+    Ok(budgets
+        .list(&ListParams::default())
+        .await
+        .map_err(|e| ChaosError::Discovery(format!("Failed to list PodDisruptionBudgets: {e}")))?
+        .items)
+}
+
+/// The budgets whose selector matches `pod`.
+fn covering_budgets<'a>(budgets: &'a [PodDisruptionBudget], pod: &Pod) -> Vec<&'a PodDisruptionBudget> {
+    let labels = pod.metadata.labels.clone().unwrap_or_default();
+    budgets
+        .iter()
+        .filter(|b| {
+            b.spec
+                .as_ref()
+                .and_then(|s| s.selector.as_ref())
+                .is_some_and(|selector| selector_matches(selector, &labels))
+        })
+        .collect()
+}
+
+/// e.g. `min_available 2, 1 disruption allowed, 3/2 healthy`.
+fn budget_summary(budget: &PodDisruptionBudget) -> String {
+    let limit = |value: &IntOrString| match value {
+        IntOrString::Int(n) => n.to_string(),
+        IntOrString::String(s) => s.clone(),
+    };
+    let spec = budget.spec.as_ref();
+    let mut parts = Vec::new();
+    if let Some(min) = spec.and_then(|s| s.min_available.as_ref()) {
+        parts.push(format!("min_available {}", limit(min)));
+    }
+    if let Some(max) = spec.and_then(|s| s.max_unavailable.as_ref()) {
+        parts.push(format!("max_unavailable {}", limit(max)));
+    }
+    if let Some(status) = budget.status.as_ref() {
+        parts.push(format!("{} disruption(s) allowed", status.disruptions_allowed));
+        parts.push(format!("{}/{} healthy", status.current_healthy, status.desired_healthy));
+    }
+    parts.join(", ")
+}
+
+/// e.g. `budget web-pdb: 1 disruption(s) allowed`, or `no budget`.
+fn budgets_summary(budgets: &[&PodDisruptionBudget]) -> String {
+    if budgets.is_empty() {
+        return "no budget".to_string();
+    }
+    budgets
+        .iter()
+        .map(|b| {
+            format!(
+                "budget {}: {} disruption(s) allowed",
+                b.metadata.name.as_deref().unwrap_or("unknown"),
+                b.status.as_ref().map_or(0, |s| s.disruptions_allowed)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
@@ -1,13 +1,17 @@
 use async_trait::async_trait;
+use chaos_core::duration::parse_duration;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use k8s_openapi::api::core::v1::Pod;
-use kube::api::{Api, DeleteParams, ListParams};
+use k8s_openapi::chrono::{DateTime, Utc};
+use kube::api::{Api, DeleteParams, ListParams, LogParams};
 use kube::Client;
 use rand::seq::SliceRandom;
 use serde::{Deserialize, Serialize};
 
+use crate::selector::validate_label_selector;
+
 pub struct PodKillSkill;
 
 #[derive(Debug, Deserialize)]
@@ -18,6 +22,15 @@ struct PodKillParams {
     namespace: String,
     #[serde(default = "default_count")]
     count: usize,
+    /// Attach pod logs to the report: the victims' tail before the kill and
+    /// the surviving/replacement pods' logs since the kill at rollback.
+    #[serde(default)]
+    capture_logs: bool,
+    #[serde(default = "default_log_tail_lines")]
+    log_tail_lines: i64,
+    /// Only capture pre-kill logs newer than this, e.g. "5m".
+    #[serde(default)]
+    log_since: Option<String>,
 }
 
 fn default_namespace() -> String {
@@ -26,10 +39,23 @@ fn default_namespace() -> String {
 fn default_count() -> usize {
     1
 }
+fn default_log_tail_lines() -> i64 {
+    50
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 struct PodKillUndoState {
     killed_pods: Vec<KilledPodInfo>,
+    #[serde(default)]
+    log_capture: Option<LogCapture>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LogCapture {
+    namespace: String,
+    label_selector: Option<String>,
+    tail_lines: i64,
+    killed_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -52,57 +78,123 @@ impl Skill for PodKillSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "label_selector": { "type": "string" },
+                "namespace": { "type": "string", "default": "default" },
+                "count": { "type": "integer", "default": 1 },
+                "capture_logs": { "type": "boolean", "default": false, "description": "Attach pod logs before the kill and after recovery to the report" },
+                "log_tail_lines": { "type": "integer", "default": 50 },
+                "log_since": { "type": "string", "description": "Only capture pre-kill logs newer than this, e.g. 5m" }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: PodKillParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid pod_kill params: {e}")))?;
+        let params: PodKillParams = parse_params("pod_kill", params)?;
+        if let Some(ref selector) = params.label_selector {
+            validate_label_selector(selector).map_err(ChaosError::Config)?;
+        }
+        if let Some(ref since) = params.log_since {
+            parse_duration(since)?;
+        }
         Ok(())
     }
 
-    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+    async fn preflight(&self, ctx: &SkillContext) -> ChaosResult<()> {
         let client = ctx
             .shared
             .downcast_ref::<Client>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
 
-        let params: PodKillParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
-
-        let pods: Api<Pod> = Api::namespaced(client.clone(), &params.namespace);
+        let params = ctx.typed_params::<PodKillParams>()?;
 
-        let mut lp = ListParams::default();
-        if let Some(ref selector) = params.label_selector {
-            lp = lp.labels(selector);
+        // Only running pods are killed, so only they count as a match
+        let running = list_running_pods(client, &params.namespace, params.label_selector.as_deref()).await?;
+        if running.is_empty() {
+            return Err(ChaosError::Config(match &params.label_selector {
+                Some(selector) => format!(
+                    "pod_kill label_selector '{selector}' matches no running pods in namespace '{}'",
+                    params.namespace
+                ),
+                None => format!("pod_kill found no running pods in namespace '{}'", params.namespace),
+            }));
         }
+        Ok(())
+    }
+
+    async fn inspect(&self, ctx: &SkillContext) -> ChaosResult<Vec<String>> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<PodKillParams>()?;
+
+        let running_pods = list_running_pods(client, &params.namespace, params.label_selector.as_deref()).await?;
+        let mut lines = vec![format!(
+            "would delete {} of {} running pods, chosen at random:",
+            params.count.min(running_pods.len()),
+            running_pods.len()
+        )];
+        lines.extend(running_pods.iter().map(|p| {
+            format!(
+                "  {}/{}",
+                p.metadata.namespace.as_deref().unwrap_or(&params.namespace),
+                p.metadata.name.as_deref().unwrap_or("unknown")
+            )
+        }));
+        Ok(lines)
+    }
 
-        let pod_list = pods
-            .list(&lp)
-            .await
-            .map_err(|e| ChaosError::Discovery(format!("Failed to list pods: {e}")))?;
-
-        let running_pods: Vec<_> = pod_list
-            .items
-            .iter()
-            .filter(|p| {
-                p.status
-                    .as_ref()
-                    .and_then(|s| s.phase.as_deref())
-                    == Some("Running")
-            })
-            .collect();
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<PodKillParams>()?;
+
+        let pods: Api<Pod> = Api::namespaced(client.clone(), &params.namespace);
+        let running_pods = list_running_pods(client, &params.namespace, params.label_selector.as_deref()).await?;
 
         if running_pods.is_empty() {
             return Err(ChaosError::Discovery("No running pods found".into()));
         }
 
+        let count = ctx.clamp("pods", params.count, ctx.limits.max_pods);
         let targets: Vec<_> = {
             let mut rng = rand::thread_rng();
             running_pods
-                .choose_multiple(&mut rng, params.count.min(running_pods.len()))
+                .choose_multiple(&mut rng, count.min(running_pods.len()))
                 .cloned()
                 .collect()
         };
 
+        if ctx.dry_run {
+            for pod in &targets {
+                ctx.record_planned(format!(
+                    "Delete pod {}/{}",
+                    pod.metadata.namespace.as_deref().unwrap_or(&params.namespace),
+                    pod.metadata.name.as_deref().unwrap_or("unknown")
+                ));
+            }
+            return Ok(RollbackHandle::dry_run("k8s.pod_kill"));
+        }
+
         let mut killed = Vec::new();
+        let since_seconds = params
+            .log_since
+            .as_deref()
+            .map(parse_duration)
+            .transpose()?
+            .map(|d| d.as_secs() as i64);
 
         for pod in &targets {
             let pod_name = pod.metadata.name.as_deref().unwrap_or("unknown");
@@ -127,9 +219,19 @@ impl Skill for PodKillSkill {
                 owner_name: owner_ref.map(|r| r.name.clone()),
             };
 
+            if params.capture_logs {
+                let lp = LogParams {
+                    tail_lines: Some(params.log_tail_lines),
+                    since_seconds,
+                    ..LogParams::default()
+                };
+                capture_pod_logs(&pods, pod_name, &lp, &format!("logs before kill: {pod_name}"), ctx)
+                    .await;
+            }
+
             match pods.delete(pod_name, &DeleteParams::default()).await {
                 Ok(_) => {
-                    tracing::info!(pod = %pod_name, namespace = %namespace, "Pod killed");
+                    ctx.record_action(format!("Deleted pod {namespace}/{pod_name}"));
                     killed.push(info);
                 }
                 Err(e) => {
@@ -138,8 +240,15 @@ impl Skill for PodKillSkill {
             }
         }
 
+        let log_capture = params.capture_logs.then(|| LogCapture {
+            namespace: params.namespace.clone(),
+            label_selector: params.label_selector.clone(),
+            tail_lines: params.log_tail_lines,
+            killed_at: Utc::now(),
+        });
         let undo = PodKillUndoState {
             killed_pods: killed,
+            log_capture,
         };
         let undo_state = serde_yaml::to_value(&undo)
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
@@ -186,13 +295,92 @@ impl Skill for PodKillSkill {
                     }
                 }
             } else {
-                tracing::warn!(
-                    pod = %pod_info.name,
-                    "Pod had no owner; cannot auto-recover. Manual intervention may be needed."
-                );
+                ctx.warn(format!(
+                    "Pod {} had no owner and won't be recreated; manual intervention may be needed",
+                    pod_info.name
+                ));
             }
         }
 
+        if let Some(ref capture) = undo.log_capture {
+            capture_post_kill_logs(client, capture, ctx).await;
+        }
+
         Ok(())
     }
 }
+
+/// Attach the logs of the pods now matching the selector, covering the window
+/// since the kill (the soak period).
+async fn capture_post_kill_logs(client: &Client, capture: &LogCapture, ctx: &SkillContext) {
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &capture.namespace);
+    let mut lp = ListParams::default();
+    if let Some(ref selector) = capture.label_selector {
+        lp = lp.labels(selector);
+    }
+    let list = match pods.list(&lp).await {
+        Ok(list) => list,
+        Err(e) => {
+            ctx.warn(format!("Failed to list pods for log capture: {e}"));
+            return;
+        }
+    };
+
+    let since = (Utc::now() - capture.killed_at).num_seconds().max(1);
+    let log_params = LogParams {
+        tail_lines: Some(capture.tail_lines),
+        since_seconds: Some(since),
+        ..LogParams::default()
+    };
+    for pod in &list.items {
+        let Some(name) = pod.metadata.name.as_deref() else {
+            continue;
+        };
+        capture_pod_logs(&pods, name, &log_params, &format!("logs since kill: {name}"), ctx).await;
+    }
+}
+
+async fn capture_pod_logs(
+    pods: &Api<Pod>,
+    name: &str,
+    log_params: &LogParams,
+    label: &str,
+    ctx: &SkillContext,
+) {
+    match pods.logs(name, log_params).await {
+        Ok(logs) if logs.trim().is_empty() => ctx.output.push(label, "(no output)"),
+        Ok(logs) => ctx.output.push(label, logs),
+        Err(e) => ctx.warn(format!("Failed to fetch logs for pod {name}: {e}")),
+    }
+}
+
+/// Running pods in `namespace` matching `label_selector`, which `execute`
+/// picks its victims from.
+pub(crate) async fn list_running_pods(
+    client: &Client,
+    namespace: &str,
+    label_selector: Option<&str>,
+) -> ChaosResult<Vec<Pod>> {
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+
+    let mut lp = ListParams::default();
+    if let Some(selector) = label_selector {
+        lp = lp.labels(selector);
+    }
+
+    let pod_list = pods
+        .list(&lp)
+        .await
+        .map_err(|e| ChaosError::Discovery(format!("Failed to list pods: {e}")))?;
+
+    Ok(pod_list
+        .items
+        .into_iter()
+        .filter(|p| {
+            p.status
+                .as_ref()
+                .and_then(|s| s.phase.as_deref())
+                == Some("Running")
+        })
+        .collect())
+}
@@ -1,32 +1,63 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
 use async_trait::async_trait;
+use chaos_core::cleanup::EXPERIMENT_ID_LABEL;
+use chaos_core::duration::parse_duration;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
-use k8s_openapi::api::core::v1::Pod;
-use kube::api::{Api, DeleteParams, PostParams};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::core::v1::{Pod, PodSpec};
+use kube::api::{Api, DeleteParams, ListParams, ObjectMeta, Patch, PatchParams, PostParams};
 use kube::Client;
+use rand::seq::SliceRandom;
 use serde::{Deserialize, Serialize};
 
+use crate::selector::validate_label_selector;
+use crate::skills::pod_kill::list_running_pods;
+
+/// Run stress-ng against CPU and memory: in a pod of its own, as a sidecar
+/// in a deployment's pods, or as an ephemeral container in running pods
+/// picked by label selector, so the load lands in those pods' own limits.
 pub struct ResourceStressSkill;
 
 #[derive(Debug, Deserialize)]
 struct ResourceStressParams {
     #[serde(default = "default_namespace")]
     namespace: String,
+    /// Deployment to add a stress sidecar to. Triggers a rollout.
+    #[serde(default)]
+    deployment: Option<String>,
+    /// Running pods to inject an ephemeral stress container into.
+    #[serde(default)]
+    label_selector: Option<String>,
+    /// How many of the `label_selector` pods to stress.
+    #[serde(default = "default_count")]
+    count: usize,
     /// CPU stress workers (number of stress-ng CPU workers)
-    #[serde(default = "default_cpu_workers")]
+    #[serde(default = "default_cpu_workers", alias = "cpu_cores")]
     cpu_workers: u32,
     /// Memory to consume, e.g. "256M"
     #[serde(default = "default_memory")]
     memory: String,
+    /// Memory to consume in MiB; overrides `memory`.
+    #[serde(default)]
+    mem_mb: Option<u32>,
     /// stress-ng image to use
     #[serde(default = "default_image")]
     image: String,
+    /// Safety cap; stress-ng exits by itself after this.
+    #[serde(default = "default_max_duration")]
+    max_duration: String,
 }
 
 fn default_namespace() -> String {
     "default".to_string()
 }
+fn default_count() -> usize {
+    1
+}
 fn default_cpu_workers() -> u32 {
     2
 }
@@ -36,11 +67,68 @@ fn default_memory() -> String {
 fn default_image() -> String {
     "alexeiled/stress-ng:latest".to_string()
 }
+fn default_max_duration() -> String {
+    "1h".to_string()
+}
+
+/// Name of the injected sidecar or ephemeral container.
+const STRESS_CONTAINER: &str = "chaos-stress";
+/// How long rollback waits for the target pods to be running again.
+const RECOVERY_TIMEOUT: Duration = Duration::from_secs(120);
+const RECOVERY_POLL: Duration = Duration::from_secs(2);
+
+impl ResourceStressParams {
+    fn memory(&self) -> String {
+        self.mem_mb.map_or_else(|| self.memory.clone(), |mb| format!("{mb}M"))
+    }
 
+    fn command(&self, timeout: Duration) -> Vec<String> {
+        vec![
+            "stress-ng".into(),
+            "--cpu".into(),
+            self.cpu_workers.to_string(),
+            "--vm".into(),
+            "1".into(),
+            "--vm-bytes".into(),
+            self.memory(),
+            "--timeout".into(),
+            format!("{}s", timeout.as_secs().max(1)),
+        ]
+    }
+}
+
+// Untagged so handles from before the deployment and pod modes still parse.
 #[derive(Debug, Serialize, Deserialize)]
-struct ResourceStressUndoState {
-    pod_name: String,
-    namespace: String,
+#[serde(untagged)]
+enum ResourceStressUndoState {
+    StressPod {
+        pod_name: String,
+        namespace: String,
+    },
+    Sidecar {
+        deployment: String,
+        namespace: String,
+        /// The pod template's containers before the sidecar was added.
+        original_containers: serde_json::Value,
+        /// The deployment's `matchLabels`, to find its pods at rollback.
+        pod_selector: String,
+    },
+    Ephemeral {
+        namespace: String,
+        label_selector: Option<String>,
+        injected_pods: Vec<InjectedPodInfo>,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct InjectedPodInfo {
+    name: String,
+    owner_kind: Option<String>,
+    owner_name: Option<String>,
+    /// The pod as it was before injection, for pods without an owner, which
+    /// rollback recreates itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    original: Option<serde_json::Value>,
 }
 
 #[async_trait]
@@ -48,74 +136,93 @@ impl Skill for ResourceStressSkill {
     fn descriptor(&self) -> SkillDescriptor {
         SkillDescriptor {
             name: "k8s.resource_stress".into(),
-            description: "Deploy a stress-ng pod to consume cluster resources".into(),
+            description: "Run stress-ng in its own pod, a deployment sidecar or running pods to consume CPU and memory".into(),
             target: TargetDomain::Kubernetes,
             reversible: true,
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "namespace": { "type": "string", "default": "default" },
+                "deployment": { "type": "string", "description": "Add a stress sidecar to this deployment's pods (triggers a rollout)" },
+                "label_selector": { "type": "string", "description": "Inject an ephemeral stress container into running pods matching this selector" },
+                "count": { "type": "integer", "default": 1, "description": "How many label_selector pods to stress" },
+                "cpu_workers": { "type": "integer", "default": 2, "description": "stress-ng CPU workers (alias: cpu_cores)" },
+                "memory": { "type": "string", "default": "256M" },
+                "mem_mb": { "type": "integer", "description": "Memory to consume in MiB; overrides memory" },
+                "image": { "type": "string", "default": "alexeiled/stress-ng:latest" },
+                "max_duration": { "type": "string", "default": "1h", "description": "Safety cap; stress-ng exits by itself after this" }
+            }
+        })
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: ResourceStressParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid resource_stress params: {e}")))?;
+        let params: ResourceStressParams = parse_params("resource_stress", params)?;
+        parse_duration(&params.max_duration)?;
+        if params.deployment.is_some() && params.label_selector.is_some() {
+            return Err(ChaosError::Config(
+                "resource_stress takes either deployment or label_selector, not both".into(),
+            ));
+        }
+        if let Some(ref selector) = params.label_selector {
+            validate_label_selector(selector).map_err(ChaosError::Config)?;
+        }
+        if params.mem_mb == Some(0) {
+            return Err(ChaosError::Config("resource_stress mem_mb must be at least 1".into()));
+        }
         Ok(())
     }
 
-    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+    async fn preflight(&self, ctx: &SkillContext) -> ChaosResult<()> {
         let client = ctx
             .shared
             .downcast_ref::<Client>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
 
-        let params: ResourceStressParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
-
-        let pod_name = format!("chaos-stress-{}", uuid::Uuid::new_v4().as_simple());
+        let params = ctx.typed_params::<ResourceStressParams>()?;
 
-        let stress_pod: Pod = serde_json::from_value(serde_json::json!({
-            "apiVersion": "v1",
-            "kind": "Pod",
-            "metadata": {
-                "name": pod_name,
-                "namespace": params.namespace,
-                "labels": {
-                    "app.kubernetes.io/managed-by": "chaos-agents",
-                    "chaos-agents/type": "resource-stress"
-                }
-            },
-            "spec": {
-                "restartPolicy": "Never",
-                "containers": [{
-                    "name": "stress",
-                    "image": params.image,
-                    "command": [
-                        "stress-ng",
-                        "--cpu", params.cpu_workers.to_string(),
-                        "--vm", "1",
-                        "--vm-bytes", params.memory,
-                        "--timeout", "3600s"
-                    ]
-                }]
+        if let Some(ref deployment) = params.deployment {
+            let api: Api<Deployment> = Api::namespaced(client.clone(), &params.namespace);
+            api.get(deployment).await.map_err(|e| {
+                ChaosError::Config(format!(
+                    "resource_stress deployment '{}/{deployment}' not found: {e}",
+                    params.namespace
+                ))
+            })?;
+        } else if let Some(ref selector) = params.label_selector {
+            let running = list_running_pods(client, &params.namespace, Some(selector)).await?;
+            if running.is_empty() {
+                return Err(ChaosError::Config(format!(
+                    "resource_stress label_selector '{selector}' matches no running pods in namespace '{}'",
+                    params.namespace
+                )));
             }
-        }))
-        .map_err(|e| ChaosError::Other(anyhow::anyhow!("Build stress pod: {e}")))?;
+        }
+        Ok(())
+    }
 
-        let pods: Api<Pod> = Api::namespaced(client.clone(), &params.namespace);
-        pods.create(&PostParams::default(), &stress_pod)
-            .await
-            .map_err(|e| {
-                ChaosError::Other(anyhow::anyhow!("Failed to create stress pod: {e}"))
-            })?;
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<ResourceStressParams>()?;
+        let max_duration = parse_duration(&params.max_duration)?;
 
-        tracing::info!(
-            pod = %pod_name,
-            cpu = params.cpu_workers,
-            memory = %params.memory,
-            "Stress pod deployed"
-        );
+        let undo = if let Some(ref deployment) = params.deployment {
+            stress_deployment(client, ctx, &params, deployment, max_duration).await?
+        } else if let Some(ref selector) = params.label_selector {
+            stress_pods(client, ctx, &params, selector, max_duration).await?
+        } else {
+            stress_pod(client, ctx, &params, max_duration).await?
+        };
 
-        let undo = ResourceStressUndoState {
-            pod_name,
-            namespace: params.namespace,
+        let Some(undo) = undo else {
+            return Ok(RollbackHandle::dry_run("k8s.resource_stress"));
         };
         let undo_state = serde_yaml::to_value(&undo)
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
@@ -132,20 +239,401 @@ impl Skill for ResourceStressSkill {
         let undo: ResourceStressUndoState = serde_yaml::from_value(handle.undo_state.clone())
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
 
-        let pods: Api<Pod> = Api::namespaced(client.clone(), &undo.namespace);
+        match undo {
+            ResourceStressUndoState::StressPod { pod_name, namespace } => {
+                let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+                match pods.delete(&pod_name, &DeleteParams::default()).await {
+                    Ok(_) => {
+                        tracing::info!(pod = %pod_name, "Stress pod deleted (rollback)");
+                    }
+                    Err(e) => {
+                        tracing::error!(pod = %pod_name, error = %e, "Failed to delete stress pod");
+                    }
+                }
+            }
+            ResourceStressUndoState::Sidecar {
+                deployment,
+                namespace,
+                original_containers,
+                pod_selector,
+            } => {
+                let api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+                // A merge patch replaces the list wholesale, dropping the sidecar.
+                let patch = serde_json::json!({
+                    "spec": { "template": { "spec": { "containers": original_containers } } }
+                });
+                match api
+                    .patch(&deployment, &PatchParams::default(), &Patch::Merge(&patch))
+                    .await
+                {
+                    Ok(_) => tracing::info!(deployment = %deployment, "Stress sidecar removed (rollback)"),
+                    Err(e) => {
+                        tracing::error!(deployment = %deployment, error = %e, "Failed to remove stress sidecar");
+                        return Ok(());
+                    }
+                }
+                verify_recovery(client, ctx, &namespace, Some(&pod_selector)).await;
+            }
+            ResourceStressUndoState::Ephemeral {
+                namespace,
+                label_selector,
+                injected_pods,
+            } => {
+                // Ephemeral containers can't be removed from a pod, so the
+                // pod is replaced: by its controller if it has one, otherwise
+                // recreated here from the spec it had before injection.
+                let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+                for pod_info in &injected_pods {
+                    if let Err(e) = pods.delete(&pod_info.name, &DeleteParams::default()).await {
+                        tracing::error!(pod = %pod_info.name, error = %e, "Failed to delete stressed pod");
+                        continue;
+                    }
+                    match pod_info.original {
+                        None => tracing::info!(
+                            pod = %pod_info.name,
+                            owner = ?pod_info.owner_name,
+                            "Stressed pod deleted for its owner to replace (rollback)"
+                        ),
+                        Some(ref original) => {
+                            if let Err(e) = recreate_pod(&pods, &pod_info.name, original).await {
+                                ctx.warn(format!(
+                                    "Pod {namespace}/{} had no owner and couldn't be recreated: {e}; manual intervention may be needed",
+                                    pod_info.name
+                                ));
+                            }
+                        }
+                    }
+                }
+                verify_recovery(client, ctx, &namespace, label_selector.as_deref()).await;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Standalone stress pod, consuming node rather than workload resources.
+async fn stress_pod(
+    client: &Client,
+    ctx: &SkillContext,
+    params: &ResourceStressParams,
+    max_duration: Duration,
+) -> ChaosResult<Option<ResourceStressUndoState>> {
+    if ctx.dry_run {
+        ctx.record_planned(format!(
+            "Create a stress-ng pod in '{}' with {} CPU workers and {} of memory load",
+            params.namespace,
+            params.cpu_workers,
+            params.memory()
+        ));
+        return Ok(None);
+    }
+
+    let pod_name = format!("chaos-stress-{}", uuid::Uuid::new_v4().as_simple());
 
+    let stress_pod: Pod = serde_json::from_value(serde_json::json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": pod_name,
+            "namespace": params.namespace,
+            "labels": {
+                "app.kubernetes.io/managed-by": "chaos-agents",
+                EXPERIMENT_ID_LABEL: ctx.experiment_id.to_string(),
+                "chaos-agents/type": "resource-stress"
+            }
+        },
+        "spec": {
+            "restartPolicy": "Never",
+            "containers": [{
+                "name": "stress",
+                "image": params.image,
+                "command": params.command(max_duration)
+            }]
+        }
+    }))
+    .map_err(|e| ChaosError::Other(anyhow::anyhow!("Build stress pod: {e}")))?;
+
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &params.namespace);
+    pods.create(&PostParams::default(), &stress_pod)
+        .await
+        .map_err(|e| {
+            ChaosError::Other(anyhow::anyhow!("Failed to create stress pod: {e}"))
+        })?;
+
+    tracing::info!(
+        pod = %pod_name,
+        cpu = params.cpu_workers,
+        memory = %params.memory(),
+        "Stress pod deployed"
+    );
+
+    Ok(Some(ResourceStressUndoState::StressPod {
+        pod_name,
+        namespace: params.namespace.clone(),
+    }))
+}
+
+/// Add a stress sidecar to the deployment's pod template.
+async fn stress_deployment(
+    client: &Client,
+    ctx: &SkillContext,
+    params: &ResourceStressParams,
+    name: &str,
+    max_duration: Duration,
+) -> ChaosResult<Option<ResourceStressUndoState>> {
+    let api: Api<Deployment> = Api::namespaced(client.clone(), &params.namespace);
+    let deployment = api.get(name).await.map_err(|e| {
+        ChaosError::Other(anyhow::anyhow!("Failed to get deployment {}/{name}: {e}", params.namespace))
+    })?;
+    let spec = deployment
+        .spec
+        .ok_or_else(|| ChaosError::Other(anyhow::anyhow!("Deployment {name} has no spec")))?;
+    let containers = spec.template.spec.map(|s| s.containers).unwrap_or_default();
+    if containers.iter().any(|c| c.name == STRESS_CONTAINER) {
+        return Err(ChaosError::Config(format!(
+            "Deployment {}/{name} already has a {STRESS_CONTAINER} container; roll back the earlier run first",
+            params.namespace
+        )));
+    }
+    let pod_selector = selector_string(&spec.selector.match_labels.unwrap_or_default());
+
+    if ctx.dry_run {
+        ctx.record_planned(format!(
+            "Add a stress-ng sidecar to deployment {}/{name} with {} CPU workers and {} of memory load (triggers a rollout)",
+            params.namespace,
+            params.cpu_workers,
+            params.memory()
+        ));
+        return Ok(None);
+    }
+
+    let original_containers = serde_json::to_value(&containers)
+        .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize containers: {e}")))?;
+    let mut patched = original_containers.clone();
+    if let Some(list) = patched.as_array_mut() {
+        list.push(serde_json::json!({
+            "name": STRESS_CONTAINER,
+            "image": params.image,
+            "command": params.command(max_duration)
+        }));
+    }
+    let patch = serde_json::json!({
+        "spec": { "template": { "spec": { "containers": patched } } }
+    });
+    api.patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map_err(|e| ChaosError::Other(anyhow::anyhow!("Failed to add stress sidecar: {e}")))?;
+
+    ctx.record_action(format!(
+        "Added a stress-ng sidecar to deployment {}/{name} ({} CPU workers, {} memory)",
+        params.namespace,
+        params.cpu_workers,
+        params.memory()
+    ));
+
+    Ok(Some(ResourceStressUndoState::Sidecar {
+        deployment: name.to_string(),
+        namespace: params.namespace.clone(),
+        original_containers,
+        pod_selector,
+    }))
+}
+
+/// Inject an ephemeral stress container into `count` of the running pods
+/// matching `selector`.
+async fn stress_pods(
+    client: &Client,
+    ctx: &SkillContext,
+    params: &ResourceStressParams,
+    selector: &str,
+    max_duration: Duration,
+) -> ChaosResult<Option<ResourceStressUndoState>> {
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &params.namespace);
+    let running_pods = list_running_pods(client, &params.namespace, Some(selector)).await?;
+
+    if running_pods.is_empty() {
+        return Err(ChaosError::Discovery("No running pods found".into()));
+    }
+
+    let count = ctx.clamp("pods", params.count, ctx.limits.max_pods);
+    let targets: Vec<_> = {
+        let mut rng = rand::thread_rng();
+        running_pods
+            .choose_multiple(&mut rng, count.min(running_pods.len()))
+            .cloned()
+            .collect()
+    };
+
+    if ctx.dry_run {
+        for pod in &targets {
+            ctx.record_planned(format!(
+                "Inject a stress-ng ephemeral container into pod {}/{} with {} CPU workers and {} of memory load",
+                params.namespace,
+                pod.metadata.name.as_deref().unwrap_or("unknown"),
+                params.cpu_workers,
+                params.memory()
+            ));
+        }
+        return Ok(None);
+    }
+
+    let mut injected = Vec::new();
+    for pod in &targets {
+        let pod_name = pod.metadata.name.as_deref().unwrap_or("unknown");
+        let owner_ref = pod
+            .metadata
+            .owner_references
+            .as_ref()
+            .and_then(|refs| refs.first());
+
+        let original = match owner_ref {
+            Some(_) => None,
+            None => Some(
+                serde_json::to_value(pod)
+                    .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize pod: {e}")))?,
+            ),
+        };
+
+        let patch = serde_json::json!({
+            "spec": { "ephemeralContainers": [{
+                "name": STRESS_CONTAINER,
+                "image": params.image,
+                "command": params.command(max_duration)
+            }]}
+        });
         match pods
-            .delete(&undo.pod_name, &DeleteParams::default())
+            .patch_ephemeral_containers(pod_name, &PatchParams::default(), &Patch::Strategic(patch))
             .await
         {
             Ok(_) => {
-                tracing::info!(pod = %undo.pod_name, "Stress pod deleted (rollback)");
+                ctx.record_action(format!(
+                    "Injected a stress-ng ephemeral container into pod {}/{pod_name}",
+                    params.namespace
+                ));
+                injected.push(InjectedPodInfo {
+                    name: pod_name.to_string(),
+                    owner_kind: owner_ref.map(|r| r.kind.clone()),
+                    owner_name: owner_ref.map(|r| r.name.clone()),
+                    original,
+                });
             }
             Err(e) => {
-                tracing::error!(pod = %undo.pod_name, error = %e, "Failed to delete stress pod");
+                ctx.warn(format!("Failed to inject stress container into pod {pod_name}: {e}"));
             }
         }
+    }
 
-        Ok(())
+    if injected.is_empty() {
+        return Err(ChaosError::Other(anyhow::anyhow!(
+            "resource_stress couldn't inject a stress container into any of the chosen pods"
+        )));
+    }
+
+    Ok(Some(ResourceStressUndoState::Ephemeral {
+        namespace: params.namespace.clone(),
+        label_selector: Some(selector.to_string()),
+        injected_pods: injected,
+    }))
+}
+
+/// Recreate an ownerless pod from its pre-injection state, once the stressed
+/// one is gone.
+async fn recreate_pod(pods: &Api<Pod>, name: &str, original: &serde_json::Value) -> anyhow::Result<()> {
+    let original: Pod = serde_json::from_value(original.clone())?;
+    let spec = original.spec.map(|spec| PodSpec {
+        ephemeral_containers: None,
+        // Let the scheduler place it again
+        node_name: None,
+        ..spec
+    });
+    let pod = Pod {
+        metadata: ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: original.metadata.namespace,
+            labels: original.metadata.labels,
+            annotations: original.metadata.annotations,
+            ..ObjectMeta::default()
+        },
+        spec,
+        status: None,
+    };
+
+    let deadline = tokio::time::Instant::now() + RECOVERY_TIMEOUT;
+    while pods.get_opt(name).await?.is_some() {
+        if tokio::time::Instant::now() >= deadline {
+            anyhow::bail!("the stressed pod was still terminating after {}s", RECOVERY_TIMEOUT.as_secs());
+        }
+        tokio::time::sleep(RECOVERY_POLL).await;
+    }
+    pods.create(&PostParams::default(), &pod).await?;
+    tracing::info!(pod = %name, "Stressed pod recreated without the stress container (rollback)");
+    Ok(())
+}
+
+/// Wait until every pod matching `selector` is running without the stress
+/// container, and report the outcome.
+async fn verify_recovery(client: &Client, ctx: &SkillContext, namespace: &str, selector: Option<&str>) {
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let mut lp = ListParams::default();
+    if let Some(selector) = selector {
+        lp = lp.labels(selector);
     }
+
+    let deadline = tokio::time::Instant::now() + RECOVERY_TIMEOUT;
+    loop {
+        let (running, pending) = match pods.list(&lp).await {
+            Ok(list) => {
+                let live: Vec<_> = list
+                    .items
+                    .iter()
+                    .filter(|p| p.metadata.deletion_timestamp.is_none())
+                    .collect();
+                let running = live.iter().filter(|p| is_recovered(p)).count();
+                (running, live.len() - running)
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to verify pod recovery");
+                return;
+            }
+        };
+        if running > 0 && pending == 0 {
+            tracing::info!(running_pods = running, "Verified pods running without the stress container");
+            ctx.output.push(
+                "recovery",
+                format!("{running} matching pod(s) running without the stress container"),
+            );
+            return;
+        }
+        if tokio::time::Instant::now() >= deadline {
+            ctx.warn(format!(
+                "After {}s, {running} matching pod(s) in '{namespace}' are running without the stress container and {pending} are not",
+                RECOVERY_TIMEOUT.as_secs()
+            ));
+            return;
+        }
+        tokio::time::sleep(RECOVERY_POLL).await;
+    }
+}
+
+fn is_recovered(pod: &Pod) -> bool {
+    let running = pod.status.as_ref().and_then(|s| s.phase.as_deref()) == Some("Running");
+    let stressed = pod.spec.as_ref().is_some_and(|spec| {
+        spec.containers.iter().any(|c| c.name == STRESS_CONTAINER)
+            || spec
+                .ephemeral_containers
+                .iter()
+                .flatten()
+                .any(|c| c.name == STRESS_CONTAINER)
+    });
+    running && !stressed
+}
+
+/// `matchLabels` as a label selector, e.g. `app=web,tier=front`.
+fn selector_string(labels: &BTreeMap<String, String>) -> String {
+    labels
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join(",")
 }
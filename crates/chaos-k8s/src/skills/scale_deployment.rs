@@ -0,0 +1,301 @@
+use async_trait::async_trait;
+use chaos_core::error::{ChaosError, ChaosResult};
+use chaos_core::rollback::RollbackHandle;
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
+use k8s_openapi::api::apps::v1::Deployment;
+use kube::api::{Api, ListParams};
+use kube::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::selector::validate_label_selector;
+use crate::skills::dns_failure::set_replicas;
+
+/// Scale deployments down, e.g. to see whether an autoscaler or operator
+/// brings them back. Rollback restores the original replica counts.
+pub struct ScaleDeploymentSkill;
+
+#[derive(Debug, Deserialize)]
+struct ScaleDeploymentParams {
+    /// Deployment to scale.
+    #[serde(default)]
+    name: Option<String>,
+    /// Deployments to scale, by their own labels.
+    #[serde(default)]
+    label_selector: Option<String>,
+    #[serde(default = "default_namespace")]
+    namespace: String,
+    /// Replica count to scale to. Default: 0.
+    #[serde(default)]
+    replicas: i32,
+    /// Allow scaling deployments in system namespaces such as kube-system.
+    #[serde(default)]
+    allow_system: bool,
+}
+
+fn default_namespace() -> String {
+    "default".to_string()
+}
+
+/// Namespaces whose deployments keep the cluster itself running.
+const SYSTEM_NAMESPACES: &[&str] = &["kube-system", "kube-public", "kube-node-lease"];
+
+fn is_system_namespace(namespace: &str) -> bool {
+    SYSTEM_NAMESPACES.contains(&namespace)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ScaleDeploymentUndoState {
+    namespace: String,
+    scaled: Vec<ScaledDeployment>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ScaledDeployment {
+    name: String,
+    /// Replica count before the scale-down.
+    replicas: i32,
+}
+
+#[async_trait]
+impl Skill for ScaleDeploymentSkill {
+    fn descriptor(&self) -> SkillDescriptor {
+        SkillDescriptor {
+            name: "k8s.scale_deployment".into(),
+            description: "Scale deployments down to fewer replicas, rollback restores the original count".into(),
+            target: TargetDomain::Kubernetes,
+            reversible: true,
+        }
+    }
+
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "description": "Deployment to scale" },
+                "label_selector": { "type": "string", "description": "Scale every deployment with these labels" },
+                "namespace": { "type": "string", "default": "default" },
+                "replicas": { "type": "integer", "default": 0, "description": "Replica count to scale down to" },
+                "allow_system": { "type": "boolean", "default": false, "description": "Allow kube-system, kube-public and kube-node-lease" }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
+    fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
+        let params: ScaleDeploymentParams = parse_params("scale_deployment", params)?;
+        match (&params.name, &params.label_selector) {
+            (None, None) => {
+                return Err(ChaosError::Config(
+                    "scale_deployment needs a deployment name or a label_selector".into(),
+                ))
+            }
+            (Some(_), Some(_)) => {
+                return Err(ChaosError::Config(
+                    "scale_deployment takes either name or label_selector, not both".into(),
+                ))
+            }
+            (None, Some(selector)) => validate_label_selector(selector).map_err(ChaosError::Config)?,
+            (Some(_), None) => {}
+        }
+        if params.replicas < 0 {
+            return Err(ChaosError::Config("scale_deployment replicas can't be negative".into()));
+        }
+        if is_system_namespace(&params.namespace) && !params.allow_system {
+            return Err(ChaosError::Config(format!(
+                "scale_deployment refuses to scale deployments in system namespace '{}' unless allow_system is set",
+                params.namespace
+            )));
+        }
+        Ok(())
+    }
+
+    async fn preflight(&self, ctx: &SkillContext) -> ChaosResult<()> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<ScaleDeploymentParams>()?;
+        if target_deployments(client, &params).await?.is_empty() {
+            return Err(ChaosError::Config(format!(
+                "scale_deployment label_selector '{}' matches no deployments in namespace '{}'",
+                params.label_selector.as_deref().unwrap_or_default(),
+                params.namespace
+            )));
+        }
+        Ok(())
+    }
+
+    async fn inspect(&self, ctx: &SkillContext) -> ChaosResult<Vec<String>> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<ScaleDeploymentParams>()?;
+        let deployments = target_deployments(client, &params).await?;
+        let mut lines = vec![format!(
+            "would scale {} deployment(s) to {} replica(s):",
+            deployments.len(),
+            params.replicas
+        )];
+        lines.extend(deployments.iter().map(|d| {
+            format!(
+                "  {}/{} ({} replica(s) now)",
+                params.namespace,
+                d.metadata.name.as_deref().unwrap_or("unknown"),
+                current_replicas(d)
+            )
+        }));
+        Ok(lines)
+    }
+
+    async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let params = ctx.typed_params::<ScaleDeploymentParams>()?;
+        let api: Api<Deployment> = Api::namespaced(client.clone(), &params.namespace);
+        let deployments = target_deployments(client, &params).await?;
+        if deployments.is_empty() {
+            return Err(ChaosError::Discovery("No deployments found".into()));
+        }
+
+        let mut targets = Vec::new();
+        for deployment in &deployments {
+            let name = deployment.metadata.name.as_deref().unwrap_or("unknown");
+            let replicas = current_replicas(deployment);
+            if replicas <= params.replicas {
+                ctx.warn(format!(
+                    "Deployment {}/{name} already has {replicas} replica(s), skipped",
+                    params.namespace
+                ));
+                continue;
+            }
+            targets.push((name, replicas));
+        }
+
+        // Every replica scaled away is a pod gone, so max_pods caps the total
+        let requested: usize = targets
+            .iter()
+            .map(|(_, replicas)| (replicas - params.replicas) as usize)
+            .sum();
+        let mut budget = ctx.clamp("pods", requested, ctx.limits.max_pods);
+
+        let mut scaled = Vec::new();
+        for (name, replicas) in targets {
+            let cut = ((replicas - params.replicas) as usize).min(budget);
+            if cut == 0 {
+                ctx.warn(format!(
+                    "Deployment {}/{name} left at {replicas} replica(s) by the blast-radius limit",
+                    params.namespace
+                ));
+                continue;
+            }
+            budget -= cut;
+            let target = replicas - cut as i32;
+            if ctx.dry_run {
+                ctx.record_planned(format!(
+                    "Scale deployment {}/{name} from {replicas} to {target} replica(s)",
+                    params.namespace
+                ));
+                continue;
+            }
+            match set_replicas(&api, name, target).await {
+                Ok(()) => {
+                    ctx.record_action(format!(
+                        "Scaled deployment {}/{name} from {replicas} to {target} replica(s)",
+                        params.namespace
+                    ));
+                    scaled.push(ScaledDeployment {
+                        name: name.to_string(),
+                        replicas,
+                    });
+                }
+                Err(e) => ctx.warn(format!(
+                    "Failed to scale deployment {}/{name}: {e}",
+                    params.namespace
+                )),
+            }
+        }
+
+        if ctx.dry_run {
+            return Ok(RollbackHandle::dry_run("k8s.scale_deployment"));
+        }
+
+        let undo = ScaleDeploymentUndoState {
+            namespace: params.namespace.clone(),
+            scaled,
+        };
+        let undo_state = serde_yaml::to_value(&undo)
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
+
+        Ok(RollbackHandle::new("k8s.scale_deployment", undo_state))
+    }
+
+    async fn rollback(&self, ctx: &SkillContext, handle: &RollbackHandle) -> ChaosResult<()> {
+        let client = ctx
+            .shared
+            .downcast_ref::<Client>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected kube::Client")))?;
+
+        let undo: ScaleDeploymentUndoState = serde_yaml::from_value(handle.undo_state.clone())
+            .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
+
+        let api: Api<Deployment> = Api::namespaced(client.clone(), &undo.namespace);
+        for deployment in &undo.scaled {
+            // Note what an autoscaler or operator did in the meantime
+            if let Ok(current) = api.get(&deployment.name).await {
+                ctx.output.push(
+                    format!("replicas before rollback: {}", deployment.name),
+                    current_replicas(&current).to_string(),
+                );
+            }
+            match set_replicas(&api, &deployment.name, deployment.replicas).await {
+                Ok(()) => ctx.record_action(format!(
+                    "Restored deployment {}/{} to {} replica(s)",
+                    undo.namespace, deployment.name, deployment.replicas
+                )),
+                Err(e) => ctx.warn(format!(
+                    "Failed to restore deployment {}/{} to {} replica(s): {e}",
+                    undo.namespace, deployment.name, deployment.replicas
+                )),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The deployment named in `params`, or those matching its selector.
+async fn target_deployments(client: &Client, params: &ScaleDeploymentParams) -> ChaosResult<Vec<Deployment>> {
+    let api: Api<Deployment> = Api::namespaced(client.clone(), &params.namespace);
+    if let Some(ref name) = params.name {
+        let deployment = api.get(name).await.map_err(|e| {
+            ChaosError::Config(format!(
+                "scale_deployment deployment '{}/{name}' not found: {e}",
+                params.namespace
+            ))
+        })?;
+        return Ok(vec![deployment]);
+    }
+
+    let mut lp = ListParams::default();
+    if let Some(ref selector) = params.label_selector {
+        lp = lp.labels(selector);
+    }
+    Ok(api
+        .list(&lp)
+        .await
+        .map_err(|e| ChaosError::Discovery(format!("Failed to list deployments: {e}")))?
+        .items)
+}
+
+fn current_replicas(deployment: &Deployment) -> i32 {
+    deployment.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1)
+}
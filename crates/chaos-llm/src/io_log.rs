@@ -0,0 +1,98 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Writes every provider request/response pair to its own JSON file, and/or
+/// as one line of a JSONL trace, for debugging what actually went over the
+/// wire.
+#[derive(Debug)]
+pub struct IoLogger {
+    dir: Option<PathBuf>,
+    trace: Option<Mutex<std::fs::File>>,
+    seq: AtomicU64,
+}
+
+/// One logged exchange. `secret` is scrubbed from the file before writing.
+pub struct Exchange<'a> {
+    pub provider: &'a str,
+    pub url: &'a str,
+    pub request: &'a serde_json::Value,
+    pub status: u16,
+    /// Raw response body; logged as JSON when it parses, as a string otherwise.
+    pub response: &'a str,
+    pub secret: Option<&'a str>,
+}
+
+impl IoLogger {
+    /// Log into `dir`, creating it if needed.
+    pub fn new(dir: &Path) -> std::io::Result<Self> {
+        std::fs::create_dir_all(dir)?;
+        Ok(Self {
+            dir: Some(dir.to_path_buf()),
+            trace: None,
+            seq: AtomicU64::new(1),
+        })
+    }
+
+    /// Log only to the JSONL trace at `path`, truncating it.
+    pub fn trace_file(path: &Path) -> std::io::Result<Self> {
+        Ok(Self {
+            dir: None,
+            trace: Some(Mutex::new(std::fs::File::create(path)?)),
+            seq: AtomicU64::new(1),
+        })
+    }
+
+    /// Also append every exchange to the JSONL trace at `path`, truncating it.
+    pub fn with_trace_file(mut self, path: &Path) -> std::io::Result<Self> {
+        self.trace = Some(Mutex::new(std::fs::File::create(path)?));
+        Ok(self)
+    }
+
+    /// Write `<timestamp>-<seq>-<provider>.json` and/or a trace line. Failures
+    /// are logged, not returned: debugging output must never break a planning
+    /// session.
+    pub fn log(&self, exchange: &Exchange<'_>) {
+        let seq = self.seq.fetch_add(1, Ordering::Relaxed);
+        let now = chrono::Utc::now();
+        let response = serde_json::from_str(exchange.response)
+            .unwrap_or_else(|_| serde_json::Value::String(exchange.response.to_string()));
+        let record = serde_json::json!({
+            "provider": exchange.provider,
+            "url": exchange.url,
+            "seq": seq,
+            "timestamp": now.to_rfc3339(),
+            "request": exchange.request,
+            "status": exchange.status,
+            "response": response,
+        });
+
+        let secret = exchange.secret.filter(|s| !s.is_empty());
+        let scrub = |text: String| match secret {
+            Some(secret) => text.replace(secret, "[REDACTED]"),
+            None => text,
+        };
+
+        if let Some(ref trace) = self.trace {
+            let line = scrub(serde_json::to_string(&record).unwrap_or_default());
+            let mut file = trace.lock().unwrap_or_else(|e| e.into_inner());
+            if let Err(e) = writeln!(file, "{line}") {
+                tracing::warn!(error = %e, "Failed to write LLM trace");
+            }
+        }
+
+        let Some(ref dir) = self.dir else {
+            return;
+        };
+        let text = scrub(serde_json::to_string_pretty(&record).unwrap_or_default());
+        let path = dir.join(format!(
+            "{}-{seq:04}-{}.json",
+            now.format("%Y%m%dT%H%M%S%.3f"),
+            exchange.provider
+        ));
+        if let Err(e) = std::fs::write(&path, text) {
+            tracing::warn!(path = %path.display(), error = %e, "Failed to write LLM I/O log");
+        }
+    }
+}
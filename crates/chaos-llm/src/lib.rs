@@ -1,4 +1,7 @@
+pub mod io_log;
 pub mod provider;
 pub mod tool;
 pub mod mcp;
 pub mod planner;
+pub mod pricing;
+pub mod target_hint;
@@ -1,6 +1,13 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command};
+use tokio::sync::{mpsc, oneshot, watch};
 
 use crate::tool::{Tool, ToolDefinition, ToolRegistry};
 
@@ -26,7 +33,8 @@ pub enum McpTransport {
         #[serde(default)]
         args: Vec<String>,
     },
-    /// SSE-based MCP server.
+    /// SSE-based MCP server: `url` is its event stream, which names the
+    /// endpoint requests are POSTed to.
     Sse { url: String },
 }
 
@@ -51,22 +59,28 @@ impl McpClient {
                 tracing::info!(
                     name = %self.config.name,
                     command = %command,
+                    args = ?args,
                     "Initializing stdio MCP server"
                 );
-                // In a full implementation, this would:
-                // 1. Spawn the child process
-                // 2. Send initialize request via JSON-RPC over stdin/stdout
-                // 3. Call tools/list to discover available tools
-                // 4. Create McpToolProxy for each discovered tool
-
-                // For now, log the intent - the actual MCP protocol implementation
-                // would use the JSON-RPC protocol over stdio.
+                let mut session = StdioSession::spawn(&self.config.name, command, args, &self.config.env)?;
+                session.handshake().await.map_err(|e| {
+                    anyhow::anyhow!("MCP server '{}' failed to initialize: {e}", self.config.name)
+                })?;
+                let definitions = session.list_tools().await.map_err(|e| {
+                    anyhow::anyhow!("MCP server '{}' failed to list tools: {e}", self.config.name)
+                })?;
                 tracing::info!(
                     name = %self.config.name,
-                    command = %command,
-                    args = ?args,
-                    "MCP stdio server configured (connect on first tool call)"
+                    tools = definitions.len(),
+                    "MCP stdio server ready"
                 );
+                let session = Arc::new(tokio::sync::Mutex::new(session));
+                self.tools = definitions
+                    .into_iter()
+                    .map(|definition| {
+                        McpToolProxy::new_stdio(self.config.name.clone(), definition, session.clone())
+                    })
+                    .collect();
             }
             McpTransport::Sse { url } => {
                 tracing::info!(
@@ -74,10 +88,23 @@ impl McpClient {
                     url = %url,
                     "Initializing SSE MCP server"
                 );
-                // In a full implementation, this would:
-                // 1. Connect to the SSE endpoint
-                // 2. Send initialize request
-                // 3. Discover tools
+                let session = SseSession::connect(&self.config.name, url)?;
+                session.ensure_initialized().await.map_err(|e| {
+                    anyhow::anyhow!("MCP server '{}' failed to initialize: {e}", self.config.name)
+                })?;
+                let definitions = session.list_tools().await.map_err(|e| {
+                    anyhow::anyhow!("MCP server '{}' failed to list tools: {e}", self.config.name)
+                })?;
+                tracing::info!(
+                    name = %self.config.name,
+                    tools = definitions.len(),
+                    "MCP SSE server ready"
+                );
+                let session = Arc::new(session);
+                self.tools = definitions
+                    .into_iter()
+                    .map(|definition| McpToolProxy::new_sse(self.config.name.clone(), definition, session.clone()))
+                    .collect();
             }
         }
         Ok(())
@@ -96,6 +123,569 @@ impl McpClient {
     }
 }
 
+/// MCP protocol version sent in `initialize`.
+const PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// How long to wait for an MCP server to answer a request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Params of the `initialize` request.
+fn initialize_params() -> serde_json::Value {
+    serde_json::json!({
+        "protocolVersion": PROTOCOL_VERSION,
+        "capabilities": {},
+        "clientInfo": {
+            "name": "chaos-agents",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+    })
+}
+
+/// The `result` of a JSON-RPC response, or its `error`.
+fn response_result(response: serde_json::Value) -> anyhow::Result<serde_json::Value> {
+    if let Some(error) = response.get("error") {
+        anyhow::bail!("MCP error: {}", error);
+    }
+    Ok(response.get("result").cloned().unwrap_or(serde_json::Value::Null))
+}
+
+/// Add the tools of one `tools/list` page to `definitions`, returning the
+/// cursor of the next page.
+fn add_tool_page(result: &serde_json::Value, definitions: &mut Vec<ToolDefinition>) -> Option<String> {
+    for tool in result["tools"].as_array().into_iter().flatten() {
+        let Some(name) = tool["name"].as_str() else {
+            continue;
+        };
+        definitions.push(ToolDefinition {
+            name: name.to_string(),
+            description: tool["description"].as_str().unwrap_or_default().to_string(),
+            parameters: match tool.get("inputSchema") {
+                Some(schema) if schema.is_object() => schema.clone(),
+                _ => serde_json::json!({ "type": "object" }),
+            },
+        });
+    }
+    result["nextCursor"]
+        .as_str()
+        .filter(|next| !next.is_empty())
+        .map(str::to_string)
+}
+
+/// Params of a `tools/list` request for the page at `cursor`.
+fn tool_page_params(cursor: Option<&str>) -> serde_json::Value {
+    match cursor {
+        Some(cursor) => serde_json::json!({ "cursor": cursor }),
+        None => serde_json::json!({}),
+    }
+}
+
+/// The text of a `tools/call` result; an error when the server flagged it
+/// with `isError`, so the planner sees a failed tool call.
+fn tool_call_text(result: serde_json::Value) -> anyhow::Result<String> {
+    let Some(content) = result["content"].as_array() else {
+        return Ok(result.to_string());
+    };
+    let text = content
+        .iter()
+        .filter_map(|c| c["text"].as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if result["isError"].as_bool() == Some(true) {
+        anyhow::bail!("{text}");
+    }
+    Ok(text)
+}
+
+/// How long a stdio MCP server gets to exit after its stdin is closed
+/// before it is killed.
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(2);
+
+/// A running stdio MCP server: JSON-RPC messages are written to its stdin
+/// and read from its stdout one per line. Calls are serialized by holding
+/// the session's lock for a whole request and its response. When dropped,
+/// its stdin is closed so the server can exit on its own, and it is killed
+/// if it hasn't within [`SHUTDOWN_GRACE`].
+pub struct StdioSession {
+    server_name: String,
+    child: Option<Child>,
+    stdin: Option<ChildStdin>,
+    stdout: BufReader<ChildStdout>,
+    next_id: u64,
+}
+
+impl StdioSession {
+    /// Start `command`, forwarding its stderr (where servers log) to tracing.
+    fn spawn(
+        server_name: &str,
+        command: &str,
+        args: &[String],
+        env: &HashMap<String, String>,
+    ) -> anyhow::Result<Self> {
+        let mut child = Command::new(command)
+            .args(args)
+            .envs(env)
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| anyhow::anyhow!("Failed to start MCP server '{server_name}' ({command}): {e}"))?;
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout is piped"));
+        if let Some(stderr) = child.stderr.take() {
+            let server = server_name.to_string();
+            tokio::spawn(async move {
+                let mut lines = BufReader::new(stderr).lines();
+                while let Ok(Some(line)) = lines.next_line().await {
+                    tracing::debug!(server = %server, "{line}");
+                }
+            });
+        }
+        Ok(Self {
+            server_name: server_name.to_string(),
+            child: Some(child),
+            stdin: Some(stdin),
+            stdout,
+            next_id: 1,
+        })
+    }
+
+    /// `initialize`, then the `notifications/initialized` notification.
+    async fn handshake(&mut self) -> anyhow::Result<()> {
+        let result = self.request("initialize", initialize_params()).await?;
+        tracing::debug!(
+            server = %self.server_name,
+            protocol = %result["protocolVersion"],
+            info = %result["serverInfo"],
+            "MCP server initialized"
+        );
+        self.send(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/initialized",
+        }))
+        .await
+    }
+
+    /// Every tool the server offers, following `nextCursor` pages.
+    async fn list_tools(&mut self) -> anyhow::Result<Vec<ToolDefinition>> {
+        let mut definitions = Vec::new();
+        let mut cursor = None;
+        loop {
+            let result = self.request("tools/list", tool_page_params(cursor.as_deref())).await?;
+            cursor = add_tool_page(&result, &mut definitions);
+            if cursor.is_none() {
+                return Ok(definitions);
+            }
+        }
+    }
+
+    /// Send a request and wait for the response with its id, skipping
+    /// notifications and anything else the server writes in between.
+    async fn request(&mut self, method: &str, params: serde_json::Value) -> anyhow::Result<serde_json::Value> {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.send(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        }))
+        .await?;
+
+        let response = tokio::time::timeout(REQUEST_TIMEOUT, self.read_response(id))
+            .await
+            .map_err(|_| anyhow::anyhow!("no response to {method} within {}s", REQUEST_TIMEOUT.as_secs()))??;
+        response_result(response)
+    }
+
+    async fn read_response(&mut self, id: u64) -> anyhow::Result<serde_json::Value> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if self.stdout.read_line(&mut line).await? == 0 {
+                anyhow::bail!("MCP server '{}' exited", self.server_name);
+            }
+            let Ok(message) = serde_json::from_str::<serde_json::Value>(line.trim()) else {
+                tracing::debug!(server = %self.server_name, line = %line.trim(), "Ignoring non-JSON output");
+                continue;
+            };
+            if message["id"].as_u64() == Some(id) {
+                return Ok(message);
+            }
+        }
+    }
+
+    async fn send(&mut self, message: &serde_json::Value) -> anyhow::Result<()> {
+        let mut line = serde_json::to_string(message)?;
+        line.push('\n');
+        let stdin = self.stdin.as_mut().expect("stdin is open until drop");
+        stdin.write_all(line.as_bytes()).await?;
+        stdin.flush().await?;
+        Ok(())
+    }
+}
+
+impl Drop for StdioSession {
+    fn drop(&mut self) {
+        // EOF on stdin is the stdio transport's shutdown signal
+        drop(self.stdin.take());
+        let Some(mut child) = self.child.take() else {
+            return;
+        };
+        let server = self.server_name.clone();
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                runtime.spawn(async move {
+                    if tokio::time::timeout(SHUTDOWN_GRACE, child.wait()).await.is_err() {
+                        tracing::debug!(server = %server, "MCP server still running after stdin closed, killing it");
+                        let _ = child.kill().await;
+                    }
+                });
+            }
+            // No runtime left to wait on; kill_on_drop ends the process
+            Err(_) => drop(child),
+        }
+    }
+}
+
+/// Reconnects in a row, without a new endpoint in between, before an SSE MCP
+/// server is given up on.
+const SSE_MAX_RECONNECTS: u32 = 5;
+
+/// Requests waiting for their response on an SSE stream, by id.
+type PendingResponses = Arc<std::sync::Mutex<HashMap<u64, PendingRequest>>>;
+
+/// A request waiting on an SSE stream.
+struct PendingRequest {
+    response: oneshot::Sender<serde_json::Value>,
+    /// `params` of each `notifications/progress` for the request.
+    progress: mpsc::UnboundedSender<serde_json::Value>,
+}
+
+/// A connection to an SSE MCP server. Responses and notifications arrive on
+/// a long-lived `GET` event stream whose `endpoint` event names the URL that
+/// requests are POSTed to. A dropped stream is reconnected in the background,
+/// and the next request initializes the new session first.
+pub struct SseSession {
+    server_name: String,
+    client: reqwest::Client,
+    /// The current stream's generation and POST endpoint; `None` while
+    /// (re)connecting.
+    endpoint: watch::Receiver<Option<(u64, String)>>,
+    pending: PendingResponses,
+    next_id: AtomicU64,
+    /// Generation of the stream the handshake was last done on.
+    initialized: tokio::sync::Mutex<Option<u64>>,
+    reader: tokio::task::JoinHandle<()>,
+}
+
+impl SseSession {
+    /// Open the event stream at `url` in the background.
+    fn connect(server_name: &str, url: &str) -> anyhow::Result<Self> {
+        let url = reqwest::Url::parse(url)
+            .map_err(|e| anyhow::anyhow!("Invalid URL '{url}' for MCP server '{server_name}': {e}"))?;
+        let client = crate::provider::http_client(None)?;
+        let (endpoint_tx, endpoint) = watch::channel(None);
+        let pending = PendingResponses::default();
+        let reader = tokio::spawn(read_events(
+            server_name.to_string(),
+            client.clone(),
+            url,
+            endpoint_tx,
+            pending.clone(),
+        ));
+        Ok(Self {
+            server_name: server_name.to_string(),
+            client,
+            endpoint,
+            pending,
+            next_id: AtomicU64::new(1),
+            initialized: tokio::sync::Mutex::new(None),
+            reader,
+        })
+    }
+
+    /// Send a request on the current session, initializing it first if the
+    /// stream is new.
+    async fn request(&self, method: &str, params: serde_json::Value) -> anyhow::Result<serde_json::Value> {
+        let endpoint = self.ensure_initialized().await?;
+        self.send_request(&endpoint, method, params, None).await
+    }
+
+    /// `tools/call`, passing the `params` of each progress notification the
+    /// server sends before the result to `on_progress`.
+    async fn call_tool(
+        &self,
+        name: &str,
+        arguments: &serde_json::Value,
+        on_progress: &mut (dyn FnMut(&serde_json::Value) + Send),
+    ) -> anyhow::Result<serde_json::Value> {
+        let endpoint = self.ensure_initialized().await?;
+        let params = serde_json::json!({
+            "name": name,
+            "arguments": arguments,
+        });
+        self.send_request(&endpoint, "tools/call", params, Some(on_progress)).await
+    }
+
+    /// Do the `initialize` handshake unless it was done on the current
+    /// stream, returning the stream's POST endpoint.
+    async fn ensure_initialized(&self) -> anyhow::Result<String> {
+        let mut initialized = self.initialized.lock().await;
+        let (generation, endpoint) = self.current_endpoint().await?;
+        if *initialized != Some(generation) {
+            let result = self.send_request(&endpoint, "initialize", initialize_params(), None).await?;
+            tracing::debug!(
+                server = %self.server_name,
+                protocol = %result["protocolVersion"],
+                info = %result["serverInfo"],
+                "MCP server initialized"
+            );
+            self.post(
+                &endpoint,
+                &serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "method": "notifications/initialized",
+                }),
+            )
+            .await?;
+            *initialized = Some(generation);
+        }
+        Ok(endpoint)
+    }
+
+    /// Every tool the server offers, following `nextCursor` pages.
+    async fn list_tools(&self) -> anyhow::Result<Vec<ToolDefinition>> {
+        let mut definitions = Vec::new();
+        let mut cursor = None;
+        loop {
+            let result = self.request("tools/list", tool_page_params(cursor.as_deref())).await?;
+            cursor = add_tool_page(&result, &mut definitions);
+            if cursor.is_none() {
+                return Ok(definitions);
+            }
+        }
+    }
+
+    /// The endpoint of the current stream, waiting for one while connecting.
+    async fn current_endpoint(&self) -> anyhow::Result<(u64, String)> {
+        let mut endpoint = self.endpoint.clone();
+        let current = tokio::time::timeout(REQUEST_TIMEOUT, endpoint.wait_for(Option::is_some))
+            .await
+            .map_err(|_| {
+                anyhow::anyhow!(
+                    "no event stream from MCP server '{}' within {}s",
+                    self.server_name,
+                    REQUEST_TIMEOUT.as_secs()
+                )
+            })?
+            .map_err(|_| anyhow::anyhow!("MCP server '{}' event stream is closed", self.server_name))?;
+        Ok(current.clone().expect("waited for an endpoint"))
+    }
+
+    /// POST a request to `endpoint` and wait for its response on the stream.
+    /// With `on_progress`, the request asks for progress notifications and
+    /// each one also restarts the timeout.
+    async fn send_request(
+        &self,
+        endpoint: &str,
+        method: &str,
+        mut params: serde_json::Value,
+        mut on_progress: Option<&mut (dyn FnMut(&serde_json::Value) + Send)>,
+    ) -> anyhow::Result<serde_json::Value> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        if on_progress.is_some() {
+            params["_meta"] = serde_json::json!({ "progressToken": id });
+        }
+        let (tx, mut rx) = oneshot::channel();
+        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+        self.pending.lock().unwrap().insert(
+            id,
+            PendingRequest {
+                response: tx,
+                progress: progress_tx,
+            },
+        );
+        let message = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        });
+        if let Err(e) = self.post(endpoint, &message).await {
+            self.pending.lock().unwrap().remove(&id);
+            return Err(e);
+        }
+        let mut deadline = tokio::time::Instant::now() + REQUEST_TIMEOUT;
+        let response = loop {
+            tokio::select! {
+                response = &mut rx => match response {
+                    Ok(response) => break response,
+                    Err(_) => anyhow::bail!("event stream dropped before the response to {method}"),
+                },
+                Some(update) = progress_rx.recv() => {
+                    if let Some(ref mut on_progress) = on_progress {
+                        on_progress(&update);
+                    }
+                    // The server is still working on it
+                    deadline = tokio::time::Instant::now() + REQUEST_TIMEOUT;
+                }
+                _ = tokio::time::sleep_until(deadline) => {
+                    self.pending.lock().unwrap().remove(&id);
+                    anyhow::bail!("no response to {method} within {}s", REQUEST_TIMEOUT.as_secs())
+                }
+            }
+        };
+        // Progress routed just before the response
+        while let Ok(update) = progress_rx.try_recv() {
+            if let Some(ref mut on_progress) = on_progress {
+                on_progress(&update);
+            }
+        }
+        response_result(response)
+    }
+
+    async fn post(&self, endpoint: &str, message: &serde_json::Value) -> anyhow::Result<()> {
+        let resp = self.client.post(endpoint).json(message).send().await?;
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await.unwrap_or_default();
+            anyhow::bail!(
+                "MCP server '{}' rejected the message ({status}): {}",
+                self.server_name,
+                crate::provider::body_preview(&text)
+            );
+        }
+        Ok(())
+    }
+}
+
+impl Drop for SseSession {
+    fn drop(&mut self) {
+        self.reader.abort();
+    }
+}
+
+/// Keep the event stream at `url` open, publishing each stream's endpoint
+/// and routing responses to their requests. Reconnects when the stream
+/// drops, failing the requests still waiting on it.
+async fn read_events(
+    server: String,
+    client: reqwest::Client,
+    url: reqwest::Url,
+    endpoint_tx: watch::Sender<Option<(u64, String)>>,
+    pending: PendingResponses,
+) {
+    let mut failures = 0;
+    for generation in 1.. {
+        let outcome = read_stream(&client, &url, generation, &endpoint_tx, &pending, &mut failures).await;
+        endpoint_tx.send_replace(None);
+        // Responses to these would have come on the dropped stream
+        pending.lock().unwrap().clear();
+        match outcome {
+            Ok(()) => tracing::warn!(server = %server, "MCP event stream ended"),
+            Err(e) => tracing::warn!(server = %server, error = %e, "MCP event stream failed"),
+        }
+        failures += 1;
+        if failures > SSE_MAX_RECONNECTS {
+            tracing::error!(server = %server, "Giving up on MCP server after {SSE_MAX_RECONNECTS} reconnects");
+            return;
+        }
+        tokio::time::sleep(Duration::from_secs(failures.into())).await;
+        tracing::info!(server = %server, attempt = failures, "Reconnecting to MCP server");
+    }
+}
+
+/// Read one connection of the event stream until it ends.
+async fn read_stream(
+    client: &reqwest::Client,
+    url: &reqwest::Url,
+    generation: u64,
+    endpoint_tx: &watch::Sender<Option<(u64, String)>>,
+    pending: &PendingResponses,
+    failures: &mut u32,
+) -> anyhow::Result<()> {
+    let mut resp = client
+        .get(url.clone())
+        .header(reqwest::header::ACCEPT, "text/event-stream")
+        .send()
+        .await?;
+    let status = resp.status();
+    if !status.is_success() {
+        anyhow::bail!("GET {url} returned {status}");
+    }
+
+    let mut buffer = Vec::new();
+    let (mut event, mut data) = (String::new(), String::new());
+    loop {
+        while let Some(end) = buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = buffer.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line);
+            let line = line.trim_end_matches(['\n', '\r']);
+            if line.is_empty() {
+                // A blank line ends the event
+                let name = std::mem::take(&mut event);
+                let data = std::mem::take(&mut data);
+                match name.as_str() {
+                    "endpoint" => {
+                        let endpoint = url.join(data.trim())?;
+                        *failures = 0;
+                        endpoint_tx.send_replace(Some((generation, endpoint.to_string())));
+                    }
+                    "" | "message" => route_message(&data, pending),
+                    _ => {}
+                }
+                continue;
+            }
+            let (field, value) = line.split_once(':').unwrap_or((line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            match field {
+                "event" => event = value.to_string(),
+                "data" => {
+                    if !data.is_empty() {
+                        data.push('\n');
+                    }
+                    data.push_str(value);
+                }
+                // Comments (keep-alives), `id` and `retry`
+                _ => {}
+            }
+        }
+        match resp.chunk().await? {
+            Some(bytes) => buffer.extend_from_slice(&bytes),
+            None => return Ok(()),
+        }
+    }
+}
+
+/// Hand a JSON-RPC response, or progress on it, to the request waiting for
+/// it. Other notifications are only logged.
+fn route_message(data: &str, pending: &PendingResponses) {
+    let Ok(message) = serde_json::from_str::<serde_json::Value>(data) else {
+        tracing::debug!(data, "Ignoring non-JSON MCP message");
+        return;
+    };
+    let is_response = message.get("result").is_some() || message.get("error").is_some();
+    match message["id"].as_u64() {
+        Some(id) if is_response => {
+            if let Some(request) = pending.lock().unwrap().remove(&id) {
+                let _ = request.response.send(message);
+            }
+        }
+        _ if message["method"] == "notifications/progress" => {
+            let params = &message["params"];
+            let token = params["progressToken"].as_u64();
+            match token.and_then(|token| pending.lock().unwrap().get(&token).map(|r| r.progress.clone())) {
+                Some(progress) => {
+                    let _ = progress.send(params.clone());
+                }
+                None => tracing::debug!(token = %params["progressToken"], "MCP progress for no pending request"),
+            }
+        }
+        _ => tracing::debug!(method = %message["method"], "MCP notification"),
+    }
+}
+
 /// A proxy tool that forwards calls to an MCP server.
 #[derive(Clone)]
 pub struct McpToolProxy {
@@ -106,136 +696,92 @@ pub struct McpToolProxy {
 
 #[derive(Clone)]
 enum McpTransportHandle {
-    Stdio {
-        command: String,
-        args: Vec<String>,
-        env: HashMap<String, String>,
-    },
-    Sse {
-        url: String,
-    },
+    /// Shared by every tool of the server, so calls reuse one process.
+    Stdio(Arc<tokio::sync::Mutex<StdioSession>>),
+    /// Shared by every tool of the server, so calls reuse one event stream.
+    Sse(Arc<SseSession>),
 }
 
 impl McpToolProxy {
     pub fn new_stdio(
         server_name: String,
         definition: ToolDefinition,
-        command: String,
-        args: Vec<String>,
-        env: HashMap<String, String>,
+        session: Arc<tokio::sync::Mutex<StdioSession>>,
     ) -> Self {
         Self {
             server_name,
             definition,
-            transport: McpTransportHandle::Stdio { command, args, env },
+            transport: McpTransportHandle::Stdio(session),
         }
     }
 
-    pub fn new_sse(server_name: String, definition: ToolDefinition, url: String) -> Self {
+    pub fn new_sse(server_name: String, definition: ToolDefinition, session: Arc<SseSession>) -> Self {
         Self {
             server_name,
             definition,
-            transport: McpTransportHandle::Sse { url },
+            transport: McpTransportHandle::Sse(session),
         }
     }
 
     async fn call_stdio(
         &self,
-        command: &str,
-        args: &[String],
-        env: &HashMap<String, String>,
+        session: &tokio::sync::Mutex<StdioSession>,
         tool_name: &str,
         arguments: &serde_json::Value,
     ) -> anyhow::Result<String> {
-        use tokio::process::Command;
-
-        let request = serde_json::json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "method": "tools/call",
-            "params": {
-                "name": tool_name,
-                "arguments": arguments,
-            }
-        });
-
-        let request_str = serde_json::to_string(&request)?;
-
-        let mut cmd = Command::new(command);
-        cmd.args(args)
-            .envs(env)
-            .stdin(std::process::Stdio::piped())
-            .stdout(std::process::Stdio::piped())
-            .stderr(std::process::Stdio::piped());
-
-        let mut child = cmd.spawn()?;
-
-        // Write request to stdin
-        if let Some(mut stdin) = child.stdin.take() {
-            use tokio::io::AsyncWriteExt;
-            stdin.write_all(request_str.as_bytes()).await?;
-            stdin.write_all(b"\n").await?;
-            drop(stdin);
-        }
-
-        let output = child.wait_with_output().await?;
-        let stdout = String::from_utf8_lossy(&output.stdout);
-
-        // Parse JSON-RPC response
-        if let Ok(response) = serde_json::from_str::<serde_json::Value>(&stdout) {
-            if let Some(result) = response.get("result") {
-                if let Some(content) = result["content"].as_array() {
-                    let text: String = content
-                        .iter()
-                        .filter_map(|c| c["text"].as_str())
-                        .collect::<Vec<_>>()
-                        .join("\n");
-                    return Ok(text);
-                }
-                return Ok(result.to_string());
-            }
-            if let Some(error) = response.get("error") {
-                anyhow::bail!("MCP error: {}", error);
-            }
-        }
+        let result = session
+            .lock()
+            .await
+            .request(
+                "tools/call",
+                serde_json::json!({
+                    "name": tool_name,
+                    "arguments": arguments,
+                }),
+            )
+            .await?;
 
-        Ok(stdout.to_string())
+        tool_call_text(result)
     }
 
     async fn call_sse(
         &self,
-        url: &str,
+        session: &SseSession,
         tool_name: &str,
         arguments: &serde_json::Value,
     ) -> anyhow::Result<String> {
-        let client = reqwest::Client::new();
-        let request = serde_json::json!({
-            "jsonrpc": "2.0",
-            "id": 1,
-            "method": "tools/call",
-            "params": {
-                "name": tool_name,
-                "arguments": arguments,
-            }
-        });
-
-        let resp = client
-            .post(url)
-            .json(&request)
-            .send()
+        let mut progress = Vec::new();
+        let result = session
+            .call_tool(tool_name, arguments, &mut |update| {
+                tracing::info!(
+                    server = %self.server_name,
+                    tool = %tool_name,
+                    progress = %update["progress"],
+                    total = %update["total"],
+                    "MCP tool progress"
+                );
+                if let Some(message) = update["message"].as_str() {
+                    progress.push(message.to_string());
+                }
+            })
             .await?;
-
-        let body: serde_json::Value = resp.json().await?;
-        if let Some(result) = body.get("result") {
-            Ok(result.to_string())
-        } else if let Some(error) = body.get("error") {
-            anyhow::bail!("MCP error: {}", error)
-        } else {
-            Ok(body.to_string())
+        match tool_call_text(result) {
+            Ok(text) => Ok(with_progress(&progress, &text)),
+            Err(e) => Err(anyhow::anyhow!(with_progress(&progress, &e.to_string()))),
         }
     }
 }
 
+/// `text` after the progress messages a tool reported while running, so the
+/// planner also sees its partial results.
+fn with_progress(progress: &[String], text: &str) -> String {
+    if progress.is_empty() {
+        return text.to_string();
+    }
+    let lines: Vec<String> = progress.iter().map(|m| format!("- {m}")).collect();
+    format!("Progress:\n{}\n\n{text}", lines.join("\n"))
+}
+
 #[async_trait]
 impl Tool for McpToolProxy {
     fn definition(&self) -> ToolDefinition {
@@ -250,12 +796,11 @@ impl Tool for McpToolProxy {
         );
 
         match &self.transport {
-            McpTransportHandle::Stdio { command, args, env } => {
-                self.call_stdio(command, args, env, &self.definition.name, &arguments)
-                    .await
+            McpTransportHandle::Stdio(session) => {
+                self.call_stdio(session, &self.definition.name, &arguments).await
             }
-            McpTransportHandle::Sse { url } => {
-                self.call_sse(url, &self.definition.name, &arguments).await
+            McpTransportHandle::Sse(session) => {
+                self.call_sse(session, &self.definition.name, &arguments).await
             }
         }
     }
@@ -1,22 +1,94 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use chaos_core::config::{DefaultDurations, TargetSet};
+use chaos_core::duration::parse_duration;
+use chaos_core::experiment::ExperimentConfig;
+use chaos_core::report::{DiscoveredResourceSummary, ExperimentReport};
+use chaos_core::skill::TargetDomain;
+use chaos_core::target_config::validate_target_config;
+use futures::TryStreamExt;
+use tokio_util::sync::CancellationToken;
+
+use crate::io_log::IoLogger;
 use crate::mcp::McpClient;
 use crate::provider::{
-    create_provider, ChatMessage, FinishReason, LlmProvider, LlmProviderConfig, Role,
+    create_provider, ChatMessage, FinishReason, LlmProvider, LlmProviderConfig, LlmResponse, Role,
+    StreamChunk, TokenUsage, ToolCall, ToolChoice,
 };
+use crate::target_hint::extract_target_config_from_prompt;
 use crate::tool::{
     DiscoverResourcesTool, ListSkillsTool, RunExperimentTool, ToolDefinition, ToolRegistry,
+    ToolResult,
 };
 
 /// Events emitted during LLM planning for UI consumption.
 #[derive(Debug, Clone)]
 pub enum PlannerEvent {
     TurnStarted { turn: u32, max_turns: u32 },
+    /// Text of the assistant message being generated; an `AssistantMessage`
+    /// with the whole of it follows.
+    AssistantDelta { text: String },
     AssistantMessage { content: String },
     ToolCallStarted { name: String, arguments: serde_json::Value },
     ToolCallCompleted { name: String, result: String, is_error: bool },
     ExperimentPlanned { name: String, target: String },
     DiscoveryResult { target: String, resource_count: usize },
     PlanningComplete { turns: u32, experiment_count: usize },
+    /// Planning stopped by the cancel token; `experiment_count` were planned but not run.
+    PlanningCancelled { turns: u32, experiment_count: usize },
     TokenUsage { input_tokens: u32, output_tokens: u32 },
+    /// A `run_experiment` call had no target, and the planner filled one in.
+    TargetConfigInjected { experiment: String, source: InjectionSource },
+    /// A message sent through [`ChaosPlanner::set_user_input_channel`] was
+    /// added to the conversation, ahead of the next turn.
+    UserMessageInjected { content: String },
+    /// [`ChaosPlanner::summarize`] sent the reports to the model.
+    SummaryStarted { report_count: usize },
+    SummaryCompleted { summary: String },
+    SummaryFailed { error: String },
+}
+
+/// Where the planner took the target of a `run_experiment` call that named
+/// none.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InjectionSource {
+    /// The named target an earlier discovery of the same domain used.
+    DiscoveredRef { target: String, name: String },
+    /// The `target_config` an earlier discovery of the same domain used.
+    Discovery { target: String },
+    /// The only target discovered so far, used although the call was for
+    /// `requested` (or named no domain).
+    OnlyDiscoveredTarget { target: String, requested: Option<String> },
+    /// A connection URL found in the user prompt.
+    Prompt,
+}
+
+impl std::fmt::Display for InjectionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DiscoveredRef { target, name } => {
+                write!(f, "named target '{name}' used by the {target} discovery")
+            }
+            Self::Discovery { target } => write!(f, "the {target} discovery's target_config"),
+            Self::OnlyDiscoveredTarget { target, requested } => {
+                write!(f, "the only discovered target ({target})")?;
+                match requested {
+                    Some(requested) => write!(f, ", although the call was for {requested}"),
+                    None => Ok(()),
+                }
+            }
+            Self::Prompt => write!(f, "a connection URL in the prompt"),
+        }
+    }
+}
+
+/// A tool call waiting for the user in step-through mode. Reply `true` to
+/// run it or `false` (or drop `reply`) to deny it.
+pub struct ToolCallApproval {
+    pub name: String,
+    pub arguments: serde_json::Value,
+    pub reply: tokio::sync::oneshot::Sender<bool>,
 }
 
 /// The LLM-driven chaos planner.
@@ -33,11 +105,28 @@ pub struct ChaosPlanner {
     max_turns: u32,
     verbose: bool,
     event_tx: Option<tokio::sync::mpsc::UnboundedSender<PlannerEvent>>,
+    /// Extra user messages to steer planning while it runs.
+    user_rx: Option<tokio::sync::mpsc::UnboundedReceiver<String>>,
+    /// Step-through mode: every tool call waits for approval on this channel.
+    approval_tx: Option<tokio::sync::mpsc::UnboundedSender<ToolCallApproval>>,
+    cancel: Option<CancellationToken>,
+    context_limit: Option<usize>,
+    require_discovery_first: bool,
+    default_durations: DefaultDurations,
+    idle_limit: u32,
+    /// Named targets the model may use via `target_ref`, with descriptions.
+    named_targets: BTreeMap<String, (TargetDomain, Option<String>)>,
+    /// Tokens used since the last `plan`; `None` while the provider has
+    /// reported no usage.
+    total_usage: Option<TokenUsage>,
+    /// Resources found by the last successful discovery of each target since
+    /// the last `plan`.
+    discovered: HashMap<TargetDomain, Vec<DiscoveredResourceSummary>>,
 }
 
 impl ChaosPlanner {
-    pub fn new(provider_config: &LlmProviderConfig) -> Self {
-        let provider = create_provider(provider_config);
+    pub fn new(provider_config: &LlmProviderConfig) -> anyhow::Result<Self> {
+        let provider = create_provider(provider_config)?;
         let mut tool_registry = ToolRegistry::new();
 
         // Register built-in tools
@@ -47,7 +136,7 @@ impl ChaosPlanner {
         tool_registry.register(Box::new(RunExperimentTool));
         tool_registry.register(Box::new(DiscoverResourcesTool));
 
-        Self {
+        Ok(Self {
             provider,
             tool_registry,
             mcp_clients: Vec::new(),
@@ -56,7 +145,17 @@ impl ChaosPlanner {
             max_turns: 10,
             verbose: false,
             event_tx: None,
-        }
+            user_rx: None,
+            approval_tx: None,
+            cancel: None,
+            context_limit: None,
+            require_discovery_first: false,
+            default_durations: DefaultDurations::default(),
+            idle_limit: DEFAULT_IDLE_LIMIT,
+            named_targets: BTreeMap::new(),
+            total_usage: None,
+            discovered: HashMap::new(),
+        })
     }
 
     /// Add an MCP server to provide additional tools.
@@ -87,6 +186,61 @@ impl ChaosPlanner {
         self.verbose = verbose;
     }
 
+    /// Keep the conversation under roughly `tokens` by eliding older tool
+    /// results before each turn. Off by default.
+    pub fn set_context_limit(&mut self, tokens: usize) {
+        self.context_limit = Some(tokens);
+    }
+
+    /// Finish early once `turns` consecutive turns after the first experiment
+    /// queue no new one, or the same tool has been called with identical
+    /// arguments `turns` times.
+    /// `0` turns this off, leaving only `max_turns`.
+    pub fn set_idle_limit(&mut self, turns: u32) {
+        self.idle_limit = turns;
+    }
+
+    /// Durations for planned experiments that don't set their own.
+    pub fn set_default_durations(&mut self, defaults: DefaultDurations) {
+        self.default_durations = defaults;
+    }
+
+    /// Offer `targets` to the model by name and description. Their configs
+    /// never enter the conversation; planned experiments carry `target_ref`
+    /// and are resolved by the caller.
+    pub fn set_named_targets(&mut self, targets: &TargetSet) {
+        self.named_targets = targets
+            .0
+            .iter()
+            .map(|(name, t)| (name.clone(), (t.target, t.description.clone())))
+            .collect();
+    }
+
+    /// Force the first turn to call `discover_resources` (via the provider's
+    /// tool choice, where supported) and reject `run_experiment` until some
+    /// discovery has succeeded, rather than relying on the prompt alone.
+    pub fn set_require_discovery_first(&mut self, require: bool) {
+        self.require_discovery_first = require;
+    }
+
+    /// Stop planning once `token` is cancelled. `plan` then returns what was
+    /// planned so far with `cancelled` set, instead of being dropped mid-turn.
+    pub fn set_cancel_token(&mut self, token: CancellationToken) {
+        self.cancel = Some(token);
+    }
+
+    /// Write every raw provider request/response to timestamped files in `dir`.
+    pub fn set_io_log_dir(&mut self, dir: &std::path::Path) -> std::io::Result<()> {
+        let log = IoLogger::new(dir)?;
+        self.provider.set_io_log(Arc::new(log));
+        Ok(())
+    }
+
+    /// Log raw provider exchanges through `log`, e.g. one also writing a trace file.
+    pub fn set_io_logger(&mut self, log: IoLogger) {
+        self.provider.set_io_log(Arc::new(log));
+    }
+
     /// Set up an event channel for TUI consumption.
     /// Returns the receiver end of the channel.
     pub fn set_event_channel(&mut self) -> tokio::sync::mpsc::UnboundedReceiver<PlannerEvent> {
@@ -95,12 +249,94 @@ impl ChaosPlanner {
         rx
     }
 
+    /// Set up a channel for steering planning while it runs: each message
+    /// sent is added to the conversation as a user message before the next
+    /// turn. Returns the sender end of the channel.
+    pub fn set_user_input_channel(&mut self) -> tokio::sync::mpsc::UnboundedSender<String> {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        self.user_rx = Some(rx);
+        tx
+    }
+
+    /// Turn on step-through mode: before each tool call runs it is sent on
+    /// the returned channel, and planning waits for the reply. A denied call
+    /// is reported to the model as an error. Returns the receiver end of the
+    /// channel.
+    pub fn set_step_through(&mut self) -> tokio::sync::mpsc::UnboundedReceiver<ToolCallApproval> {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        self.approval_tx = Some(tx);
+        rx
+    }
+
+    /// Whether `tool_call` may run: always outside step-through mode,
+    /// otherwise as the user replies. `None` if planning was cancelled while
+    /// waiting.
+    async fn approve_tool_call(&self, tool_call: &ToolCall, cancel: &Option<CancellationToken>) -> Option<bool> {
+        let Some(ref tx) = self.approval_tx else {
+            return Some(true);
+        };
+        let (reply, response) = tokio::sync::oneshot::channel();
+        let pending = ToolCallApproval {
+            name: tool_call.name.clone(),
+            arguments: tool_call.arguments.clone(),
+            reply,
+        };
+        if tx.send(pending).is_err() {
+            return Some(false);
+        }
+        tokio::select! {
+            approved = response => Some(approved.unwrap_or(false)),
+            _ = wait_for_cancel(cancel) => None,
+        }
+    }
+
+    /// Add any messages waiting on the user input channel to the
+    /// conversation. Returns whether there were any.
+    fn take_user_messages(&mut self) -> bool {
+        let Some(ref mut rx) = self.user_rx else {
+            return false;
+        };
+        let mut pending = Vec::new();
+        while let Ok(content) = rx.try_recv() {
+            pending.push(content);
+        }
+        for content in &pending {
+            tracing::info!("Injecting user message into planning");
+            self.messages.push(ChatMessage {
+                role: Role::User,
+                content: content.clone(),
+                tool_calls: Vec::new(),
+                tool_call_id: None,
+            });
+            self.emit_event(PlannerEvent::UserMessageInjected {
+                content: content.clone(),
+            });
+        }
+        !pending.is_empty()
+    }
+
     fn emit_event(&self, event: PlannerEvent) {
         if let Some(ref tx) = self.event_tx {
             let _ = tx.send(event);
         }
     }
 
+    /// Ask the model for its next message, emitting its text as it arrives.
+    async fn stream_turn(
+        &self,
+        tools: &[ToolDefinition],
+        tool_choice: &ToolChoice,
+    ) -> anyhow::Result<LlmResponse> {
+        let mut stream = self.provider.chat_stream(&self.messages, tools, tool_choice);
+        while let Some(chunk) = stream.try_next().await? {
+            match chunk {
+                StreamChunk::Delta(text) => self.emit_event(PlannerEvent::AssistantDelta { text }),
+                StreamChunk::Done(response) => return Ok(response),
+            }
+        }
+        anyhow::bail!("{} response ended without finishing", self.provider.name())
+    }
+
     /// Update the skills list (call after agents are initialized).
     pub fn update_skills(&mut self, skills: Vec<ToolDefinition>) {
         self.tool_registry.register(Box::new(ListSkillsTool { skills }));
@@ -109,12 +345,28 @@ impl ChaosPlanner {
     /// Run the planner with a user prompt.
     /// Returns the final assistant message and a list of experiment configs it wants to run.
     pub async fn plan(&mut self, user_prompt: &str) -> anyhow::Result<PlanResult> {
+        self.check_model().await?;
         self.messages.clear();
+        self.total_usage = None;
+        self.discovered.clear();
 
         // Add system message
+        let mut system_prompt = self.system_prompt.clone();
+        if !self.named_targets.is_empty() {
+            system_prompt.push_str(
+                "\n\nNamed targets (pass `target_ref` with the name instead of `target_config` \
+                 to discover_resources and run_experiment):",
+            );
+            for (name, (target, description)) in &self.named_targets {
+                system_prompt.push_str(&format!("\n- {name} ({target})"));
+                if let Some(description) = description {
+                    system_prompt.push_str(&format!(": {description}"));
+                }
+            }
+        }
         self.messages.push(ChatMessage {
             role: Role::System,
-            content: self.system_prompt.clone(),
+            content: system_prompt,
             tool_calls: Vec::new(),
             tool_call_id: None,
         });
@@ -129,13 +381,29 @@ impl ChaosPlanner {
 
         let tool_defs = self.tool_registry.definitions();
         let mut experiments = Vec::new();
+        let mut raw_experiments = Vec::new();
 
         // Track target configs from discover_resources calls so we can inject them
         // into run_experiment calls if the LLM omits them.
         let mut discovered_targets: std::collections::HashMap<String, serde_json::Value> =
             std::collections::HashMap::new();
+        // Same, for discoveries that named a target by `target_ref`.
+        let mut discovered_refs: std::collections::HashMap<String, String> =
+            std::collections::HashMap::new();
+        // Targets whose most recent discovery succeeded but found nothing.
+        let mut empty_targets: Vec<String> = Vec::new();
+        let mut discovered_any = false;
+        // For spotting a model that keeps calling tools without getting anywhere.
+        let mut call_counts: std::collections::HashMap<String, u32> =
+            std::collections::HashMap::new();
+        let mut idle_turns = 0;
+
+        let cancel = self.cancel.clone();
 
         for turn in 0..self.max_turns {
+            if is_cancelled(&cancel) {
+                return Ok(self.cancelled(turn, experiments, raw_experiments, empty_targets));
+            }
             tracing::info!(turn, "LLM planner turn");
             self.emit_event(PlannerEvent::TurnStarted {
                 turn: turn + 1,
@@ -145,7 +413,22 @@ impl ChaosPlanner {
                 eprintln!("[turn {}/{}] Thinking...", turn + 1, self.max_turns);
             }
 
-            let response = self.provider.chat(&self.messages, &tool_defs).await?;
+            // Steering counts as progress: the model gets a fresh idle budget
+            if self.take_user_messages() {
+                idle_turns = 0;
+            }
+            self.trim_history();
+            let tool_choice = if self.require_discovery_first && turn == 0 {
+                ToolChoice::Tool("discover_resources".into())
+            } else {
+                ToolChoice::Auto
+            };
+            let response = tokio::select! {
+                response = self.stream_turn(&tool_defs, &tool_choice) => response?,
+                _ = wait_for_cancel(&cancel) => {
+                    return Ok(self.cancelled(turn, experiments, raw_experiments, empty_targets));
+                }
+            };
 
             if let Some(usage) = &response.usage {
                 tracing::debug!(
@@ -153,10 +436,7 @@ impl ChaosPlanner {
                     output = usage.output_tokens,
                     "Token usage"
                 );
-                self.emit_event(PlannerEvent::TokenUsage {
-                    input_tokens: usage.input_tokens,
-                    output_tokens: usage.output_tokens,
-                });
+                self.record_usage(usage);
             }
 
             // Add assistant response to history
@@ -173,21 +453,24 @@ impl ChaosPlanner {
             }
 
             match response.finish_reason {
+                // A message sent while the model was answering still gets a turn
+                FinishReason::Stop if self.take_user_messages() => {
+                    idle_turns = 0;
+                }
                 FinishReason::Stop => {
                     tracing::info!("LLM planner finished");
-                    self.emit_event(PlannerEvent::PlanningComplete {
-                        turns: turn + 1,
-                        experiment_count: experiments.len(),
-                    });
-                    return Ok(PlanResult {
-                        message: response.message.content,
-                        experiments,
-                        turns: turn + 1,
-                    });
+                    return Ok(self.finished(response.message.content, turn + 1, experiments, raw_experiments, empty_targets));
                 }
                 FinishReason::ToolUse => {
+                    let mut rediscovered_empty = false;
+                    let queued_before = experiments.len();
+                    let mut repeated_call = None;
+
                     // Execute each tool call
                     for tool_call in &response.message.tool_calls {
+                        if is_cancelled(&cancel) {
+                            return Ok(self.cancelled(turn + 1, experiments, raw_experiments, empty_targets));
+                        }
                         tracing::info!(
                             tool = %tool_call.name,
                             "Executing tool call"
@@ -200,10 +483,47 @@ impl ChaosPlanner {
                             eprintln!("[tool] {}()", tool_call.name);
                         }
 
-                        let mut result = self
-                            .tool_registry
-                            .execute(&tool_call.name, tool_call.arguments.clone())
-                            .await;
+                        let count = call_counts
+                            .entry(format!("{}{}", tool_call.name, tool_call.arguments))
+                            .or_default();
+                        *count += 1;
+                        if self.idle_limit > 0 && *count >= self.idle_limit {
+                            repeated_call = Some(tool_call.name.clone());
+                        }
+
+                        let premature = self.require_discovery_first
+                            && tool_call.name == "run_experiment"
+                            && !discovered_any;
+                        let denied = !premature
+                            && match self.approve_tool_call(tool_call, &cancel).await {
+                                Some(approved) => !approved,
+                                None => {
+                                    return Ok(self.cancelled(turn + 1, experiments, raw_experiments, empty_targets));
+                                }
+                            };
+                        let mut result = if premature {
+                            ToolResult {
+                                tool_call_id: String::new(),
+                                content: "Error: run_experiment rejected: call discover_resources \
+                                          on the target first and plan against what it finds."
+                                    .into(),
+                                is_error: true,
+                            }
+                        } else if denied {
+                            tracing::info!(tool = %tool_call.name, "Tool call denied by the user");
+                            ToolResult {
+                                tool_call_id: String::new(),
+                                content: format!(
+                                    "Error: the user denied this {} call; don't repeat it unchanged.",
+                                    tool_call.name
+                                ),
+                                is_error: true,
+                            }
+                        } else {
+                            self.tool_registry
+                                .execute(&tool_call.name, tool_call.arguments.clone())
+                                .await
+                        };
                         result.tool_call_id = tool_call.id.clone();
 
                         self.emit_event(PlannerEvent::ToolCallCompleted {
@@ -214,7 +534,13 @@ impl ChaosPlanner {
 
                         // Capture target configs from discover_resources calls
                         if tool_call.name == "discover_resources" {
-                            if let (Some(target), Some(config)) = (
+                            discovered_any |= !result.is_error;
+                            if let (Some(target), Some(name)) = (
+                                tool_call.arguments["target"].as_str(),
+                                tool_call.arguments["target_ref"].as_str(),
+                            ) {
+                                discovered_refs.insert(target.to_string(), name.to_string());
+                            } else if let (Some(target), Some(config)) = (
                                 tool_call.arguments["target"].as_str(),
                                 tool_call.arguments.get("target_config"),
                             ) {
@@ -222,74 +548,141 @@ impl ChaosPlanner {
                                     .insert(target.to_string(), config.clone());
                             }
                             // Emit discovery event with resource count
-                            let resource_count = result
-                                .content
-                                .parse::<serde_json::Value>()
-                                .ok()
+                            let discovery = result.content.parse::<serde_json::Value>().ok();
+                            let resource_count = discovery
+                                .as_ref()
                                 .and_then(|v| v["total_resources"].as_u64())
                                 .unwrap_or(0) as usize;
+                            let target = tool_call.arguments["target"]
+                                .as_str()
+                                .unwrap_or("unknown")
+                                .to_string();
+                            // Kept for the plan's blast radius
+                            let domain = serde_json::from_value::<TargetDomain>(tool_call.arguments["target"].clone());
+                            if let (false, Ok(domain), Some(discovery)) = (result.is_error, domain, discovery) {
+                                let resources = discovery["resources"]
+                                    .as_array()
+                                    .map(|resources| {
+                                        resources
+                                            .iter()
+                                            .map(|r| DiscoveredResourceSummary {
+                                                resource_type: r["type"].as_str().unwrap_or_default().to_string(),
+                                                name: r["name"].as_str().unwrap_or_default().to_string(),
+                                            })
+                                            .collect()
+                                    })
+                                    .unwrap_or_default();
+                                self.discovered.insert(domain, resources);
+                            }
                             self.emit_event(PlannerEvent::DiscoveryResult {
-                                target: tool_call.arguments["target"]
-                                    .as_str()
-                                    .unwrap_or("unknown")
-                                    .to_string(),
+                                target: target.clone(),
                                 resource_count,
                             });
+
+                            if !result.is_error && resource_count == 0 {
+                                if empty_targets.contains(&target) {
+                                    rediscovered_empty = true;
+                                } else {
+                                    empty_targets.push(target);
+                                }
+                                result.content.push_str(
+                                    "\n\nNote: discovery found no resources on this target. \
+                                     Skills that act on existing tables, pods or services \
+                                     will be skipped; do not plan them against this target.",
+                                );
+                            } else if !result.is_error {
+                                empty_targets.retain(|t| *t != target);
+                            }
                         }
 
                         // Intercept run_experiment calls to capture experiment configs
-                        if tool_call.name == "run_experiment" {
+                        if tool_call.name == "run_experiment" && !premature && !denied {
                             let mut exp_args = tool_call.arguments.clone();
 
                             // Auto-inject target_config if missing or null
                             let has_target_config = exp_args
                                 .get("target_config")
-                                .map_or(false, |v| !v.is_null() && v.is_object());
-                            if !has_target_config {
+                                .is_some_and(|v| !v.is_null() && v.is_object());
+                            let has_target_ref = exp_args["target_ref"].is_string();
+                            let discovered_ref = exp_args["target"]
+                                .as_str()
+                                .and_then(|t| discovered_refs.get(t))
+                                .filter(|_| !has_target_config && !has_target_ref);
+                            let mut injected_from = None;
+                            if let Some(name) = discovered_ref {
+                                exp_args["target_ref"] = name.clone().into();
+                                injected_from = Some(InjectionSource::DiscoveredRef {
+                                    target: exp_args["target"].as_str().unwrap_or_default().to_string(),
+                                    name: name.clone(),
+                                });
+                            } else if !has_target_config && !has_target_ref {
                                 let target_key = exp_args["target"]
                                     .as_str()
                                     .map(|s| s.to_string());
 
                                 // Try exact target match first
                                 let injected = target_key.as_ref().and_then(|t| {
-                                    discovered_targets.get(t).cloned()
+                                    discovered_targets
+                                        .get(t)
+                                        .map(|c| (c.clone(), InjectionSource::Discovery { target: t.clone() }))
                                 });
 
                                 // Fallback: use the only discovered target if there's exactly one
                                 let config_to_inject = injected.or_else(|| {
                                     if discovered_targets.len() == 1 {
-                                        discovered_targets.values().next().cloned()
+                                        discovered_targets.iter().next().map(|(t, c)| {
+                                            let source = InjectionSource::OnlyDiscoveredTarget {
+                                                target: t.clone(),
+                                                requested: target_key.clone(),
+                                            };
+                                            (c.clone(), source)
+                                        })
                                     } else {
                                         None
                                     }
                                 });
 
-                                if let Some(config) = config_to_inject {
+                                if let Some((config, source)) = config_to_inject {
                                     exp_args["target_config"] = config;
-                                    if self.verbose && self.event_tx.is_none() {
-                                        eprintln!("[planner] Auto-injected target_config from prior discovery");
-                                    }
+                                    injected_from = Some(source);
+                                } else if let Some(config) = extract_target_config_from_prompt(
+                                    user_prompt,
+                                    exp_args["target"].as_str(),
+                                ) {
+                                    exp_args["target_config"] = config;
+                                    injected_from = Some(InjectionSource::Prompt);
                                 } else if self.verbose && self.event_tx.is_none() {
                                     eprintln!("[planner] Warning: no target_config available to inject");
                                 }
                             }
+                            if let Some(source) = injected_from {
+                                let experiment = exp_args["name"].as_str().unwrap_or("unnamed").to_string();
+                                tracing::info!(experiment = %experiment, source = %source, "Injected target into run_experiment");
+                                if self.verbose && self.event_tx.is_none() {
+                                    eprintln!("[planner] '{experiment}': target filled in from {source}");
+                                }
+                                self.emit_event(PlannerEvent::TargetConfigInjected { experiment, source });
+                            }
 
-                            let exp_name = tool_call.arguments["name"]
-                                .as_str()
-                                .unwrap_or("unnamed")
-                                .to_string();
-                            let exp_target = tool_call.arguments["target"]
-                                .as_str()
-                                .unwrap_or("unknown")
-                                .to_string();
-                            self.emit_event(PlannerEvent::ExperimentPlanned {
-                                name: exp_name.clone(),
-                                target: exp_target,
-                            });
-                            if self.verbose && self.event_tx.is_none() {
-                                eprintln!("[experiment] Planned: {}", exp_name);
+                            match to_experiment_config(&exp_args, &self.default_durations, &self.named_targets) {
+                                Ok(config) => {
+                                    self.emit_event(PlannerEvent::ExperimentPlanned {
+                                        name: config.name.clone(),
+                                        target: config.target.to_string(),
+                                    });
+                                    if self.verbose && self.event_tx.is_none() {
+                                        eprintln!("[experiment] Planned: {}", config.name);
+                                    }
+                                    experiments.push(config);
+                                }
+                                // Let the model fix it rather than failing after planning.
+                                Err(e) => {
+                                    tracing::warn!(error = %e, "Rejected invalid run_experiment call");
+                                    result.content = format!("Error: experiment not queued: {e}");
+                                    result.is_error = true;
+                                }
                             }
-                            experiments.push(exp_args);
+                            raw_experiments.push(exp_args);
                         }
 
                         // Add tool result to conversation
@@ -300,43 +693,357 @@ impl ChaosPlanner {
                             tool_call_id: Some(result.tool_call_id),
                         });
                     }
+
+                    // Re-running discovery against a target that was already empty
+                    // won't change the outcome; stop instead of burning turns.
+                    if rediscovered_empty && experiments.is_empty() {
+                        tracing::info!(
+                            targets = ?empty_targets,
+                            "Discovery found no resources, stopping planning"
+                        );
+                        let message = format!(
+                            "Discovery found no resources on: {}. Nothing to run.",
+                            empty_targets.join(", ")
+                        );
+                        return Ok(self.finished(message, turn + 1, experiments, raw_experiments, empty_targets));
+                    }
+
+                    // Discovery turns before the first experiment aren't idle
+                    if experiments.len() > queued_before {
+                        idle_turns = 0;
+                    } else if !experiments.is_empty() {
+                        idle_turns += 1;
+                    }
+                    let stalled = match repeated_call {
+                        Some(tool) => Some(format!(
+                            "{tool} called {} times with the same arguments",
+                            self.idle_limit
+                        )),
+                        None if self.idle_limit > 0 && idle_turns >= self.idle_limit => Some(
+                            format!("{idle_turns} turns in a row without a new experiment"),
+                        ),
+                        None => None,
+                    };
+                    if let Some(reason) = stalled {
+                        tracing::warn!(reason = %reason, "Planner made no progress, stopping early");
+                        let message = format!(
+                            "Stopped planning early: {reason}. {} experiment(s) planned.",
+                            experiments.len()
+                        );
+                        self.emit_event(PlannerEvent::AssistantMessage {
+                            content: message.clone(),
+                        });
+                        return Ok(self.finished(message, turn + 1, experiments, raw_experiments, empty_targets));
+                    }
                 }
                 FinishReason::MaxTokens => {
                     tracing::warn!("LLM hit max tokens, stopping");
-                    self.emit_event(PlannerEvent::PlanningComplete {
-                        turns: turn + 1,
-                        experiment_count: experiments.len(),
-                    });
-                    return Ok(PlanResult {
-                        message: response.message.content,
-                        experiments,
-                        turns: turn + 1,
-                    });
+                    return Ok(self.finished(response.message.content, turn + 1, experiments, raw_experiments, empty_targets));
                 }
                 FinishReason::Other(reason) => {
                     tracing::warn!(reason = %reason, "Unexpected finish reason");
-                    self.emit_event(PlannerEvent::PlanningComplete {
-                        turns: turn + 1,
-                        experiment_count: experiments.len(),
-                    });
-                    return Ok(PlanResult {
-                        message: response.message.content,
-                        experiments,
-                        turns: turn + 1,
-                    });
+                    return Ok(self.finished(response.message.content, turn + 1, experiments, raw_experiments, empty_targets));
                 }
             }
         }
 
+        Ok(self.finished("Max turns reached".to_string(), self.max_turns, experiments, raw_experiments, empty_targets))
+    }
+
+    /// Ask the model for an incident-style narrative of what was run and what
+    /// was observed, written for people who won't read the raw reports. Uses a
+    /// fresh conversation without tools, so it can be called after [`plan`]
+    /// (or without it).
+    ///
+    /// [`plan`]: Self::plan
+    pub async fn summarize(&self, reports: &[ExperimentReport]) -> anyhow::Result<String> {
+        self.emit_event(PlannerEvent::SummaryStarted {
+            report_count: reports.len(),
+        });
+        match self.request_summary(reports).await {
+            Ok(summary) => {
+                self.emit_event(PlannerEvent::SummaryCompleted {
+                    summary: summary.clone(),
+                });
+                Ok(summary)
+            }
+            Err(e) => {
+                self.emit_event(PlannerEvent::SummaryFailed {
+                    error: e.to_string(),
+                });
+                Err(e)
+            }
+        }
+    }
+
+    async fn request_summary(&self, reports: &[ExperimentReport]) -> anyhow::Result<String> {
+        if reports.is_empty() {
+            anyhow::bail!("No experiment reports to summarize");
+        }
+        let messages = [
+            ChatMessage {
+                role: Role::System,
+                content: SUMMARY_SYSTEM_PROMPT.to_string(),
+                tool_calls: Vec::new(),
+                tool_call_id: None,
+            },
+            ChatMessage {
+                role: Role::User,
+                content: format!(
+                    "Experiment reports (JSON):\n\n{}",
+                    serde_json::to_string_pretty(reports)?
+                ),
+                tool_calls: Vec::new(),
+                tool_call_id: None,
+            },
+        ];
+
+        tracing::info!(reports = reports.len(), "Requesting LLM summary");
+        let cancel = self.cancel.clone();
+        let response = tokio::select! {
+            response = self.provider.chat(&messages, &[], &ToolChoice::Auto) => response?,
+            _ = wait_for_cancel(&cancel) => anyhow::bail!("Summary cancelled"),
+        };
+        if let Some(usage) = &response.usage {
+            self.emit_event(PlannerEvent::TokenUsage {
+                input_tokens: usage.input_tokens,
+                output_tokens: usage.output_tokens,
+            });
+        }
+
+        let summary = response.message.content.trim();
+        if summary.is_empty() {
+            anyhow::bail!("{} returned an empty summary", self.provider.name());
+        }
+        Ok(summary.to_string())
+    }
+
+    /// Fail fast on a model the provider doesn't offer, instead of on the first
+    /// chat request. A built-in list only warns, since it may be out of date,
+    /// and a provider that can't be listed is not checked at all.
+    async fn check_model(&self) -> anyhow::Result<()> {
+        let provider = self.provider.name();
+        let model = self.provider.model();
+        let list = match self.provider.list_models().await {
+            Ok(list) => list,
+            Err(e) => {
+                tracing::debug!(provider, error = %e, "Could not list models, skipping check");
+                return Ok(());
+            }
+        };
+        if list.contains(model) {
+            return Ok(());
+        }
+
+        let hint = list
+            .closest(model)
+            .map(|m| format!("; did you mean '{m}'?"))
+            .unwrap_or_default();
+        if list.live {
+            anyhow::bail!("Model '{model}' is not available from {provider}{hint}");
+        }
+        tracing::warn!("Model '{model}' is not a known {provider} model{hint}");
+        Ok(())
+    }
+
+    /// Once the estimated size exceeds the context limit, replace the content
+    /// of old tool results (oldest first) with a short note until it fits.
+    /// The system prompt, the user prompt, the last [`KEEP_RECENT_TURNS`]
+    /// turns and `run_experiment` results are never touched, and every tool
+    /// result keeps its message so providers still see call/result pairs.
+    fn trim_history(&mut self) {
+        let Some(limit) = self.context_limit else {
+            return;
+        };
+        let mut total: usize = self.messages.iter().map(estimate_tokens).sum();
+        if total <= limit {
+            return;
+        }
+
+        let tool_names: std::collections::HashMap<String, String> = self
+            .messages
+            .iter()
+            .flat_map(|m| &m.tool_calls)
+            .map(|c| (c.id.clone(), c.name.clone()))
+            .collect();
+        let recent_start = self
+            .messages
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| m.role == Role::Assistant)
+            .map(|(i, _)| i)
+            .rev()
+            .nth(KEEP_RECENT_TURNS - 1)
+            .unwrap_or(0);
+
+        let mut trimmed = 0;
+        for message in &mut self.messages[..recent_start] {
+            if total <= limit {
+                break;
+            }
+            if message.role != Role::Tool || message.content.starts_with(TRIMMED_MARKER) {
+                continue;
+            }
+            let tool = message
+                .tool_call_id
+                .as_ref()
+                .and_then(|id| tool_names.get(id))
+                .map(String::as_str)
+                .unwrap_or("unknown");
+            if tool == "run_experiment" {
+                continue;
+            }
+            let before = estimate_tokens(message);
+            message.content = format!(
+                "{TRIMMED_MARKER} {} chars of earlier {tool} output removed to save context]",
+                message.content.len()
+            );
+            total = total - before + estimate_tokens(message);
+            trimmed += 1;
+        }
+
+        if trimmed > 0 {
+            tracing::info!(trimmed, estimated_tokens = total, limit, "Trimmed planner history");
+        } else {
+            tracing::warn!(estimated_tokens = total, limit, "Planner history over context limit, nothing left to trim");
+        }
+    }
+
+    /// Add one response's usage to the running total and report it.
+    fn record_usage(&mut self, usage: &TokenUsage) {
+        let total = self.total_usage.get_or_insert(TokenUsage {
+            input_tokens: 0,
+            output_tokens: 0,
+        });
+        total.input_tokens = total.input_tokens.saturating_add(usage.input_tokens);
+        total.output_tokens = total.output_tokens.saturating_add(usage.output_tokens);
+        self.emit_event(PlannerEvent::TokenUsage {
+            input_tokens: usage.input_tokens,
+            output_tokens: usage.output_tokens,
+        });
+    }
+
+    /// Partial result after a cancel, once `turns` LLM turns had completed.
+    fn cancelled(
+        &self,
+        turns: u32,
+        experiments: Vec<ExperimentConfig>,
+        raw_experiments: Vec<serde_json::Value>,
+        empty_targets: Vec<String>,
+    ) -> PlanResult {
+        tracing::info!(turns, experiments = experiments.len(), "LLM planning cancelled");
+        self.emit_event(PlannerEvent::PlanningCancelled {
+            turns,
+            experiment_count: experiments.len(),
+        });
+        self.plan_result("Planning cancelled".to_string(), turns, experiments, raw_experiments, empty_targets, true)
+    }
+
+    /// Planning ended after `turns` LLM turns with `message`.
+    fn finished(
+        &self,
+        message: String,
+        turns: u32,
+        experiments: Vec<ExperimentConfig>,
+        raw_experiments: Vec<serde_json::Value>,
+        empty_targets: Vec<String>,
+    ) -> PlanResult {
         self.emit_event(PlannerEvent::PlanningComplete {
-            turns: self.max_turns,
+            turns,
             experiment_count: experiments.len(),
         });
-        Ok(PlanResult {
-            message: "Max turns reached".to_string(),
+        self.plan_result(message, turns, experiments, raw_experiments, empty_targets, false)
+    }
+
+    /// Every exit of [`plan`](Self::plan) builds its result here, with the
+    /// usage and discoveries accumulated so far.
+    fn plan_result(
+        &self,
+        message: String,
+        turns: u32,
+        experiments: Vec<ExperimentConfig>,
+        raw_experiments: Vec<serde_json::Value>,
+        empty_targets: Vec<String>,
+        cancelled: bool,
+    ) -> PlanResult {
+        PlanResult {
+            message,
             experiments,
-            turns: self.max_turns,
-        })
+            raw_experiments,
+            turns,
+            empty_targets,
+            cancelled,
+            total_usage: self.total_usage.clone(),
+            discovered: self.discovered.clone(),
+        }
+    }
+}
+
+/// Parse one run_experiment call into an [`ExperimentConfig`].
+fn to_experiment_config(
+    exp: &serde_json::Value,
+    defaults: &DefaultDurations,
+    named_targets: &BTreeMap<String, (TargetDomain, Option<String>)>,
+) -> Result<ExperimentConfig, String> {
+    let name = exp["name"].as_str().unwrap_or("unnamed");
+    if let Some(raw) = exp["duration"].as_str() {
+        parse_duration(raw).map_err(|e| format!("'{name}': {e}"))?;
+    }
+    let json = serde_json::to_string(exp).map_err(|e| e.to_string())?;
+    let mut config: ExperimentConfig = serde_yaml::from_str(&json)
+        .map_err(|e| format!("'{name}' is not a valid experiment config: {e}"))?;
+    config.validate().map_err(|e| e.to_string())?;
+    // Only the operator may opt into skills without rollback or run local
+    // commands.
+    config.allow_irreversible = false;
+    config.pre_hook = None;
+    config.post_hook = None;
+    if let Some(ref target_ref) = config.target_ref {
+        match named_targets.get(target_ref) {
+            None => return Err(format!("'{name}': unknown target_ref '{target_ref}'")),
+            Some((target, _)) if *target != config.target => {
+                return Err(format!(
+                    "'{name}': target_ref '{target_ref}' is a {target} target, not {}",
+                    config.target
+                ))
+            }
+            Some(_) => {}
+        }
+    } else {
+        validate_target_config(config.target, &config.target_config)
+            .map_err(|e| format!("'{name}': {e}"))?;
+    }
+    defaults.apply(&mut config);
+    Ok(config)
+}
+
+/// Idle turns (or identical repeated calls) tolerated before planning stops early.
+pub const DEFAULT_IDLE_LIMIT: u32 = 5;
+
+/// Turns (assistant messages plus their tool results) that trimming never touches.
+const KEEP_RECENT_TURNS: usize = 2;
+
+const TRIMMED_MARKER: &str = "[trimmed:";
+
+/// Rough token count: about four characters per token for English and JSON.
+fn estimate_tokens(message: &ChatMessage) -> usize {
+    let calls: usize = message
+        .tool_calls
+        .iter()
+        .map(|c| c.name.len() + c.arguments.to_string().len())
+        .sum();
+    (message.content.len() + calls) / 4 + 4
+}
+
+fn is_cancelled(token: &Option<CancellationToken>) -> bool {
+    token.as_ref().is_some_and(CancellationToken::is_cancelled)
+}
+
+/// Resolves once `token` is cancelled; never, if there is no token.
+async fn wait_for_cancel(token: &Option<CancellationToken>) {
+    match token {
+        Some(token) => token.cancelled().await,
+        None => std::future::pending().await,
     }
 }
 
@@ -345,12 +1052,35 @@ impl ChaosPlanner {
 pub struct PlanResult {
     /// Final message from the LLM.
     pub message: String,
-    /// Experiment configs the LLM wants to execute (from run_experiment tool calls).
-    pub experiments: Vec<serde_json::Value>,
+    /// Experiments the LLM wants to execute (from run_experiment tool calls),
+    /// with `target_config` filled in and default durations applied. A
+    /// duration may still be missing if no default covers the target.
+    pub experiments: Vec<ExperimentConfig>,
+    /// Every run_experiment call's arguments after `target_config` injection,
+    /// including ones rejected as invalid.
+    pub raw_experiments: Vec<serde_json::Value>,
     /// Number of turns used.
     pub turns: u32,
+    /// Targets where discovery succeeded but found no resources.
+    pub empty_targets: Vec<String>,
+    /// Planning was stopped by the cancel token; `experiments` holds what was
+    /// planned up to then and must not be run.
+    pub cancelled: bool,
+    /// Tokens used across all turns; `None` if the provider reports no
+    /// usage (e.g. Ollama).
+    pub total_usage: Option<TokenUsage>,
+    /// Resources found by the last successful discovery of each target, to
+    /// project the experiments' blast radius.
+    pub discovered: HashMap<TargetDomain, Vec<DiscoveredResourceSummary>>,
 }
 
+const SUMMARY_SYSTEM_PROMPT: &str = "You are writing the summary of a chaos engineering run for \
+engineers and stakeholders who will not read the raw results. You are given the structured report \
+of every experiment that ran. Write a short incident-style summary in plain text (no tables): an \
+overview line, then what was injected against which target and for how long, what was observed \
+(failed skills, degradation and time to recovery where reported), whether rollback fully restored \
+the target, and any follow-ups. Only state what the reports show; say so when something is unknown.";
+
 fn default_system_prompt() -> String {
     r#"You are a chaos engineering agent. Your job is to plan and execute controlled chaos experiments against infrastructure to test resilience.
 
@@ -367,15 +1097,17 @@ Your workflow — you MUST complete ALL steps without stopping to ask for confir
 5. Use `run_experiment` to execute the chaos plan
 6. After calling `run_experiment`, provide a brief summary of what was planned
 
-CRITICAL: You are running in a non-interactive pipeline. The user has already approved execution by running this command. Do NOT ask for confirmation, feedback, or permission. Do NOT stop to explain what you will do — just do it. You MUST call `run_experiment` at least once before finishing. If discovery returns resources, proceed to plan and execute experiments immediately.
+CRITICAL: You are running in a non-interactive pipeline. The user has already approved execution by running this command. Do NOT ask for confirmation, feedback, or permission. Do NOT stop to explain what you will do — just do it. You MUST call `run_experiment` at least once before finishing, unless discovery found no resources. If discovery returns resources, proceed to plan and execute experiments immediately.
 
 Important rules:
 - Start with less destructive experiments and escalate gradually
 - All experiments have automatic rollback — be mindful of duration
-- When calling `run_experiment`, you MUST include `target_config` with the same connection info you used for `discover_resources`
+- When calling `run_experiment`, you MUST include `target_config` (or `target_ref`) with the same connection info you used for `discover_resources`
 - For servers, target relevant services based on discovery results
 - Never target system-critical services (sshd, systemd, etc.)
 - Keep experiment durations reasonable (1m-5m for testing)
-- If discovery fails or returns no resources, still attempt a reasonable experiment based on available information"#
+- Only target tables, pods and services that discovery returned; never invent resource names
+- If discovery returns no resources on a target, do not plan experiments that act on existing resources there; say that nothing was found and finish
+- If discovery fails with an error, fix the connection details and retry, or finish and report the error"#
         .to_string()
 }
@@ -0,0 +1,140 @@
+//! Approximate LLM pricing, for reporting what a planning run cost.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::provider::TokenUsage;
+
+/// USD per million tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModelPrice {
+    pub input_per_million: f64,
+    pub output_per_million: f64,
+}
+
+/// Built-in prices by model name prefix, so dated snapshots such as
+/// `claude-sonnet-4-5-20250929` match their family (see [`matches_prefix`]).
+/// List prices at the time of writing; override them with a `pricing`
+/// section in the plan config.
+const BUILTIN_PRICES: &[(&str, f64, f64)] = &[
+    ("claude-opus-4-5", 5.0, 25.0),
+    ("claude-opus-4", 15.0, 75.0),
+    ("claude-sonnet-4", 3.0, 15.0),
+    ("claude-3-7-sonnet", 3.0, 15.0),
+    ("claude-3-5-sonnet", 3.0, 15.0),
+    ("claude-haiku-4-5", 1.0, 5.0),
+    ("claude-3-5-haiku", 0.8, 4.0),
+    ("gpt-5", 1.25, 10.0),
+    ("gpt-5-mini", 0.25, 2.0),
+    ("gpt-5-nano", 0.05, 0.4),
+    ("gpt-4.1", 2.0, 8.0),
+    ("gpt-4.1-mini", 0.4, 1.6),
+    ("gpt-4.1-nano", 0.1, 0.4),
+    ("gpt-4o", 2.5, 10.0),
+    ("gpt-4o-mini", 0.15, 0.6),
+    ("o3", 2.0, 8.0),
+    ("o3-pro", 20.0, 80.0),
+    ("o3-mini", 1.1, 4.4),
+    ("o4-mini", 1.1, 4.4),
+];
+
+/// Estimated cost in USD of `usage` on `model`, from the built-in prices.
+/// `None` for a model with no known price.
+pub fn estimate_cost(model: &str, usage: &TokenUsage) -> Option<f64> {
+    estimate_cost_with(&BTreeMap::new(), model, usage)
+}
+
+/// Like [`estimate_cost`], with `overrides` (model name prefix to price)
+/// taking precedence over the built-in prices.
+pub fn estimate_cost_with(
+    overrides: &BTreeMap<String, ModelPrice>,
+    model: &str,
+    usage: &TokenUsage,
+) -> Option<f64> {
+    let price = price_for(overrides, model)?;
+    Some(
+        (usage.input_tokens as f64 * price.input_per_million
+            + usage.output_tokens as f64 * price.output_per_million)
+            / 1_000_000.0,
+    )
+}
+
+/// The price of the longest prefix of `model` listed in `overrides` or the
+/// built-in table; on equal prefixes the override wins.
+fn price_for(overrides: &BTreeMap<String, ModelPrice>, model: &str) -> Option<ModelPrice> {
+    let builtin = BUILTIN_PRICES.iter().map(|&(name, input, output)| {
+        (
+            name,
+            ModelPrice {
+                input_per_million: input,
+                output_per_million: output,
+            },
+        )
+    });
+    overrides
+        .iter()
+        .map(|(name, price)| (name.as_str(), *price))
+        .chain(builtin)
+        .filter(|(name, _)| matches_prefix(model, name))
+        .min_by_key(|(name, _)| std::cmp::Reverse(name.len()))
+        .map(|(_, price)| price)
+}
+
+/// Whether `prefix` names `model`'s family: the rest of the name must be
+/// empty or a version/date suffix such as `-5-20250929`, so `o3` doesn't
+/// price `o3-pro` and `gpt-4o` doesn't price `gpt-4o-audio`.
+fn matches_prefix(model: &str, prefix: &str) -> bool {
+    match model.strip_prefix(prefix) {
+        Some("") => true,
+        Some(rest) => rest
+            .strip_prefix('-')
+            .is_some_and(|r| r.starts_with(|c: char| c.is_ascii_digit())),
+        None => false,
+    }
+}
+
+/// `cost` for display, e.g. `$0.0123`.
+pub fn format_cost(cost: f64) -> String {
+    if cost < 0.01 {
+        format!("${cost:.4}")
+    } else {
+        format!("${cost:.2}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(model: &str) -> Option<f64> {
+        price_for(&BTreeMap::new(), model).map(|p| p.input_per_million)
+    }
+
+    #[test]
+    fn dated_snapshots_match_their_family() {
+        assert_eq!(price("claude-sonnet-4-5-20250929"), Some(3.0));
+        assert_eq!(price("o3-2025-04-16"), Some(2.0));
+        assert_eq!(price("claude-opus-4-5-20251101"), Some(5.0));
+    }
+
+    #[test]
+    fn named_variants_dont_take_the_base_price() {
+        assert_eq!(price("o3-pro"), Some(20.0));
+        assert_eq!(price("o3-mini"), Some(1.1));
+        assert_eq!(price("gpt-4o-audio-preview"), None);
+    }
+
+    #[test]
+    fn overrides_win_on_equal_prefixes() {
+        let overrides = BTreeMap::from([(
+            "o3".to_string(),
+            ModelPrice {
+                input_per_million: 1.0,
+                output_per_million: 4.0,
+            },
+        )]);
+        assert_eq!(price_for(&overrides, "o3").map(|p| p.input_per_million), Some(1.0));
+        assert_eq!(price_for(&overrides, "o3-pro").map(|p| p.input_per_million), Some(20.0));
+    }
+}
@@ -1,6 +1,13 @@
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
 use async_trait::async_trait;
+use futures::{Stream, TryStreamExt};
 use serde::{Deserialize, Serialize};
 
+use crate::io_log::{Exchange, IoLogger};
 use crate::tool::ToolDefinition;
 
 /// Configuration for selecting an LLM provider.
@@ -13,6 +20,21 @@ pub enum LlmProviderConfig {
         model: String,
         #[serde(default = "default_max_tokens")]
         max_tokens: u32,
+        /// Extra headers sent with every request, e.g. for an API gateway.
+        #[serde(default)]
+        headers: HashMap<String, String>,
+        /// Proxy URL for this provider only; overrides `HTTPS_PROXY`/`HTTP_PROXY`.
+        #[serde(default)]
+        proxy: Option<String>,
+        /// Retries of rate-limited, overloaded or failed requests.
+        #[serde(default)]
+        retry: RetryPolicy,
+        /// Sampling temperature; the provider's default when unset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        temperature: Option<f32>,
+        /// Nucleus sampling cutoff; the provider's default when unset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        top_p: Option<f32>,
     },
     Openai {
         api_key: String,
@@ -22,6 +44,21 @@ pub enum LlmProviderConfig {
         base_url: Option<String>,
         #[serde(default = "default_max_tokens")]
         max_tokens: u32,
+        /// Extra headers sent with every request, e.g. for an API gateway.
+        #[serde(default)]
+        headers: HashMap<String, String>,
+        /// Proxy URL for this provider only; overrides `HTTPS_PROXY`/`HTTP_PROXY`.
+        #[serde(default)]
+        proxy: Option<String>,
+        /// Retries of rate-limited, overloaded or failed requests.
+        #[serde(default)]
+        retry: RetryPolicy,
+        /// Sampling temperature; the provider's default when unset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        temperature: Option<f32>,
+        /// Nucleus sampling cutoff; the provider's default when unset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        top_p: Option<f32>,
     },
     Ollama {
         #[serde(default = "default_ollama_url")]
@@ -29,9 +66,72 @@ pub enum LlmProviderConfig {
         model: String,
         #[serde(default = "default_max_tokens")]
         max_tokens: u32,
+        /// Extra headers sent with every request, e.g. for an API gateway.
+        #[serde(default)]
+        headers: HashMap<String, String>,
+        /// Proxy URL for this provider only; overrides `HTTPS_PROXY`/`HTTP_PROXY`.
+        #[serde(default)]
+        proxy: Option<String>,
+        /// Retries of rate-limited, overloaded or failed requests.
+        #[serde(default)]
+        retry: RetryPolicy,
+        /// Sampling temperature; the provider's default when unset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        temperature: Option<f32>,
+        /// Nucleus sampling cutoff; the provider's default when unset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        top_p: Option<f32>,
+        /// How long Ollama keeps the model loaded after a request, e.g. `30m`,
+        /// or seconds (`-1` keeps it loaded). Defaults to 30m so planning turns
+        /// don't reload it.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        keep_alive: Option<KeepAlive>,
+        /// Per-request timeout. Defaults to 10m, since local inference is slow.
+        #[serde(
+            default,
+            with = "chaos_core::duration::option",
+            skip_serializing_if = "Option::is_none"
+        )]
+        timeout: Option<Duration>,
     },
 }
 
+impl LlmProviderConfig {
+    /// The configured model name.
+    pub fn model(&self) -> &str {
+        match self {
+            Self::Anthropic { model, .. } | Self::Openai { model, .. } | Self::Ollama { model, .. } => model,
+        }
+    }
+
+    /// Replace `temperature` and `top_p` with those that are `Some`, e.g.
+    /// from command-line flags.
+    pub fn override_sampling(&mut self, temperature: Option<f32>, top_p: Option<f32>) {
+        let (t, p) = match self {
+            Self::Anthropic { temperature, top_p, .. }
+            | Self::Openai { temperature, top_p, .. }
+            | Self::Ollama { temperature, top_p, .. } => (temperature, top_p),
+        };
+        if temperature.is_some() {
+            *t = temperature;
+        }
+        if top_p.is_some() {
+            *p = top_p;
+        }
+    }
+}
+
+/// Ollama's `keep_alive`: a duration string or a number of seconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeepAlive {
+    Seconds(i64),
+    Duration(String),
+}
+
+const DEFAULT_OLLAMA_KEEP_ALIVE: &str = "30m";
+const DEFAULT_OLLAMA_TIMEOUT: Duration = Duration::from_secs(600);
+
 fn default_anthropic_model() -> String {
     "claude-sonnet-4-5-20250929".to_string()
 }
@@ -96,18 +196,457 @@ pub struct TokenUsage {
     pub output_tokens: u32,
 }
 
+/// Which tool, if any, the model must call this turn.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ToolChoice {
+    /// The model decides.
+    #[default]
+    Auto,
+    /// The model must call some tool.
+    Any,
+    /// The model must call the named tool.
+    Tool(String),
+}
+
+/// Models a provider offers.
+#[derive(Debug, Clone)]
+pub struct ModelList {
+    pub models: Vec<String>,
+    /// Fetched from the provider rather than a built-in list. Only a live list
+    /// is trusted to reject a configured model.
+    pub live: bool,
+}
+
+impl ModelList {
+    /// Whether `model` is offered, treating an untagged Ollama name as `:latest`.
+    pub fn contains(&self, model: &str) -> bool {
+        self.models.iter().any(|m| Self::same_model(m, model))
+    }
+
+    /// Whether the offered name `listed` refers to `model`.
+    pub fn same_model(listed: &str, model: &str) -> bool {
+        listed == model || listed.strip_suffix(":latest") == Some(model)
+    }
+
+    /// The offered model closest to `model`, if any is close enough to be a typo.
+    pub fn closest(&self, model: &str) -> Option<&str> {
+        let target = model.to_lowercase();
+        self.models
+            .iter()
+            .map(|m| {
+                let name = m.strip_suffix(":latest").unwrap_or(m).to_lowercase();
+                (m, edit_distance(&target, &name))
+            })
+            .filter(|(m, d)| *d <= (model.len() / 3).max(2) || m.starts_with(model))
+            .min_by_key(|(_, d)| *d)
+            .map(|(m, _)| m.as_str())
+    }
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                prev
+            } else {
+                1 + prev.min(cur).min(row[j])
+            };
+            prev = cur;
+        }
+    }
+    row[b.len()]
+}
+
+/// Anthropic models known at release time; Anthropic's API is not queried.
+const ANTHROPIC_MODELS: &[&str] = &[
+    "claude-opus-4-1-20250805",
+    "claude-opus-4-1",
+    "claude-opus-4-20250514",
+    "claude-opus-4-0",
+    "claude-sonnet-4-5-20250929",
+    "claude-sonnet-4-5",
+    "claude-sonnet-4-20250514",
+    "claude-sonnet-4-0",
+    "claude-haiku-4-5-20251001",
+    "claude-haiku-4-5",
+    "claude-3-7-sonnet-20250219",
+    "claude-3-7-sonnet-latest",
+    "claude-3-5-haiku-20241022",
+    "claude-3-5-haiku-latest",
+];
+
+/// One item of a [`LlmProvider::chat_stream`] response.
+#[derive(Debug, Clone)]
+pub enum StreamChunk {
+    /// Assistant text generated since the previous chunk.
+    Delta(String),
+    /// The complete response, as `chat` returns it; always the last item.
+    Done(LlmResponse),
+}
+
+pub type ChatStream<'a> = Pin<Box<dyn Stream<Item = anyhow::Result<StreamChunk>> + Send + 'a>>;
+
 /// A unified interface for LLM providers.
 #[async_trait]
 pub trait LlmProvider: Send + Sync {
     /// Send a chat completion request with optional tool definitions.
+    /// Providers without tool-choice support treat every `tool_choice` as `Auto`.
     async fn chat(
         &self,
         messages: &[ChatMessage],
         tools: &[ToolDefinition],
+        tool_choice: &ToolChoice,
     ) -> anyhow::Result<LlmResponse>;
 
+    /// Like [`chat`](Self::chat), but yields the assistant's text as it is
+    /// generated, ending with the complete response. The default makes one
+    /// `chat` request and yields only that.
+    fn chat_stream<'a>(
+        &'a self,
+        messages: &'a [ChatMessage],
+        tools: &'a [ToolDefinition],
+        tool_choice: &'a ToolChoice,
+    ) -> ChatStream<'a> {
+        Box::pin(futures::stream::once(async move {
+            self.chat(messages, tools, tool_choice)
+                .await
+                .map(StreamChunk::Done)
+        }))
+    }
+
     /// Provider name for logging.
     fn name(&self) -> &str;
+
+    /// Model requests are sent to.
+    fn model(&self) -> &str;
+
+    /// Models the provider offers.
+    async fn list_models(&self) -> anyhow::Result<ModelList>;
+
+    /// Record raw request/response bodies to `log`.
+    fn set_io_log(&mut self, _log: Arc<IoLogger>) {}
+}
+
+/// POST `body`, retrying transient failures, and return the status with the
+/// parsed response, writing the exchange to `io_log` when set.
+async fn post_json(
+    request: reqwest::RequestBuilder,
+    body: &serde_json::Value,
+    provider: &str,
+    retry: &RetryPolicy,
+    io_log: Option<&IoLogger>,
+    secret: Option<&str>,
+) -> anyhow::Result<(reqwest::StatusCode, serde_json::Value)> {
+    let (client, request) = request.json(body).build_split();
+    let request = request?;
+    let url = request.url().to_string();
+
+    let resp = execute_with_retry(&client, request, retry, provider).await?;
+    let status = resp.status();
+    let text = resp.text().await?;
+
+    if let Some(log) = io_log {
+        log.log(&Exchange {
+            provider,
+            url: &url,
+            request: body,
+            status: status.as_u16(),
+            response: &text,
+            secret,
+        });
+    }
+
+    Ok((status, parse_json_body(provider, &url, status, &text)?))
+}
+
+/// `request` with the configured extra `headers` added.
+fn extra_headers(
+    mut request: reqwest::RequestBuilder,
+    headers: &HashMap<String, String>,
+) -> reqwest::RequestBuilder {
+    for (name, value) in headers {
+        request = request.header(name, value);
+    }
+    request
+}
+
+/// Retries of provider requests that failed transiently.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetryPolicy {
+    /// Attempts in total, including the first.
+    pub max_attempts: u32,
+    /// Wait before the first retry, doubling for each one after. A
+    /// `Retry-After` header from the server takes precedence.
+    #[serde(with = "chaos_core::duration")]
+    pub base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Rate limited, server error, bad gateway, unavailable, and Anthropic's overloaded.
+const RETRYABLE_STATUSES: [u16; 5] = [429, 500, 502, 503, 529];
+
+/// Send `request`, retrying on a retryable status or a failed connection.
+/// Whatever the last attempt returns is handed back for the caller to report.
+async fn execute_with_retry(
+    client: &reqwest::Client,
+    mut request: reqwest::Request,
+    retry: &RetryPolicy,
+    provider: &str,
+) -> anyhow::Result<reqwest::Response> {
+    let mut attempt = 1;
+    loop {
+        let next = if attempt < retry.max_attempts {
+            request.try_clone()
+        } else {
+            None
+        };
+        let result = client.execute(request).await;
+        let Some(next) = next else {
+            return Ok(result?);
+        };
+        let backoff = retry.base_delay.saturating_mul(1 << (attempt - 1).min(16));
+        let (delay, reason) = match &result {
+            Ok(resp) if RETRYABLE_STATUSES.contains(&resp.status().as_u16()) => {
+                (retry_after(resp).unwrap_or(backoff), resp.status().to_string())
+            }
+            Err(e) if e.is_connect() => (backoff, e.to_string()),
+            _ => return Ok(result?),
+        };
+        tracing::warn!(
+            provider,
+            attempt,
+            max_attempts = retry.max_attempts,
+            ?delay,
+            %reason,
+            "Transient LLM API error, retrying"
+        );
+        tokio::time::sleep(delay).await;
+        request = next;
+        attempt += 1;
+    }
+}
+
+/// The wait a `Retry-After` (seconds) or `retry-after-ms` header asks for.
+fn retry_after(resp: &reqwest::Response) -> Option<Duration> {
+    let header = |name: &str| resp.headers().get(name)?.to_str().ok()?.trim().parse::<f64>().ok();
+    let secs = match header("retry-after-ms") {
+        Some(ms) => ms / 1000.0,
+        None => header("retry-after")?,
+    };
+    Duration::try_from_secs_f64(secs).ok()
+}
+
+/// GET `request` with the configured extra `headers` and return the parsed body.
+async fn get_json(
+    request: reqwest::RequestBuilder,
+    headers: &HashMap<String, String>,
+    provider: &str,
+) -> anyhow::Result<serde_json::Value> {
+    let resp = extra_headers(request, headers).send().await?;
+    let status = resp.status();
+    let url = resp.url().to_string();
+    let text = resp.text().await?;
+    if !status.is_success() {
+        anyhow::bail!("{provider} API error ({status}): {}", body_preview(&text));
+    }
+    parse_json_body(provider, &url, status, &text)
+}
+
+/// Builds the complete response from a streamed body, one line at a time.
+trait StreamAccumulator: Send {
+    /// Take one line of the body, returning any new assistant text.
+    fn line(&mut self, line: &str) -> anyhow::Result<Option<String>>;
+
+    /// The response the streamed lines add up to.
+    fn finish(self) -> anyhow::Result<LlmResponse>;
+}
+
+/// A streamed response being read, with what's needed to log it at the end.
+struct BodyStream<'a, A> {
+    resp: reqwest::Response,
+    /// Received bytes not yet split into lines.
+    pending: Vec<u8>,
+    /// Everything received, for the I/O log.
+    raw: Vec<u8>,
+    acc: Option<A>,
+    provider: &'a str,
+    url: String,
+    request: serde_json::Value,
+    io_log: Option<&'a IoLogger>,
+    secret: Option<&'a str>,
+}
+
+/// POST `body` like [`post_json`], but stream the response through `acc`,
+/// yielding text as it arrives and then the complete response.
+fn post_stream<'a, A: StreamAccumulator + 'a>(
+    request: reqwest::RequestBuilder,
+    body: serde_json::Value,
+    provider: &'a str,
+    retry: &'a RetryPolicy,
+    io_log: Option<&'a IoLogger>,
+    secret: Option<&'a str>,
+    acc: A,
+) -> ChatStream<'a> {
+    let start = async move {
+        let (client, request) = request.json(&body).build_split();
+        let request = request?;
+        let url = request.url().to_string();
+
+        let resp = execute_with_retry(&client, request, retry, provider).await?;
+        let status = resp.status();
+        if !status.is_success() {
+            let text = resp.text().await?;
+            if let Some(log) = io_log {
+                log.log(&Exchange {
+                    provider,
+                    url: &url,
+                    request: &body,
+                    status: status.as_u16(),
+                    response: &text,
+                    secret,
+                });
+            }
+            anyhow::bail!("{provider} API error ({status}): {}", body_preview(&text));
+        }
+
+        Ok(BodyStream {
+            resp,
+            pending: Vec::new(),
+            raw: Vec::new(),
+            acc: Some(acc),
+            provider,
+            url,
+            request: body,
+            io_log,
+            secret,
+        })
+    };
+    Box::pin(
+        futures::stream::once(start)
+            .map_ok(|body| futures::stream::try_unfold(body, next_chunk))
+            .try_flatten(),
+    )
+}
+
+/// Read until there's new text or the body ends.
+async fn next_chunk<A: StreamAccumulator>(
+    mut body: BodyStream<'_, A>,
+) -> anyhow::Result<Option<(StreamChunk, BodyStream<'_, A>)>> {
+    let Some(acc) = body.acc.as_mut() else {
+        return Ok(None);
+    };
+    loop {
+        while let Some(end) = body.pending.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = body.pending.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line);
+            match acc.line(line.trim_end())? {
+                Some(text) if !text.is_empty() => return Ok(Some((StreamChunk::Delta(text), body))),
+                _ => {}
+            }
+        }
+
+        match body.resp.chunk().await? {
+            Some(bytes) => {
+                body.raw.extend_from_slice(&bytes);
+                body.pending.extend_from_slice(&bytes);
+            }
+            // The last line may lack its newline
+            None if !body.pending.is_empty() => body.pending.push(b'\n'),
+            None => break,
+        }
+    }
+
+    if let Some(log) = body.io_log {
+        log.log(&Exchange {
+            provider: body.provider,
+            url: &body.url,
+            request: &body.request,
+            status: body.resp.status().as_u16(),
+            response: &String::from_utf8_lossy(&body.raw),
+            secret: body.secret,
+        });
+    }
+    let response = body.acc.take().map(A::finish).transpose()?;
+    Ok(response.map(|response| (StreamChunk::Done(response), body)))
+}
+
+/// Characters of a raw response body quoted in error messages.
+const BODY_PREVIEW_CHARS: usize = 300;
+
+/// Parse a response body as JSON. When it isn't (a proxy's HTML error page,
+/// a `base_url` pointing at the wrong server), the error names the status
+/// and quotes the start of the body instead of just the parser's complaint.
+pub(crate) fn parse_json_body(
+    provider: &str,
+    url: &str,
+    status: reqwest::StatusCode,
+    text: &str,
+) -> anyhow::Result<serde_json::Value> {
+    serde_json::from_str(text).map_err(|e| {
+        anyhow::anyhow!(
+            "{provider} returned a non-JSON response from {url} ({status}); check the URL \
+             and any proxy in between. Body: {} (parse error: {e})",
+            body_preview(text)
+        )
+    })
+}
+
+/// `text` with whitespace collapsed, cut to [`BODY_PREVIEW_CHARS`].
+pub(crate) fn body_preview(text: &str) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.is_empty() {
+        return "<empty body>".into();
+    }
+    match collapsed.char_indices().nth(BODY_PREVIEW_CHARS) {
+        Some((cut, _)) => format!("{}...", &collapsed[..cut]),
+        None => collapsed,
+    }
+}
+
+/// HTTP client for provider and MCP calls. Proxies come from `HTTPS_PROXY`,
+/// `HTTP_PROXY` and `NO_PROXY` unless `proxy` overrides them; `NO_PROXY` is
+/// honored either way.
+pub(crate) fn http_client(proxy: Option<&str>) -> anyhow::Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder();
+    if let Some(url) = proxy {
+        let proxy = reqwest::Proxy::all(url)
+            .map_err(|e| anyhow::anyhow!("Invalid proxy URL '{url}': {e}"))?
+            .no_proxy(reqwest::NoProxy::from_env());
+        builder = builder.proxy(proxy);
+    }
+    Ok(builder.build()?)
+}
+
+/// Set `temperature` and `top_p` on a request body (or Ollama's `options`),
+/// leaving out the unset ones so the provider's defaults apply.
+fn set_sampling(target: &mut serde_json::Value, temperature: Option<f32>, top_p: Option<f32>) {
+    // Through the decimal form, so 0.2 goes out as 0.2 rather than the f32's
+    // 0.20000000298023224
+    let number = |v: f32| {
+        v.to_string()
+            .parse::<f64>()
+            .map_or(serde_json::Value::Null, serde_json::Value::from)
+    };
+    if let Some(temperature) = temperature {
+        target["temperature"] = number(temperature);
+    }
+    if let Some(top_p) = top_p {
+        target["top_p"] = number(top_p);
+    }
 }
 
 /// Anthropic Claude provider.
@@ -116,30 +655,60 @@ pub struct AnthropicProvider {
     api_key: String,
     model: String,
     max_tokens: u32,
+    temperature: Option<f32>,
+    top_p: Option<f32>,
+    headers: HashMap<String, String>,
+    retry: RetryPolicy,
+    io_log: Option<Arc<IoLogger>>,
 }
 
 impl AnthropicProvider {
     pub fn new(api_key: String, model: String, max_tokens: u32) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: http_client(None).unwrap_or_default(),
             api_key,
             model,
             max_tokens,
+            temperature: None,
+            top_p: None,
+            headers: HashMap::new(),
+            retry: RetryPolicy::default(),
+            io_log: None,
         }
     }
-}
 
-#[async_trait]
-impl LlmProvider for AnthropicProvider {
-    fn name(&self) -> &str {
-        "anthropic"
+    /// Send `temperature` and `top_p`, where set, with every request.
+    pub fn with_sampling(mut self, temperature: Option<f32>, top_p: Option<f32>) -> Self {
+        self.temperature = temperature;
+        self.top_p = top_p;
+        self
     }
 
-    async fn chat(
+    /// Retry transient failures according to `retry`.
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Send `headers` with every request.
+    pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Route requests through `proxy` instead of the environment's proxy.
+    pub fn with_proxy(mut self, proxy: &str) -> anyhow::Result<Self> {
+        self.client = http_client(Some(proxy))?;
+        Ok(self)
+    }
+
+    /// The Messages API request for this conversation.
+    fn request_body(
         &self,
         messages: &[ChatMessage],
         tools: &[ToolDefinition],
-    ) -> anyhow::Result<LlmResponse> {
+        tool_choice: &ToolChoice,
+    ) -> serde_json::Value {
         let system_msg = messages
             .iter()
             .find(|m| m.role == Role::System)
@@ -205,25 +774,72 @@ impl LlmProvider for AnthropicProvider {
             "messages": api_messages,
         });
 
+        set_sampling(&mut body, self.temperature, self.top_p);
         if let Some(sys) = system_msg {
             body["system"] = serde_json::json!(sys);
         }
         if !api_tools.is_empty() {
             body["tools"] = serde_json::json!(api_tools);
+            match tool_choice {
+                ToolChoice::Auto => {}
+                ToolChoice::Any => body["tool_choice"] = serde_json::json!({ "type": "any" }),
+                ToolChoice::Tool(name) => {
+                    body["tool_choice"] = serde_json::json!({ "type": "tool", "name": name })
+                }
+            }
         }
 
-        let resp = self
+        body
+    }
+
+    fn request(&self) -> reqwest::RequestBuilder {
+        let request = self
             .client
             .post("https://api.anthropic.com/v1/messages")
             .header("x-api-key", &self.api_key)
             .header("anthropic-version", "2023-06-01")
-            .header("content-type", "application/json")
-            .json(&body)
-            .send()
-            .await?;
+            .header("content-type", "application/json");
+        extra_headers(request, &self.headers)
+    }
+}
 
-        let status = resp.status();
-        let response_body: serde_json::Value = resp.json().await?;
+#[async_trait]
+impl LlmProvider for AnthropicProvider {
+    fn name(&self) -> &str {
+        "anthropic"
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn list_models(&self) -> anyhow::Result<ModelList> {
+        Ok(ModelList {
+            models: ANTHROPIC_MODELS.iter().map(|m| m.to_string()).collect(),
+            live: false,
+        })
+    }
+
+    fn set_io_log(&mut self, log: Arc<IoLogger>) {
+        self.io_log = Some(log);
+    }
+
+    async fn chat(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[ToolDefinition],
+        tool_choice: &ToolChoice,
+    ) -> anyhow::Result<LlmResponse> {
+        let body = self.request_body(messages, tools, tool_choice);
+        let (status, response_body) = post_json(
+            self.request(),
+            &body,
+            self.name(),
+            &self.retry,
+            self.io_log.as_deref(),
+            Some(&self.api_key),
+        )
+        .await?;
 
         if !status.is_success() {
             anyhow::bail!(
@@ -235,6 +851,25 @@ impl LlmProvider for AnthropicProvider {
 
         parse_anthropic_response(&response_body)
     }
+
+    fn chat_stream<'a>(
+        &'a self,
+        messages: &'a [ChatMessage],
+        tools: &'a [ToolDefinition],
+        tool_choice: &'a ToolChoice,
+    ) -> ChatStream<'a> {
+        let mut body = self.request_body(messages, tools, tool_choice);
+        body["stream"] = serde_json::json!(true);
+        post_stream(
+            self.request(),
+            body,
+            self.name(),
+            &self.retry,
+            self.io_log.as_deref(),
+            Some(&self.api_key),
+            AnthropicStream::default(),
+        )
+    }
 }
 
 fn parse_anthropic_response(body: &serde_json::Value) -> anyhow::Result<LlmResponse> {
@@ -284,6 +919,90 @@ fn parse_anthropic_response(body: &serde_json::Value) -> anyhow::Result<LlmRespo
     })
 }
 
+/// Rebuilds an Anthropic message from its server-sent events.
+#[derive(Default)]
+struct AnthropicStream {
+    /// Content blocks by index, as `content_block_start` announced them.
+    content: Vec<serde_json::Value>,
+    /// `input_json_delta` fragments of each tool_use block, by index.
+    tool_input: HashMap<usize, String>,
+    stop_reason: Option<String>,
+    usage: serde_json::Value,
+}
+
+impl StreamAccumulator for AnthropicStream {
+    fn line(&mut self, line: &str) -> anyhow::Result<Option<String>> {
+        let Some(data) = line.strip_prefix("data:") else {
+            return Ok(None);
+        };
+        let event: serde_json::Value = serde_json::from_str(data.trim())?;
+        let index = event["index"].as_u64().unwrap_or(0) as usize;
+        match event["type"].as_str() {
+            Some("message_start") => self.usage = event["message"]["usage"].clone(),
+            Some("content_block_start") => {
+                if self.content.len() <= index {
+                    self.content.resize(index + 1, serde_json::Value::Null);
+                }
+                self.content[index] = event["content_block"].clone();
+            }
+            Some("content_block_delta") => {
+                let delta = &event["delta"];
+                match delta["type"].as_str() {
+                    Some("text_delta") => {
+                        let text = delta["text"].as_str().unwrap_or("");
+                        if let Some(serde_json::Value::String(block_text)) = self
+                            .content
+                            .get_mut(index)
+                            .and_then(|block| block.get_mut("text"))
+                        {
+                            block_text.push_str(text);
+                        }
+                        return Ok(Some(text.to_string()));
+                    }
+                    Some("input_json_delta") => {
+                        self.tool_input
+                            .entry(index)
+                            .or_default()
+                            .push_str(delta["partial_json"].as_str().unwrap_or(""));
+                    }
+                    _ => {}
+                }
+            }
+            Some("message_delta") => {
+                if let Some(reason) = event["delta"]["stop_reason"].as_str() {
+                    self.stop_reason = Some(reason.to_string());
+                }
+                if let Some(output) = event["usage"]["output_tokens"].as_u64() {
+                    self.usage["output_tokens"] = output.into();
+                }
+            }
+            Some("error") => anyhow::bail!("Anthropic API error: {}", event["error"]),
+            _ => {}
+        }
+        Ok(None)
+    }
+
+    fn finish(mut self) -> anyhow::Result<LlmResponse> {
+        for (index, input) in self.tool_input {
+            if let Some(block) = self.content.get_mut(index) {
+                block["input"] = if input.is_empty() {
+                    serde_json::json!({})
+                } else {
+                    serde_json::from_str(&input)?
+                };
+            }
+        }
+        let mut body = serde_json::json!({
+            "content": self.content,
+            "stop_reason": self.stop_reason,
+        });
+        if self.usage.is_object() {
+            body["usage"] = self.usage;
+        }
+        parse_anthropic_response(&body)
+    }
+}
+
 /// OpenAI-compatible provider (works with OpenAI, Azure OpenAI, and compatible APIs).
 pub struct OpenAiProvider {
     client: reqwest::Client,
@@ -291,31 +1010,61 @@ pub struct OpenAiProvider {
     model: String,
     base_url: String,
     max_tokens: u32,
+    temperature: Option<f32>,
+    top_p: Option<f32>,
+    headers: HashMap<String, String>,
+    retry: RetryPolicy,
+    io_log: Option<Arc<IoLogger>>,
 }
 
 impl OpenAiProvider {
     pub fn new(api_key: String, model: String, base_url: Option<String>, max_tokens: u32) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: http_client(None).unwrap_or_default(),
             api_key,
             model,
             base_url: base_url.unwrap_or_else(|| "https://api.openai.com/v1".to_string()),
             max_tokens,
+            temperature: None,
+            top_p: None,
+            headers: HashMap::new(),
+            retry: RetryPolicy::default(),
+            io_log: None,
         }
     }
-}
 
-#[async_trait]
-impl LlmProvider for OpenAiProvider {
-    fn name(&self) -> &str {
-        "openai"
+    /// Send `temperature` and `top_p`, where set, with every request.
+    pub fn with_sampling(mut self, temperature: Option<f32>, top_p: Option<f32>) -> Self {
+        self.temperature = temperature;
+        self.top_p = top_p;
+        self
     }
 
-    async fn chat(
+    /// Retry transient failures according to `retry`.
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Send `headers` with every request.
+    pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Route requests through `proxy` instead of the environment's proxy.
+    pub fn with_proxy(mut self, proxy: &str) -> anyhow::Result<Self> {
+        self.client = http_client(Some(proxy))?;
+        Ok(self)
+    }
+
+    /// The chat completions request for this conversation.
+    fn request_body(
         &self,
         messages: &[ChatMessage],
         tools: &[ToolDefinition],
-    ) -> anyhow::Result<LlmResponse> {
+        tool_choice: &ToolChoice,
+    ) -> serde_json::Value {
         let api_messages: Vec<serde_json::Value> = messages
             .iter()
             .map(|m| {
@@ -373,22 +1122,79 @@ impl LlmProvider for OpenAiProvider {
             "max_tokens": self.max_tokens,
             "messages": api_messages,
         });
+        set_sampling(&mut body, self.temperature, self.top_p);
 
         if !api_tools.is_empty() {
             body["tools"] = serde_json::json!(api_tools);
+            match tool_choice {
+                ToolChoice::Auto => {}
+                ToolChoice::Any => body["tool_choice"] = serde_json::json!("required"),
+                ToolChoice::Tool(name) => {
+                    body["tool_choice"] =
+                        serde_json::json!({ "type": "function", "function": { "name": name } })
+                }
+            }
         }
 
-        let resp = self
+        body
+    }
+
+    fn request(&self) -> reqwest::RequestBuilder {
+        let request = self
             .client
             .post(format!("{}/chat/completions", self.base_url))
             .header("Authorization", format!("Bearer {}", self.api_key))
-            .header("Content-Type", "application/json")
-            .json(&body)
-            .send()
-            .await?;
+            .header("Content-Type", "application/json");
+        extra_headers(request, &self.headers)
+    }
+}
 
-        let status = resp.status();
-        let response_body: serde_json::Value = resp.json().await?;
+#[async_trait]
+impl LlmProvider for OpenAiProvider {
+    fn name(&self) -> &str {
+        "openai"
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn list_models(&self) -> anyhow::Result<ModelList> {
+        let request = self
+            .client
+            .get(format!("{}/models", self.base_url))
+            .header("Authorization", format!("Bearer {}", self.api_key));
+        let body = get_json(request, &self.headers, "OpenAI").await?;
+        let mut models: Vec<String> = body["data"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|m| m["id"].as_str().map(String::from))
+            .collect();
+        models.sort();
+        Ok(ModelList { models, live: true })
+    }
+
+    fn set_io_log(&mut self, log: Arc<IoLogger>) {
+        self.io_log = Some(log);
+    }
+
+    async fn chat(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[ToolDefinition],
+        tool_choice: &ToolChoice,
+    ) -> anyhow::Result<LlmResponse> {
+        let body = self.request_body(messages, tools, tool_choice);
+        let (status, response_body) = post_json(
+            self.request(),
+            &body,
+            self.name(),
+            &self.retry,
+            self.io_log.as_deref(),
+            Some(&self.api_key),
+        )
+        .await?;
 
         if !status.is_success() {
             anyhow::bail!("OpenAI API error ({}): {}", status, response_body);
@@ -396,6 +1202,27 @@ impl LlmProvider for OpenAiProvider {
 
         parse_openai_response(&response_body)
     }
+
+    fn chat_stream<'a>(
+        &'a self,
+        messages: &'a [ChatMessage],
+        tools: &'a [ToolDefinition],
+        tool_choice: &'a ToolChoice,
+    ) -> ChatStream<'a> {
+        let mut body = self.request_body(messages, tools, tool_choice);
+        body["stream"] = serde_json::json!(true);
+        // Usage otherwise never arrives when streaming
+        body["stream_options"] = serde_json::json!({ "include_usage": true });
+        post_stream(
+            self.request(),
+            body,
+            self.name(),
+            &self.retry,
+            self.io_log.as_deref(),
+            Some(&self.api_key),
+            OpenAiStream::default(),
+        )
+    }
 }
 
 fn parse_openai_response(body: &serde_json::Value) -> anyhow::Result<LlmResponse> {
@@ -414,9 +1241,7 @@ fn parse_openai_response(body: &serde_json::Value) -> anyhow::Result<LlmResponse
                 .filter_map(|tc| {
                     let id = tc["id"].as_str()?.to_string();
                     let name = tc["function"]["name"].as_str()?.to_string();
-                    let args_str = tc["function"]["arguments"].as_str().unwrap_or("{}");
-                    let arguments: serde_json::Value =
-                        serde_json::from_str(args_str).unwrap_or(serde_json::json!({}));
+                    let arguments = tool_arguments(&tc["function"]["arguments"]);
                     Some(ToolCall {
                         id,
                         name,
@@ -452,41 +1277,171 @@ fn parse_openai_response(body: &serde_json::Value) -> anyhow::Result<LlmResponse
     })
 }
 
+/// Rebuilds an OpenAI chat completion from its `data:` chunks.
+#[derive(Default)]
+struct OpenAiStream {
+    content: String,
+    /// Tool calls by index, with their argument fragments joined.
+    tool_calls: Vec<serde_json::Value>,
+    finish_reason: Option<String>,
+    usage: Option<serde_json::Value>,
+}
+
+impl StreamAccumulator for OpenAiStream {
+    fn line(&mut self, line: &str) -> anyhow::Result<Option<String>> {
+        let Some(data) = line.strip_prefix("data:").map(str::trim) else {
+            return Ok(None);
+        };
+        if data == "[DONE]" {
+            return Ok(None);
+        }
+        let chunk: serde_json::Value = serde_json::from_str(data)?;
+        if let Some(error) = chunk.get("error") {
+            anyhow::bail!("OpenAI API error: {error}");
+        }
+        if chunk["usage"].is_object() {
+            self.usage = Some(chunk["usage"].clone());
+        }
+
+        let choice = &chunk["choices"][0];
+        if let Some(reason) = choice["finish_reason"].as_str() {
+            self.finish_reason = Some(reason.to_string());
+        }
+        let delta = &choice["delta"];
+        for (i, tc) in delta["tool_calls"].as_array().into_iter().flatten().enumerate() {
+            let index = tc["index"].as_u64().map_or(i, |index| index as usize);
+            while self.tool_calls.len() <= index {
+                self.tool_calls.push(serde_json::json!({
+                    "id": "",
+                    "function": { "name": "", "arguments": "" },
+                }));
+            }
+            let call = &mut self.tool_calls[index];
+            if let Some(id) = tc["id"].as_str() {
+                call["id"] = id.into();
+            }
+            if let Some(name) = tc["function"]["name"].as_str() {
+                call["function"]["name"] = name.into();
+            }
+            if let Some(serde_json::Value::String(arguments)) =
+                call["function"].get_mut("arguments")
+            {
+                arguments.push_str(tc["function"]["arguments"].as_str().unwrap_or(""));
+            }
+        }
+
+        let text = delta["content"].as_str().unwrap_or("");
+        self.content.push_str(text);
+        Ok(Some(text.to_string()))
+    }
+
+    fn finish(self) -> anyhow::Result<LlmResponse> {
+        let mut body = serde_json::json!({
+            "choices": [{
+                "message": { "content": self.content, "tool_calls": self.tool_calls },
+                "finish_reason": self.finish_reason,
+            }],
+        });
+        if let Some(usage) = self.usage {
+            body["usage"] = usage;
+        }
+        parse_openai_response(&body)
+    }
+}
+
+/// Tool-call arguments as an object. OpenAI sends them as a JSON string;
+/// Ollama and some OpenAI-compatible servers send the object itself.
+fn tool_arguments(raw: &serde_json::Value) -> serde_json::Value {
+    match raw {
+        serde_json::Value::String(s) => {
+            serde_json::from_str(s).unwrap_or_else(|_| serde_json::json!({}))
+        }
+        serde_json::Value::Object(_) => raw.clone(),
+        _ => serde_json::json!({}),
+    }
+}
+
 /// Ollama provider (local LLM inference).
 pub struct OllamaProvider {
     client: reqwest::Client,
     base_url: String,
     model: String,
     max_tokens: u32,
+    temperature: Option<f32>,
+    top_p: Option<f32>,
+    headers: HashMap<String, String>,
+    keep_alive: KeepAlive,
+    timeout: Duration,
+    retry: RetryPolicy,
+    io_log: Option<Arc<IoLogger>>,
 }
 
 impl OllamaProvider {
     pub fn new(base_url: String, model: String, max_tokens: u32) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: http_client(None).unwrap_or_default(),
             base_url,
             model,
             max_tokens,
+            temperature: None,
+            top_p: None,
+            headers: HashMap::new(),
+            keep_alive: KeepAlive::Duration(DEFAULT_OLLAMA_KEEP_ALIVE.into()),
+            timeout: DEFAULT_OLLAMA_TIMEOUT,
+            retry: RetryPolicy::default(),
+            io_log: None,
         }
     }
-}
 
-#[async_trait]
-impl LlmProvider for OllamaProvider {
-    fn name(&self) -> &str {
-        "ollama"
+    /// Keep the model loaded for `keep_alive` after each request.
+    pub fn with_keep_alive(mut self, keep_alive: KeepAlive) -> Self {
+        self.keep_alive = keep_alive;
+        self
     }
 
-    async fn chat(
+    /// Give up on a request after `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Send `temperature` and `top_p`, where set, with every request.
+    pub fn with_sampling(mut self, temperature: Option<f32>, top_p: Option<f32>) -> Self {
+        self.temperature = temperature;
+        self.top_p = top_p;
+        self
+    }
+
+    /// Retry transient failures according to `retry`.
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Send `headers` with every request.
+    pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Route requests through `proxy` instead of the environment's proxy.
+    pub fn with_proxy(mut self, proxy: &str) -> anyhow::Result<Self> {
+        self.client = http_client(Some(proxy))?;
+        Ok(self)
+    }
+
+    /// The `/api/chat` request for this conversation.
+    fn request_body(
         &self,
         messages: &[ChatMessage],
         tools: &[ToolDefinition],
-    ) -> anyhow::Result<LlmResponse> {
+        tool_choice: &ToolChoice,
+    ) -> serde_json::Value {
         // Ollama uses OpenAI-compatible API
         let api_messages: Vec<serde_json::Value> = messages
             .iter()
             .map(|m| {
-                serde_json::json!({
+                let mut message = serde_json::json!({
                     "role": match m.role {
                         Role::System => "system",
                         Role::User => "user",
@@ -494,7 +1449,21 @@ impl LlmProvider for OllamaProvider {
                         Role::Tool => "tool",
                     },
                     "content": m.content,
-                })
+                });
+                // Without its own calls in the history the model loses track
+                // of what it already ran. Ollama wants arguments as objects.
+                if !m.tool_calls.is_empty() {
+                    message["tool_calls"] = m
+                        .tool_calls
+                        .iter()
+                        .map(|tc| {
+                            serde_json::json!({
+                                "function": { "name": tc.name, "arguments": tc.arguments }
+                            })
+                        })
+                        .collect();
+                }
+                message
             })
             .collect();
 
@@ -516,103 +1485,245 @@ impl LlmProvider for OllamaProvider {
             "model": self.model,
             "messages": api_messages,
             "stream": false,
+            "keep_alive": self.keep_alive,
             "options": {
                 "num_predict": self.max_tokens,
             }
         });
+        set_sampling(&mut body["options"], self.temperature, self.top_p);
 
         if !api_tools.is_empty() {
             body["tools"] = serde_json::json!(api_tools);
+            if *tool_choice != ToolChoice::Auto {
+                tracing::debug!(?tool_choice, "Ollama has no tool_choice; letting the model decide");
+            }
         }
 
-        let resp = self
+        body
+    }
+
+    fn request(&self) -> reqwest::RequestBuilder {
+        let request = self
             .client
             .post(format!("{}/api/chat", self.base_url))
             .header("Content-Type", "application/json")
-            .json(&body)
-            .send()
-            .await?;
+            .timeout(self.timeout);
+        extra_headers(request, &self.headers)
+    }
+}
 
-        let status = resp.status();
-        let response_body: serde_json::Value = resp.json().await?;
+#[async_trait]
+impl LlmProvider for OllamaProvider {
+    fn name(&self) -> &str {
+        "ollama"
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    async fn list_models(&self) -> anyhow::Result<ModelList> {
+        let request = self.client.get(format!("{}/api/tags", self.base_url));
+        let body = get_json(request, &self.headers, "Ollama").await?;
+        let mut models: Vec<String> = body["models"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|m| m["name"].as_str().map(String::from))
+            .collect();
+        models.sort();
+        Ok(ModelList { models, live: true })
+    }
+
+    fn set_io_log(&mut self, log: Arc<IoLogger>) {
+        self.io_log = Some(log);
+    }
+
+    async fn chat(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[ToolDefinition],
+        tool_choice: &ToolChoice,
+    ) -> anyhow::Result<LlmResponse> {
+        let body = self.request_body(messages, tools, tool_choice);
+        let (status, response_body) =
+            post_json(self.request(), &body, self.name(), &self.retry, self.io_log.as_deref(), None)
+                .await?;
 
         if !status.is_success() {
             anyhow::bail!("Ollama API error ({}): {}", status, response_body);
         }
 
-        let content = response_body["message"]["content"]
-            .as_str()
-            .unwrap_or("")
-            .to_string();
+        Ok(parse_ollama_response(&response_body))
+    }
 
-        let tool_calls: Vec<ToolCall> = response_body["message"]["tool_calls"]
-            .as_array()
-            .map(|tcs| {
-                tcs.iter()
-                    .enumerate()
-                    .filter_map(|(i, tc)| {
-                        let name = tc["function"]["name"].as_str()?.to_string();
-                        let arguments = tc["function"]["arguments"].clone();
-                        Some(ToolCall {
-                            id: format!("call_{i}"),
-                            name,
-                            arguments,
-                        })
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
+    fn chat_stream<'a>(
+        &'a self,
+        messages: &'a [ChatMessage],
+        tools: &'a [ToolDefinition],
+        tool_choice: &'a ToolChoice,
+    ) -> ChatStream<'a> {
+        let mut body = self.request_body(messages, tools, tool_choice);
+        body["stream"] = serde_json::json!(true);
+        post_stream(
+            self.request(),
+            body,
+            self.name(),
+            &self.retry,
+            self.io_log.as_deref(),
+            None,
+            OllamaStream::default(),
+        )
+    }
+}
 
-        let finish_reason = if !tool_calls.is_empty() {
-            FinishReason::ToolUse
-        } else {
-            FinishReason::Stop
-        };
+fn parse_ollama_response(response_body: &serde_json::Value) -> LlmResponse {
+    let content = response_body["message"]["content"]
+        .as_str()
+        .unwrap_or("")
+        .to_string();
 
-        Ok(LlmResponse {
-            message: ChatMessage {
-                role: Role::Assistant,
-                content,
-                tool_calls,
-                tool_call_id: None,
-            },
-            finish_reason,
-            usage: None,
+    let tool_calls: Vec<ToolCall> = response_body["message"]["tool_calls"]
+        .as_array()
+        .map(|tcs| {
+            tcs.iter()
+                .enumerate()
+                .filter_map(|(i, tc)| {
+                    let name = tc["function"]["name"].as_str()?.to_string();
+                    let arguments = tool_arguments(&tc["function"]["arguments"]);
+                    // Older versions send no id
+                    let id = tc["id"]
+                        .as_str()
+                        .map(String::from)
+                        .unwrap_or_else(|| format!("call_{i}"));
+                    Some(ToolCall {
+                        id,
+                        name,
+                        arguments,
+                    })
+                })
+                .collect()
         })
+        .unwrap_or_default();
+
+    let finish_reason = if !tool_calls.is_empty() {
+        FinishReason::ToolUse
+    } else {
+        FinishReason::Stop
+    };
+
+    LlmResponse {
+        message: ChatMessage {
+            role: Role::Assistant,
+            content,
+            tool_calls,
+            tool_call_id: None,
+        },
+        finish_reason,
+        usage: None,
+    }
+}
+
+/// Rebuilds an Ollama chat response from its line-delimited JSON chunks.
+#[derive(Default)]
+struct OllamaStream {
+    content: String,
+    tool_calls: Vec<serde_json::Value>,
+}
+
+impl StreamAccumulator for OllamaStream {
+    fn line(&mut self, line: &str) -> anyhow::Result<Option<String>> {
+        if line.is_empty() {
+            return Ok(None);
+        }
+        let chunk: serde_json::Value = serde_json::from_str(line)?;
+        if let Some(error) = chunk["error"].as_str() {
+            anyhow::bail!("Ollama API error: {error}");
+        }
+        if let Some(tool_calls) = chunk["message"]["tool_calls"].as_array() {
+            self.tool_calls.extend(tool_calls.iter().cloned());
+        }
+        let text = chunk["message"]["content"].as_str().unwrap_or("");
+        self.content.push_str(text);
+        Ok(Some(text.to_string()))
+    }
+
+    fn finish(self) -> anyhow::Result<LlmResponse> {
+        Ok(parse_ollama_response(&serde_json::json!({
+            "message": { "content": self.content, "tool_calls": self.tool_calls },
+        })))
     }
 }
 
-/// Create an LLM provider from config.
-pub fn create_provider(config: &LlmProviderConfig) -> Box<dyn LlmProvider> {
-    match config {
+/// Create an LLM provider from config. Fails only on an invalid `proxy`.
+pub fn create_provider(config: &LlmProviderConfig) -> anyhow::Result<Box<dyn LlmProvider>> {
+    Ok(match config {
         LlmProviderConfig::Anthropic {
             api_key,
             model,
             max_tokens,
-        } => Box::new(AnthropicProvider::new(
-            api_key.clone(),
-            model.clone(),
-            *max_tokens,
-        )),
+            headers,
+            proxy,
+            retry,
+            temperature,
+            top_p,
+        } => {
+            let provider = AnthropicProvider::new(api_key.clone(), model.clone(), *max_tokens)
+                .with_headers(headers.clone())
+                .with_retry(retry.clone())
+                .with_sampling(*temperature, *top_p);
+            match proxy {
+                Some(proxy) => Box::new(provider.with_proxy(proxy)?),
+                None => Box::new(provider),
+            }
+        }
         LlmProviderConfig::Openai {
             api_key,
             model,
             base_url,
             max_tokens,
-        } => Box::new(OpenAiProvider::new(
-            api_key.clone(),
-            model.clone(),
-            base_url.clone(),
-            *max_tokens,
-        )),
+            headers,
+            proxy,
+            retry,
+            temperature,
+            top_p,
+        } => {
+            let provider =
+                OpenAiProvider::new(api_key.clone(), model.clone(), base_url.clone(), *max_tokens)
+                    .with_headers(headers.clone())
+                    .with_retry(retry.clone())
+                    .with_sampling(*temperature, *top_p);
+            match proxy {
+                Some(proxy) => Box::new(provider.with_proxy(proxy)?),
+                None => Box::new(provider),
+            }
+        }
         LlmProviderConfig::Ollama {
             base_url,
             model,
             max_tokens,
-        } => Box::new(OllamaProvider::new(
-            base_url.clone(),
-            model.clone(),
-            *max_tokens,
-        )),
-    }
+            headers,
+            proxy,
+            retry,
+            keep_alive,
+            timeout,
+            temperature,
+            top_p,
+        } => {
+            let mut provider = OllamaProvider::new(base_url.clone(), model.clone(), *max_tokens)
+                .with_headers(headers.clone())
+                .with_retry(retry.clone())
+                .with_sampling(*temperature, *top_p);
+            if let Some(keep_alive) = keep_alive {
+                provider = provider.with_keep_alive(keep_alive.clone());
+            }
+            if let Some(timeout) = timeout {
+                provider = provider.with_timeout(*timeout);
+            }
+            match proxy {
+                Some(proxy) => Box::new(provider.with_proxy(proxy)?),
+                None => Box::new(provider),
+            }
+        }
+    })
 }
@@ -0,0 +1,80 @@
+//! Fallback target configs built from the user's own words, for when the LLM
+//! omits `target_config`.
+
+/// Try to extract a target_config from connection URLs in the user prompt,
+/// or from environment/defaults for kubernetes.
+pub fn extract_target_config_from_prompt(
+    prompt: &str,
+    target: Option<&str>,
+) -> Option<serde_json::Value> {
+    // Look for database connection URLs in the prompt
+    for word in prompt.split_whitespace() {
+        let word = word.trim_matches(|c: char| c == '"' || c == '\'' || c == ',' || c == ')');
+        if word.starts_with("postgres://") || word.starts_with("postgresql://") {
+            let lower = prompt.to_lowercase();
+            let db_type = if lower.contains("cockroach") || lower.contains("crdb") {
+                "cockroach_db"
+            } else if lower.contains("yugabyte") || lower.contains("ysql") {
+                "yugabyte_db"
+            } else {
+                "postgres"
+            };
+            return Some(serde_json::json!({
+                "connection_url": word,
+                "db_type": db_type
+            }));
+        }
+        if word.starts_with("mysql://") {
+            return Some(serde_json::json!({
+                "connection_url": word,
+                "db_type": "mysql"
+            }));
+        }
+        if word.starts_with("mongodb://") || word.starts_with("mongodb+srv://") {
+            return Some(serde_json::json!({
+                "connection_url": word,
+                "db_type": "mongodb"
+            }));
+        }
+    }
+
+    // For kubernetes, use KUBECONFIG env var or default ~/.kube/config
+    if matches!(target, Some("kubernetes" | "k8s")) {
+        let kubeconfig = std::env::var("KUBECONFIG").ok().or_else(|| {
+            let home = std::env::var("HOME").ok()?;
+            let default_path = format!("{home}/.kube/config");
+            if std::path::Path::new(&default_path).exists() {
+                Some(default_path)
+            } else {
+                None
+            }
+        });
+
+        let namespace =
+            extract_namespace_from_prompt(prompt).unwrap_or_else(|| "default".to_string());
+
+        let mut config = serde_json::json!({ "namespace": namespace });
+        if let Some(path) = kubeconfig {
+            config["kubeconfig"] = serde_json::Value::String(path);
+        }
+        return Some(config);
+    }
+
+    None
+}
+
+/// Try to extract a kubernetes namespace from the prompt.
+pub fn extract_namespace_from_prompt(prompt: &str) -> Option<String> {
+    let lower = prompt.to_lowercase();
+    for kw in &["namespace ", "ns "] {
+        if let Some(pos) = lower.find(kw) {
+            let rest = &prompt[pos + kw.len()..];
+            let ns = rest.split_whitespace().next()?;
+            let ns = ns.trim_matches(|c: char| c == '"' || c == '\'' || c == ',');
+            if !ns.is_empty() {
+                return Some(ns.to_string());
+            }
+        }
+    }
+    None
+}
@@ -1,9 +1,12 @@
 use async_trait::async_trait;
 
 use chaos_core::agent::{Agent, AgentStatus};
-use chaos_core::discovery::DiscoveredResource;
+use chaos_core::cleanup::{CleanupAction, CleanupItem};
+use chaos_core::discovery::{DiscoveredResource, Discovery, DiscoveryError};
 use chaos_core::error::ChaosResult;
 use chaos_core::skill::{Skill, SkillContext, TargetDomain};
+use chaos_core::skill_registry::add_registered_skills;
+use chaos_core::target_config::validate_target_config;
 
 use crate::config::ServerTargetConfig;
 use crate::service_discovery::ServiceDiscoverer;
@@ -14,31 +17,55 @@ use crate::skills::permission_change::PermissionChangeSkill;
 use crate::skills::service_stop::ServiceStopSkill;
 use crate::ssh::SshSession;
 
+/// Prints the systemd state, followed by the failed units when it's `degraded`.
+const HEALTH_CHECK: &str = "state=$(systemctl is-system-running 2>/dev/null); echo \"$state\"; \
+     [ \"$state\" = degraded ] && systemctl --failed --no-legend --plain 2>/dev/null; true";
+
+/// Lists leftover disk fill files and stress-ng pid files as `<kind> <path>`:
+/// per-experiment work dirs first, then the flat names older releases used.
+/// Disk fills go under their `target_mount`, so every mount point is scanned.
+const LEFTOVER_SCAN: &str = "for m in $({ echo /tmp; echo /var/tmp; \
+     awk '{print $2}' /proc/mounts 2>/dev/null; } | sort -u); do \
+     for f in \"${m%/}\"/chaos-agent/*/*.fill; do \
+     [ -e \"$f\" ] && echo \"fill $f\"; done; done; \
+     for f in /tmp/chaos-agent-*.fill /var/tmp/chaos-agent-*.fill; do \
+     [ -e \"$f\" ] && echo \"fill $f\"; done; \
+     for p in /tmp/chaos-agent/*/*.pid /tmp/chaos-*-stress-*.pid; do \
+     [ -e \"$p\" ] && echo \"pid $p\"; done; true";
+
+const AGENT_NAME: &str = "server-chaos-agent";
+
 pub struct ServerAgent {
     config: ServerTargetConfig,
     sessions: Vec<SshSession>,
+    /// Hosts that couldn't be reached at initialize, reported by discovery.
+    unreachable: Vec<DiscoveryError>,
     status: AgentStatus,
     skills: Vec<Box<dyn Skill>>,
 }
 
 impl ServerAgent {
     pub fn new(config: ServerTargetConfig) -> Self {
-        let skills: Vec<Box<dyn Skill>> = vec![
+        let mut skills: Vec<Box<dyn Skill>> = vec![
             Box::new(DiskFillSkill),
             Box::new(PermissionChangeSkill),
             Box::new(ServiceStopSkill),
             Box::new(CpuStressSkill),
             Box::new(MemoryStressSkill),
         ];
+        add_registered_skills(&mut skills, TargetDomain::Server, AGENT_NAME);
         Self {
             config,
             sessions: Vec::new(),
+            unreachable: Vec::new(),
             status: AgentStatus::Idle,
             skills,
         }
     }
 
     pub fn from_yaml(value: &serde_yaml::Value) -> ChaosResult<Self> {
+        validate_target_config(TargetDomain::Server, value)
+            .map_err(chaos_core::error::ChaosError::Config)?;
         let config: ServerTargetConfig = serde_yaml::from_value(value.clone())
             .map_err(|e| chaos_core::error::ChaosError::Config(format!("Invalid server config: {e}")))?;
         Ok(Self::new(config))
@@ -52,7 +79,7 @@ impl Agent for ServerAgent {
     }
 
     fn name(&self) -> &str {
-        "server-chaos-agent"
+        AGENT_NAME
     }
 
     fn status(&self) -> AgentStatus {
@@ -61,18 +88,33 @@ impl Agent for ServerAgent {
 
     async fn initialize(&mut self) -> ChaosResult<()> {
         self.status = AgentStatus::Initializing;
+        // Initialized once per experiment (and by the health pre-check); reconnect fresh.
+        self.sessions.clear();
+        self.unreachable.clear();
 
+        // One down host doesn't block the rest; fail only if none connect
         for host_config in &self.config.hosts {
-            let session = SshSession::connect(host_config)
-                .await
-                .map_err(|e| {
-                    chaos_core::error::ChaosError::Connection(anyhow::anyhow!(
-                        "SSH connection to {} failed: {e}",
-                        host_config.host
-                    ))
-                })?;
-            tracing::info!(host = %host_config.host, "SSH connection established");
-            self.sessions.push(session);
+            match SshSession::connect(host_config, self.config.exec).await {
+                Ok(session) => {
+                    tracing::info!(host = %host_config.host, "SSH connection established");
+                    self.sessions.push(session);
+                }
+                Err(e) => {
+                    tracing::warn!(host = %host_config.host, error = %e, "SSH connection failed");
+                    self.unreachable.push(DiscoveryError {
+                        scope: host_config.host.clone(),
+                        error: format!("SSH connection failed: {e}"),
+                    });
+                }
+            }
+        }
+
+        if self.sessions.is_empty() && !self.unreachable.is_empty() {
+            let errors: Vec<String> = self.unreachable.iter().map(ToString::to_string).collect();
+            return Err(chaos_core::error::ChaosError::Connection(anyhow::anyhow!(
+                "SSH connection failed on every host: {}",
+                errors.join("; ")
+            )));
         }
 
         self.status = AgentStatus::Ready;
@@ -80,28 +122,37 @@ impl Agent for ServerAgent {
         Ok(())
     }
 
-    async fn discover(&mut self) -> ChaosResult<Vec<Box<dyn DiscoveredResource>>> {
+    async fn discover(&mut self) -> ChaosResult<Discovery> {
         self.status = AgentStatus::Discovering;
 
         if !self.config.discovery.enabled {
             self.status = AgentStatus::Ready;
-            return Ok(Vec::new());
+            return Ok(Discovery::default());
         }
 
-        let mut all_resources: Vec<Box<dyn DiscoveredResource>> = Vec::new();
+        let mut discovery = Discovery {
+            errors: self.unreachable.clone(),
+            ..Default::default()
+        };
 
+        // One unreachable host doesn't hide the others
         for session in &self.sessions {
-            let resources = ServiceDiscoverer::discover(
+            let resources = match ServiceDiscoverer::discover(
                 session,
                 &self.config.discovery.exclude_services,
             )
             .await
-            .map_err(|e| {
-                chaos_core::error::ChaosError::Discovery(format!(
-                    "Discovery on {} failed: {e}",
-                    session.host
-                ))
-            })?;
+            {
+                Ok(resources) => resources,
+                Err(e) => {
+                    tracing::warn!(host = %session.host, error = %e, "Server discovery failed");
+                    discovery.errors.push(DiscoveryError {
+                        scope: session.host.clone(),
+                        error: e.to_string(),
+                    });
+                    continue;
+                }
+            };
 
             tracing::info!(
                 host = %session.host,
@@ -112,12 +163,12 @@ impl Agent for ServerAgent {
             );
 
             for r in resources {
-                all_resources.push(Box::new(r));
+                discovery.resources.push(Box::new(r));
             }
         }
 
         self.status = AgentStatus::Ready;
-        Ok(all_resources)
+        discovery.into_result()
     }
 
     fn skills(&self) -> Vec<&dyn Skill> {
@@ -143,11 +194,16 @@ impl Agent for ServerAgent {
 
         // We can't move the session, so we create a new connection for the context.
         // In a production implementation, we'd use an Arc<SshSession> pool.
-        let host_config = self.config.hosts.first().ok_or_else(|| {
-            chaos_core::error::ChaosError::Connection(anyhow::anyhow!("No host configs"))
-        })?;
+        let host_config = self
+            .config
+            .hosts
+            .iter()
+            .find(|h| h.host == session.host)
+            .ok_or_else(|| {
+                chaos_core::error::ChaosError::Connection(anyhow::anyhow!("No host configs"))
+            })?;
 
-        let new_session = SshSession::connect(host_config)
+        let new_session = SshSession::connect(host_config, self.config.exec)
             .await
             .map_err(|e| {
                 chaos_core::error::ChaosError::Connection(anyhow::anyhow!(
@@ -159,9 +215,91 @@ impl Agent for ServerAgent {
         Ok(SkillContext {
             shared: Box::new(new_session),
             params: serde_yaml::Value::Null,
+            parsed_params: Default::default(),
+            output: Default::default(),
+            experiment_id: uuid::Uuid::nil(),
+            experiment_name: String::new(),
+            skill_name: String::new(),
+            dry_run: false,
+            limits: Default::default(),
         })
     }
 
+    async fn health_check(&self) -> ChaosResult<()> {
+        let mut problems = Vec::new();
+        for session in &self.sessions {
+            match session.exec(HEALTH_CHECK).await {
+                Ok((_, stdout, _)) => {
+                    // Hosts without systemd report nothing beyond being reachable.
+                    let mut lines = stdout.lines();
+                    if lines.next().map(str::trim) == Some("degraded") {
+                        let failed: Vec<&str> = lines.filter_map(|l| l.split_whitespace().next()).collect();
+                        problems.push(format!("{} has failed units: {}", session.host, failed.join(", ")));
+                    }
+                }
+                Err(e) => problems.push(format!("{} unreachable: {e}", session.host)),
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(chaos_core::error::ChaosError::Unhealthy(problems.join("; ")))
+        }
+    }
+
+    async fn cleanup(&mut self, dry_run: bool) -> ChaosResult<Vec<CleanupItem>> {
+        let mut items = Vec::new();
+
+        for session in &self.sessions {
+            let (_, stdout, _) = session.exec(LEFTOVER_SCAN).await.map_err(|e| {
+                chaos_core::error::ChaosError::Discovery(format!(
+                    "Cleanup scan on {} failed: {e}",
+                    session.host
+                ))
+            })?;
+
+            for line in stdout.lines() {
+                let Some((kind, path)) = line.split_once(' ') else {
+                    continue;
+                };
+                let (kind, what, cmd) = match kind {
+                    "fill" => ("file", "disk fill file from server.disk_fill", format!("rm -f {path}")),
+                    "pid" => (
+                        "process",
+                        "stress-ng started by server.cpu_stress / server.memory_stress",
+                        format!("kill $(cat {path} 2>/dev/null) 2>/dev/null; rm -f {path}"),
+                    ),
+                    _ => continue,
+                };
+                let cmd = format!("{cmd} && {{ rmdir $(dirname {path}) 2>/dev/null; true; }}");
+                let detail = match experiment_from_path(path) {
+                    Some(id) => format!("{what} (experiment {id})"),
+                    None => what.to_string(),
+                };
+                let action = if dry_run {
+                    CleanupAction::WouldRemove
+                } else {
+                    match session.exec(&cmd).await {
+                        Ok((0, _, _)) => CleanupAction::Removed,
+                        Ok((code, _, stderr)) => {
+                            CleanupAction::Failed(format!("exit {code}: {}", stderr.trim()))
+                        }
+                        Err(e) => CleanupAction::Failed(e.to_string()),
+                    }
+                };
+                items.push(CleanupItem::new(
+                    kind,
+                    format!("{}:{path}", session.host),
+                    detail,
+                    action,
+                ));
+            }
+        }
+
+        Ok(items)
+    }
+
     async fn shutdown(&mut self) -> ChaosResult<()> {
         self.sessions.clear();
         self.status = AgentStatus::Idle;
@@ -169,3 +307,10 @@ impl Agent for ServerAgent {
         Ok(())
     }
 }
+
+/// Experiment id from a `.../chaos-agent/<id>/<file>` work-dir path.
+fn experiment_from_path(path: &str) -> Option<&str> {
+    let mut parts = path.rsplit('/').skip(1);
+    let id = parts.next()?;
+    (parts.next()? == "chaos-agent").then_some(id)
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -5,6 +7,68 @@ pub struct ServerTargetConfig {
     pub hosts: Vec<HostConfig>,
     #[serde(default)]
     pub discovery: DiscoveryConfig,
+    #[serde(default)]
+    pub exec: ExecConfig,
+}
+
+impl ServerTargetConfig {
+    /// JSON Schema for this target config.
+    pub fn schema() -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "required": ["hosts"],
+            "properties": {
+                "hosts": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "required": ["host", "username"],
+                        "properties": {
+                            "host": { "type": "string" },
+                            "port": { "type": "integer", "default": 22 },
+                            "username": { "type": "string" },
+                            "auth": {
+                                "oneOf": [
+                                    {
+                                        "type": "object",
+                                        "required": ["type", "private_key_path"],
+                                        "properties": {
+                                            "type": { "const": "key" },
+                                            "private_key_path": { "type": "string" }
+                                        }
+                                    },
+                                    {
+                                        "type": "object",
+                                        "required": ["type", "password"],
+                                        "properties": {
+                                            "type": { "const": "password" },
+                                            "password": { "type": "string" }
+                                        }
+                                    }
+                                ]
+                            }
+                        }
+                    }
+                },
+                "discovery": {
+                    "type": "object",
+                    "properties": {
+                        "enabled": { "type": "boolean", "default": true },
+                        "exclude_services": { "type": "array", "items": { "type": "string" } }
+                    }
+                },
+                "exec": {
+                    "type": "object",
+                    "properties": {
+                        "command_timeout": { "type": "string", "default": "5m" },
+                        "max_output_bytes": { "type": "integer", "default": 1048576 },
+                        "reconnect_attempts": { "type": "integer", "default": 3 },
+                        "reconnect_delay": { "type": "string", "default": "2s" }
+                    }
+                }
+            }
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,6 +121,51 @@ impl Default for DiscoveryConfig {
     }
 }
 
+/// How commands run over SSH, for discovery, skills and rollback alike.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ExecConfig {
+    /// A command still running after this fails with a timeout error.
+    #[serde(default = "default_command_timeout", with = "chaos_core::duration")]
+    pub command_timeout: Duration,
+    /// Stdout and stderr are each cut after this many bytes.
+    #[serde(default = "default_max_output_bytes")]
+    pub max_output_bytes: usize,
+    /// How often to reconnect and retry when the connection drops, while
+    /// connecting or before a command gets its exit status.
+    #[serde(default = "default_reconnect_attempts")]
+    pub reconnect_attempts: u32,
+    /// Pause before each reconnect.
+    #[serde(default = "default_reconnect_delay", with = "chaos_core::duration")]
+    pub reconnect_delay: Duration,
+}
+
+impl Default for ExecConfig {
+    fn default() -> Self {
+        Self {
+            command_timeout: default_command_timeout(),
+            max_output_bytes: default_max_output_bytes(),
+            reconnect_attempts: default_reconnect_attempts(),
+            reconnect_delay: default_reconnect_delay(),
+        }
+    }
+}
+
+fn default_command_timeout() -> Duration {
+    Duration::from_secs(300)
+}
+
+fn default_max_output_bytes() -> usize {
+    1024 * 1024
+}
+
+fn default_reconnect_attempts() -> u32 {
+    3
+}
+
+fn default_reconnect_delay() -> Duration {
+    Duration::from_secs(2)
+}
+
 fn default_true() -> bool {
     true
 }
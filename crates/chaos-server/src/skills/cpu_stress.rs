@@ -1,7 +1,8 @@
 use async_trait::async_trait;
+use chaos_core::cleanup::server_work_dir;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use serde::{Deserialize, Serialize};
 
 use crate::ssh::SshSession;
@@ -35,9 +36,17 @@ impl Skill for CpuStressSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "workers": { "type": "integer", "default": 2 }
+            }
+        })
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: CpuStressParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid cpu_stress params: {e}")))?;
+        let _: CpuStressParams = parse_params("cpu_stress", params)?;
         Ok(())
     }
 
@@ -47,17 +56,24 @@ impl Skill for CpuStressSkill {
             .downcast_ref::<SshSession>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected SshSession")))?;
 
-        let params: CpuStressParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<CpuStressParams>()?;
 
-        let pid_file = format!("/tmp/chaos-cpu-stress-{}.pid", uuid::Uuid::new_v4().as_simple());
+        let work_dir = server_work_dir("/tmp", ctx.experiment_id);
+        let pid_file = format!("{work_dir}/cpu-stress-{}.pid", uuid::Uuid::new_v4().as_simple());
 
-        // Start stress-ng in background, save PID
+        // Start stress-ng in background, save PID. A retry after a dropped
+        // connection finds the PID file and doesn't start a second one.
         let cmd = format!(
-            "nohup stress-ng --cpu {} --timeout 3600s > /dev/null 2>&1 & echo $! > {}",
-            params.workers, pid_file
+            "mkdir -p {work_dir}; [ -e {pid_file} ] && kill -0 $(cat {pid_file}) 2>/dev/null || \
+             {{ nohup stress-ng --cpu {} --timeout 3600s > /dev/null 2>&1 & echo $! > {pid_file}; }}",
+            params.workers
         );
 
+        if ctx.dry_run {
+            ctx.record_planned(format!("On {}: {cmd}", ssh.host));
+            return Ok(RollbackHandle::dry_run("server.cpu_stress"));
+        }
+
         let (exit_code, _, stderr) = ssh.exec(&cmd).await.map_err(|e| {
             ChaosError::Other(anyhow::anyhow!("SSH exec failed: {e}"))
         })?;
@@ -95,8 +111,8 @@ impl Skill for CpuStressSkill {
 
         // Kill process and clean up
         let cmd = format!(
-            "kill $(cat {} 2>/dev/null) 2>/dev/null; pkill -f 'stress-ng --cpu' 2>/dev/null; rm -f {}",
-            undo.pid_file, undo.pid_file
+            "kill $(cat {} 2>/dev/null) 2>/dev/null; pkill -f 'stress-ng --cpu' 2>/dev/null; rm -f {}; rmdir $(dirname {}) 2>/dev/null",
+            undo.pid_file, undo.pid_file, undo.pid_file
         );
 
         match ssh.exec(&cmd).await {
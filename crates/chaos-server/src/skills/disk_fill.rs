@@ -1,7 +1,8 @@
 use async_trait::async_trait;
+use chaos_core::cleanup::server_work_dir;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use serde::{Deserialize, Serialize};
 
 use crate::ssh::SshSession;
@@ -40,9 +41,18 @@ impl Skill for DiskFillSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "size": { "type": "string", "default": "1GB" },
+                "target_mount": { "type": "string", "default": "/tmp" }
+            }
+        })
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: DiskFillParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid disk_fill params: {e}")))?;
+        let _: DiskFillParams = parse_params("disk_fill", params)?;
         Ok(())
     }
 
@@ -52,20 +62,25 @@ impl Skill for DiskFillSkill {
             .downcast_ref::<SshSession>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected SshSession")))?;
 
-        let params: DiskFillParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<DiskFillParams>()?;
 
+        let work_dir = server_work_dir(&params.target_mount, ctx.experiment_id);
         let file_id = uuid::Uuid::new_v4().as_simple().to_string();
-        let file_path = format!("{}/chaos-agent-{}.fill", params.target_mount, file_id);
+        let file_path = format!("{work_dir}/fill-{file_id}.fill");
 
         let cmd = format!(
-            "fallocate -l {} {} 2>/dev/null || dd if=/dev/zero of={} bs=1M count={} 2>/dev/null",
+            "mkdir -p {work_dir} && {{ fallocate -l {} {} 2>/dev/null || dd if=/dev/zero of={} bs=1M count={} 2>/dev/null; }}",
             params.size,
             file_path,
             file_path,
             parse_size_mb(&params.size)
         );
 
+        if ctx.dry_run {
+            ctx.record_planned(format!("On {}: {cmd}", ssh.host));
+            return Ok(RollbackHandle::dry_run("server.disk_fill"));
+        }
+
         let (exit_code, _stdout, stderr) = ssh.exec(&cmd).await.map_err(|e| {
             ChaosError::Other(anyhow::anyhow!("SSH exec failed: {e}"))
         })?;
@@ -104,7 +119,11 @@ impl Skill for DiskFillSkill {
         let undo: DiskFillUndoState = serde_yaml::from_value(handle.undo_state.clone())
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
 
-        let cmd = format!("rm -f {}", undo.file_path);
+        // Drop the experiment directory too once nothing else is left in it.
+        let cmd = format!(
+            "rm -f {path} && {{ rmdir $(dirname {path}) 2>/dev/null; true; }}",
+            path = undo.file_path
+        );
         let (exit_code, _, stderr) = ssh.exec(&cmd).await.map_err(|e| {
             ChaosError::Other(anyhow::anyhow!("SSH exec failed: {e}"))
         })?;
@@ -1,7 +1,8 @@
 use async_trait::async_trait;
+use chaos_core::cleanup::server_work_dir;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use serde::{Deserialize, Serialize};
 
 use crate::ssh::SshSession;
@@ -41,9 +42,18 @@ impl Skill for MemoryStressSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "memory": { "type": "string", "default": "256M" },
+                "workers": { "type": "integer", "default": 1 }
+            }
+        })
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: MemoryStressParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid memory_stress params: {e}")))?;
+        let _: MemoryStressParams = parse_params("memory_stress", params)?;
         Ok(())
     }
 
@@ -53,19 +63,24 @@ impl Skill for MemoryStressSkill {
             .downcast_ref::<SshSession>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected SshSession")))?;
 
-        let params: MemoryStressParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<MemoryStressParams>()?;
 
-        let pid_file = format!(
-            "/tmp/chaos-mem-stress-{}.pid",
-            uuid::Uuid::new_v4().as_simple()
-        );
+        let work_dir = server_work_dir("/tmp", ctx.experiment_id);
+        let pid_file = format!("{work_dir}/mem-stress-{}.pid", uuid::Uuid::new_v4().as_simple());
 
+        // A retry after a dropped connection finds the PID file and doesn't
+        // start a second stressor
         let cmd = format!(
-            "nohup stress-ng --vm {} --vm-bytes {} --timeout 3600s > /dev/null 2>&1 & echo $! > {}",
-            params.workers, params.memory, pid_file
+            "mkdir -p {work_dir}; [ -e {pid_file} ] && kill -0 $(cat {pid_file}) 2>/dev/null || \
+             {{ nohup stress-ng --vm {} --vm-bytes {} --timeout 3600s > /dev/null 2>&1 & echo $! > {pid_file}; }}",
+            params.workers, params.memory
         );
 
+        if ctx.dry_run {
+            ctx.record_planned(format!("On {}: {cmd}", ssh.host));
+            return Ok(RollbackHandle::dry_run("server.memory_stress"));
+        }
+
         let (exit_code, _, stderr) = ssh.exec(&cmd).await.map_err(|e| {
             ChaosError::Other(anyhow::anyhow!("SSH exec failed: {e}"))
         })?;
@@ -102,8 +117,8 @@ impl Skill for MemoryStressSkill {
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("Parse undo: {e}")))?;
 
         let cmd = format!(
-            "kill $(cat {} 2>/dev/null) 2>/dev/null; pkill -f 'stress-ng --vm' 2>/dev/null; rm -f {}",
-            undo.pid_file, undo.pid_file
+            "kill $(cat {} 2>/dev/null) 2>/dev/null; pkill -f 'stress-ng --vm' 2>/dev/null; rm -f {}; rmdir $(dirname {}) 2>/dev/null",
+            undo.pid_file, undo.pid_file, undo.pid_file
         );
 
         match ssh.exec(&cmd).await {
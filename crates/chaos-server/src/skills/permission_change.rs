@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use serde::{Deserialize, Serialize};
 
 use crate::ssh::SshSession;
@@ -40,9 +40,18 @@ impl Skill for PermissionChangeSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "paths": { "type": "array", "items": { "type": "string" }, "description": "Default: discovered service config paths" },
+                "mode": { "type": "string", "default": "000" }
+            }
+        })
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: PermissionParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid permission_change params: {e}")))?;
+        let _: PermissionParams = parse_params("permission_change", params)?;
         Ok(())
     }
 
@@ -52,8 +61,7 @@ impl Skill for PermissionChangeSkill {
             .downcast_ref::<SshSession>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected SshSession")))?;
 
-        let params: PermissionParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<PermissionParams>()?;
 
         let paths = if params.paths.is_empty() {
             // Discover some config directories
@@ -83,12 +91,20 @@ impl Skill for PermissionChangeSkill {
                 .map_err(|e| ChaosError::Other(anyhow::anyhow!("SSH exec failed: {e}")))?;
 
             if exit_code != 0 || stdout.trim().is_empty() {
-                tracing::warn!(path = %path, "Could not read permissions, skipping");
+                ctx.warn(format!("Could not read permissions of {path} on {}, skipped", ssh.host));
                 continue;
             }
 
             let original_mode = stdout.trim().to_string();
 
+            if ctx.dry_run {
+                ctx.record_planned(format!(
+                    "Change {path} on {} from {original_mode} to {}",
+                    ssh.host, params.mode
+                ));
+                continue;
+            }
+
             // Change permissions
             let (exit_code, _, stderr) = ssh
                 .exec(&format!("chmod {} {}", params.mode, path))
@@ -96,17 +112,14 @@ impl Skill for PermissionChangeSkill {
                 .map_err(|e| ChaosError::Other(anyhow::anyhow!("SSH exec failed: {e}")))?;
 
             if exit_code != 0 {
-                tracing::warn!(path = %path, error = %stderr, "chmod failed");
+                ctx.warn(format!("chmod {} {path} failed on {}: {}", params.mode, ssh.host, stderr.trim()));
                 continue;
             }
 
-            tracing::info!(
-                host = %ssh.host,
-                path = %path,
-                old_mode = %original_mode,
-                new_mode = %params.mode,
-                "Permissions changed"
-            );
+            ctx.record_action(format!(
+                "Changed {path} on {} from {original_mode} to {}",
+                ssh.host, params.mode
+            ));
 
             undo_entries.push(PermissionUndoEntry {
                 host: ssh.host.clone(),
@@ -115,6 +128,10 @@ impl Skill for PermissionChangeSkill {
             });
         }
 
+        if ctx.dry_run {
+            return Ok(RollbackHandle::dry_run("server.permission_change"));
+        }
+
         let undo_state = serde_yaml::to_value(&undo_entries)
             .map_err(|e| ChaosError::Other(anyhow::anyhow!("Serialize undo: {e}")))?;
 
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use chaos_core::error::{ChaosError, ChaosResult};
 use chaos_core::rollback::RollbackHandle;
-use chaos_core::skill::{Skill, SkillContext, SkillDescriptor, TargetDomain};
+use chaos_core::skill::{parse_params, Skill, SkillContext, SkillDescriptor, TargetDomain};
 use serde::{Deserialize, Serialize};
 
 use crate::ssh::SshSession;
@@ -44,45 +44,61 @@ impl Skill for ServiceStopSkill {
         }
     }
 
+    fn params_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "max_services": { "type": "integer", "default": 1 },
+                "services": { "type": "array", "items": { "type": "string" }, "description": "Default: picked from discovered services" }
+            }
+        })
+    }
+
+    fn requires_resources(&self) -> bool {
+        true
+    }
+
     fn validate_params(&self, params: &serde_yaml::Value) -> ChaosResult<()> {
-        let _: ServiceStopParams = serde_yaml::from_value(params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid service_stop params: {e}")))?;
+        let _: ServiceStopParams = parse_params("service_stop", params)?;
         Ok(())
     }
 
+    async fn inspect(&self, ctx: &SkillContext) -> ChaosResult<Vec<String>> {
+        let ssh = ctx
+            .shared
+            .downcast_ref::<SshSession>()
+            .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected SshSession")))?;
+
+        let params = ctx.typed_params::<ServiceStopParams>()?;
+
+        if !params.services.is_empty() {
+            return Ok(params
+                .services
+                .iter()
+                .map(|s| format!("{}: would stop {s}", ssh.host))
+                .collect());
+        }
+        let available = eligible_services(ssh).await?;
+        let mut lines = vec![format!(
+            "{}: would stop {} of {} eligible services, chosen at random:",
+            ssh.host,
+            params.max_services.min(available.len()),
+            available.len()
+        )];
+        lines.extend(available.iter().map(|s| format!("  {s}")));
+        Ok(lines)
+    }
+
     async fn execute(&self, ctx: &SkillContext) -> ChaosResult<RollbackHandle> {
         let ssh = ctx
             .shared
             .downcast_ref::<SshSession>()
             .ok_or_else(|| ChaosError::Connection(anyhow::anyhow!("Expected SshSession")))?;
 
-        let params: ServiceStopParams = serde_yaml::from_value(ctx.params.clone())
-            .map_err(|e| ChaosError::Config(format!("Invalid params: {e}")))?;
+        let params = ctx.typed_params::<ServiceStopParams>()?;
 
         let services_to_stop = if params.services.is_empty() {
-            // Discover services and pick random ones
-            let (_, stdout, _) = ssh
-                .exec("systemctl list-units --type=service --state=running --no-legend --plain 2>/dev/null || true")
-                .await
-                .map_err(|e| ChaosError::Other(anyhow::anyhow!("SSH exec failed: {e}")))?;
-
-            let excluded = [
-                "sshd", "ssh", "systemd", "dbus", "NetworkManager", "network",
-                "firewalld", "iptables", "ufw", "chaos",
-            ];
-
-            let available: Vec<String> = stdout
-                .lines()
-                .filter_map(|line| {
-                    let name = line.split_whitespace().next()?;
-                    let name = name.trim_end_matches(".service");
-                    if excluded.iter().any(|&e| name.contains(e)) {
-                        None
-                    } else {
-                        Some(name.to_string())
-                    }
-                })
-                .collect();
+            let available = eligible_services(ssh).await?;
 
             use rand::seq::SliceRandom;
             let mut rng = rand::thread_rng();
@@ -100,6 +116,13 @@ impl Skill for ServiceStopSkill {
             ));
         }
 
+        if ctx.dry_run {
+            for service in &services_to_stop {
+                ctx.record_planned(format!("Stop {service} on {}", ssh.host));
+            }
+            return Ok(RollbackHandle::dry_run("server.service_stop"));
+        }
+
         let mut stopped = Vec::new();
 
         for service in &services_to_stop {
@@ -109,11 +132,11 @@ impl Skill for ServiceStopSkill {
             })?;
 
             if exit_code != 0 {
-                tracing::warn!(service = %service, error = %stderr, "Failed to stop service");
+                ctx.warn(format!("Failed to stop {service} on {}: {}", ssh.host, stderr.trim()));
                 continue;
             }
 
-            tracing::info!(host = %ssh.host, service = %service, "Service stopped");
+            ctx.record_action(format!("Stopped {service} on {}", ssh.host));
             stopped.push(StoppedService {
                 host: ssh.host.clone(),
                 service_name: service.clone(),
@@ -161,3 +184,29 @@ impl Skill for ServiceStopSkill {
         Ok(())
     }
 }
+
+/// Running services on the host, minus ones that would cut off access.
+async fn eligible_services(ssh: &SshSession) -> ChaosResult<Vec<String>> {
+    let (_, stdout, _) = ssh
+        .exec("systemctl list-units --type=service --state=running --no-legend --plain 2>/dev/null || true")
+        .await
+        .map_err(|e| ChaosError::Other(anyhow::anyhow!("SSH exec failed: {e}")))?;
+
+    let excluded = [
+        "sshd", "ssh", "systemd", "dbus", "NetworkManager", "network",
+        "firewalld", "iptables", "ufw", "chaos",
+    ];
+
+    Ok(stdout
+        .lines()
+        .filter_map(|line| {
+            let name = line.split_whitespace().next()?;
+            let name = name.trim_end_matches(".service");
+            if excluded.iter().any(|&e| name.contains(e)) {
+                None
+            } else {
+                Some(name.to_string())
+            }
+        })
+        .collect())
+}
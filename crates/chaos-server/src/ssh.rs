@@ -1,14 +1,52 @@
 use async_ssh2_tokio::client::{AuthMethod, Client, ServerCheckMethod};
+use chaos_core::duration::format_duration;
+use russh::ChannelMsg;
+use tokio::sync::RwLock;
 
-use crate::config::HostConfig;
+use crate::config::{ExecConfig, HostConfig};
+
+/// Appended to stdout or stderr cut off at [`ExecConfig::max_output_bytes`].
+const TRUNCATED_MARKER: &str = "\n[... output truncated ...]\n";
 
 pub struct SshSession {
-    client: Client,
+    client: RwLock<Client>,
     pub host: String,
+    config: HostConfig,
+    exec: ExecConfig,
+}
+
+/// Why a command attempt failed.
+enum ExecError {
+    /// The connection is gone; worth reconnecting and running the command again.
+    Disconnected(anyhow::Error),
+    Failed(anyhow::Error),
 }
 
 impl SshSession {
-    pub async fn connect(config: &HostConfig) -> anyhow::Result<Self> {
+    /// Connect to `config`, retrying up to `exec.reconnect_attempts` times.
+    pub async fn connect(config: &HostConfig, exec: ExecConfig) -> anyhow::Result<Self> {
+        let mut attempt = 0;
+        let client = loop {
+            match Self::open(config).await {
+                Ok(client) => break client,
+                Err(e) if attempt < exec.reconnect_attempts => {
+                    attempt += 1;
+                    tracing::warn!(host = %config.host, attempt, error = %e, "SSH connect failed, retrying");
+                    tokio::time::sleep(exec.reconnect_delay).await;
+                }
+                Err(e) => return Err(e),
+            }
+        };
+
+        Ok(Self {
+            client: RwLock::new(client),
+            host: config.host.clone(),
+            config: config.clone(),
+            exec,
+        })
+    }
+
+    async fn open(config: &HostConfig) -> anyhow::Result<Client> {
         let auth = match &config.auth {
             crate::config::AuthConfig::Key { private_key_path } => {
                 let expanded = shellexpand::tilde(private_key_path).to_string();
@@ -20,27 +58,128 @@ impl SshSession {
             }
         };
 
-        let client = Client::connect(
+        Ok(Client::connect(
             (config.host.as_str(), config.port),
             &config.username,
             auth,
             ServerCheckMethod::NoCheck,
         )
-        .await?;
-
-        Ok(Self {
-            client,
-            host: config.host.clone(),
-        })
+        .await?)
     }
 
     /// Execute a remote command and return (exit_code, stdout, stderr).
+    /// Fails once the command has run for `command_timeout`; stdout and
+    /// stderr are each cut at `max_output_bytes`, ending with a marker.
+    ///
+    /// If the connection drops before the command reports its exit status,
+    /// the session reconnects and runs the command again, up to
+    /// `reconnect_attempts` times. Commands should therefore be safe to
+    /// repeat, as the skills' and rollback's are.
     pub async fn exec(&self, command: &str) -> anyhow::Result<(i32, String, String)> {
-        let result = self.client.execute(command).await?;
-        Ok((
-            result.exit_status as i32,
-            result.stdout,
-            result.stderr,
-        ))
+        let timeout = self.exec.command_timeout;
+        let mut attempt = 0;
+        loop {
+            match tokio::time::timeout(timeout, self.run(command)).await {
+                Ok(Ok(output)) => return Ok(output),
+                Ok(Err(ExecError::Disconnected(e))) if attempt < self.exec.reconnect_attempts => {
+                    attempt += 1;
+                    tracing::warn!(host = %self.host, attempt, error = %e, "SSH connection lost, reconnecting");
+                    tokio::time::sleep(self.exec.reconnect_delay).await;
+                    // A failed reconnect shows up as another disconnect on the next attempt.
+                    match Self::open(&self.config).await {
+                        Ok(client) => *self.client.write().await = client,
+                        Err(e) => tracing::warn!(host = %self.host, error = %e, "SSH reconnect failed"),
+                    }
+                }
+                Ok(Err(ExecError::Disconnected(e) | ExecError::Failed(e))) => return Err(e),
+                Err(_) => anyhow::bail!(
+                    "command on {} timed out after {}: {command}",
+                    self.host,
+                    format_duration(timeout)
+                ),
+            }
+        }
+    }
+
+    async fn run(&self, command: &str) -> Result<(i32, String, String), ExecError> {
+        let mut channel = self
+            .client
+            .read()
+            .await
+            .get_channel()
+            .await
+            .map_err(|e| ExecError::Disconnected(e.into()))?;
+        channel
+            .exec(true, command)
+            .await
+            .map_err(|e| ExecError::Disconnected(e.into()))?;
+
+        let mut stdout = CappedOutput::new(self.exec.max_output_bytes);
+        let mut stderr = CappedOutput::new(self.exec.max_output_bytes);
+        let mut exit_status = None;
+        // The exit status can arrive before the last data; read until the channel closes.
+        while let Some(msg) = channel.wait().await {
+            match msg {
+                ChannelMsg::Data { ref data } => stdout.push(data),
+                ChannelMsg::ExtendedData { ref data, ext: 1 } => stderr.push(data),
+                ChannelMsg::ExitStatus { exit_status: status } => exit_status = Some(status),
+                _ => {}
+            }
+        }
+
+        let Some(exit_status) = exit_status else {
+            // Without a status the command was either killed by a signal or
+            // the connection went away under it.
+            return Err(if self.client.read().await.is_closed() {
+                ExecError::Disconnected(anyhow::anyhow!(
+                    "connection to {} closed while running: {command}",
+                    self.host
+                ))
+            } else {
+                ExecError::Failed(anyhow::anyhow!("command on {} exited without a status", self.host))
+            });
+        };
+        if stdout.truncated || stderr.truncated {
+            tracing::warn!(
+                host = %self.host,
+                command,
+                max_bytes = self.exec.max_output_bytes,
+                "SSH command output truncated"
+            );
+        }
+        Ok((exit_status as i32, stdout.finish(), stderr.finish()))
+    }
+}
+
+/// Output buffer that keeps the first `max` bytes.
+struct CappedOutput {
+    buf: Vec<u8>,
+    max: usize,
+    truncated: bool,
+}
+
+impl CappedOutput {
+    fn new(max: usize) -> Self {
+        Self {
+            buf: Vec::new(),
+            max,
+            truncated: false,
+        }
+    }
+
+    fn push(&mut self, data: &[u8]) {
+        let room = self.max.saturating_sub(self.buf.len());
+        if data.len() > room {
+            self.truncated = true;
+        }
+        self.buf.extend_from_slice(&data[..data.len().min(room)]);
+    }
+
+    fn finish(self) -> String {
+        let mut out = String::from_utf8_lossy(&self.buf).into_owned();
+        if self.truncated {
+            out.push_str(TRUNCATED_MARKER);
+        }
+        out
     }
 }
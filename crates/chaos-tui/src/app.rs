@@ -1,6 +1,7 @@
 use crate::dashboard::DashboardState;
 use crate::wizard::WizardState;
 
+#[allow(clippy::large_enum_variant)]
 pub enum AppScreen {
     Wizard(WizardState),
     Dashboard(DashboardState),
@@ -11,6 +12,12 @@ pub struct App {
     pub should_quit: bool,
 }
 
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl App {
     pub fn new() -> Self {
         Self {
@@ -30,6 +30,7 @@ pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect, active: boo
         .map(|entry| {
             let (prefix, style) = match entry.role.as_str() {
                 "assistant" => ("AI", Style::default().fg(Color::Green)),
+                "user" => ("You", Style::default().fg(Color::Magenta)),
                 "tool" => (">>", Style::default().fg(Color::Yellow)),
                 "system" => ("**", Style::default().fg(Color::Cyan)),
                 _ => ("  ", theme::normal_style()),
@@ -54,7 +55,7 @@ pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect, active: boo
             if inner_width == 0 {
                 1
             } else {
-                (len.max(1) + inner_width - 1) / inner_width
+                len.max(1).div_ceil(inner_width)
             }
         })
         .sum();
@@ -4,17 +4,26 @@ pub mod resources;
 pub mod progress;
 pub mod rollback;
 pub mod report;
+pub mod plan_review;
 
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
+use chaos_core::config::ChaosConfig;
+use chaos_core::duration::format_duration;
 use chaos_core::event::ExperimentEvent;
-use chaos_llm::planner::PlannerEvent;
+use chaos_core::experiment::ExperimentConfig;
+use chaos_core::redact::redact_json;
+use chaos_llm::planner::{PlannerEvent, ToolCallApproval};
+use chaos_llm::provider::TokenUsage;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::prelude::*;
-use ratatui::widgets::Paragraph;
+use ratatui::widgets::{ListItem, Paragraph};
+use uuid::Uuid;
 
+use crate::execution::PlanApproval;
 use crate::theme;
 use crate::widgets::spinner::Spinner;
+use crate::widgets::too_small::render_if_too_small;
 use crate::wizard::WizardOutput;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -22,12 +31,16 @@ pub enum DashboardAction {
     None,
     CancelExperiment,
     CancelAndQuit,
+    /// Send this message to the planner, to steer the rest of planning.
+    SteerPlanner(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DashboardPhase {
     Planning,
     Discovering,
+    /// Planned experiments wait for the user to edit and approve them.
+    Review,
     Executing,
     Waiting,
     RollingBack,
@@ -41,6 +54,7 @@ impl DashboardPhase {
         match self {
             Self::Planning => "Planning",
             Self::Discovering => "Discovering",
+            Self::Review => "Review",
             Self::Executing => "Executing",
             Self::Waiting => "Waiting",
             Self::RollingBack => "RollingBack",
@@ -66,14 +80,34 @@ pub struct ConversationEntry {
 pub struct ResourceEntry {
     pub resource_type: String,
     pub name: String,
+    /// Everything discovery gathered about the resource (row counts, labels,
+    /// ports, unit states...).
+    pub details: serde_yaml::Value,
+}
+
+impl ResourceEntry {
+    pub fn detail_lines(&self) -> Vec<String> {
+        serde_yaml::to_string(&self.details)
+            .unwrap_or_default()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+}
+
+pub struct ExperimentRun {
+    pub id: Uuid,
+    pub name: String,
 }
 
 pub struct SkillProgress {
+    pub experiment_id: Uuid,
     pub skill_name: String,
     pub success: Option<bool>,
 }
 
 pub struct RollbackProgress {
+    pub experiment_id: Uuid,
     pub skill_name: String,
     pub success: Option<bool>,
 }
@@ -84,17 +118,49 @@ pub struct DashboardState {
     pub conversation: Vec<ConversationEntry>,
     pub conversation_scroll: usize,
     pub conversation_auto_scroll: bool,
+    /// Conversation entry of the assistant message still streaming in.
+    pub assistant_streaming: Option<usize>,
     /// Cached from last render so key handler knows the max offset.
     pub rendered_max_scroll: std::cell::Cell<usize>,
     pub resources: Vec<ResourceEntry>,
+    /// Typed while the resources panel is focused; matches type or name.
+    pub resource_filter: String,
+    /// Position in [`DashboardState::visible_resources`].
+    pub resource_selected: usize,
+    /// Scroll offset of the selected resource's details while they're open.
+    pub resource_detail: Option<usize>,
     pub skills: Vec<SkillProgress>,
     pub rollback_steps: Vec<RollbackProgress>,
     pub final_report: Option<String>,
     pub active_panel: usize,
     pub current_turn: u32,
+    /// Experiments in the plan, and how many of them have started.
+    pub experiment_count: usize,
+    pub current_experiment: usize,
+    /// Experiments that have started, in order, for grouping skills and
+    /// rollback steps.
+    pub experiment_runs: Vec<ExperimentRun>,
+    /// Index into `experiment_runs` whose skills and rollback steps the
+    /// panels show; `None` shows every experiment's, grouped.
+    pub experiment_view: Option<usize>,
     pub max_turns: u32,
+    /// Tokens used so far by planning and the summary; `None` until the
+    /// provider reports usage.
+    pub total_usage: Option<TokenUsage>,
     pub spinner: Spinner,
     pub started_at: Instant,
+    /// Set once the user cancels; the background task keeps running until rollback is done.
+    pub cancel_requested: bool,
+    /// Quit the TUI as soon as the background task has finished (Ctrl+W).
+    pub quit_when_finished: bool,
+    /// Set while the plan waits for approval.
+    pub plan_review: Option<plan_review::PlanReview>,
+    /// The plan as approved, for exporting with Ctrl+S.
+    pub approved_experiments: Vec<ExperimentConfig>,
+    /// Message being typed to steer the planner ([i] while planning).
+    pub steer_input: Option<String>,
+    /// Step-through mode: the planner's tool call waiting for [y]/[n].
+    pub pending_tool_call: Option<ToolCallApproval>,
 }
 
 impl DashboardState {
@@ -105,36 +171,120 @@ impl DashboardState {
             conversation: Vec::new(),
             conversation_scroll: 0,
             conversation_auto_scroll: true,
+            assistant_streaming: None,
             rendered_max_scroll: std::cell::Cell::new(0),
             resources: Vec::new(),
+            resource_filter: String::new(),
+            resource_selected: 0,
+            resource_detail: None,
             skills: Vec::new(),
             rollback_steps: Vec::new(),
             final_report: None,
             active_panel: 0,
             current_turn: 0,
+            experiment_count: 0,
+            current_experiment: 0,
+            experiment_runs: Vec::new(),
+            experiment_view: None,
             max_turns: 0,
+            total_usage: None,
             spinner: Spinner::new(),
             started_at: Instant::now(),
+            cancel_requested: false,
+            quit_when_finished: false,
+            plan_review: None,
+            approved_experiments: Vec::new(),
+            steer_input: None,
+            pending_tool_call: None,
         }
     }
 
+    /// Show the planned experiments for editing. Execution waits until the
+    /// user approves them or cancels.
+    pub fn begin_review(&mut self, approval: PlanApproval) {
+        if self.cancel_requested {
+            // Dropping the approval tells the task to run nothing
+            return;
+        }
+        self.phase = DashboardPhase::Review;
+        self.conversation.push(ConversationEntry {
+            role: "system".into(),
+            content: format!(
+                "Review the {} planned experiment(s): [Enter] edit a value, [a] approve",
+                approval.experiments.len()
+            ),
+        });
+        self.plan_review = Some(plan_review::PlanReview::new(approval));
+        self.auto_scroll_conversation();
+    }
+
+    /// Hold the planner's next tool call until the user allows or denies it.
+    pub fn begin_tool_approval(&mut self, approval: ToolCallApproval) {
+        if self.cancel_requested {
+            // Dropping the approval denies the call
+            return;
+        }
+        self.conversation.push(ConversationEntry {
+            role: "system".into(),
+            content: format!(
+                "Approve {}({})? [y] allow, [n] deny",
+                approval.name,
+                redacted(&approval.arguments)
+            ),
+        });
+        self.pending_tool_call = Some(approval);
+        self.auto_scroll_conversation();
+    }
+
+    /// Reply to the pending tool call, if any.
+    fn answer_tool_call(&mut self, approved: bool) {
+        let Some(approval) = self.pending_tool_call.take() else {
+            return;
+        };
+        let verdict = if approved { "Allowed" } else { "Denied" };
+        self.conversation.push(ConversationEntry {
+            role: "system".into(),
+            content: format!("{verdict} {}()", approval.name),
+        });
+        let _ = approval.reply.send(approved);
+        self.auto_scroll_conversation();
+    }
+
     pub fn handle_planner_event(&mut self, event: PlannerEvent) {
         match event {
             PlannerEvent::TurnStarted { turn, max_turns } => {
                 self.current_turn = turn;
                 self.max_turns = max_turns;
+                self.assistant_streaming = None;
+            }
+            PlannerEvent::AssistantDelta { text } => {
+                match self.assistant_streaming {
+                    Some(i) => self.conversation[i].content.push_str(&text),
+                    None => {
+                        self.assistant_streaming = Some(self.conversation.len());
+                        self.conversation.push(ConversationEntry {
+                            role: "assistant".into(),
+                            content: text,
+                        });
+                    }
+                }
+                self.auto_scroll_conversation();
             }
             PlannerEvent::AssistantMessage { content } => {
-                self.conversation.push(ConversationEntry {
-                    role: "assistant".into(),
-                    content,
-                });
+                match self.assistant_streaming.take() {
+                    // Replace the streamed text with the final message
+                    Some(i) => self.conversation[i].content = content,
+                    None => self.conversation.push(ConversationEntry {
+                        role: "assistant".into(),
+                        content,
+                    }),
+                }
                 self.auto_scroll_conversation();
             }
-            PlannerEvent::ToolCallStarted { name, .. } => {
+            PlannerEvent::ToolCallStarted { name, arguments } => {
                 self.conversation.push(ConversationEntry {
                     role: "tool".into(),
-                    content: format!("Calling {}()...", name),
+                    content: format!("Calling {name}({})...", tool_call_summary(&name, &arguments)),
                 });
                 self.auto_scroll_conversation();
             }
@@ -143,24 +293,6 @@ impl DashboardState {
                 result,
                 is_error,
             } => {
-                // Parse discover_resources results to populate the resources panel
-                if name == "discover_resources" && !is_error {
-                    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&result) {
-                        if let Some(resources) = json["resources"].as_array() {
-                            for r in resources {
-                                if let (Some(rtype), Some(rname)) =
-                                    (r["type"].as_str(), r["name"].as_str())
-                                {
-                                    self.resources.push(ResourceEntry {
-                                        resource_type: rtype.to_string(),
-                                        name: rname.to_string(),
-                                    });
-                                }
-                            }
-                        }
-                    }
-                }
-
                 let prefix = if is_error { "ERROR" } else { "OK" };
                 let result_preview = if result.len() > 200 {
                     format!("{}...", &result[..200])
@@ -196,7 +328,10 @@ impl DashboardState {
             PlannerEvent::PlanningComplete {
                 experiment_count, ..
             } => {
-                if experiment_count > 0 {
+                self.experiment_count = experiment_count;
+                if self.plan_review.is_some() {
+                    // The plan may reach review before this event does
+                } else if experiment_count > 0 {
                     self.phase = DashboardPhase::Executing;
                 } else {
                     self.phase = DashboardPhase::Complete;
@@ -209,32 +344,186 @@ impl DashboardState {
                 });
                 self.auto_scroll_conversation();
             }
+            PlannerEvent::PlanningCancelled {
+                turns,
+                experiment_count,
+            } => {
+                self.phase = DashboardPhase::Cancelled;
+                self.conversation.push(ConversationEntry {
+                    role: "system".into(),
+                    content: format!(
+                        "Planning cancelled after {turns} turns, {experiment_count} experiments queued (not run)"
+                    ),
+                });
+                self.auto_scroll_conversation();
+            }
             PlannerEvent::TokenUsage {
                 input_tokens,
                 output_tokens,
             } => {
+                let total = self.total_usage.get_or_insert(TokenUsage {
+                    input_tokens: 0,
+                    output_tokens: 0,
+                });
+                total.input_tokens = total.input_tokens.saturating_add(input_tokens);
+                total.output_tokens = total.output_tokens.saturating_add(output_tokens);
                 self.conversation.push(ConversationEntry {
                     role: "system".into(),
                     content: format!("Tokens: {input_tokens} in / {output_tokens} out"),
                 });
             }
+            PlannerEvent::TargetConfigInjected { experiment, source } => {
+                self.conversation.push(ConversationEntry {
+                    role: "system".into(),
+                    content: format!("'{experiment}': target filled in from {source}"),
+                });
+                self.auto_scroll_conversation();
+            }
+            PlannerEvent::UserMessageInjected { content } => {
+                self.conversation.push(ConversationEntry {
+                    role: "user".into(),
+                    content,
+                });
+                self.auto_scroll_conversation();
+            }
+            PlannerEvent::SummaryStarted { report_count } => {
+                self.final_report = Some(format!("Summarizing {report_count} report(s)..."));
+            }
+            PlannerEvent::SummaryCompleted { summary } => {
+                self.final_report = Some(summary);
+                self.conversation.push(ConversationEntry {
+                    role: "system".into(),
+                    content: "Summary written (Experiment Report panel)".into(),
+                });
+                self.auto_scroll_conversation();
+            }
+            PlannerEvent::SummaryFailed { error } => {
+                self.final_report = None;
+                self.conversation.push(ConversationEntry {
+                    role: "system".into(),
+                    content: format!("Could not summarize the results: {error}"),
+                });
+                self.auto_scroll_conversation();
+            }
         }
     }
 
+    pub fn add_resources(&mut self, resources: Vec<ResourceEntry>) {
+        self.resources.extend(resources);
+    }
+
+    /// The experiments whose skills and rollback steps the panels show.
+    pub fn viewed_runs(&self) -> &[ExperimentRun] {
+        match self.experiment_view {
+            Some(i) => &self.experiment_runs[i..=i],
+            None => &self.experiment_runs,
+        }
+    }
+
+    /// " ({count}) ", plus which experiment is shown when there's a choice.
+    pub fn view_label(&self, count: usize) -> String {
+        match self.experiment_view {
+            Some(i) => format!(
+                ": {} ({count}) [{}/{}] ",
+                self.experiment_runs[i].name,
+                i + 1,
+                self.experiment_runs.len()
+            ),
+            None => format!(" ({count}) "),
+        }
+    }
+
+    /// Write the approved plan as a `chaos run` config in the working
+    /// directory, so the run can be repeated without the LLM.
+    pub fn export_experiments(&mut self) {
+        let path = format!(
+            "chaos-experiments-{}.yaml",
+            chrono::Local::now().format("%Y%m%dT%H%M%S")
+        );
+        let config = ChaosConfig {
+            experiments: self.approved_experiments.clone(),
+            default_durations: Default::default(),
+            safety: Default::default(),
+            targets: Default::default(),
+            profiles: Default::default(),
+        };
+        let written = serde_yaml::to_string(&config)
+            .map_err(anyhow::Error::from)
+            .and_then(|yaml| Ok(std::fs::write(&path, yaml)?));
+        let content = match written {
+            Ok(()) => format!(
+                "Saved {} experiment(s) to {path}; re-run with `chaos run {path}`",
+                config.experiments.len()
+            ),
+            Err(e) => format!("Failed to save experiments to {path}: {e}"),
+        };
+        self.conversation.push(ConversationEntry {
+            role: "system".into(),
+            content,
+        });
+        self.auto_scroll_conversation();
+    }
+
+    /// Indices into `resources` matching `resource_filter` (case-insensitive,
+    /// on type or name), grouped by type and sorted by name within each.
+    pub fn visible_resources(&self) -> Vec<usize> {
+        let filter = self.resource_filter.to_lowercase();
+        let mut visible: Vec<usize> = (0..self.resources.len())
+            .filter(|&i| {
+                let r = &self.resources[i];
+                r.resource_type.to_lowercase().contains(&filter)
+                    || r.name.to_lowercase().contains(&filter)
+            })
+            .collect();
+        visible.sort_by_key(|&i| (&self.resources[i].resource_type, &self.resources[i].name));
+        visible
+    }
+
+    pub fn selected_resource(&self) -> Option<&ResourceEntry> {
+        self.visible_resources()
+            .get(self.resource_selected)
+            .map(|&i| &self.resources[i])
+    }
+
+    /// Whether the resources panel is focused and showing resources (not
+    /// the summary that replaces it).
+    fn resources_focused(&self) -> bool {
+        self.active_panel == 1 && self.final_report.is_none() && !self.resources.is_empty()
+    }
+
     pub fn handle_experiment_event(&mut self, event: ExperimentEvent) {
         match event {
-            ExperimentEvent::Started { .. } => {
+            ExperimentEvent::Started { experiment_id, .. } => {
                 self.phase = DashboardPhase::Executing;
+                self.current_experiment += 1;
+                // Approved experiments run one at a time, in order
+                let name = self
+                    .approved_experiments
+                    .get(self.current_experiment - 1)
+                    .map(|e| e.name.clone())
+                    .unwrap_or_else(|| format!("experiment {}", self.current_experiment));
+                let content = if self.experiment_count > 0 {
+                    format!(
+                        "Experiment {}/{} started: {name}",
+                        self.current_experiment, self.experiment_count
+                    )
+                } else {
+                    format!("Experiment started: {name}")
+                };
+                self.experiment_runs.push(ExperimentRun {
+                    id: experiment_id,
+                    name,
+                });
                 self.conversation.push(ConversationEntry {
                     role: "system".into(),
-                    content: "Experiment started".into(),
+                    content,
                 });
                 self.auto_scroll_conversation();
             }
             ExperimentEvent::SkillExecuted {
+                experiment_id,
                 skill_name,
                 success,
-                ..
             } => {
                 let status = if success { "OK" } else { "FAILED" };
                 self.conversation.push(ConversationEntry {
@@ -242,6 +531,7 @@ impl DashboardState {
                     content: format!("Skill {skill_name}: {status}"),
                 });
                 self.skills.push(SkillProgress {
+                    experiment_id,
                     skill_name,
                     success: Some(success),
                 });
@@ -251,7 +541,23 @@ impl DashboardState {
                 self.phase = DashboardPhase::Waiting;
                 self.conversation.push(ConversationEntry {
                     role: "system".into(),
-                    content: format!("Waiting for {duration:?}..."),
+                    content: format!("Waiting for {}...", format_duration(duration)),
+                });
+                self.auto_scroll_conversation();
+            }
+            ExperimentEvent::Degraded { error, .. } => {
+                self.conversation.push(ConversationEntry {
+                    role: "system".into(),
+                    content: format!("Target degraded: {error}"),
+                });
+                self.auto_scroll_conversation();
+            }
+            ExperimentEvent::Recovered {
+                time_to_recovery, ..
+            } => {
+                self.conversation.push(ConversationEntry {
+                    role: "system".into(),
+                    content: format!("Target recovered after {}", format_duration(time_to_recovery)),
                 });
                 self.auto_scroll_conversation();
             }
@@ -264,9 +570,9 @@ impl DashboardState {
                 self.auto_scroll_conversation();
             }
             ExperimentEvent::RollbackStepCompleted {
+                experiment_id,
                 skill_name,
                 success,
-                ..
             } => {
                 let status = if success { "OK" } else { "FAILED" };
                 self.conversation.push(ConversationEntry {
@@ -274,11 +580,15 @@ impl DashboardState {
                     content: format!("Rollback {skill_name}: {status}"),
                 });
                 self.rollback_steps.push(RollbackProgress {
+                    experiment_id,
                     skill_name,
                     success: Some(success),
                 });
                 self.auto_scroll_conversation();
             }
+            ExperimentEvent::Completed { .. } if self.cancel_requested => {
+                self.finish_cancelled();
+            }
             ExperimentEvent::Completed { .. } => {
                 self.phase = DashboardPhase::Complete;
                 self.conversation.push(ConversationEntry {
@@ -288,6 +598,8 @@ impl DashboardState {
                 self.auto_scroll_conversation();
             }
             ExperimentEvent::Failed { error, .. } => {
+                // A failed stream never sends the final AssistantMessage
+                self.assistant_streaming = None;
                 self.conversation.push(ConversationEntry {
                     role: "system".into(),
                     content: format!("Experiment failed: {error}"),
@@ -295,22 +607,96 @@ impl DashboardState {
                 self.phase = DashboardPhase::Failed(error);
                 self.auto_scroll_conversation();
             }
+            ExperimentEvent::Skipped {
+                experiment_name,
+                reason,
+            } => {
+                self.conversation.push(ConversationEntry {
+                    role: "system".into(),
+                    content: format!("Skipped {experiment_name}: {reason}"),
+                });
+                if self.cancel_requested {
+                    self.finish_cancelled();
+                } else {
+                    self.phase = DashboardPhase::Complete;
+                }
+                self.auto_scroll_conversation();
+            }
+            ExperimentEvent::Blocked {
+                experiment_name,
+                reason,
+            } => {
+                self.conversation.push(ConversationEntry {
+                    role: "system".into(),
+                    content: format!("Blocked {experiment_name}: {reason}"),
+                });
+                self.phase = DashboardPhase::Failed(reason);
+                self.auto_scroll_conversation();
+            }
+        }
+    }
+
+    /// Called once the background execution task has exited.
+    pub fn execution_finished(&mut self) {
+        if self.cancel_requested && self.phase != DashboardPhase::Cancelled {
+            self.finish_cancelled();
         }
     }
 
+    fn request_cancel(&mut self, content: &str) {
+        self.cancel_requested = true;
+        if let Some(mut review) = self.plan_review.take() {
+            review.reject();
+        }
+        self.pending_tool_call = None;
+        self.conversation.push(ConversationEntry {
+            role: "system".into(),
+            content: content.into(),
+        });
+        self.auto_scroll_conversation();
+    }
+
+    fn finish_cancelled(&mut self) {
+        self.phase = DashboardPhase::Cancelled;
+        let failed = self
+            .rollback_steps
+            .iter()
+            .filter(|r| r.success == Some(false))
+            .count();
+        let content = if self.skills.is_empty() {
+            "Cancelled before any chaos was applied".to_string()
+        } else if failed == 0 {
+            format!(
+                "Cancelled, {} steps rolled back",
+                self.rollback_steps.len()
+            )
+        } else {
+            format!(
+                "Cancelled, {} steps rolled back ({failed} FAILED, manual cleanup may be needed)",
+                self.rollback_steps.len()
+            )
+        };
+        self.conversation.push(ConversationEntry {
+            role: "system".into(),
+            content,
+        });
+        self.auto_scroll_conversation();
+    }
+
+    /// Whether a steering message can still reach the planner.
+    pub fn can_steer(&self) -> bool {
+        matches!(self.phase, DashboardPhase::Planning | DashboardPhase::Discovering)
+            && self.plan_review.is_none()
+            && !self.cancel_requested
+    }
+
     fn auto_scroll_conversation(&mut self) {
         self.conversation_auto_scroll = true;
     }
 
     pub fn elapsed_display(&self) -> String {
-        let secs = self.started_at.elapsed().as_secs();
-        if secs < 60 {
-            format!("{secs}s")
-        } else if secs < 3600 {
-            format!("{}m {}s", secs / 60, secs % 60)
-        } else {
-            format!("{}h {}m {}s", secs / 3600, (secs % 3600) / 60, secs % 60)
-        }
+        // Whole seconds, so the status bar doesn't flicker through tenths
+        format_duration(Duration::from_secs(self.started_at.elapsed().as_secs()))
     }
 
     pub fn tick(&mut self) {
@@ -318,7 +704,87 @@ impl DashboardState {
     }
 }
 
+/// Longest tool-call summary shown in the chat.
+const MAX_SUMMARY_CHARS: usize = 300;
+
+/// `arguments` with secrets blanked out.
+fn redacted(arguments: &serde_json::Value) -> serde_json::Value {
+    let mut arguments = arguments.clone();
+    redact_json(&mut arguments);
+    arguments
+}
+
+/// The redacted arguments of a tool call, shortened for the chat: the
+/// experiment and its skills for `run_experiment`, the target for
+/// `discover_resources`, the JSON for anything else.
+fn tool_call_summary(name: &str, arguments: &serde_json::Value) -> String {
+    let arguments = redacted(arguments);
+    let field = |key: &str| match arguments.get(key) {
+        None | Some(serde_json::Value::Null) => None,
+        Some(serde_json::Value::String(s)) => Some(s.clone()),
+        Some(other) => Some(other.to_string()),
+    };
+    let fields = match name {
+        "run_experiment" => {
+            let skills = arguments.get("skills").and_then(|s| s.as_array()).map(|skills| {
+                let skills: Vec<String> = skills
+                    .iter()
+                    .map(|skill| {
+                        let name = skill.get("skill_name").and_then(|n| n.as_str()).unwrap_or("?");
+                        match skill.get("params") {
+                            Some(params) if params.as_object().is_some_and(|p| !p.is_empty()) => {
+                                format!("{name} {params}")
+                            }
+                            _ => name.to_string(),
+                        }
+                    })
+                    .collect();
+                format!("[{}]", skills.join(", "))
+            });
+            vec![
+                ("name", field("name")),
+                ("target", field("target")),
+                ("target_ref", field("target_ref")),
+                ("skills", skills),
+                ("duration", field("duration")),
+            ]
+        }
+        "discover_resources" => vec![
+            ("target", field("target")),
+            ("target_ref", field("target_ref")),
+            ("target_config", field("target_config")),
+        ],
+        _ if arguments.as_object().is_some_and(|a| a.is_empty()) => Vec::new(),
+        _ => vec![("", Some(arguments.to_string()))],
+    };
+    let summary = fields
+        .into_iter()
+        .filter_map(|(key, value)| {
+            let value = value?;
+            Some(if key.is_empty() { value } else { format!("{key}={value}") })
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    if summary.chars().count() > MAX_SUMMARY_CHARS {
+        format!("{}...", summary.chars().take(MAX_SUMMARY_CHARS).collect::<String>())
+    } else {
+        summary
+    }
+}
+
+/// Smallest terminal the dashboard can draw in.
+const MIN_WIDTH: u16 = 40;
+const MIN_HEIGHT: u16 = 15;
+/// Below this width the panels are stacked in one column.
+const NARROW_WIDTH: u16 = 100;
+/// Height of a stacked panel that isn't active: its title and one line.
+const COLLAPSED_HEIGHT: u16 = 3;
+
 pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect) {
+    if render_if_too_small(frame, area, MIN_WIDTH, MIN_HEIGHT) {
+        return;
+    }
+
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -331,102 +797,317 @@ pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect) {
     // Status bar
     status::render(state, frame, main_chunks[0]);
 
-    // Main content: 2x2 grid
-    let content_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(main_chunks[1]);
+    if state.plan_review.is_some() {
+        plan_review::render(state, frame, main_chunks[1]);
+        let help = Paragraph::new(
+            " [Up/Down] Select  [Enter] Edit  [Esc] Discard edit  [a] Approve & run  [Ctrl+C] Cancel",
+        )
+        .style(theme::dim_style());
+        frame.render_widget(help, main_chunks[2]);
+        return;
+    }
 
-    let left_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
-        .split(content_chunks[0]);
-
-    let right_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(content_chunks[1]);
+    let [conversation_area, resources_area, progress_area, rollback_area] =
+        panel_areas(main_chunks[1], state.active_panel);
 
     // Conversation (top-left, larger)
-    conversation::render(state, frame, left_chunks[0], state.active_panel == 0);
+    conversation::render(state, frame, conversation_area, state.active_panel == 0);
 
     // Skill progress (bottom-left)
-    progress::render(state, frame, left_chunks[1], state.active_panel == 2);
+    progress::render(state, frame, progress_area, state.active_panel == 2);
 
-    // Resources (top-right)
-    resources::render(state, frame, right_chunks[0], state.active_panel == 1);
+    // Resources (top-right), replaced by the LLM summary once one is requested
+    if state.final_report.is_some() {
+        report::render(state, frame, resources_area, state.active_panel == 1);
+    } else {
+        resources::render(state, frame, resources_area, state.active_panel == 1);
+    }
 
     // Rollback (bottom-right)
-    rollback::render(state, frame, right_chunks[1], state.active_panel == 3);
+    rollback::render(state, frame, rollback_area, state.active_panel == 3);
 
     // Help bar
     let help_text = if state.phase.is_finished() {
         " [q] Quit  [Tab] Switch panel  [Up/Down] Scroll"
+    } else if state.cancel_requested {
+        " Cancelling, waiting for rollback...  [Ctrl+W] Force quit  [Tab] Panel  [Up/Down] Scroll"
     } else {
         " [Ctrl+C] Cancel  [Ctrl+W] Cancel & Quit  [Tab] Panel  [Up/Down] Scroll"
     };
-    let help = Paragraph::new(help_text).style(theme::dim_style());
+    let help_text = match state.resource_detail {
+        _ if !state.resources_focused() => help_text.to_string(),
+        Some(_) => format!("{help_text}  [Esc] Back to resources"),
+        None if state.resource_filter.is_empty() => {
+            format!("{help_text}  [Enter] Resource details  [type] Filter")
+        }
+        None => format!("{help_text}  [Enter] Resource details  [Esc] Clear filter"),
+    };
+    let help_text = if state.experiment_runs.len() > 1 {
+        format!("{help_text}  [Left/Right] Experiment")
+    } else {
+        help_text
+    };
+    let help_text = if state.approved_experiments.is_empty() {
+        help_text
+    } else {
+        format!("{help_text}  [Ctrl+S] Save plan")
+    };
+    let help_text = if state.can_steer() {
+        format!("{help_text}  [i] Message the planner")
+    } else {
+        help_text
+    };
+    let help_text = match state.pending_tool_call {
+        Some(ref call) => format!(" Tool call {}() waiting  [y] Allow  [n] Deny  [Ctrl+C] Cancel", call.name),
+        None => help_text,
+    };
+    let help = match state.steer_input {
+        Some(ref input) => Paragraph::new(format!(
+            " Message> {input}_  [Enter] Send  [Esc] Discard"
+        ))
+        .style(theme::normal_style()),
+        None => Paragraph::new(help_text).style(theme::dim_style()),
+    };
     frame.render_widget(help, main_chunks[2]);
 }
 
+/// Conversation, resources, progress and rollback areas: a 2x2 grid, or on
+/// narrow terminals one column in panel order where `active` takes all the
+/// room the others leave. When even that doesn't fit, the others shrink to
+/// their title line.
+fn panel_areas(area: Rect, active: usize) -> [Rect; 4] {
+    if area.width < NARROW_WIDTH {
+        let collapsed = if area.height >= 4 * COLLAPSED_HEIGHT {
+            COLLAPSED_HEIGHT
+        } else {
+            1
+        };
+        let constraints = (0..4).map(|panel| {
+            if panel == active {
+                Constraint::Min(0)
+            } else {
+                Constraint::Length(collapsed)
+            }
+        });
+        let column = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(area);
+        return [column[0], column[1], column[2], column[3]];
+    }
+
+    let content_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    let left_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(content_chunks[0]);
+
+    let right_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(content_chunks[1]);
+
+    [left_chunks[0], right_chunks[0], left_chunks[1], right_chunks[1]]
+}
+
+/// Items for one experiment's skills or rollback steps, under a header with
+/// its name when several experiments are shown.
+pub(super) fn experiment_items<'a>(
+    state: &'a DashboardState,
+    steps: impl Iterator<Item = (Uuid, &'a str, Option<bool>)> + Clone,
+) -> Vec<ListItem<'a>> {
+    let runs = state.viewed_runs();
+    let mut items = Vec::new();
+    for run in runs {
+        let run_steps: Vec<_> = steps.clone().filter(|(id, _, _)| *id == run.id).collect();
+        if runs.len() > 1 {
+            items.push(ListItem::new(Line::from(Span::styled(
+                format!(" {} ({})", run.name, run_steps.len()),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ))));
+        }
+        for (_, name, success) in run_steps {
+            let (icon, style) = match success {
+                Some(true) => ("OK", theme::success_style()),
+                Some(false) => ("FAIL", theme::error_style()),
+                None => ("...", Style::default().fg(Color::Yellow)),
+            };
+            items.push(ListItem::new(Line::from(vec![
+                Span::styled(format!("  [{icon:>4}] "), style),
+                Span::styled(name, theme::normal_style()),
+            ])));
+        }
+    }
+    items
+}
+
 pub fn handle_key(state: &mut DashboardState, key: KeyEvent, should_quit: &mut bool) -> DashboardAction {
-    // Ctrl+C: cancel experiment, stay in TUI
+    // Ctrl+C: cancel experiment, stay in TUI while rollback runs
     if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
-        if !state.phase.is_finished() {
-            state.phase = DashboardPhase::Cancelled;
-            state.conversation.push(ConversationEntry {
-                role: "system".into(),
-                content: "Experiment cancelled by user (Ctrl+C)".into(),
-            });
-            state.auto_scroll_conversation();
+        if !state.phase.is_finished() && !state.cancel_requested {
+            state.request_cancel("Cancel requested (Ctrl+C), rolling back applied chaos...");
             return DashboardAction::CancelExperiment;
         }
         return DashboardAction::None;
     }
 
-    // Ctrl+W: cancel experiment and quit TUI
+    // Ctrl+W: cancel experiment and quit TUI once rollback is done.
+    // A second Ctrl+W quits immediately.
     if key.code == KeyCode::Char('w') && key.modifiers.contains(KeyModifiers::CONTROL) {
-        if !state.phase.is_finished() {
-            state.phase = DashboardPhase::Cancelled;
+        if state.phase.is_finished() || state.quit_when_finished {
+            *should_quit = true;
+            return DashboardAction::None;
+        }
+        state.quit_when_finished = true;
+        if state.cancel_requested {
+            return DashboardAction::None;
+        }
+        state.request_cancel(
+            "Cancel requested (Ctrl+W), closing TUI after rollback... (Ctrl+W again to force)",
+        );
+        return DashboardAction::CancelAndQuit;
+    }
+
+    if let Some(ref mut review) = state.plan_review {
+        if let plan_review::ReviewAction::Approved { experiments, edits } = review.handle_key(key) {
+            state.approved_experiments = review.experiments.clone();
+            state.experiment_count = state.approved_experiments.len();
+            state.plan_review = None;
+            state.phase = DashboardPhase::Executing;
             state.conversation.push(ConversationEntry {
                 role: "system".into(),
-                content: "Experiment cancelled, closing TUI (Ctrl+W)".into(),
+                content: format!("Plan approved: {experiments} experiment(s), {edits} value(s) edited"),
             });
+            state.auto_scroll_conversation();
         }
-        *should_quit = true;
-        return DashboardAction::CancelAndQuit;
+        return DashboardAction::None;
     }
 
-    match key.code {
-        KeyCode::Char('q') => {
-            if state.phase.is_finished() {
-                *should_quit = true;
+    if let Some(ref mut input) = state.steer_input {
+        match key.code {
+            KeyCode::Enter => {
+                let message = input.trim().to_string();
+                state.steer_input = None;
+                if !message.is_empty() && state.can_steer() {
+                    state.conversation.push(ConversationEntry {
+                        role: "system".into(),
+                        content: "Message queued for the planner's next turn".into(),
+                    });
+                    state.auto_scroll_conversation();
+                    return DashboardAction::SteerPlanner(message);
+                }
             }
+            KeyCode::Esc => state.steer_input = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => input.push(c),
+            _ => {}
+        }
+        return DashboardAction::None;
+    }
+
+    if state.pending_tool_call.is_some() {
+        match key.code {
+            KeyCode::Char('y') => state.answer_tool_call(true),
+            KeyCode::Char('n') => state.answer_tool_call(false),
+            _ => {}
+        }
+        return DashboardAction::None;
+    }
+
+    // Ctrl+S: save the approved plan as a config file
+    if key.code == KeyCode::Char('s') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        if !state.approved_experiments.is_empty() {
+            state.export_experiments();
+        }
+        return DashboardAction::None;
+    }
+
+    match key.code {
+        KeyCode::Char('q') if state.phase.is_finished() => {
+            *should_quit = true;
         }
         KeyCode::Tab => {
             state.active_panel = (state.active_panel + 1) % 4;
         }
-        KeyCode::Up => {
-            if state.active_panel == 0 {
-                if state.conversation_auto_scroll {
-                    // Switch from auto-scroll to manual, start near the bottom
-                    state.conversation_auto_scroll = false;
-                    state.conversation_scroll = state.rendered_max_scroll.get().saturating_sub(1);
-                } else {
-                    state.conversation_scroll = state.conversation_scroll.saturating_sub(1);
-                }
+        KeyCode::Char('i') if state.can_steer() && !state.resources_focused() => {
+            state.steer_input = Some(String::new());
+        }
+        // Cycle the skill and rollback panels through all experiments, then each one
+        KeyCode::Right if state.experiment_runs.len() > 1 => {
+            state.experiment_view = match state.experiment_view {
+                None => Some(0),
+                Some(i) if i + 1 < state.experiment_runs.len() => Some(i + 1),
+                Some(_) => None,
+            };
+        }
+        KeyCode::Left if state.experiment_runs.len() > 1 => {
+            state.experiment_view = match state.experiment_view {
+                None => Some(state.experiment_runs.len() - 1),
+                Some(0) => None,
+                Some(i) => Some(i - 1),
+            };
+        }
+        KeyCode::Up if state.active_panel == 0 => {
+            if state.conversation_auto_scroll {
+                // Switch from auto-scroll to manual, start near the bottom
+                state.conversation_auto_scroll = false;
+                state.conversation_scroll = state.rendered_max_scroll.get().saturating_sub(1);
+            } else {
+                state.conversation_scroll = state.conversation_scroll.saturating_sub(1);
             }
         }
-        KeyCode::Down => {
-            if state.active_panel == 0 {
-                if !state.conversation_auto_scroll {
-                    state.conversation_scroll += 1;
-                    if state.conversation_scroll >= state.rendered_max_scroll.get() {
-                        state.conversation_auto_scroll = true;
-                    }
-                }
+        KeyCode::Down if state.active_panel == 0 && !state.conversation_auto_scroll => {
+            state.conversation_scroll += 1;
+            if state.conversation_scroll >= state.rendered_max_scroll.get() {
+                state.conversation_auto_scroll = true;
             }
         }
+        KeyCode::Up if state.resources_focused() => match state.resource_detail {
+            Some(ref mut offset) => *offset = offset.saturating_sub(1),
+            None => state.resource_selected = state.resource_selected.saturating_sub(1),
+        },
+        KeyCode::Down if state.resources_focused() => match state.resource_detail {
+            Some(offset) => {
+                let lines = state.selected_resource().map_or(0, |r| r.detail_lines().len());
+                state.resource_detail = Some((offset + 1).min(lines.saturating_sub(1)));
+            }
+            None if state.resource_selected + 1 < state.visible_resources().len() => {
+                state.resource_selected += 1;
+            }
+            None => {}
+        },
+        KeyCode::Enter if state.resources_focused() && state.selected_resource().is_some() => {
+            state.resource_detail = match state.resource_detail {
+                Some(_) => None,
+                None => Some(0),
+            };
+        }
+        KeyCode::Esc if state.resource_detail.is_some() => {
+            state.resource_detail = None;
+        }
+        // Type-to-filter while the resource list is showing
+        KeyCode::Char(c)
+            if state.resources_focused()
+                && state.resource_detail.is_none()
+                && !key.modifiers.contains(KeyModifiers::CONTROL) =>
+        {
+            state.resource_filter.push(c);
+            state.resource_selected = 0;
+        }
+        KeyCode::Backspace if state.resources_focused() && state.resource_detail.is_none() => {
+            state.resource_filter.pop();
+            state.resource_selected = 0;
+        }
+        KeyCode::Esc if state.resources_focused() && !state.resource_filter.is_empty() => {
+            state.resource_filter.clear();
+            state.resource_selected = 0;
+        }
         _ => {}
     }
     DashboardAction::None
@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use chaos_core::blast_radius::BlastRadius;
+use chaos_core::duration::parse_duration;
+use chaos_core::experiment::ExperimentConfig;
+use chaos_core::report::DiscoveredResourceSummary;
+use chaos_core::skill::TargetDomain;
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+
+use super::DashboardState;
+use crate::execution::{validate_experiment, PlanApproval};
+use crate::theme;
+use crate::widgets::input::{InputAction, TextInput};
+
+/// An editable value of the plan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Duration { experiment: usize },
+    Count { experiment: usize, skill: usize },
+    Params { experiment: usize, skill: usize },
+}
+
+/// Planned experiments shown for editing before anything runs.
+pub struct PlanReview {
+    pub experiments: Vec<ExperimentConfig>,
+    discovered: HashMap<TargetDomain, Vec<DiscoveredResourceSummary>>,
+    reply: Option<tokio::sync::oneshot::Sender<Option<Vec<ExperimentConfig>>>>,
+    selected: usize,
+    editor: Option<TextInput>,
+    error: Option<String>,
+    edits: usize,
+}
+
+pub enum ReviewAction {
+    None,
+    Approved { experiments: usize, edits: usize },
+}
+
+impl PlanReview {
+    pub fn new(approval: PlanApproval) -> Self {
+        Self {
+            experiments: approval.experiments,
+            discovered: approval.discovered,
+            reply: Some(approval.reply),
+            selected: 0,
+            editor: None,
+            error: None,
+            edits: 0,
+        }
+    }
+
+    /// Tell the execution task to run nothing.
+    pub fn reject(&mut self) {
+        if let Some(reply) = self.reply.take() {
+            let _ = reply.send(None);
+        }
+    }
+
+    fn approve(&mut self) {
+        if let Some(reply) = self.reply.take() {
+            let _ = reply.send(Some(self.experiments.clone()));
+        }
+    }
+
+    /// What `experiment` may touch with its current (possibly edited) params.
+    fn blast_radius(&self, experiment: &ExperimentConfig) -> BlastRadius {
+        let discovered = self.discovered.get(&experiment.target).map(Vec::as_slice).unwrap_or_default();
+        BlastRadius::project(experiment, discovered)
+    }
+
+    fn fields(&self) -> Vec<Field> {
+        let mut fields = Vec::new();
+        for (experiment, config) in self.experiments.iter().enumerate() {
+            fields.push(Field::Duration { experiment });
+            for skill in 0..config.skills.len() {
+                fields.push(Field::Count { experiment, skill });
+                fields.push(Field::Params { experiment, skill });
+            }
+        }
+        fields
+    }
+
+    fn value(&self, field: Field) -> String {
+        match field {
+            Field::Duration { experiment } => self.experiments[experiment]
+                .duration
+                .map(compact_duration)
+                .unwrap_or_default(),
+            Field::Count { experiment, skill } => {
+                self.experiments[experiment].skills[skill].count.to_string()
+            }
+            Field::Params { experiment, skill } => {
+                let params = &self.experiments[experiment].skills[skill].params;
+                if params.is_null() {
+                    String::new()
+                } else {
+                    serde_json::to_string(params).unwrap_or_default()
+                }
+            }
+        }
+    }
+
+    /// Parse `input` into `field` and validate the edited experiment,
+    /// leaving the plan untouched on error.
+    fn apply(&mut self, field: Field, input: &str) -> Result<(), String> {
+        let input = input.trim();
+        let (index, mut edited) = match field {
+            Field::Duration { experiment }
+            | Field::Count { experiment, .. }
+            | Field::Params { experiment, .. } => (experiment, self.experiments[experiment].clone()),
+        };
+        match field {
+            Field::Duration { .. } => {
+                let duration = parse_duration(input).map_err(|e| e.to_string())?;
+                edited.duration = Some(duration);
+            }
+            Field::Count { skill, .. } => {
+                let count = input
+                    .parse()
+                    .map_err(|_| format!("Invalid count '{input}': expected a whole number"))?;
+                edited.skills[skill].count = count;
+            }
+            Field::Params { skill, .. } => {
+                let params: serde_yaml::Value = if input.is_empty() {
+                    serde_yaml::Value::Null
+                } else {
+                    serde_yaml::from_str(input).map_err(|e| format!("Invalid params: {e}"))?
+                };
+                if !(params.is_null() || params.is_mapping()) {
+                    return Err("Params must be an object, e.g. {\"rows_per_table\": 50}".into());
+                }
+                edited.skills[skill].params = params;
+            }
+        }
+        validate_experiment(&edited)?;
+        self.experiments[index] = edited;
+        Ok(())
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> ReviewAction {
+        let fields = self.fields();
+
+        if let Some(ref mut editor) = self.editor {
+            match key.code {
+                KeyCode::Esc => {
+                    self.editor = None;
+                    self.error = None;
+                }
+                _ => {
+                    if let InputAction::Submit = editor.handle_key(key) {
+                        let input = editor.content.clone();
+                        let field = fields[self.selected];
+                        let before = self.value(field);
+                        match self.apply(field, &input) {
+                            Ok(()) => {
+                                if self.value(field) != before {
+                                    self.edits += 1;
+                                }
+                                self.editor = None;
+                                self.error = None;
+                            }
+                            Err(e) => self.error = Some(e),
+                        }
+                    }
+                }
+            }
+            return ReviewAction::None;
+        }
+
+        match key.code {
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down if self.selected + 1 < fields.len() => self.selected += 1,
+            KeyCode::Enter if !fields.is_empty() => {
+                let field = fields[self.selected];
+                let label = match field {
+                    Field::Duration { .. } => " Duration (e.g. 30s, 5m, 1h30m) ",
+                    Field::Count { .. } => " Count ",
+                    Field::Params { .. } => " Params (JSON or YAML flow, e.g. {rows_per_table: 50}) ",
+                };
+                let mut editor = TextInput::new(label).with_content(&self.value(field));
+                editor.focused = true;
+                self.editor = Some(editor);
+            }
+            KeyCode::Char('a') => {
+                self.approve();
+                return ReviewAction::Approved {
+                    experiments: self.experiments.len(),
+                    edits: self.edits,
+                };
+            }
+            _ => {}
+        }
+        ReviewAction::None
+    }
+}
+
+impl Drop for PlanReview {
+    fn drop(&mut self) {
+        self.reject();
+    }
+}
+
+/// `1h30m` style, which [`parse_duration`] reads back.
+fn compact_duration(d: Duration) -> String {
+    let secs = d.as_secs();
+    let parts = [(secs / 86_400, "d"), (secs / 3600 % 24, "h"), (secs / 60 % 60, "m"), (secs % 60, "s")];
+    let out: String = parts
+        .iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, unit)| format!("{n}{unit}"))
+        .collect();
+    if out.is_empty() {
+        "0s".into()
+    } else {
+        out
+    }
+}
+
+pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect) {
+    let Some(ref review) = state.plan_review else {
+        return;
+    };
+
+    let editor_height = if review.editor.is_some() { 3 } else { 0 };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),
+            Constraint::Length(editor_height),
+            Constraint::Length(1),
+        ])
+        .split(area);
+
+    let fields = review.fields();
+    let mut items = Vec::new();
+    let mut selected_row = None;
+    for (i, experiment) in review.experiments.iter().enumerate() {
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled(format!(" {} ", experiment.name), theme::title_style()),
+            Span::styled(format!("({})", experiment.target), theme::dim_style()),
+        ])));
+        items.push(ListItem::new(Line::from(vec![
+            Span::styled(format!("{:<40} ", "   blast radius"), Style::default().fg(Color::Yellow)),
+            Span::styled(review.blast_radius(experiment).summary(), Style::default().fg(Color::Yellow)),
+        ])));
+        for (index, field) in fields.iter().enumerate() {
+            let label = match *field {
+                Field::Duration { experiment } if experiment == i => "   duration".to_string(),
+                Field::Count { experiment, skill } if experiment == i => {
+                    format!("   {} count", review.experiments[i].skills[skill].skill_name)
+                }
+                Field::Params { experiment, skill } if experiment == i => {
+                    format!("   {} params", review.experiments[i].skills[skill].skill_name)
+                }
+                _ => continue,
+            };
+            if index == review.selected {
+                selected_row = Some(items.len());
+            }
+            let value = review.value(*field);
+            items.push(ListItem::new(Line::from(vec![
+                Span::styled(format!("{label:<40} "), Style::default().fg(Color::Cyan)),
+                Span::styled(
+                    if value.is_empty() { "(none)".to_string() } else { value },
+                    theme::normal_style(),
+                ),
+            ])));
+        }
+    }
+
+    let block = Block::default()
+        .title(" Review plan: edit before anything runs ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::selected_style());
+    let mut list_state = ListState::default().with_selected(selected_row);
+    frame.render_stateful_widget(list, chunks[0], &mut list_state);
+
+    if let Some(ref editor) = review.editor {
+        editor.render(chunks[1], frame.buffer_mut());
+    }
+
+    let footer = match review.error {
+        Some(ref error) => Paragraph::new(format!(" {error}")).style(theme::error_style()),
+        None if review.edits > 0 => {
+            Paragraph::new(format!(" {} value(s) edited", review.edits)).style(theme::dim_style())
+        }
+        None => Paragraph::new(""),
+    };
+    frame.render_widget(footer, chunks[2]);
+}
@@ -1,7 +1,7 @@
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::widgets::{Block, Borders, List, Paragraph};
 
-use super::DashboardState;
+use super::{experiment_items, DashboardState};
 use crate::theme;
 
 pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect, active: bool) {
@@ -11,12 +11,22 @@ pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect, active: boo
         Style::default().fg(Color::DarkGray)
     };
 
+    let items = experiment_items(
+        state,
+        state.skills.iter().map(|s| (s.experiment_id, s.skill_name.as_str(), s.success)),
+    );
+    let shown = state
+        .skills
+        .iter()
+        .filter(|s| state.viewed_runs().iter().any(|r| r.id == s.experiment_id))
+        .count();
+
     let block = Block::default()
-        .title(format!(" Skill Execution ({}) ", state.skills.len()))
+        .title(format!(" Skill Execution{}", state.view_label(shown)))
         .borders(Borders::ALL)
         .border_style(border_style);
 
-    if state.skills.is_empty() {
+    if shown == 0 {
         let empty = Paragraph::new("  No skills executed yet")
             .style(theme::dim_style())
             .block(block);
@@ -24,22 +34,6 @@ pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect, active: boo
         return;
     }
 
-    let items: Vec<ListItem> = state
-        .skills
-        .iter()
-        .map(|s| {
-            let (icon, style) = match s.success {
-                Some(true) => ("OK", theme::success_style()),
-                Some(false) => ("FAIL", theme::error_style()),
-                None => ("...", Style::default().fg(Color::Yellow)),
-            };
-            ListItem::new(Line::from(vec![
-                Span::styled(format!("  [{icon:>4}] "), style),
-                Span::styled(&s.skill_name, theme::normal_style()),
-            ]))
-        })
-        .collect();
-
     let list = List::new(items).block(block);
     frame.render_widget(list, area);
 }
@@ -4,11 +4,17 @@ use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use super::DashboardState;
 use crate::theme;
 
-pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect) {
+pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect, active: bool) {
+    let border_style = if active {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default().fg(Color::Green)
+    };
+
     let block = Block::default()
         .title(" Experiment Report ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Green));
+        .border_style(border_style);
 
     let content = state
         .final_report
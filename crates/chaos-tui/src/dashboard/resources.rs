@@ -1,5 +1,5 @@
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
 
 use super::DashboardState;
 use crate::theme;
@@ -11,8 +11,36 @@ pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect, active: boo
         Style::default().fg(Color::DarkGray)
     };
 
+    if let (Some(offset), Some(resource)) = (state.resource_detail, state.selected_resource()) {
+        let block = Block::default()
+            .title(format!(" {} {} ", resource.resource_type, resource.name))
+            .borders(Borders::ALL)
+            .border_style(border_style);
+        let lines: Vec<Line> = resource
+            .detail_lines()
+            .into_iter()
+            .map(|l| Line::styled(format!(" {l}"), theme::normal_style()))
+            .collect();
+        let detail = Paragraph::new(lines)
+            .block(block)
+            .scroll((offset.min(u16::MAX as usize) as u16, 0));
+        frame.render_widget(detail, area);
+        return;
+    }
+
+    let visible = state.visible_resources();
+    let title = if state.resource_filter.is_empty() {
+        format!(" Resources ({}) ", state.resources.len())
+    } else {
+        format!(
+            " Resources ({}/{}) filter: {} ",
+            visible.len(),
+            state.resources.len(),
+            state.resource_filter
+        )
+    };
     let block = Block::default()
-        .title(format!(" Resources ({}) ", state.resources.len()))
+        .title(title)
         .borders(Borders::ALL)
         .border_style(border_style);
 
@@ -24,20 +52,43 @@ pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect, active: boo
         return;
     }
 
-    let items: Vec<ListItem> = state
-        .resources
-        .iter()
-        .map(|r| {
-            ListItem::new(Line::from(vec![
-                Span::styled(
-                    format!("  [{:>10}] ", r.resource_type),
-                    Style::default().fg(Color::Cyan),
-                ),
-                Span::styled(&r.name, theme::normal_style()),
-            ]))
-        })
-        .collect();
-
-    let list = List::new(items).block(block);
-    frame.render_widget(list, area);
+    if visible.is_empty() {
+        let empty = Paragraph::new(format!("  No resources match '{}'", state.resource_filter))
+            .style(theme::dim_style())
+            .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    // `visible` is sorted by type, so each type is one run under a header.
+    let mut items = Vec::new();
+    let mut selected_row = None;
+    let mut current_type = None;
+    for (position, &index) in visible.iter().enumerate() {
+        let resource = &state.resources[index];
+        if current_type != Some(&resource.resource_type) {
+            current_type = Some(&resource.resource_type);
+            let count = visible
+                .iter()
+                .filter(|&&i| state.resources[i].resource_type == resource.resource_type)
+                .count();
+            items.push(ListItem::new(Line::from(Span::styled(
+                format!(" {} ({count})", resource.resource_type),
+                Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+            ))));
+        }
+        if position == state.resource_selected {
+            selected_row = Some(items.len());
+        }
+        items.push(ListItem::new(Line::from(Span::styled(
+            format!("   {}", resource.name),
+            theme::normal_style(),
+        ))));
+    }
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(theme::selected_style());
+    let mut list_state = ListState::default().with_selected(selected_row.filter(|_| active));
+    frame.render_stateful_widget(list, area, &mut list_state);
 }
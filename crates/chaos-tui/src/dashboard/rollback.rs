@@ -1,7 +1,7 @@
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::widgets::{Block, Borders, List, Paragraph};
 
-use super::DashboardState;
+use super::{experiment_items, DashboardState};
 use crate::theme;
 
 pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect, active: bool) {
@@ -11,12 +11,25 @@ pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect, active: boo
         Style::default().fg(Color::DarkGray)
     };
 
+    let items = experiment_items(
+        state,
+        state
+            .rollback_steps
+            .iter()
+            .map(|r| (r.experiment_id, r.skill_name.as_str(), r.success)),
+    );
+    let shown = state
+        .rollback_steps
+        .iter()
+        .filter(|r| state.viewed_runs().iter().any(|run| run.id == r.experiment_id))
+        .count();
+
     let block = Block::default()
-        .title(format!(" Rollback ({}) ", state.rollback_steps.len()))
+        .title(format!(" Rollback{}", state.view_label(shown)))
         .borders(Borders::ALL)
         .border_style(border_style);
 
-    if state.rollback_steps.is_empty() {
+    if shown == 0 {
         let label = if state.phase == super::DashboardPhase::RollingBack {
             "  Rolling back..."
         } else {
@@ -29,22 +42,6 @@ pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect, active: boo
         return;
     }
 
-    let items: Vec<ListItem> = state
-        .rollback_steps
-        .iter()
-        .map(|r| {
-            let (icon, style) = match r.success {
-                Some(true) => ("OK", theme::success_style()),
-                Some(false) => ("FAIL", theme::error_style()),
-                None => ("...", Style::default().fg(Color::Yellow)),
-            };
-            ListItem::new(Line::from(vec![
-                Span::styled(format!("  [{icon:>4}] "), style),
-                Span::styled(&r.skill_name, theme::normal_style()),
-            ]))
-        })
-        .collect();
-
     let list = List::new(items).block(block);
     frame.render_widget(list, area);
 }
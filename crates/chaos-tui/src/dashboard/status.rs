@@ -1,3 +1,4 @@
+use chaos_llm::pricing::{estimate_cost, format_cost};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
@@ -6,7 +7,7 @@ use crate::theme;
 
 pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect) {
     let phase_label = state.phase.label();
-    let spinner = if phase_label != "Complete" && !phase_label.starts_with("Failed") {
+    let spinner = if !state.phase.is_finished() {
         format!("{} ", state.spinner.frame())
     } else {
         String::new()
@@ -18,11 +19,34 @@ pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect) {
         String::new()
     };
 
+    let experiment_info = if state.experiment_count > 0 {
+        format!(
+            "  Experiment {}/{}",
+            state.current_experiment, state.experiment_count
+        )
+    } else {
+        String::new()
+    };
+
+    let token_info = match state.total_usage {
+        Some(ref usage) => {
+            let cost = estimate_cost(state.wizard_output.provider_config.model(), usage)
+                .map(|cost| format!(" (~{})", format_cost(cost)))
+                .unwrap_or_default();
+            format!(
+                "  Tokens: {} in / {} out{cost}",
+                usage.input_tokens, usage.output_tokens
+            )
+        }
+        None => String::new(),
+    };
+
     let phase_style = theme::phase_style(phase_label);
 
     let status_line = Line::from(vec![
         Span::styled(format!(" {spinner}Phase: "), Style::default().fg(Color::White)),
         Span::styled(format!("[{phase_label}]"), phase_style),
+        Span::styled(experiment_info, Style::default().fg(Color::White)),
         Span::raw("  "),
         Span::styled(
             format!("Elapsed: {}", state.elapsed_display()),
@@ -45,6 +69,7 @@ pub fn render(state: &DashboardState, frame: &mut Frame, area: Rect) {
         ),
         Span::raw("  "),
         Span::styled(turn_info, theme::dim_style()),
+        Span::styled(token_info, theme::dim_style()),
     ]);
 
     let block = Block::default()
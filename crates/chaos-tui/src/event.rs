@@ -18,18 +18,13 @@ impl EventHandler {
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
         let thread = std::thread::spawn(move || loop {
             if event::poll(tick_rate).unwrap_or(false) {
-                match event::read() {
-                    Ok(CrosstermEvent::Key(key)) => {
-                        if tx.send(TuiEvent::Key(key)).is_err() {
-                            break;
-                        }
-                    }
-                    Ok(CrosstermEvent::Resize(w, h)) => {
-                        if tx.send(TuiEvent::Resize(w, h)).is_err() {
-                            break;
-                        }
-                    }
-                    _ => {}
+                let sent = match event::read() {
+                    Ok(CrosstermEvent::Key(key)) => tx.send(TuiEvent::Key(key)),
+                    Ok(CrosstermEvent::Resize(w, h)) => tx.send(TuiEvent::Resize(w, h)),
+                    _ => Ok(()),
+                };
+                if sent.is_err() {
+                    break;
                 }
             } else if tx.send(TuiEvent::Tick).is_err() {
                 break;
@@ -14,6 +14,7 @@ use crossterm::{
 };
 use ratatui::prelude::*;
 use ratatui::Terminal;
+use tokio_util::sync::CancellationToken;
 
 use app::{App, AppScreen};
 use dashboard::{DashboardAction, DashboardState};
@@ -53,7 +54,12 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> anyho
 
     let mut planner_rx: Option<tokio::sync::mpsc::UnboundedReceiver<_>> = None;
     let mut experiment_rx: Option<tokio::sync::mpsc::UnboundedReceiver<_>> = None;
+    let mut approval_rx: Option<tokio::sync::mpsc::UnboundedReceiver<_>> = None;
+    let mut resources_rx: Option<tokio::sync::mpsc::UnboundedReceiver<_>> = None;
+    let mut tool_approval_rx: Option<tokio::sync::mpsc::UnboundedReceiver<_>> = None;
     let mut task_handle: Option<tokio::task::JoinHandle<()>> = None;
+    let mut cancel_token: Option<CancellationToken> = None;
+    let mut steer_tx: Option<tokio::sync::mpsc::UnboundedSender<String>> = None;
 
     loop {
         // Render
@@ -82,13 +88,19 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> anyho
                                         app.should_quit = true;
                                     }
                                     WizardTransition::StartExecution => {
-                                        match state.into_output() {
-                                            Ok(output) => {
-                                                let (p_rx, e_rx, handle) =
-                                                    execution::spawn_execution(output.clone());
-                                                planner_rx = Some(p_rx);
-                                                experiment_rx = Some(e_rx);
-                                                task_handle = Some(handle);
+                                        let started = state.into_output().and_then(|output| {
+                                            Ok((execution::spawn_execution(output.clone())?, output))
+                                        });
+                                        match started {
+                                            Ok((handles, output)) => {
+                                                planner_rx = Some(handles.planner_rx);
+                                                experiment_rx = Some(handles.event_rx);
+                                                approval_rx = Some(handles.approval_rx);
+                                                resources_rx = Some(handles.resources_rx);
+                                                task_handle = Some(handles.task);
+                                                cancel_token = Some(handles.cancel);
+                                                steer_tx = Some(handles.steer_tx);
+                                                tool_approval_rx = handles.tool_approval_rx;
                                                 app.screen = AppScreen::Dashboard(
                                                     DashboardState::from_wizard_output(output),
                                                 );
@@ -115,10 +127,20 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> anyho
                                 TuiEvent::Key(key) => {
                                     if let AppScreen::Dashboard(ref mut state) = app.screen {
                                         let action = dashboard::handle_key(state, key, &mut app.should_quit);
-                                        if matches!(action, DashboardAction::CancelExperiment | DashboardAction::CancelAndQuit) {
-                                            if let Some(handle) = task_handle.take() {
-                                                handle.abort();
+                                        // Cooperative cancel: the task skips remaining chaos and rolls back
+                                        match action {
+                                            DashboardAction::CancelExperiment | DashboardAction::CancelAndQuit => {
+                                                if let Some(ref cancel) = cancel_token {
+                                                    cancel.cancel();
+                                                }
                                             }
+                                            // Picked up by the planner before its next turn
+                                            DashboardAction::SteerPlanner(message) => {
+                                                if let Some(ref tx) = steer_tx {
+                                                    let _ = tx.send(message);
+                                                }
+                                            }
+                                            DashboardAction::None => {}
                                         }
                                     }
                                 }
@@ -142,6 +164,42 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> anyho
                                             }
                                         }
                                     }
+                                    // Plan awaiting approval
+                                    if let Some(ref mut rx) = approval_rx {
+                                        while let Ok(approval) = rx.try_recv() {
+                                            if let AppScreen::Dashboard(ref mut state) = app.screen {
+                                                state.begin_review(approval);
+                                            }
+                                        }
+                                    }
+                                    // Tool calls awaiting approval in step-through mode
+                                    if let Some(ref mut rx) = tool_approval_rx {
+                                        while let Ok(approval) = rx.try_recv() {
+                                            if let AppScreen::Dashboard(ref mut state) = app.screen {
+                                                state.begin_tool_approval(approval);
+                                            }
+                                        }
+                                    }
+                                    // Resources from planning-time discovery
+                                    if let Some(ref mut rx) = resources_rx {
+                                        while let Ok(resources) = rx.try_recv() {
+                                            if let AppScreen::Dashboard(ref mut state) = app.screen {
+                                                state.add_resources(resources);
+                                            }
+                                        }
+                                    }
+                                    // Detect the background task exiting (after rollback on cancel)
+                                    if task_handle.as_ref().is_some_and(|h| h.is_finished()) {
+                                        task_handle = None;
+                                        if let AppScreen::Dashboard(ref mut state) = app.screen {
+                                            state.execution_finished();
+                                        }
+                                    }
+                                    if let AppScreen::Dashboard(ref state) = app.screen {
+                                        if task_handle.is_none() && state.quit_when_finished {
+                                            app.should_quit = true;
+                                        }
+                                    }
                                 }
                                 TuiEvent::Resize(_, _) => {}
                             }
@@ -157,6 +215,36 @@ async fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> anyho
                             state.handle_planner_event(event);
                         }
                     }
+                    Some(approval) = async {
+                        match approval_rx.as_mut() {
+                            Some(rx) => rx.recv().await,
+                            None => std::future::pending().await,
+                        }
+                    } => {
+                        if let AppScreen::Dashboard(ref mut state) = app.screen {
+                            state.begin_review(approval);
+                        }
+                    }
+                    Some(approval) = async {
+                        match tool_approval_rx.as_mut() {
+                            Some(rx) => rx.recv().await,
+                            None => std::future::pending().await,
+                        }
+                    } => {
+                        if let AppScreen::Dashboard(ref mut state) = app.screen {
+                            state.begin_tool_approval(approval);
+                        }
+                    }
+                    Some(resources) = async {
+                        match resources_rx.as_mut() {
+                            Some(rx) => rx.recv().await,
+                            None => std::future::pending().await,
+                        }
+                    } => {
+                        if let AppScreen::Dashboard(ref mut state) = app.screen {
+                            state.add_resources(resources);
+                        }
+                    }
                     Some(event) = async {
                         match experiment_rx.as_mut() {
                             Some(rx) => rx.recv().await,
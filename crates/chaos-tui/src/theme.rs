@@ -34,6 +34,7 @@ pub fn phase_style(phase: &str) -> Style {
     match phase {
         "Discovering" => Style::default().fg(BRAND_CYAN),
         "Planning" => Style::default().fg(BRAND_BLUE),
+        "Review" => Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
         "Executing" => Style::default().fg(BRAND_ORANGE),
         "Waiting" => Style::default().fg(Color::Yellow),
         "RollingBack" => Style::default().fg(BRAND_ORANGE),
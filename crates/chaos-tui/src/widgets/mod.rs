@@ -1,3 +1,4 @@
 pub mod input;
 pub mod selector;
 pub mod spinner;
+pub mod too_small;
@@ -7,6 +7,12 @@ pub struct Spinner {
     tick: usize,
 }
 
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Spinner {
     pub fn new() -> Self {
         Self { tick: 0 }
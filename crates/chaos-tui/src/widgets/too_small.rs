@@ -0,0 +1,33 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Paragraph, Wrap};
+
+use crate::theme;
+
+/// Show a notice instead of a screen that needs at least `width`x`height`
+/// cells. Returns whether `area` was too small, in which case the caller
+/// should draw nothing else.
+pub fn render_if_too_small(frame: &mut Frame, area: Rect, width: u16, height: u16) -> bool {
+    if area.width >= width && area.height >= height {
+        return false;
+    }
+    let lines = vec![
+        Line::styled("Terminal too small", theme::title_style()),
+        Line::styled(
+            format!("need at least {width}x{height}, have {}x{}", area.width, area.height),
+            theme::dim_style(),
+        ),
+    ];
+    let top = area.height.saturating_sub(lines.len() as u16) / 2;
+    let notice = Rect {
+        y: area.y + top,
+        height: area.height - top,
+        ..area
+    };
+    frame.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        notice,
+    );
+    true
+}
@@ -6,12 +6,17 @@ pub mod target_config;
 pub mod prompt;
 pub mod review;
 
+use chaos_core::experiment::ExperimentConfig;
+use chaos_core::safety::{ProductionMatch, SafetyConfig};
+use chaos_core::skill::TargetDomain;
 use chaos_llm::provider::LlmProviderConfig;
+use chaos_llm::target_hint::{extract_namespace_from_prompt, extract_target_config_from_prompt};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::prelude::*;
 
 use crate::widgets::input::TextInput;
 use crate::widgets::selector::{Selector, SelectorItem};
+use crate::widgets::too_small::render_if_too_small;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WizardScreen {
@@ -38,6 +43,11 @@ pub struct WizardOutput {
     pub prompt: String,
     pub max_turns: u32,
     pub duration: String,
+    /// The user confirmed on the review screen that production-looking
+    /// targets are intended.
+    pub allow_production: bool,
+    /// Ask before the planner runs each tool call.
+    pub step_through: bool,
 }
 
 pub struct WizardState {
@@ -73,10 +83,21 @@ pub struct WizardState {
     // Prompt
     pub prompt_input: TextInput,
     pub duration_input: TextInput,
+    // Production-looking targets found when entering review
+    pub production_matches: Vec<ProductionMatch>,
+    pub production_acknowledged: bool,
+    // Approve each planner tool call in the dashboard
+    pub step_through: bool,
     // Error
     pub error_message: Option<String>,
 }
 
+impl Default for WizardState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl WizardState {
     pub fn new() -> Self {
         let anthropic_detected = std::env::var("ANTHROPIC_API_KEY").is_ok();
@@ -201,10 +222,124 @@ impl WizardState {
             target_field_index: 0,
             prompt_input: TextInput::new(" Chaos Prompt ").with_multiline(),
             duration_input: TextInput::new(" Duration ").with_content("5m"),
+            production_matches: Vec::new(),
+            production_acknowledged: false,
+            step_through: false,
             error_message: None,
         }
     }
 
+    /// The `target_config` the target form describes, if a target is selected.
+    pub fn target_config(&self) -> Option<(TargetDomain, serde_yaml::Value)> {
+        let (domain, config) = match self.selected_target.as_deref()? {
+            "database" => {
+                let db_type = match self.db_type_selector.selected_index() {
+                    1 => "mysql",
+                    2 => "mongodb",
+                    _ => "postgres",
+                };
+                let list: Vec<&str> = self
+                    .db_schemas_input
+                    .content
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .collect();
+                let list_field = if db_type == "mongodb" { "databases" } else { "schemas" };
+                let mut config = serde_json::json!({
+                    "connection_url": self.db_url_input.content,
+                    "db_type": db_type,
+                });
+                config[list_field] = list.into();
+                (TargetDomain::Database, config)
+            }
+            "kubernetes" => {
+                let mut config = serde_json::json!({ "namespace": self.k8s_namespace_input.content });
+                if !self.k8s_label_input.content.is_empty() {
+                    config["label_selector"] = self.k8s_label_input.content.clone().into();
+                }
+                if !self.k8s_kubeconfig_input.content.is_empty() {
+                    config["kubeconfig"] = self.k8s_kubeconfig_input.content.clone().into();
+                }
+                (TargetDomain::Kubernetes, config)
+            }
+            "server" => {
+                let port = self.server_port_input.content.trim();
+                let auth = match self.server_auth_selector.selected_index() {
+                    1 => serde_json::json!({ "type": "password", "password": self.server_auth_value_input.content }),
+                    _ => serde_json::json!({ "type": "key", "private_key_path": self.server_auth_value_input.content }),
+                };
+                let config = serde_json::json!({
+                    "hosts": [{
+                        "host": self.server_host_input.content,
+                        // Left as typed when it isn't a number, so validation reports it
+                        "port": port.parse::<u64>().map(serde_json::Value::from).unwrap_or_else(|_| port.into()),
+                        "username": self.server_username_input.content,
+                        "auth": auth,
+                    }]
+                });
+                (TargetDomain::Server, config)
+            }
+            _ => return None,
+        };
+        Some((domain, serde_yaml::to_value(config).unwrap_or_default()))
+    }
+
+    /// Targets named in the prompt or the target fields that look like
+    /// production. The planner may still pick others; those are checked again
+    /// before execution.
+    pub fn find_production_targets(&self) -> Vec<ProductionMatch> {
+        let prompt = &self.prompt_input.content;
+        let mut targets: Vec<(TargetDomain, serde_json::Value)> = Vec::new();
+        if let Some(config) = extract_target_config_from_prompt(prompt, None) {
+            targets.push((TargetDomain::Database, config));
+        }
+        if let Some(namespace) = extract_namespace_from_prompt(prompt) {
+            targets.push((TargetDomain::Kubernetes, serde_json::json!({ "namespace": namespace })));
+        }
+        match self.selected_target.as_deref() {
+            Some("database") if !self.db_url_input.content.is_empty() => targets.push((
+                TargetDomain::Database,
+                serde_json::json!({ "connection_url": self.db_url_input.content }),
+            )),
+            Some("kubernetes") => {
+                let mut config = serde_json::json!({ "namespace": self.k8s_namespace_input.content });
+                if !self.k8s_kubeconfig_input.content.is_empty() {
+                    config["kubeconfig"] = self.k8s_kubeconfig_input.content.clone().into();
+                }
+                targets.push((TargetDomain::Kubernetes, config));
+            }
+            Some("server") if !self.server_host_input.content.is_empty() => targets.push((
+                TargetDomain::Server,
+                serde_json::json!({ "hosts": [{ "host": self.server_host_input.content }] }),
+            )),
+            _ => {}
+        }
+
+        let experiments: Vec<ExperimentConfig> = targets
+            .into_iter()
+            .map(|(target, config)| ExperimentConfig {
+                name: target.to_string(),
+                target,
+                target_ref: None,
+                target_config: serde_yaml::to_value(config).unwrap_or_default(),
+                skills: Vec::new(),
+                duration: None,
+                soak: Default::default(),
+                health_poll_interval: None,
+                parallel: false,
+                resource_filters: Vec::new(),
+                allow_irreversible: false,
+                pre_hook: None,
+                post_hook: None,
+                hook_timeout: None,
+                dry_run: false,
+                limits: Default::default(),
+            })
+            .collect();
+        SafetyConfig::default().check(&experiments)
+    }
+
     pub fn into_output(&self) -> anyhow::Result<WizardOutput> {
         let provider = self
             .selected_provider
@@ -220,6 +355,11 @@ impl WizardState {
                     self.model_input.content.clone()
                 },
                 max_tokens: 4096,
+                headers: Default::default(),
+                proxy: None,
+                retry: Default::default(),
+                temperature: None,
+                top_p: None,
             },
             "openai" => LlmProviderConfig::Openai {
                 api_key: self.api_key_input.content.clone(),
@@ -234,6 +374,11 @@ impl WizardState {
                     Some(self.base_url_input.content.clone())
                 },
                 max_tokens: 4096,
+                headers: Default::default(),
+                proxy: None,
+                retry: Default::default(),
+                temperature: None,
+                top_p: None,
             },
             "ollama" => LlmProviderConfig::Ollama {
                 base_url: if self.base_url_input.content.is_empty() {
@@ -247,6 +392,13 @@ impl WizardState {
                     self.model_input.content.clone()
                 },
                 max_tokens: 4096,
+                headers: Default::default(),
+                proxy: None,
+                retry: Default::default(),
+                temperature: None,
+                top_p: None,
+                keep_alive: None,
+                timeout: None,
             },
             _ => anyhow::bail!("Unknown provider: {provider}"),
         };
@@ -263,17 +415,28 @@ impl WizardState {
         } else {
             self.duration_input.content.trim().to_string()
         };
+        chaos_core::duration::parse_duration(&duration)?;
 
         Ok(WizardOutput {
             provider_config,
             prompt: self.prompt_input.content.clone(),
             max_turns,
             duration,
+            allow_production: self.production_acknowledged,
+            step_through: self.step_through,
         })
     }
 }
 
+/// Smallest terminal every wizard screen fits in (the target config form is
+/// the tallest).
+const MIN_WIDTH: u16 = 50;
+const MIN_HEIGHT: u16 = 22;
+
 pub fn render(state: &WizardState, frame: &mut Frame, area: Rect) {
+    if render_if_too_small(frame, area, MIN_WIDTH, MIN_HEIGHT) {
+        return;
+    }
     match state.screen {
         WizardScreen::Welcome => welcome::render(state, frame, area),
         WizardScreen::SelectProvider => provider::render(state, frame, area),
@@ -1,3 +1,4 @@
+use chaos_core::duration::parse_duration;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
@@ -17,6 +18,7 @@ pub fn render(state: &WizardState, frame: &mut Frame, area: Rect) {
             Constraint::Length(1),  // subtitle
             Constraint::Min(6),    // prompt textarea
             Constraint::Length(3), // duration input
+            Constraint::Length(1),  // error
             Constraint::Length(2),  // help
         ])
         .split(area);
@@ -101,21 +103,21 @@ pub fn render(state: &WizardState, frame: &mut Frame, area: Rect) {
         }
     }
 
+    // Error message
+    if let Some(ref err) = state.error_message {
+        let error = Paragraph::new(format!(" Error: {err}")).style(theme::error_style());
+        frame.render_widget(error, chunks[4]);
+    }
+
     let help = Paragraph::new(" [Tab] Switch field  [Ctrl+D] Submit  [Enter] New line (prompt)  [Esc] Back")
         .style(theme::dim_style());
-    frame.render_widget(help, chunks[4]);
+    frame.render_widget(help, chunks[5]);
 }
 
 pub fn handle_key(state: &mut WizardState, key: KeyEvent) -> WizardTransition {
     // Ctrl+D to submit
     if key.code == KeyCode::Char('d') && key.modifiers.contains(KeyModifiers::CONTROL) {
-        if state.prompt_input.content.trim().is_empty() {
-            state.error_message = Some("Prompt cannot be empty".to_string());
-            return WizardTransition::Stay;
-        }
-        state.error_message = None;
-        state.screen = WizardScreen::Review;
-        return WizardTransition::Next(WizardScreen::Review);
+        return submit(state);
     }
 
     // Tab to switch between prompt and duration
@@ -141,13 +143,7 @@ pub fn handle_key(state: &mut WizardState, key: KeyEvent) -> WizardTransition {
     if state.target_field_index == FIELD_DURATION {
         // For duration field, Enter submits (same as Ctrl+D)
         if key.code == KeyCode::Enter {
-            if state.prompt_input.content.trim().is_empty() {
-                state.error_message = Some("Prompt cannot be empty".to_string());
-                return WizardTransition::Stay;
-            }
-            state.error_message = None;
-            state.screen = WizardScreen::Review;
-            return WizardTransition::Next(WizardScreen::Review);
+            return submit(state);
         }
         state.duration_input.handle_key(key);
     } else {
@@ -156,3 +152,23 @@ pub fn handle_key(state: &mut WizardState, key: KeyEvent) -> WizardTransition {
     }
     WizardTransition::Stay
 }
+
+fn submit(state: &mut WizardState) -> WizardTransition {
+    if state.prompt_input.content.trim().is_empty() {
+        state.error_message = Some("Prompt cannot be empty".to_string());
+        return WizardTransition::Stay;
+    }
+    let duration = state.duration_input.content.trim();
+    if !duration.is_empty() {
+        if let Err(e) = parse_duration(duration) {
+            state.error_message = Some(e.to_string());
+            state.target_field_index = FIELD_DURATION;
+            return WizardTransition::Stay;
+        }
+    }
+    state.error_message = None;
+    state.production_matches = state.find_production_targets();
+    state.production_acknowledged = false;
+    state.screen = WizardScreen::Review;
+    WizardTransition::Next(WizardScreen::Review)
+}
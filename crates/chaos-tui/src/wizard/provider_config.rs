@@ -145,8 +145,10 @@ pub fn handle_key(state: &mut WizardState, key: KeyEvent) -> WizardTransition {
     let has_env_key = env_api_key(&provider).is_some();
 
     let max_fields = match provider.as_str() {
-        "anthropic" => if has_env_key { 2 } else { 3 },  // skip api_key when from env
-        "openai" => if has_env_key { 3 } else { 4 },     // skip api_key when from env
+        "anthropic" if has_env_key => 2, // skip api_key when from env
+        "anthropic" => 3,
+        "openai" if has_env_key => 3,    // skip api_key when from env
+        "openai" => 4,
         "ollama" => 3,     // base_url, model, max_turns
         _ => 3,
     };
@@ -168,11 +170,11 @@ pub fn handle_key(state: &mut WizardState, key: KeyEvent) -> WizardTransition {
             // Validate — only require API key if not detected from env
             state.error_message = None;
             match provider.as_str() {
-                "anthropic" | "openai" => {
-                    if !has_env_key && state.api_key_input.content.is_empty() {
-                        state.error_message = Some("API key is required".to_string());
-                        return WizardTransition::Stay;
-                    }
+                "anthropic" | "openai"
+                    if !has_env_key && state.api_key_input.content.is_empty() =>
+                {
+                    state.error_message = Some("API key is required".to_string());
+                    return WizardTransition::Stay;
                 }
                 _ => {}
             }
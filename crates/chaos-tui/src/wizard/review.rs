@@ -55,7 +55,7 @@ pub fn render(state: &WizardState, frame: &mut Frame, area: Rect) {
         state.prompt_input.content.clone()
     };
 
-    let summary = vec![
+    let mut summary = vec![
         Line::from(vec![
             Span::styled("  Provider: ", Style::default().fg(Color::Cyan)),
             Span::styled(capitalize(provider), theme::normal_style()),
@@ -75,6 +75,17 @@ pub fn render(state: &WizardState, frame: &mut Frame, area: Rect) {
             Span::styled("  Duration: ", Style::default().fg(Color::Cyan)),
             Span::styled(duration, theme::normal_style()),
         ]),
+        Line::from(vec![
+            Span::styled("  Step-through:", Style::default().fg(Color::Cyan)),
+            Span::styled(
+                if state.step_through {
+                    " on, approve each tool call  [s] Turn off"
+                } else {
+                    " off  [s] Approve each tool call"
+                },
+                theme::normal_style(),
+            ),
+        ]),
         Line::from(""),
         Line::from(vec![
             Span::styled("  Prompt:", Style::default().fg(Color::Cyan)),
@@ -84,6 +95,21 @@ pub fn render(state: &WizardState, frame: &mut Frame, area: Rect) {
         ]),
     ];
 
+    let warn_style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+    if !state.production_matches.is_empty() {
+        summary.push(Line::from(""));
+        summary.push(Line::from(Span::styled(
+            "  !!! These targets look like PRODUCTION !!!",
+            warn_style,
+        )));
+        for m in &state.production_matches {
+            summary.push(Line::from(Span::styled(
+                format!("    - {} ({})", m.subject, m.reason),
+                Style::default().fg(Color::Red),
+            )));
+        }
+    }
+
     let block = Block::default()
         .title(" Configuration Summary ")
         .borders(Borders::ALL)
@@ -93,23 +119,43 @@ pub fn render(state: &WizardState, frame: &mut Frame, area: Rect) {
     frame.render_widget(paragraph, chunks[2]);
 
     // Confirm button
-    let confirm = Paragraph::new("  [Enter] Start Experiment    [Esc] Go Back")
-        .style(Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+    let (confirm_text, color, help_text) = if state.production_matches.is_empty() {
+        (
+            "  [Enter] Start Experiment    [Esc] Go Back",
+            Color::Green,
+            " Press Enter to start execution or Esc to go back",
+        )
+    } else {
+        (
+            "  [!] I understand, start anyway    [Esc] Go Back",
+            Color::Red,
+            " Enter is disabled for production-looking targets: press ! to confirm or Esc to go back",
+        )
+    };
+    let confirm = Paragraph::new(confirm_text)
+        .style(Style::default().fg(color).add_modifier(Modifier::BOLD))
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Green)),
+                .border_style(Style::default().fg(color)),
         );
     frame.render_widget(confirm, chunks[3]);
 
-    let help =
-        Paragraph::new(" Press Enter to start execution or Esc to go back").style(theme::dim_style());
+    let help = Paragraph::new(help_text).style(theme::dim_style());
     frame.render_widget(help, chunks[4]);
 }
 
-pub fn handle_key(_state: &mut WizardState, key: KeyEvent) -> WizardTransition {
+pub fn handle_key(state: &mut WizardState, key: KeyEvent) -> WizardTransition {
     match key.code {
-        KeyCode::Enter => WizardTransition::StartExecution,
+        KeyCode::Enter if state.production_matches.is_empty() => WizardTransition::StartExecution,
+        KeyCode::Char('!') if !state.production_matches.is_empty() => {
+            state.production_acknowledged = true;
+            WizardTransition::StartExecution
+        }
+        KeyCode::Char('s') => {
+            state.step_through = !state.step_through;
+            WizardTransition::Stay
+        }
         _ => WizardTransition::Stay,
     }
 }
@@ -2,6 +2,8 @@ use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
+use chaos_core::target_config::validate_target_config;
+
 use super::{WizardScreen, WizardState, WizardTransition};
 use crate::theme;
 
@@ -180,25 +182,11 @@ pub fn handle_key(state: &mut WizardState, key: KeyEvent) -> WizardTransition {
         }
         KeyCode::Enter => {
             state.error_message = None;
-            // Validate required fields
-            match target.as_str() {
-                "database" => {
-                    if state.db_url_input.content.is_empty() {
-                        state.error_message = Some("Connection URL is required".to_string());
-                        return WizardTransition::Stay;
-                    }
-                }
-                "server" => {
-                    if state.server_host_input.content.is_empty() {
-                        state.error_message = Some("Host is required".to_string());
-                        return WizardTransition::Stay;
-                    }
-                    if state.server_username_input.content.is_empty() {
-                        state.error_message = Some("Username is required".to_string());
-                        return WizardTransition::Stay;
-                    }
+            if let Some((domain, config)) = state.target_config() {
+                if let Err(e) = validate_target_config(domain, &config) {
+                    state.error_message = Some(e);
+                    return WizardTransition::Stay;
                 }
-                _ => {}
             }
             state.screen = WizardScreen::EnterPrompt;
             WizardTransition::Next(WizardScreen::EnterPrompt)